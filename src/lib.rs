@@ -5,7 +5,20 @@ pub struct QueryBuilder<'a> {
     pub table: String,
     pub qtype: QueryType,
     pub list: Vec<KeywordList>,
-    pub hq: Option<[&'a str; 26]>
+    pub hq: Option<[&'a str; 26]>,
+    pub dialect: Dialect,
+    pub targets: Vec<String>,
+    pub join_sources: Vec<String>,
+    pub join_conditions: Vec<String>,
+    pub orderings: Vec<String>,
+    pub row_limit: Option<i32>,
+    pub row_offset: Option<i32>,
+    pub into: Vec<String>,
+    clauses: Vec<(KeywordList, usize)>,
+    pub statements: Vec<String>,
+    pub hints: Vec<String>,
+    bindings: Vec<(usize, String)>,
+    binding_error: Option<String>
 }
 
 /// Implementations For QueryBuilder.
@@ -38,7 +51,20 @@ impl<'a> QueryBuilder<'a> {
                         table: "".to_string(),
                         qtype: QueryType::Select,
                         list: vec![KeywordList::Select],
-                        hq: Some(hq)
+                        hq: Some(hq),
+                        dialect: Dialect::Mysql,
+                        targets: vec![],
+                        join_sources: vec![],
+                        join_conditions: vec![],
+                        orderings: vec![],
+                        row_limit: None,
+                        row_offset: None,
+                        into: vec![],
+                        clauses: vec![],
+                        statements: vec![],
+                        hints: vec![],
+                        bindings: vec![],
+                        binding_error: None
                     })
                 } else {
                     let mut query = "SELECT ".to_string();
@@ -60,7 +86,20 @@ impl<'a> QueryBuilder<'a> {
                         table: "".to_string(),
                         qtype: QueryType::Select,
                         list: vec![KeywordList::Select],
-                        hq: Some(hq)
+                        hq: Some(hq),
+                        dialect: Dialect::Mysql,
+                        targets: vec![],
+                        join_sources: vec![],
+                        join_conditions: vec![],
+                        orderings: vec![],
+                        row_limit: None,
+                        row_offset: None,
+                        into: vec![],
+                        clauses: vec![],
+                        statements: vec![],
+                        hints: vec![],
+                        bindings: vec![],
+                        binding_error: None
                     })
                 }
             },
@@ -87,7 +126,20 @@ impl<'a> QueryBuilder<'a> {
             table: "".to_string(),
             qtype: QueryType::Delete,
             list: vec![KeywordList::Delete],
-            hq: None
+            hq: None,
+            dialect: Dialect::Mysql,
+            targets: vec![],
+            join_sources: vec![],
+            join_conditions: vec![],
+            orderings: vec![],
+            row_limit: None,
+            row_offset: None,
+            into: vec![],
+            clauses: vec![],
+            statements: vec![],
+            hints: vec![],
+            bindings: vec![],
+            binding_error: None
         })
     }
 
@@ -108,7 +160,20 @@ impl<'a> QueryBuilder<'a> {
             table: "".to_string(),
             qtype: QueryType::Update,
             list: vec![KeywordList::Update],
-            hq: None
+            hq: None,
+            dialect: Dialect::Mysql,
+            targets: vec![],
+            join_sources: vec![],
+            join_conditions: vec![],
+            orderings: vec![],
+            row_limit: None,
+            row_offset: None,
+            into: vec![],
+            clauses: vec![],
+            statements: vec![],
+            hints: vec![],
+            bindings: vec![],
+            binding_error: None
         })
     }
 
@@ -127,12 +192,138 @@ impl<'a> QueryBuilder<'a> {
     /// 
     /// ```
     pub fn insert(columns: Vec<&str>, values: Vec<ValueType>) -> std::result::Result<Self, std::io::Error> {
+        Self::insert_with("INSERT INTO", columns, values)
+    }
+
+    /// Insert ignore constructor. Use it if you want to build an Insert Query which skips the rows that cause duplicate key errors. It's written as `INSERT OR IGNORE INTO` on sqlite and `INSERT INTO ... ON CONFLICT DO NOTHING` on postgresql.
+    ///     
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){
+    ///     let fields = vec!["id", "name"];
+    ///     let values = vec![ValueType::Int32(5), ValueType::String("necdet".to_string())];
+    /// 
+    ///     let query = QueryBuilder::insert_ignore(fields, values).unwrap().table("users").finish();
+    /// 
+    ///     assert_eq!(query, "INSERT IGNORE INTO users (id, name) VALUES (5, 'necdet');");
+    /// }
+    /// 
+    /// ```
+    pub fn insert_ignore(columns: Vec<&str>, values: Vec<ValueType>) -> std::result::Result<Self, std::io::Error> {
+        Self::insert_with("INSERT IGNORE INTO", columns, values)
+    }
+
+    /// Replace constructor. Use it if you want to build a Replace Query, it deletes the old row and inserts the new one if there is a duplicate key. It's written as `INSERT OR REPLACE INTO` on sqlite, `.dialect()` method panics for it on postgresql.
+    ///     
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){
+    ///     let fields = vec!["id", "name"];
+    ///     let values = vec![ValueType::Int32(5), ValueType::String("necdet".to_string())];
+    /// 
+    ///     let query = QueryBuilder::replace(fields, values).unwrap().table("users").finish();
+    /// 
+    ///     assert_eq!(query, "REPLACE INTO users (id, name) VALUES (5, 'necdet');");
+    /// }
+    /// 
+    /// ```
+    pub fn replace(columns: Vec<&str>, values: Vec<ValueType>) -> std::result::Result<Self, std::io::Error> {
+        Self::insert_with("REPLACE INTO", columns, values)
+    }
+
+    /// Low priority insert constructor. Use it if you want to delay the insert until no other clients are reading from the table. It's mysql specific, `.dialect()` method panics for it on the other dialects.
+    ///     
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){
+    ///     let fields = vec!["id", "name"];
+    ///     let values = vec![ValueType::Int32(5), ValueType::String("necdet".to_string())];
+    /// 
+    ///     let query = QueryBuilder::insert_low_priority(fields, values).unwrap().table("logs").finish();
+    /// 
+    ///     assert_eq!(query, "INSERT LOW_PRIORITY INTO logs (id, name) VALUES (5, 'necdet');");
+    /// }
+    /// 
+    /// ```
+    pub fn insert_low_priority(columns: Vec<&str>, values: Vec<ValueType>) -> std::result::Result<Self, std::io::Error> {
+        Self::insert_with("INSERT LOW_PRIORITY INTO", columns, values)
+    }
+
+    /// Insert select constructor. Use it if you want to insert the rows that returned from a select query. You can pass an empty vector to the columns argument if the selected columns match the table's columns.
+    ///     
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){
+    ///     let mut select = QueryBuilder::select(vec!["id", "name"]).unwrap();
+    ///     select.table("users").where_("active", "=", ValueType::Boolean(false));
+    /// 
+    ///     let query = QueryBuilder::insert_select(vec!["id", "name"], &select).unwrap().table("archived_users").finish();
+    /// 
+    ///     assert_eq!(query, "INSERT INTO archived_users (id, name) SELECT id, name FROM users WHERE active = false;");
+    /// }
+    /// 
+    /// ```
+    pub fn insert_select(columns: Vec<&str>, select: &QueryBuilder) -> std::result::Result<Self, std::io::Error> {
+        match select.qtype {
+            QueryType::Select | QueryType::Count => (),
+            _ => panic!("you can only pass select queries to the select argument of insert_select constructor.")
+        }
+
+        let hq = Self::load_hqs();
+
+        match QueryBuilder::sanitize_columns(&columns, hq) {
+            Ok(_) => (),
+            Err(_) => {
+                return Err(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "query cannot build on insert_select constructor: because inserted arbitrary query on columns parameter."))
+            }
+        }
+
+        let query = match columns.len() {
+            0 => "INSERT INTO ".to_string(),
+            _ => format!("INSERT INTO ({}) ", columns.join(", "))
+        };
+
+        let mut insert = Self {
+            query,
+            table: "".to_string(),
+            qtype: QueryType::Insert,
+            list: vec![KeywordList::Insert],
+            hq: Some(hq),
+            dialect: Dialect::Mysql,
+            targets: vec![],
+            join_sources: vec![],
+            join_conditions: vec![],
+            orderings: vec![],
+            row_limit: None,
+            row_offset: None,
+            into: vec![],
+            clauses: vec![],
+            statements: select.statements.clone(),
+            hints: vec![],
+            bindings: vec![],
+            binding_error: None
+        };
+
+        insert.push_statement("", select, "");
+
+        Ok(insert)
+    }
+
+    fn insert_with(keyword: &str, columns: Vec<&str>, values: Vec<ValueType>) -> std::result::Result<Self, std::io::Error> {
         match values.len() {
             0 => panic!("you cannot pass an empty vector to the values argument"),
             _ => ()
         }
 
-        let mut query = "INSERT INTO".to_string();
+        let mut query = keyword.to_string();
 
         let hq = Self::load_hqs();
 
@@ -153,6 +344,7 @@ impl<'a> QueryBuilder<'a> {
 
         let mut columns_string = "(".to_string();
         let mut values_string = "(".to_string();
+        let mut bindings = vec![];
 
         for (i, column) in columns.into_iter().enumerate() {
             for (p, value) in values.iter().enumerate() {
@@ -164,25 +356,44 @@ impl<'a> QueryBuilder<'a> {
                             columns_string = format!("{}, {}", columns_string, column);
                         }
 
-                        if p == 0 {
-                            values_string = format!("{}{}", values_string, value);
-                        } else {
-                            values_string = format!("{}, {}", values_string, value);
+                        if p != 0 {
+                            values_string.push_str(", ");
                         }
+
+                        let (text, bound) = Self::bind_value(value);
+                        bindings.extend(bound.into_iter().map(|(offset, literal)| (values_string.len() + offset, literal)));
+                        values_string.push_str(&text);
                     },
                     false => continue
                 }
             }
         }
 
-        query = format!("{} {}) VALUES {})", query, columns_string, values_string);
+        query = format!("{} {}) VALUES ", query, columns_string);
+
+        let start = query.len();
+
+        query = format!("{}{})", query, values_string);
 
         return Ok(Self {
             query,
             table: "".to_string(),
             qtype: QueryType::Insert,
             list: vec![KeywordList::Insert],
-            hq: Some(hq)
+            hq: Some(hq),
+            dialect: Dialect::Mysql,
+            targets: vec![],
+            join_sources: vec![],
+            join_conditions: vec![],
+            orderings: vec![],
+            row_limit: None,
+            row_offset: None,
+            into: vec![],
+            clauses: vec![],
+            statements: vec![],
+            hints: vec![],
+            bindings: bindings.into_iter().map(|(offset, literal)| (start + offset, literal)).collect(),
+            binding_error: None
         })
     }
 
@@ -200,6 +411,8 @@ impl<'a> QueryBuilder<'a> {
     pub fn table(&mut self, table: &str) -> &mut Self {
         match self.qtype {
             QueryType::Select => {
+                self.mark_clause(KeywordList::Table);
+
                 self.query = format!("{} {}", self.query, table);
                 self.table = table.to_string();
             },
@@ -208,9 +421,9 @@ impl<'a> QueryBuilder<'a> {
                 self.table = table.to_string()
             },
             QueryType::Insert => {
-                let split_the_query = self.query.split(" INTO ").collect::<Vec<&str>>();
-    
-                self.query = format!("INSERT INTO {} {}", table, split_the_query[1]);
+                let index = self.query.find(" INTO ").unwrap() + " INTO ".len();
+
+                self.insert_into_query(index, &format!("{} ", table));
                 self.table = table.to_string();
             }
             QueryType::Update => {
@@ -255,10 +468,23 @@ impl<'a> QueryBuilder<'a> {
             table: "".to_string(),
             qtype: QueryType::Count,
             list: vec![KeywordList::Count],
-            hq: Some(Self::load_hqs())
+            hq: Some(Self::load_hqs()),
+            dialect: Dialect::Mysql,
+            targets: vec![],
+            join_sources: vec![],
+            join_conditions: vec![],
+            orderings: vec![],
+            row_limit: None,
+            row_offset: None,
+            into: vec![],
+            clauses: vec![],
+            statements: vec![],
+            hints: vec![],
+            bindings: vec![],
+            binding_error: None
         }
     }
-    /// add the "WHERE" keyword with it's synthax.
+    /// add the "WHERE" keyword with it's synthax. The value can be a `ValueType` or an `Expr` for comparing with another column or an expression.
     /// ```rust
     /// 
     /// use qubl::{QueryBuilder, ValueType};
@@ -270,7 +496,9 @@ impl<'a> QueryBuilder<'a> {
     /// }
     /// 
     /// ```
-    pub fn where_(&mut self, column: &str, mut mark: &str, value: ValueType) -> &mut Self {
+    pub fn where_<T: Into<Expr>>(&mut self, column: &str, mut mark: &str, value: T) -> &mut Self {
+        let value: Expr = value.into();
+
         match Self::sanitize_mark(mark) {
             Ok(_) => (),
             Err(error) => panic!("{}", error)
@@ -286,12 +514,12 @@ impl<'a> QueryBuilder<'a> {
             Err(error) => panic!("{}", error)
         }
 
-        match self.sanitize_input(&value) {
+        match self.sanitize_expr(&value) {
             Ok(_) => (),
             Err(error) => panic!("{}", error)
         }
 
-        if let ValueType::Null = value {
+        if let Expr::Value(ValueType::Null) = value {
             match mark {
                 "=" => mark = "IS",
                 "!=" | "<>" => mark = "IS NOT",
@@ -300,7 +528,11 @@ impl<'a> QueryBuilder<'a> {
             }
         }
 
-        self.query = format!("{} WHERE {} {} {}", self.query, column, mark, value);
+        self.mark_clause(KeywordList::Where);
+        self.query = format!("{} WHERE {} {} ", self.query, column, mark);
+
+        let (text, bindings) = self.bind_expr(&value);
+        self.push_bound(&text, bindings);
 
         self.list.push(KeywordList::Where);
 
@@ -325,18 +557,19 @@ impl<'a> QueryBuilder<'a> {
             _ => ()
         }
 
+        self.mark_clause(KeywordList::Where);
         self.query = format!("{} WHERE {} IN (", self.query, column);
 
         let length_of_ins = ins.len();
 
         for (index, value) in ins.into_iter().enumerate() {
-            if index + 1 == length_of_ins {
-                self.query = format!("{}{})", self.query, value);
-                    
-                continue;
-            }
+            let (text, bindings) = Self::bind_value(value);
+            self.push_bound(&text, bindings);
 
-            self.query = format!("{}{}, ", self.query, value);
+            match index + 1 == length_of_ins {
+                true => self.query.push(')'),
+                false => self.query.push_str(", ")
+            }
         }
 
         self.list.push(KeywordList::WhereIn);
@@ -361,18 +594,19 @@ impl<'a> QueryBuilder<'a> {
             _ => ()
         }
 
+        self.mark_clause(KeywordList::Where);
         self.query = format!("{} WHERE {} NOT IN (", self.query, column);
 
         let length_of_ins = ins.len();
 
         for (index, value) in ins.into_iter().enumerate() {
-            if index + 1 == length_of_ins {
-                self.query = format!("{}{})", self.query, value);
-                    
-                continue;
-            }
+            let (text, bindings) = Self::bind_value(value);
+            self.push_bound(&text, bindings);
 
-            self.query = format!("{}{}, ", self.query, value);
+            match index + 1 == length_of_ins {
+                true => self.query.push(')'),
+                false => self.query.push_str(", ")
+            }
         }
 
         self.list.push(KeywordList::WhereNotIn);
@@ -392,6 +626,7 @@ impl<'a> QueryBuilder<'a> {
     ///     assert_eq!(query, "SELECT * FROM users WHERE id IN (1, 5, 10);")
     /// }
     pub fn where_in_custom(&mut self, column: &str, query: &str) -> &mut Self {
+        self.mark_clause(KeywordList::Where);
         self.query = format!("{} WHERE {} IN ({})", self.query, column, query);
 
         self.list.push(KeywordList::WhereIn);
@@ -411,6 +646,7 @@ impl<'a> QueryBuilder<'a> {
     ///     assert_eq!(query, "SELECT * FROM users WHERE id NOT IN (1, 5, 10);")
     /// }
     pub fn where_not_in_custom(&mut self, column: &str, query: &str) -> &mut Self {
+        self.mark_clause(KeywordList::Where);
         self.query = format!("{} WHERE {} NOT IN ({})", self.query, column, query);
 
         self.list.push(KeywordList::WhereNotIn);
@@ -446,13 +682,13 @@ impl<'a> QueryBuilder<'a> {
         let length_of_ins = ins.len();
 
         for (index, value) in ins.into_iter().enumerate() {
-            if index + 1 == length_of_ins {
-                self.query = format!("{}{})", self.query, value);
-                    
-                continue;
-            }
+            let (text, bindings) = Self::bind_value(value);
+            self.push_bound(&text, bindings);
 
-            self.query = format!("{}{}, ", self.query, value);
+            match index + 1 == length_of_ins {
+                true => self.query.push(')'),
+                false => self.query.push_str(", ")
+            }
         }
 
         self.list.push(KeywordList::AndIn);
@@ -487,13 +723,13 @@ impl<'a> QueryBuilder<'a> {
         let length_of_ins = ins.len();
 
         for (index, value) in ins.into_iter().enumerate() {
-            if index + 1 == length_of_ins {
-                self.query = format!("{}{})", self.query, value);
-                    
-                continue;
-            }
+            let (text, bindings) = Self::bind_value(value);
+            self.push_bound(&text, bindings);
 
-            self.query = format!("{}{}, ", self.query, value);
+            match index + 1 == length_of_ins {
+                true => self.query.push(')'),
+                false => self.query.push_str(", ")
+            }
         }
 
         self.list.push(KeywordList::AndNotIn);
@@ -574,13 +810,13 @@ impl<'a> QueryBuilder<'a> {
         let length_of_ins = ins.len();
 
         for (index, value) in ins.into_iter().enumerate() {
-            if index + 1 == length_of_ins {
-                self.query = format!("{}{})", self.query, value);
-                    
-                continue;
-            }
+            let (text, bindings) = Self::bind_value(value);
+            self.push_bound(&text, bindings);
 
-            self.query = format!("{}{}, ", self.query, value);
+            match index + 1 == length_of_ins {
+                true => self.query.push(')'),
+                false => self.query.push_str(", ")
+            }
         }
 
         self.list.push(KeywordList::AndIn);
@@ -614,13 +850,13 @@ impl<'a> QueryBuilder<'a> {
         let length_of_ins = ins.len();
 
         for (index, value) in ins.into_iter().enumerate() {
-            if index + 1 == length_of_ins {
-                self.query = format!("{}{})", self.query, value);
-                    
-                continue;
-            }
+            let (text, bindings) = Self::bind_value(value);
+            self.push_bound(&text, bindings);
 
-            self.query = format!("{}{}, ", self.query, value);
+            match index + 1 == length_of_ins {
+                true => self.query.push(')'),
+                false => self.query.push_str(", ")
+            }
         }
 
         self.list.push(KeywordList::AndNotIn);
@@ -701,6 +937,10 @@ impl<'a> QueryBuilder<'a> {
         match self.list.last() {
             Some(keyword) => match keyword {
                 _ => {
+                    if let BracketType::Where = parenthesis_type {
+                        self.mark_clause(KeywordList::Where);
+                    }
+
                     self.query = format!("{} {} (", self.query, parenthesis_type);
                     
                     match parenthesis_type {
@@ -739,8 +979,10 @@ impl<'a> QueryBuilder<'a> {
     /// }
     /// 
     /// ```
-    pub fn open_parenthesis_with(&mut self, parenthesis_type: BracketType, column: &str, mut mark: &str, value: ValueType) -> &mut Self {
-        if let ValueType::Null = value {
+    pub fn open_parenthesis_with<T: Into<Expr>>(&mut self, parenthesis_type: BracketType, column: &str, mut mark: &str, value: T) -> &mut Self {
+        let value: Expr = value.into();
+
+        if let Expr::Value(ValueType::Null) = value {
             match mark {
                 "=" => mark = "IS",
                 "!=" | "<>" => mark = "IS NOT",
@@ -752,7 +994,14 @@ impl<'a> QueryBuilder<'a> {
         match self.list.last() {
             Some(keyword) => match keyword {
                 _ => {
-                    self.query = format!("{} {} ({} {} {}", self.query, parenthesis_type, column, mark, value);
+                    if let BracketType::Where = parenthesis_type {
+                        self.mark_clause(KeywordList::Where);
+                    }
+
+                    self.query = format!("{} {} ({} {} ", self.query, parenthesis_type, column, mark);
+
+                    let (text, bindings) = self.bind_expr(&value);
+                    self.push_bound(&text, bindings);
                     
                     match parenthesis_type {
                         BracketType::Where => self.list.push(KeywordList::LeftBracketWhere),
@@ -780,19 +1029,63 @@ impl<'a> QueryBuilder<'a> {
         self
     }
 
-    /// it benefits to set timezone when you make your query. It's very flexible, always put on very beginning of the query, you can use it later than any other method.
+    /// it benefits to set timezone when you make your query. It's very flexible, always put on very beginning of the query, you can use it later than any other method. The statement is built with `SetBuilder`, so it's same with `.set_variables()` method with `SessionVariable::TimeZone`.
     pub fn time_zone(&mut self, timezone: Timezone) -> &mut Self {
-        self.query = format!("SET time_zone = {}; {}", timezone, self.query);
+        self.statements.insert(0, SetBuilder::new().session(SessionVariable::TimeZone(timezone)).render());
 
-        self.list.insert(1, KeywordList::Timezone);
         self
     }
 
     /// it benefits to set global timezone when you make your query. It's very flexible, always put on very beginning of the query, you can use it later than any other method.
     pub fn global_time_zone(&mut self, timezone: Timezone) -> &mut Self {
-        self.query = format!("SET GLOBAL time_zone = {}; {}", timezone, self.query);
+        self.statements.insert(0, SetBuilder::new().global(SessionVariable::TimeZone(timezone)).render());
+
+        self
+    }
+
+    /// It puts the `SET` statement of a `SetBuilder` in front of the query, like `.time_zone()` method does.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, SetBuilder, SessionVariable, ValueType};
+    /// 
+    /// fn main(){
+    ///     let variables = SetBuilder::new().session(SessionVariable::ForeignKeyChecks(false)).clone();
+    /// 
+    ///     let query = QueryBuilder::delete().unwrap()
+    ///                              .table("users")
+    ///                              .where_("id", "=", ValueType::Int32(5))
+    ///                              .set_variables(&variables)
+    ///                              .finish();
+    /// 
+    ///     assert_eq!(query, "SET foreign_key_checks = 0; DELETE FROM users WHERE id = 5;")
+    /// }
+    /// 
+    /// ```
+    pub fn set_variables(&mut self, variables: &SetBuilder) -> &mut Self {
+        self.statements.insert(0, variables.render());
 
-        self.list.insert(1, KeywordList::GlobalTimezone);
+        self
+    }
+
+    /// It adds the `INTO` keyword which stores the selected values into the given user variables, such as `SELECT COUNT(*) FROM users INTO @total`. The names are written without the "@" character.
+    pub fn into_variables(&mut self, names: Vec<&str>) -> &mut Self {
+        match self.qtype {
+            QueryType::Select | QueryType::Count => (),
+            _ => panic!(".into_variables() method can only be used with select queries.")
+        }
+
+        if names.is_empty() {
+            panic!("you cannot pass an empty vector to the names argument.")
+        }
+
+        for name in names.iter() {
+            SetBuilder::check_name(name);
+        }
+
+        self.into.extend(names.iter().map(|name| format!("@{}", name)));
+
+        self.list.push(KeywordList::IntoVariables);
         self
     }
 
@@ -808,7 +1101,9 @@ impl<'a> QueryBuilder<'a> {
     /// 
     ///     assert_eq!(query, "SELECT * FROM users WHERE id = 10 OR name = 'necdet';")
     /// }
-   pub fn or(&mut self, column: &str, mut mark: &str, value: ValueType) -> &mut Self {
+   pub fn or<T: Into<Expr>>(&mut self, column: &str, mut mark: &str, value: T) -> &mut Self {
+        let value: Expr = value.into();
+
         match self.sanitize_column(column) {
             Ok(_) => (),
             Err(error) => panic!("{}", error)
@@ -819,12 +1114,12 @@ impl<'a> QueryBuilder<'a> {
             Err(error) => panic!("{}", error)
         }
 
-        match self.sanitize_input(&value) {
+        match self.sanitize_expr(&value) {
             Ok(_) => (),
             Err(error) => panic!("{}", error)
         }
 
-        if let ValueType::Null = value {
+        if let Expr::Value(ValueType::Null) = value {
             match mark {
                 "=" => mark = "IS",
                 "!=" | "<>" => mark = "IS NOT",
@@ -833,7 +1128,10 @@ impl<'a> QueryBuilder<'a> {
             }
         }
 
-        self.query = format!("{} OR {} {} {}", self.query, column, mark, value);
+        self.query = format!("{} OR {} {} ", self.query, column, mark);
+
+        let (text, bindings) = self.bind_expr(&value);
+        self.push_bound(&text, bindings);
 
 
         self.list.push(KeywordList::Or);
@@ -858,7 +1156,27 @@ impl<'a> QueryBuilder<'a> {
     /// }
     /// 
     /// ```
-    pub fn set(&mut self, column: &str, value: ValueType) -> &mut Self {
+    /// 
+    /// It also accepts an `Expr` to assign the result of an expression:
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, Expr};
+    /// 
+    /// fn main(){
+    ///     let query = QueryBuilder::update().unwrap()
+    ///                              .table("posts")
+    ///                              .set("views", Expr::col("views") + 1)
+    ///                              .where_("id", "=", ValueType::Int32(1))
+    ///                              .finish();
+    /// 
+    ///     assert_eq!(query, "UPDATE posts SET views = views + 1 WHERE id = 1;")
+    /// }
+    /// 
+    /// ```
+    pub fn set<T: Into<Expr>>(&mut self, column: &str, value: T) -> &mut Self {
+        let value: Expr = value.into();
+
         match self.hq {
             Some(_) => (),
             None => self.hq = Some(Self::load_hqs())
@@ -869,21 +1187,25 @@ impl<'a> QueryBuilder<'a> {
             Err(error) => panic!("{}", error)
         }
 
-        match self.sanitize_input(&value) {
+        match self.sanitize_expr(&value) {
             Ok(_) => (),
             Err(error) => panic!("{}", error)
         }
 
         match self.list.last() {
-            Some(keyword) => {
-                match keyword {
-                    KeywordList::Set => self.query = format!("{}, {} = {}", self.query, column, value),
-                    _ => self.query = format!("{} SET {} = {}", self.query, column, value)
+            Some(_) => match self.in_set_clause() {
+                true => self.query = format!("{}, {} = ", self.query, column),
+                false => {
+                    self.mark_clause(KeywordList::Set);
+                    self.query = format!("{} SET {} = ", self.query, column)
                 }
             },
             None => panic!("that's impossible to come here.")
         }
 
+        let (text, bindings) = self.bind_expr(&value);
+        self.push_bound(&text, bindings);
+
         self.list.push(KeywordList::Set);
 
         self
@@ -901,7 +1223,9 @@ impl<'a> QueryBuilder<'a> {
     /// 
     ///     assert_eq!(query, "SELECT * FROM users WHERE id = 10 AND name = 'necdet';")
     /// }
-    pub fn and(&mut self, column: &str, mut mark: &str, value: ValueType) -> &mut Self {
+    pub fn and<T: Into<Expr>>(&mut self, column: &str, mut mark: &str, value: T) -> &mut Self {
+        let value: Expr = value.into();
+
         match self.sanitize_column(column) {
             Ok(_) => (),
             Err(error) => panic!("{}", error)
@@ -912,12 +1236,12 @@ impl<'a> QueryBuilder<'a> {
             Err(error) => panic!("{}", error)
         }
 
-        match self.sanitize_input(&value) {
+        match self.sanitize_expr(&value) {
             Ok(_) => (),
             Err(error) => panic!("{}", error)
         }
 
-        if let ValueType::Null = value {
+        if let Expr::Value(ValueType::Null) = value {
             match mark {
                 "=" => mark = "IS",
                 "!=" | "<>" => mark = "IS NOT",
@@ -926,7 +1250,10 @@ impl<'a> QueryBuilder<'a> {
             }
         }
 
-        self.query = format!("{} AND {} {} {}", self.query, column, mark, value);
+        self.query = format!("{} AND {} {} ", self.query, column, mark);
+
+        let (text, bindings) = self.bind_expr(&value);
+        self.push_bound(&text, bindings);
 
         self.list.push(KeywordList::And);
 
@@ -946,7 +1273,9 @@ impl<'a> QueryBuilder<'a> {
     ///     assert_eq!(query, "SELECT * FROM users WHERE id = 10 LIMIT 5 OFFSET 0;")
     /// }
     pub fn offset(&mut self, offset: i32) -> &mut Self {
-        self.query = format!("{} OFFSET {}", self.query, offset);
+        self.check_order_and_limit("OFFSET");
+
+        self.row_offset = Some(offset);
 
         self.list.push(KeywordList::Offset);
 
@@ -966,496 +1295,532 @@ impl<'a> QueryBuilder<'a> {
     ///     assert_eq!(query, "SELECT * FROM users WHERE id = 10 LIMIT 5 OFFSET 0;")
     /// }
     pub fn limit(&mut self, limit: i32) -> &mut Self {
-        self.query = format!("{} LIMIT {}", self.query, limit);
+        self.check_order_and_limit("LIMIT");
+
+        self.row_limit = Some(limit);
 
         self.list.push(KeywordList::Limit);
 
         self
     }
 
-    /// It adds the "LIKE" keyword with it's synthax.
+    /// It adds the keyset (cursor) pagination synthax: a comparison of the cursor columns with the values of the last row of the previous page, the matching "ORDER BY" and the "LIMIT". Pass an empty vector to the last_values argument for the first page. It should be the last method you call before finishing the query.
+    /// 
+    /// Row value comparison, such as `(created_at, id) > ('2024-01-01', 15)`, is used on mysql and postgresql. On sqlite it uses the expanded form: `(created_at > '2024-01-01' OR (created_at = '2024-01-01' AND id > 15))`, since older sqlite versions don't support row values.
+    /// 
     /// ```rust
     /// 
     /// use qubl::{QueryBuilder, ValueType};
     /// 
     /// fn main(){
+    ///     let last_values = vec![ValueType::Datetime("2024-01-01 10:00:00".to_string()), ValueType::Int32(15)];
+    /// 
     ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
-    ///                              .table("blogs")
-    ///                              .like(vec!["description", "title"], "qubl is awesome!")
+    ///                              .table("posts")
+    ///                              .where_("published", "=", ValueType::Boolean(true))
+    ///                              .paginate_after(vec!["created_at", "id"], last_values, "DESC", 20)
     ///                              .finish();
     /// 
-    ///     assert_eq!(query, "SELECT * FROM blogs WHERE description LIKE '%qubl is awesome!%' OR title LIKE '%qubl is awesome!%';")
+    ///     assert_eq!(query, "SELECT * FROM posts WHERE (published = true) AND (created_at, id) < ('2024-01-01 10:00:00', 15) ORDER BY created_at DESC, id DESC LIMIT 20;")
     /// }
     /// 
-    /// // it has more niche and different usages, for them check the tests.
-    pub fn like(&mut self, columns: Vec<&str>, operand: &str) -> &mut Self {
-        match columns.len() {
-            0 => panic!("you cannot pass an empty vector to the columns"),
-            _ => ()
+    /// ```
+    pub fn paginate_after(&mut self, cursor_columns: Vec<&str>, last_values: Vec<ValueType>, direction: &str, page_size: i32) -> &mut Self {
+        if cursor_columns.is_empty() {
+            panic!("you cannot pass an empty vector to the cursor_columns argument")
         }
 
-        let hqs = match self.hq {
-            Some(hqs) => hqs,
-            None => {
-                let load_hqs = Self::load_hqs();
-                self.hq = Some(load_hqs);
+        if !last_values.is_empty() && last_values.len() != cursor_columns.len() {
+            panic!("the length of last_values argument should be same with the length of cursor_columns argument.")
+        }
 
-                load_hqs
-            }
-        };
+        if self.list.iter().any(|keyword| matches!(keyword, KeywordList::OrderBy | KeywordList::Field | KeywordList::Limit | KeywordList::Offset)) {
+            panic!("paginate_after method adds it's own ORDER BY and LIMIT keywords, you cannot use it with ordering or limiting methods.")
+        }
 
-        match Self::sanitize_columns(&columns, hqs) {
-            Ok(_) => {
-                match self.sanitize_str(operand){
-                    Ok(_) => (),
-                    Err(error) => {
-                        println!("That Error Occured in like method: {}", error);
-                
-                        self.list.push(KeywordList::Like);
-        
-                        return self
-                    }
-                }
-        
-                match self.list.last() {
-                    Some(keyword) => {
-                        if keyword == &KeywordList::Where || keyword == &KeywordList::WhereIn || keyword == &KeywordList::WhereNotIn {
-                            let length_of_columns = columns.len();
-        
-                            for (i, column) in columns.into_iter().enumerate() {
-                                match length_of_columns {
-                                    1 => {
-                                        if i == 0 {
-                                            self.query = format!("{} AND {} LIKE '%{}%'", self.query, column, operand)
-                                        }  
-                                    },
-                                    _ => {
-                                        if i == 0 {
-                                            self.query = format!("{} AND ({} LIKE '%{}%'", self.query, column, operand)
-                                        } else if i + 1 == length_of_columns {
-                                            self.query = format!("{} OR {} LIKE '%{}%')", self.query, column, operand)
-                                        } else {
-                                            self.query = format!("{} OR {} LIKE '%{}%'", self.query, column, operand)
-                                        }
-                                    }
-                                }
-                            }
-                        } else if keyword == &KeywordList::LeftBracketWhere || keyword == &KeywordList::LeftBracketAnd || keyword == &KeywordList::LeftBracketOr {
-                            for (i, column) in columns.into_iter().enumerate() {
-                                if i == 0 {
-                                    self.query = format!("{}{} LIKE '%{}%'", self.query, column, operand)
-                                } else {
-                                    self.query = format!("{}, AND {} LIKE '%{}%'", self.query, column, operand)
-                                }
-                            }
-                        } else {
-                            for (i, column) in columns.into_iter().enumerate() {
-                                if i == 0 {
-                                    self.query = format!("{} WHERE {} LIKE '%{}%'", self.query, column, operand);
-                                } else {
-                                    self.query = format!("{} OR {} LIKE '%{}%'", self.query, column, operand);
-                                }
-                            }
-                        }
-                    },
-                    None => panic!("Our current implementation does not support to use '.like()' later not other than WHERE, IN or NOT IN queries.")
-                }
+        match self.hq {
+            Some(_) => (),
+            None => self.hq = Some(Self::load_hqs())
+        }
 
-                return self
-            },
-            Err(error) => panic!("That error occured in '.like()' method: {}", error)
+        match Self::sanitize_columns(&cursor_columns, self.hq.unwrap()) {
+            Ok(_) => (),
+            Err(error) => panic!("{}", error)
         }
-    }
 
-    /// It adds the "ORDER BY" keyword with it's synthax. It only accepts "ASC", "DESC", "asc", "desc" values.
-    /// ```rust
-    /// 
-    /// use qubl::{QueryBuilder, ValueType};
-    /// 
-    /// fn main(){
-    ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
-    ///                              .table("users")
-    ///                              .where_("age", ">", ValueType::Int32(25))
-    ///                              .order_by("id", "ASC")
-    ///                              .limit(5)
-    ///                              .offset(0)
-    ///                              .finish();
-    /// 
-    ///     assert_eq!(query, "SELECT * FROM users WHERE age > 25 ORDER BY id ASC LIMIT 5 OFFSET 0;")
-    /// }
-    pub fn order_by(&mut self, column: &str, mut ordering: &str) -> &mut Self {
-        match self.sanitize_column(column) {
+        match Self::sanitize_inputs(&last_values, self.hq.unwrap()) {
             Ok(_) => (),
-            Err(error) => {
-                println!("{}", error);
+            Err(error) => panic!("{}", error)
+        }
 
-                self.list.push(KeywordList::OrderBy);
+        if last_values.iter().any(|value| matches!(value, ValueType::Null)) {
+            panic!("cursor values cannot be NULL, keyset pagination only works on non nullable columns.")
+        }
 
-                return self
+        let mark = match direction {
+            "ASC" | "asc" => ">",
+            "DESC" | "desc" => "<",
+            _ => panic!("Panicking in paginate_after method: There is no other direction options than ASC or DESC.")
+        };
+
+        if !last_values.is_empty() {
+            let mut condition = String::new();
+            let mut bindings = vec![];
+
+            let push_value = |condition: &mut String, bindings: &mut Vec<(usize, String)>, value: &ValueType| {
+                let (text, bound) = Self::bind_value(value);
+
+                bindings.extend(bound.into_iter().map(|(offset, literal)| (condition.len() + offset, literal)));
+                condition.push_str(&text);
+            };
+
+            match (cursor_columns.len(), &self.dialect) {
+                (1, _) => {
+                    condition = format!("{} {} ", cursor_columns[0], mark);
+                    push_value(&mut condition, &mut bindings, &last_values[0]);
+                },
+                (_, Dialect::Sqlite) => {
+                    condition.push('(');
+
+                    for (i, column) in cursor_columns.iter().enumerate() {
+                        if i > 0 {
+                            condition.push_str(" OR (");
+                        }
+
+                        for p in 0..i {
+                            condition.push_str(&format!("{} = ", cursor_columns[p]));
+                            push_value(&mut condition, &mut bindings, &last_values[p]);
+                            condition.push_str(" AND ");
+                        }
+
+                        condition.push_str(&format!("{} {} ", column, mark));
+                        push_value(&mut condition, &mut bindings, &last_values[i]);
+
+                        if i > 0 {
+                            condition.push(')');
+                        }
+                    }
+
+                    condition.push(')');
+                },
+                _ => {
+                    condition = format!("({}) {} (", cursor_columns.join(", "), mark);
+
+                    for (i, value) in last_values.iter().enumerate() {
+                        if i > 0 {
+                            condition.push_str(", ");
+                        }
+
+                        push_value(&mut condition, &mut bindings, value);
+                    }
+
+                    condition.push(')');
+                }
             }
-        }
 
-        match ordering {
-            "asc" => ordering = "ASC",
-            "desc" => ordering = "DESC",
-            "ASC" => ordering = "ASC",
-            "DESC" => ordering = "DESC",
-            &_ => panic!("Panicking in order_by method: There is no other ordering options than ASC or DESC.")
+            self.and_where(&condition, bindings);
         }
 
-        match self.list.last() {
-            Some(keyword) => match keyword {
-                KeywordList::OrderBy | KeywordList::Field => self.query = format!("{}, {} {}", self.query, column, ordering),
-                _ => self.query = format!("{} ORDER BY {} {}", self.query, column, ordering)
-            },
-            None => panic!("It's almost impossible you to come here.")
+        for column in cursor_columns {
+            self.order_by(column, direction);
         }
 
-        self.list.push(KeywordList::OrderBy);
-
-        self
+        self.limit(page_size)
     }
 
-    /// A practical method that adds a query for shuffling the lines.
+    /// It encodes the values of the last row of a page into an opaque cursor token, so you can give it to your clients and get the next page with it later. The token is only an encoding, it's not signed or encrypted, so sign it on your side if your clients shouldn't be able to change it.
+    /// 
     /// ```rust
     /// 
     /// use qubl::{QueryBuilder, ValueType};
     /// 
     /// fn main(){
+    ///     let token = QueryBuilder::encode_cursor(&vec![ValueType::Datetime("2024-01-01 10:00:00".to_string()), ValueType::Int32(15)]);
+    ///     let last_values = QueryBuilder::decode_cursor(&token).unwrap();
+    /// 
     ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
-    ///                              .table("users")
-    ///                              .where_("age", ">", ValueType::Int32(25))
-    ///                              .order_random()
-    ///                              .limit(5)
-    ///                              .offset(0)
+    ///                              .table("posts")
+    ///                              .paginate_after(vec!["created_at", "id"], last_values, "ASC", 20)
     ///                              .finish();
     /// 
-    ///     assert_eq!(query, "SELECT * FROM users WHERE age > 25 ORDER BY RAND() LIMIT 5 OFFSET 0;")
+    ///     assert_eq!(query, "SELECT * FROM posts WHERE (created_at, id) > ('2024-01-01 10:00:00', 15) ORDER BY created_at ASC, id ASC LIMIT 20;")
     /// }
-    pub fn order_random(&mut self) -> &mut Self {
-        if self.query.contains("ORDER BY") {
-            panic!("Error in order_random method: you cannot add ordering option twice on a query.");
+    /// 
+    /// ```
+    pub fn encode_cursor(last_values: &[ValueType]) -> String {
+        let mut raw = String::new();
+
+        for value in last_values.iter() {
+            let (tag, payload) = match value {
+                ValueType::String(val) => ("s", val.to_string()),
+                ValueType::Datetime(val) => ("d", val.to_string()),
+                ValueType::JsonString(val) => ("j", val.to_string()),
+                ValueType::Null => ("n", "".to_string()),
+                ValueType::Boolean(val) => ("b", val.to_string()),
+                ValueType::Int8(val) => ("i8", val.to_string()),
+                ValueType::Int16(val) => ("i16", val.to_string()),
+                ValueType::Int32(val) => ("i32", val.to_string()),
+                ValueType::Int64(val) => ("i64", val.to_string()),
+                ValueType::Int128(val) => ("i128", val.to_string()),
+                ValueType::Uint8(val) => ("u8", val.to_string()),
+                ValueType::Uint16(val) => ("u16", val.to_string()),
+                ValueType::Uint32(val) => ("u32", val.to_string()),
+                ValueType::Uint64(val) => ("u64", val.to_string()),
+                ValueType::Usize(val) => ("usize", val.to_string()),
+                ValueType::Float32(val) => ("f32", val.to_string()),
+                ValueType::Float64(val) => ("f64", val.to_string()),
+                ValueType::EpochTime(val) => ("e", val.to_string())
+            };
+
+            raw = format!("{}{}:{}:{}", raw, tag, payload.len(), payload);
         }
 
-        self.query = format!("{} ORDER BY RAND()", self.query);
-        self.list.push(KeywordList::OrderBy);
-
-        self
+        raw.bytes().map(|byte| format!("{:02x}", byte)).collect()
     }
 
-    /// Adds "FIELD()" function with it's synthax. It's used on ordering depending on strings.
-    /// ```rust
-    /// 
-    /// use qubl::{QueryBuilder, ValueType};
-    /// 
-    /// fn main(){
-    ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
-    ///                              .table("users")
-    ///                              .where_("age", ">", ValueType::Int32(25))
-    ///                              .order_by_field("role", vec!["admin", "member", "observer"])
-    ///                              .limit(5)
-    ///                              .offset(0)
-    ///                              .finish();
-    /// 
-    ///     assert_eq!(query, "SELECT * FROM users WHERE age > 25 ORDER BY FIELD(role, 'admin', 'member', 'observer') LIMIT 5 OFFSET 0;")
-    /// }
-    pub fn order_by_field(&mut self, column: &str, ordering: Vec<&str>) -> &mut Self {
-        match ordering.len() {
-            0 => panic!("you cannot pass an empty vector to the ordering argument"),
-            _ => ()
+    /// It decodes a cursor token which is created with `QueryBuilder::encode_cursor()` back to the values of the last row. It returns an error if the token is not a valid encoding, it cannot detect a token which is changed into another valid one.
+    pub fn decode_cursor(token: &str) -> std::result::Result<Vec<ValueType>, std::io::Error> {
+        let invalid = || std::io::Error::new(std::io::ErrorKind::InvalidInput, "cursor token is invalid.");
+
+        if token.len() % 2 == 1 || !token.is_ascii() {
+            return Err(invalid())
         }
 
-        match self.list.last() {
-            Some(keyword) => match keyword {
-                KeywordList::OrderBy => {
-                    let mut split_the_query = self.query.split(" ORDER BY ");
-                
-                    self.query = format!("{} ORDER BY {}, FIELD({}", split_the_query.nth(0).unwrap(), split_the_query.nth(0).unwrap(), column);
+        let mut bytes = vec![];
 
-                    for item in ordering {
-                        self.query = format!("{}, '{}'", self.query, item)
-                    }
+        for i in (0..token.len()).step_by(2) {
+            match u8::from_str_radix(&token[i..i + 2], 16) {
+                Ok(byte) => bytes.push(byte),
+                Err(_) => return Err(invalid())
+            }
+        }
 
-                    self.query = format!("{})", self.query);
-                },
-                KeywordList::Field => {
-                    self.query = format!("{}, FIELD({}", self.query, column);
+        let raw = match String::from_utf8(bytes) {
+            Ok(raw) => raw,
+            Err(_) => return Err(invalid())
+        };
 
-                    for item in ordering {
-                        self.query = format!("{}, '{}'", self.query, item)
-                    }
+        let mut values = vec![];
+        let mut rest = raw.as_str();
 
-                    self.query = format!("{})", self.query);
-                },
-                _ => {
-                    let mut new_part_of_query = format!("ORDER BY FIELD({}", column);
+        while !rest.is_empty() {
+            let (tag, after_tag) = rest.split_once(':').ok_or_else(invalid)?;
+            let (length, after_length) = after_tag.split_once(':').ok_or_else(invalid)?;
+            let length = length.parse::<usize>().map_err(|_| invalid())?;
 
-                    for item in ordering {
-                        new_part_of_query = format!("{}, '{}'", new_part_of_query, item)
-                    }
+            if after_length.len() < length || !after_length.is_char_boundary(length) {
+                return Err(invalid())
+            }
 
-                    self.query = format!("{} {})", self.query, new_part_of_query);
-                }
-            },
-            None => panic!("It's almost impossible you to come here.")
+            let (payload, after_payload) = after_length.split_at(length);
+
+            let value = match tag {
+                "s" => ValueType::String(payload.to_string()),
+                "d" => ValueType::Datetime(payload.to_string()),
+                "j" => ValueType::JsonString(payload.to_string()),
+                "n" => ValueType::Null,
+                "b" => ValueType::Boolean(payload.parse().map_err(|_| invalid())?),
+                "i8" => ValueType::Int8(payload.parse().map_err(|_| invalid())?),
+                "i16" => ValueType::Int16(payload.parse().map_err(|_| invalid())?),
+                "i32" => ValueType::Int32(payload.parse().map_err(|_| invalid())?),
+                "i64" => ValueType::Int64(payload.parse().map_err(|_| invalid())?),
+                "i128" => ValueType::Int128(payload.parse().map_err(|_| invalid())?),
+                "u8" => ValueType::Uint8(payload.parse().map_err(|_| invalid())?),
+                "u16" => ValueType::Uint16(payload.parse().map_err(|_| invalid())?),
+                "u32" => ValueType::Uint32(payload.parse().map_err(|_| invalid())?),
+                "u64" => ValueType::Uint64(payload.parse().map_err(|_| invalid())?),
+                "usize" => ValueType::Usize(payload.parse().map_err(|_| invalid())?),
+                "f32" => ValueType::Float32(payload.parse().map_err(|_| invalid())?),
+                "f64" => ValueType::Float64(payload.parse().map_err(|_| invalid())?),
+                "e" => ValueType::EpochTime(payload.parse().map_err(|_| invalid())?),
+                _ => return Err(invalid())
+            };
+
+            values.push(value);
+            rest = after_payload;
         }
 
-        self.list.push(KeywordList::Field);
-
-        self
+        Ok(values)
     }
 
-    /// It adds the "GROUP BY" keyword with it's Synthax.
-    pub fn group_by(&mut self, column: &str) -> &mut Self {
-        self.query = format!("{} GROUP BY {}", self.query, column);
-
-        self.list.push(KeywordList::GroupBy);
-
-        self
-    }
+    /// It adds a condition which is a boolean expression itself, such as a spatial predicate. It uses the "WHERE" keyword if the query doesn't have it yet, otherwise the existing conditions are wrapped in parenthesis and it's added with the "AND" keyword. Inside of a parenthesis which is just opened, it's put as the first condition of it, use `.and_predicate()` and `.or_predicate()` for the rest of the parenthesis.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, Expr, Geometry};
+    /// 
+    /// fn main(){
+    ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                              .table("zones")
+    ///                              .where_predicate(Expr::st_contains(Expr::col("area"), Geometry::Point(28.97, 41.01)))
+    ///                              .finish();
+    /// 
+    ///     assert_eq!(query, "SELECT * FROM zones WHERE ST_Contains(area, POINT(28.97, 41.01));")
+    /// }
+    /// 
+    /// ```
+    pub fn where_predicate<T: Into<Expr>>(&mut self, predicate: T) -> &mut Self {
+        let predicate = predicate.into();
 
-    pub fn having(&mut self, column: &str, mut mark: &str, value: ValueType) -> &mut Self {
-        match self.sanitize_column(column) {
-            Ok(_) => (),
-            Err(error) => panic!("{}", error)
+        if self.in_opened_parenthesis() {
+            return self.push_predicate("AND", KeywordList::And, predicate);
         }
 
-        match Self::sanitize_mark(mark) {
-            Ok(_) => (),
-            Err(error) => panic!("{}", error)
+        match self.hq {
+            Some(_) => (),
+            None => self.hq = Some(Self::load_hqs())
         }
 
-        match self.sanitize_input(&value) {
+        match self.sanitize_expr(&predicate) {
             Ok(_) => (),
             Err(error) => panic!("{}", error)
         }
 
-        if let ValueType::Null = value {
-            match mark {
-                "=" => mark = "IS",
-                "!=" | "<>" => mark = "IS NOT",
-                "IS" | "IS NOT" => (),
-                _ => mark = "IS"
-            }
-        }
-
-        self.query = format!("{} HAVING {} {} {}", self.query, column, mark, value);
-
-        self.list.push(KeywordList::Having);
+        let (condition, bindings) = self.bind_expr(&predicate);
+        self.and_where(&condition, bindings);
 
         self
     }
 
-    /// it adds the `INNER JOIN` keyword with it's synthax.
+    /// It adds a boolean expression as a condition with the "AND" keyword. Inside of a parenthesis which is just opened, the keyword is omitted.
+    /// 
     /// ```rust
     /// 
-    /// use qubl::{QueryBuilder, ValueType};
+    /// use qubl::{QueryBuilder, Expr, JsonSearchMode, ValueType};
     /// 
-    /// fn main(){ 
-    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
-    ///                             .table("students s")
-    ///                             .inner_join("grades g", "s.id", "=", "g.student_id")
-    ///                             .where_("id", "=", ValueType::Int32(10))
-    ///                             .finish();
-    ///
-    ///    assert_eq!(query, "SELECT * FROM students s INNER JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+    /// fn main(){
+    ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                              .table("users")
+    ///                              .where_("active", "=", true)
+    ///                              .and_predicate(Expr::json_contains_path(Expr::col("profile"), JsonSearchMode::One, vec!["phone", "email"]))
+    ///                              .finish();
+    /// 
+    ///     assert_eq!(query, "SELECT * FROM users WHERE active = true AND JSON_CONTAINS_PATH(profile, 'one', '$.phone', '$.email');")
     /// }
     /// 
     /// ```
-    pub fn inner_join(&mut self, table: &str, left: &str, mark: &str, right: &str) -> &mut Self {
-        self.query = format!("{} INNER JOIN {} ON {} {} {}", self.query, table, left, mark, right);
-        self.list.push(KeywordList::InnerJoin);
-        self
+    pub fn and_predicate<T: Into<Expr>>(&mut self, predicate: T) -> &mut Self {
+        self.push_predicate("AND", KeywordList::And, predicate.into())
     }
 
-     /// it adds the `LEFT JOIN` keyword with it's synthax.
+    /// It adds a boolean expression as a condition with the "OR" keyword. Inside of a parenthesis which is just opened, the keyword is omitted.
+    /// 
     /// ```rust
     /// 
-    /// use qubl::{QueryBuilder, ValueType};
+    /// use qubl::{QueryBuilder, Expr, JsonSearchMode, ValueType};
     /// 
-    /// fn main(){ 
-    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
-    ///                             .table("students s")
-    ///                             .left_join("grades g", "s.id", "=", "g.student_id")
-    ///                             .where_("id", "=", ValueType::Int32(10))
-    ///                             .finish();
-    ///
-    ///    assert_eq!(query, "SELECT * FROM students s LEFT JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+    /// fn main(){
+    ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                              .table("posts")
+    ///                              .where_("author_id", "=", 5)
+    ///                              .or_predicate(Expr::member_of(Expr::val(5), Expr::json_get(Expr::col("meta"), "editors")))
+    ///                              .finish();
+    /// 
+    ///     assert_eq!(query, "SELECT * FROM posts WHERE author_id = 5 OR 5 MEMBER OF(meta->'$.editors');")
     /// }
     /// 
     /// ```
-    pub fn left_join(&mut self, table: &str, left: &str, mark: &str, right: &str) -> &mut Self {
-        self.query = format!("{} LEFT JOIN {} ON {} {} {}", self.query, table, left, mark, right);
-        self.list.push(KeywordList::LeftJoin);
-        self
+    pub fn or_predicate<T: Into<Expr>>(&mut self, predicate: T) -> &mut Self {
+        self.push_predicate("OR", KeywordList::Or, predicate.into())
     }
 
-    /// it adds the `RIGHT JOIN` keyword with it's synthax.
+    /// It opens a parenthesis with a boolean expression as it's first condition, like `.open_parenthesis_with()` method does for the comparisons.
+    /// 
     /// ```rust
     /// 
-    /// use qubl::{QueryBuilder, ValueType};
+    /// use qubl::{QueryBuilder, Expr, BracketType, JsonSearchMode};
     /// 
-    /// fn main(){ 
-    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
-    ///                             .table("students s")
-    ///                             .right_join("grades g", "s.id", "=", "g.student_id")
-    ///                             .where_("id", "=", ValueType::Int32(10))
-    ///                             .finish();
-    ///
-    ///    assert_eq!(query, "SELECT * FROM students s RIGHT JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+    /// fn main(){
+    ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                              .table("users")
+    ///                              .where_("active", "=", true)
+    ///                              .open_parenthesis_predicate(BracketType::And, Expr::compare(Expr::json_get_text(Expr::col("profile"), "city"), "=", String::from("Izmir")))
+    ///                              .or_predicate(Expr::json_search(Expr::col("profile"), JsonSearchMode::One, String::from("%izmir%")).is_not_null())
+    ///                              .close_parenthesis()
+    ///                              .finish();
+    /// 
+    ///     assert_eq!(query, "SELECT * FROM users WHERE active = true AND (profile->>'$.city' = 'Izmir' OR JSON_SEARCH(profile, 'one', '%izmir%') IS NOT NULL);")
     /// }
     /// 
     /// ```
-    pub fn right_join(&mut self, table: &str, left: &str, mark: &str, right: &str) -> &mut Self {
-        self.query = format!("{} RIGHT JOIN {} ON {} {} {}", self.query, table, left, mark, right);
-        self.list.push(KeywordList::RightJoin);
-        self
+    pub fn open_parenthesis_predicate<T: Into<Expr>>(&mut self, parenthesis_type: BracketType, predicate: T) -> &mut Self {
+        let keyword = match parenthesis_type {
+            BracketType::Where => KeywordList::LeftBracketWhere,
+            BracketType::And => KeywordList::LeftBracketAnd,
+            BracketType::Or => KeywordList::LeftBracketOr
+        };
+
+        self.push_predicate(&format!("{} (", parenthesis_type), keyword, predicate.into())
     }
 
-    /// it adds the `CROSS JOIN` keyword with it's synthax.
-    /// ```rust
-    /// 
-    /// use qubl::{QueryBuilder, ValueType};
-    /// 
-    /// fn main(){ 
-    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
-    ///                             .table("students s")
-    ///                             .cross_join("grades g")
-    ///                             .where_("id", "=", ValueType::Int32(10))
-    ///                             .finish();
-    ///
-    ///    assert_eq!(query, "SELECT * FROM students s RIGHT JOIN grades g ON s.id = g.student_id WHERE id = 10;");
-    /// }
-    /// 
-    /// ```
-    pub fn cross_join(&mut self, table: &str) -> &mut Self {
-        self.query = format!("{} CROSS JOIN {}", self.query, table);
-        self.list.push(KeywordList::RightJoin);
+    fn push_predicate(&mut self, keyword: &str, list_keyword: KeywordList, predicate: Expr) -> &mut Self {
+        match self.hq {
+            Some(_) => (),
+            None => self.hq = Some(Self::load_hqs())
+        }
+
+        match self.sanitize_expr(&predicate) {
+            Ok(_) => (),
+            Err(error) => panic!("{}", error)
+        }
+
+        let in_opened_parenthesis = self.in_opened_parenthesis();
+
+        if !in_opened_parenthesis && keyword.starts_with("WHERE") {
+            self.mark_clause(KeywordList::Where);
+        }
+
+        match (in_opened_parenthesis, keyword.ends_with('(')) {
+            (true, _) => (),
+            (false, true) => self.query = format!("{} {}", self.query, keyword),
+            (false, false) => self.query = format!("{} {} ", self.query, keyword)
+        }
+
+        let (text, bindings) = self.bind_expr(&predicate);
+        self.push_bound(&text, bindings);
+
+        self.list.push(list_keyword);
+
         self
     }
 
-    /// it adds the `NATURAL JOIN` keyword with it's synthax.
+    fn in_opened_parenthesis(&self) -> bool {
+        self.query.ends_with('(') && matches!(self.list.last(), Some(KeywordList::LeftBracketWhere) | Some(KeywordList::LeftBracketAnd) | Some(KeywordList::LeftBracketOr))
+    }
+
+    /// It adds a condition that filters the rows which their point column is in the given radius (in meters) of the given latitude & longitude, with `ST_Distance_Sphere()` function. It's added like the `.where_predicate()` method does.
+    /// 
     /// ```rust
     /// 
-    /// use qubl::{QueryBuilder, ValueType};
+    /// use qubl::QueryBuilder;
     /// 
-    /// fn main(){ 
-    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
-    ///                             .table("students s")
-    ///                             .natural_join("grades g")
-    ///                             .where_("id", "=", ValueType::Int32(10))
-    ///                             .finish();
-    ///
-    ///    assert_eq!(query, "SELECT * FROM students s RIGHT JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+    /// fn main(){
+    ///     let query = QueryBuilder::select(vec!["id", "name"]).unwrap()
+    ///                              .table("stores")
+    ///                              .within_radius("location", 41.01, 28.97, 5000.0)
+    ///                              .finish();
+    /// 
+    ///     assert_eq!(query, "SELECT id, name FROM stores WHERE ST_Distance_Sphere(location, POINT(28.97, 41.01)) <= 5000;")
     /// }
     /// 
     /// ```
-    pub fn natural_join(&mut self, table: &str) -> &mut Self {
-        self.query = format!("{} NATURAL JOIN {}", self.query, table);
-        self.list.push(KeywordList::RightJoin);
-        self
-    }
+    pub fn within_radius(&mut self, column: &str, latitude: f64, longitude: f64, meters: f64) -> &mut Self {
+        let center = match self.dialect {
+            Dialect::Postgresql => Expr::geometry(Geometry::Wkt(format!("POINT({} {})", longitude, latitude)), Some(4326)),
+            _ => Expr::geometry(Geometry::Point(longitude, latitude), None)
+        };
 
+        let distance = Expr::st_distance_sphere(Expr::col(column), center);
 
-    /// it adds the `UNION` keyword and its synthax. You can pass multiple queries to union with:
-    /// 
+        self.where_predicate(Expr::Binary(Box::new(distance), "<=".to_string(), Box::new(Expr::val(meters))))
+    }
+
+    /// It adds the "LIKE" keyword with it's synthax.
     /// ```rust
     /// 
     /// use qubl::{QueryBuilder, ValueType};
     /// 
     /// fn main(){
-    ///     let mut union_1 = QueryBuilder::select(vec!["name", "age", "id"]).unwrap();
-    ///     union_1.table("users").where_("age", ">", ValueType::Int32(7));
-    ///
-    ///     let union_2 = QueryBuilder::select(vec!["name", "age", "id"]).unwrap()
-    ///                                .table("users")
-    ///                                .where_("age", "<", ValueType::Int32(15))
-    ///                                .union(vec![union_1])
-    ///                                .finish();
-    ///
-    ///     assert_eq!(union_2, "(SELECT name, age, id FROM users WHERE age < 15) UNION (SELECT name, age, id FROM users WHERE age > 7);");
+    ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                              .table("blogs")
+    ///                              .like(vec!["description", "title"], "qubl is awesome!")
+    ///                              .finish();
+    /// 
+    ///     assert_eq!(query, "SELECT * FROM blogs WHERE description LIKE '%qubl is awesome!%' OR title LIKE '%qubl is awesome!%';")
     /// }
     /// 
-    /// ```
-    pub fn union(&mut self, others: Vec<QueryBuilder<'_>>) -> &mut Self {
-        match self.list.last() {
-            Some(keyword) => {
-                match keyword {
-                    KeywordList::Union | KeywordList::UnionAll => {
-                        for other in others {
-                            self.query = format!("{} UNION ({})", self.query, other.query)
-                        }
-                    },
-                    _ => {
-                        self.query = format!("({})", self.query);
-                        
-                        for other in others {
-                            self.query = format!("{} UNION ({})", self.query, other.query)
-                        }
-                    }
-                }
-            },
-            None => panic!("it's impossible to came here!")
+    /// // it has more niche and different usages, for them check the tests.
+    pub fn like(&mut self, columns: Vec<&str>, operand: &str) -> &mut Self {
+        match columns.len() {
+            0 => panic!("you cannot pass an empty vector to the columns"),
+            _ => ()
         }
 
-        self.list.push(KeywordList::Union);
+        let hqs = match self.hq {
+            Some(hqs) => hqs,
+            None => {
+                let load_hqs = Self::load_hqs();
+                self.hq = Some(load_hqs);
 
-        self
-    }
+                load_hqs
+            }
+        };
 
+        match Self::sanitize_columns(&columns, hqs) {
+            Ok(_) => {
+                match self.sanitize_str(operand){
+                    Ok(_) => (),
+                    Err(error) => {
+                        println!("That Error Occured in like method: {}", error);
+                
+                        self.list.push(KeywordList::Like);
+        
+                        return self
+                    }
+                }
+        
+                match self.list.last() {
+                    Some(keyword) => {
+                        if keyword == &KeywordList::Where || keyword == &KeywordList::WhereIn || keyword == &KeywordList::WhereNotIn {
+                            let length_of_columns = columns.len();
+        
+                            for (i, column) in columns.into_iter().enumerate() {
+                                match length_of_columns {
+                                    1 => {
+                                        if i == 0 {
+                                            self.query = format!("{} AND {} LIKE ", self.query, column);
+                                            self.push_like(operand);
+                                        }  
+                                    },
+                                    _ => {
+                                        if i == 0 {
+                                            self.query = format!("{} AND ({} LIKE ", self.query, column);
+                                            self.push_like(operand);
+                                        } else if i + 1 == length_of_columns {
+                                            self.query = format!("{} OR {} LIKE ", self.query, column);
+                                            self.push_like(operand);
+                                            self.query.push(')');
+                                        } else {
+                                            self.query = format!("{} OR {} LIKE ", self.query, column);
+                                            self.push_like(operand);
+                                        }
+                                    }
+                                }
+                            }
+                        } else if keyword == &KeywordList::LeftBracketWhere || keyword == &KeywordList::LeftBracketAnd || keyword == &KeywordList::LeftBracketOr {
+                            for (i, column) in columns.into_iter().enumerate() {
+                                if i == 0 {
+                                    self.query = format!("{}{} LIKE ", self.query, column);
+                                    self.push_like(operand);
+                                } else {
+                                    self.query = format!("{}, AND {} LIKE ", self.query, column);
+                                    self.push_like(operand);
+                                }
+                            }
+                        } else {
+                            for (i, column) in columns.into_iter().enumerate() {
+                                if i == 0 {
+                                    self.mark_clause(KeywordList::Where);
 
-    /// it adds the `UNION` keyword and its synthax. You can pass multiple queries to union with:
-    /// 
-    /// ```rust
-    /// 
-    /// use qubl::{QueryBuilder, ValueType};
-    /// 
-    /// fn main(){
-    ///     let mut union_1 = QueryBuilder::select(vec!["id", "title", "description", "published"]).unwrap();
-    ///     union_1.table("blogs").like(vec!["title"], "text");
-    ///
-    ///     let mut union_2 = QueryBuilder::select(vec!["id", "title", "description", "published"]).unwrap();
-    ///     union_2.table("blogs").like(vec!["description"], "some text");
-    ///
-    ///     let union_3 = QueryBuilder::select(vec!["id", "title", "description", "published"]).unwrap()
-    ///                                .table("blogs")
-    ///                                .where_("published", "=", ValueType::Boolean(true))
-    ///                                .union_all(vec![union_1, union_2])
-    ///                                .finish();
-    ///
-    ///     assert_eq!(union_3, "(SELECT id, title, description, published FROM blogs WHERE published = true) UNION ALL (SELECT id, title, description, published FROM blogs WHERE title LIKE '%text%') UNION ALL (SELECT id, title, description, published FROM blogs WHERE description LIKE '%some text%');");
-    /// }
-    /// 
-    /// ```
-    /// 
-    pub fn union_all(&mut self, others: Vec<QueryBuilder<'_>>) -> &mut Self {
-        match self.list.last() {
-            Some(keyword) => {
-                match keyword {
-                    KeywordList::Union | KeywordList::UnionAll => {
-                        for other in others {
-                            self.query = format!("{} UNION ALL ({})", self.query, other.query)
+                                    self.query = format!("{} WHERE {} LIKE ", self.query, column);
+                                    self.push_like(operand);
+                                } else {
+                                    self.query = format!("{} OR {} LIKE ", self.query, column);
+                                    self.push_like(operand);
+                                }
+                            }
                         }
                     },
-                    _ => {
-                        self.query = format!("({})", self.query);
-                        
-                        for other in others {
-                            self.query = format!("{} UNION ALL ({})", self.query, other.query)
-                        }
-                    }
+                    None => panic!("Our current implementation does not support to use '.like()' later not other than WHERE, IN or NOT IN queries.")
                 }
+
+                return self
             },
-            None => panic!("it's impossible to came here!")
+            Err(error) => panic!("That error occured in '.like()' method: {}", error)
         }
-
-        self.list.push(KeywordList::UnionAll);
-
-        self
     }
 
-    /// A wildcard method that gives you the chance to write a part of your query. Warning, it does not add any keyword to builder, i'll encourage to add proper keyword to it with `.append_keyword()` method for your custom query, otherwise you should continue building your query by yourself with that function, or you've to be prepared to encounter bugs.  
-    /// 
+    /// It adds the "ORDER BY" keyword with it's synthax. It only accepts "ASC", "DESC", "asc", "desc" values.
     /// ```rust
     /// 
     /// use qubl::{QueryBuilder, ValueType};
@@ -1463,796 +1828,1000 @@ impl<'a> QueryBuilder<'a> {
     /// fn main(){
     ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
     ///                              .table("users")
-    ///                              .append_custom("WHERE age > 25 ORDER BY FIELD(role, 'admin', 'member', 'observer') LIMIT 5 OFFSET 0")
+    ///                              .where_("age", ">", ValueType::Int32(25))
+    ///                              .order_by("id", "ASC")
+    ///                              .limit(5)
+    ///                              .offset(0)
     ///                              .finish();
     /// 
-    ///     assert_eq!(query, "SELECT * FROM users WHERE age > 25 ORDER BY FIELD(role, 'admin', 'member', 'observer') LIMIT 5 OFFSET 0;")
+    ///     assert_eq!(query, "SELECT * FROM users WHERE age > 25 ORDER BY id ASC LIMIT 5 OFFSET 0;")
     /// }
-    /// ```
-    /// 
-    pub fn append_custom(&mut self, query: &str) -> &mut Self {
-        self.query = format!("{} {}", self.query, query);
+    pub fn order_by(&mut self, column: &str, mut ordering: &str) -> &mut Self {
+        match self.sanitize_column(column) {
+            Ok(_) => (),
+            Err(error) => {
+                println!("{}", error);
+
+                self.list.push(KeywordList::OrderBy);
+
+                return self
+            }
+        }
+
+        match ordering {
+            "asc" => ordering = "ASC",
+            "desc" => ordering = "DESC",
+            "ASC" => ordering = "ASC",
+            "DESC" => ordering = "DESC",
+            &_ => panic!("Panicking in order_by method: There is no other ordering options than ASC or DESC.")
+        }
+
+        self.check_order_and_limit("ORDER BY");
+
+        self.orderings.push(format!("{} {}", column, ordering));
+
+        self.list.push(KeywordList::OrderBy);
 
         self
     }
 
-    /// A wildcard method that benefits you to append a keyword to the keyword list, so the QueryBuilder can build your queries properly, later than you appended your custom string to your query. It should be used with `.append_custom()` method. 
-    /// 
+    /// A practical method that adds a query for shuffling the lines.
     /// ```rust
     /// 
-    /// use qubl::{QueryBuilder, ValueType, KeywordList};
+    /// use qubl::{QueryBuilder, ValueType};
     /// 
     /// fn main(){
     ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
     ///                              .table("users")
-    ///                              .append_custom("WHERE age > 25 ORDER BY FIELD(role, 'admin', 'member', 'observer') LIMIT 5 OFFSET 0")
-    ///                              .append_keyword(KeywordList::Where)
-    ///                              .append_keyword(KeywordList::Field)
-    ///                              .append_keyword(KeywordList::Limit)
-    ///                              .append_keyword(KeywordList::Offset)
+    ///                              .where_("age", ">", ValueType::Int32(25))
+    ///                              .order_random()
+    ///                              .limit(5)
+    ///                              .offset(0)
     ///                              .finish();
     /// 
-    ///     assert_eq!(query, "SELECT * FROM users WHERE age > 25 ORDER BY FIELD(role, 'admin', 'member', 'observer') LIMIT 5 OFFSET 0;")
+    ///     assert_eq!(query, "SELECT * FROM users WHERE age > 25 ORDER BY RAND() LIMIT 5 OFFSET 0;")
     /// }
-    /// ```
-    /// 
-    pub fn append_keyword(&mut self, keyword: KeywordList) -> &mut Self {
-        self.list.push(keyword);
+    pub fn order_random(&mut self) -> &mut Self {
+        if !self.orderings.is_empty() {
+            panic!("Error in order_random method: you cannot add ordering option twice on a query.");
+        }
+
+        self.check_order_and_limit("ORDER BY");
+
+        self.orderings.push("RAND()".to_string());
+        self.list.push(KeywordList::OrderBy);
 
         self
     }
-    
-    /// It applies "JSON_EXTRACT()" mysql function with it's Synthax. If you encounter any syntactic bugs or deficiencies about that function, please report it via opening an issue.
-    /// 
+
+    /// Adds "FIELD()" function with it's synthax. It's used on ordering depending on strings.
     /// ```rust
     /// 
     /// use qubl::{QueryBuilder, ValueType};
     /// 
     /// fn main(){
-    ///     let query = QueryBuilder::select(["*"].to_vec()).unwrap()
-    ///                              .json_extract("articles", "[0]", Some("blog1"))
-    ///                              .json_extract("articles", "[1]", Some("blog2"))
-    ///                              .json_extract("articles", "[2]", Some("blog3"))
+    ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
     ///                              .table("users")
-    ///                              .where_("published", "=", ValueType::Int32(1))
+    ///                              .where_("age", ">", ValueType::Int32(25))
+    ///                              .order_by_field("role", vec!["admin", "member", "observer"])
+    ///                              .limit(5)
+    ///                              .offset(0)
     ///                              .finish();
     /// 
-    ///     assert_eq!(query, "SELECT JSON_EXTRACT(articles, '$[0]') AS blog1, JSON_EXTRACT(articles, '$[1]') AS blog2, JSON_EXTRACT(articles, '$[2]') AS blog3 FROM users WHERE published = 1;")
+    ///     assert_eq!(query, "SELECT * FROM users WHERE age > 25 ORDER BY FIELD(role, 'admin', 'member', 'observer') LIMIT 5 OFFSET 0;")
     /// }
-    /// 
-    /// ```
-    pub fn json_extract(&mut self, haystack: &str, needle: &str, _as: Option<&str>) -> &mut Self {
-        match self.list.last() {
-            Some(keyword) => {
-                match keyword {
-                    KeywordList::LeftBracketWhere | KeywordList::LeftBracketAnd | KeywordList::LeftBracketOr => {
-                        panic!("you cannot use .json_extract() method with bracket methods for now, this will be implemented on future updates.")
-                    },
-                    KeywordList::Where => {
-                        if _as.is_some() {
-                            println!("Warning: You've gave _as value to some variant and used it later than 'WHERE' keyword on .json_extract() method. In that usage, that value has no effect, you should gave it none value.");
-                        }
+    pub fn order_by_field(&mut self, column: &str, ordering: Vec<&str>) -> &mut Self {
+        match ordering.len() {
+            0 => panic!("you cannot pass an empty vector to the ordering argument"),
+            _ => ()
+        }
 
-                        match self.table.as_str() == haystack {
-                            true => {
-                                let mut split_the_query = self.query.split(haystack);
-                                let string_for_replace = format!("JSON_EXTRACT({}, '${}')", haystack, needle);
+        self.check_order_and_limit("ORDER BY");
 
-                                self.query = format!("SELECT{}{}{}", self.table, string_for_replace, split_the_query.nth(2).unwrap()) 
-                            },
-                            false => {
-                                let string_for_replace = format!("JSON_EXTRACT({}, '${}')", haystack, needle);
+        let mut field = format!("FIELD({}", column);
 
-                                self.query = self.query.replace(haystack,&string_for_replace)
-                            }
-                        }
-                    },
-                    KeywordList::And => {
-                        if _as.is_some() {
-                            println!("Warning: You've gave _as value to some variant and used it later than 'AND' keyword on .json_extract() method. In that usage, that value has no effect, you should gave it none value.");
-                        }
+        for item in ordering {
+            field = format!("{}, '{}'", field, item)
+        }
 
-                        let query_to_comp = format!("AND {}", haystack);
+        self.orderings.push(format!("{})", field));
 
-                        match self.table.as_str() == haystack {
-                            true => {
-                                let mut split_the_query = self.query.split(&query_to_comp);
-                                let string_for_replace = format!("JSON_EXTRACT({}, '${}')", haystack, needle);
+        self.list.push(KeywordList::Field);
 
-                                self.query = format!("{}AND {}{}", split_the_query.nth(0).unwrap(), string_for_replace, split_the_query.nth(0).unwrap()) 
-                            },
-                            false => {
-                                match self.query.matches(&query_to_comp).count() {
-                                    0 => (),
-                                    1 => {
-                                        let string_for_replace = format!("AND JSON_EXTRACT({}, '${}')", haystack, needle);
+        self
+    }
 
-                                        self.query = self.query.replace(&query_to_comp,&string_for_replace)
-                                    }
-                                    _ => {
-                                        let split_the_query = self.query.split(&query_to_comp).collect::<Vec<&str>>();
+    /// It adds the "GROUP BY" keyword with it's Synthax.
+    pub fn group_by(&mut self, column: &str) -> &mut Self {
+        self.mark_clause(KeywordList::GroupBy);
 
-                                        let mut last_chunk = "".to_string();
-                                        let mut new_chunk = "".to_string();
-                                        let length_of_split = split_the_query.len();
-                                        
-                                        for (index, chunk) in split_the_query.into_iter().enumerate() {
-                                            if index + 1 == length_of_split {
-                                                last_chunk = chunk.to_string()
-                                            } else if index == 0 {
-                                                new_chunk = format!("{}", chunk);
-                                            } else {
-                                                new_chunk = format!("{}{}{}", new_chunk, query_to_comp, chunk)
-                                            }
-                                        }
+        self.query = format!("{} GROUP BY {}", self.query, column);
 
-                                        let string_for_replace = format!("AND JSON_EXTRACT({}, '${}')", haystack, needle);
+        self.list.push(KeywordList::GroupBy);
 
-                                        self.query = format!("{} {} {}", new_chunk, string_for_replace, last_chunk)
-                                    }
-                                }
-                            }
-                        }
-                    },
-                    KeywordList::Or => {
-                        if _as.is_some() {
-                            println!("Warning: You've gave _as value to some variant and used it later than 'OR' keyword on .json_extract() method. In that usage, that value has no effect, you should gave it none value.");
-                        }
+        self
+    }
 
-                        let query_to_comp = format!("OR {}", haystack);
+    pub fn having<T: Into<Expr>>(&mut self, column: &str, mut mark: &str, value: T) -> &mut Self {
+        let value: Expr = value.into();
 
-                        match self.table.as_str() == haystack {
-                            true => {
-                                let mut split_the_query = self.query.split(&query_to_comp);
-                                let string_for_replace = format!("JSON_EXTRACT({}, '${}')", haystack, needle);
+        match self.sanitize_column(column) {
+            Ok(_) => (),
+            Err(error) => panic!("{}", error)
+        }
 
-                                self.query = format!("{}OR {}{}", split_the_query.nth(0).unwrap(), string_for_replace, split_the_query.nth(0).unwrap()) 
-                            },
-                            false => {
-                                match self.query.matches(&query_to_comp).count() {
-                                    0 => (),
-                                    1 => {
-                                        let string_for_replace = format!("OR JSON_EXTRACT({}, '${}')", haystack, needle);
+        match Self::sanitize_mark(mark) {
+            Ok(_) => (),
+            Err(error) => panic!("{}", error)
+        }
 
-                                        self.query = self.query.replace(&query_to_comp,&string_for_replace)
-                                    }
-                                    _ => {
-                                        let split_the_query = self.query.split(&query_to_comp).collect::<Vec<&str>>();
+        match self.sanitize_expr(&value) {
+            Ok(_) => (),
+            Err(error) => panic!("{}", error)
+        }
 
-                                        let mut last_chunk = "".to_string();
-                                        let mut new_chunk = "".to_string();
-                                        let length_of_split = split_the_query.len();
-                                        
-                                        for (index, chunk) in split_the_query.into_iter().enumerate() {
-                                            if index + 1 == length_of_split {
-                                                last_chunk = chunk.to_string()
-                                            } else if index == 0 {
-                                                new_chunk = format!("{}", chunk);
-                                            } else {
-                                                new_chunk = format!("{}{}{}", new_chunk, query_to_comp, chunk)
-                                            }
-                                        }
+        if let Expr::Value(ValueType::Null) = value {
+            match mark {
+                "=" => mark = "IS",
+                "!=" | "<>" => mark = "IS NOT",
+                "IS" | "IS NOT" => (),
+                _ => mark = "IS"
+            }
+        }
 
-                                        let string_for_replace = format!("OR JSON_EXTRACT({}, '${}')", haystack, needle);
+        self.mark_clause(KeywordList::Having);
 
-                                        self.query = format!("{} {} {}", new_chunk, string_for_replace, last_chunk)
-                                    }
-                                }
-                            }
-                        }
-                    },
-                    KeywordList::Select => {
-                        let string_for_put = format!("JSON_EXTRACT({}, '${}')", haystack, needle);
+        self.query = format!("{} HAVING {} {} ", self.query, column, mark);
 
-                        match _as {
-                            Some(_as) => self.query = format!("SELECT {} AS {} FROM", string_for_put, _as),
-                            None => self.query = format!("SELECT {} FROM", string_for_put),
-                        }
-                    },
-                    KeywordList::Table => {
-                        let string_for_put = format!("JSON_EXTRACT({}, '${}')", haystack, needle);
+        let (text, bindings) = self.bind_expr(&value);
+        self.push_bound(&text, bindings);
 
-                        match _as {
-                            Some(_as) => self.query = format!("SELECT {} AS {} FROM {}", string_for_put, _as, self.table),
-                            None => self.query = format!("SELECT {} FROM {}", string_for_put, self.table),
-                        }
-                    },
-                    KeywordList::OrderBy => {
-                        if _as.is_some() {
-                            println!("Warning: You've gave _as value to some variant and used it later than 'ORDER BY' operator on .json_extract() method. In that usage, that value has no effect, you should gave it none value.");
-                        }
+        self.list.push(KeywordList::Having);
 
-                        match self.query.matches(" ORDER BY ").count() {
-                            0 => (),
-                            1 => {
-                                let split_the_query = self.query.clone();
-                                let mut split_the_query = split_the_query.split(" ORDER BY ");
+        self
+    }
 
-                                let string_for_put = format!("ORDER BY JSON_EXTRACT({}, '${}')", haystack, needle);
-        
-                                match _as {
-                                    Some(_as) => self.query = format!("{} {} AS {}", split_the_query.nth(0).unwrap(), string_for_put, _as),
-                                    None => self.query = format!("{} {}", split_the_query.nth(0).unwrap(), string_for_put)
-                                }
+    /// it adds the `INNER JOIN` keyword with it's synthax.
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){ 
+    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                             .table("students s")
+    ///                             .inner_join("grades g", "s.id", "=", "g.student_id")
+    ///                             .where_("id", "=", ValueType::Int32(10))
+    ///                             .finish();
+    ///
+    ///    assert_eq!(query, "SELECT * FROM students s INNER JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+    /// }
+    /// 
+    /// ```
+    pub fn inner_join(&mut self, table: &str, left: &str, mark: &str, right: &str) -> &mut Self {
+        self.join("INNER JOIN", table, Some(format!("{} {} {}", left, mark, right)), KeywordList::InnerJoin)
+    }
 
-                                match split_the_query.nth(0) {
-                                    Some(comparison) => {
-                                        match comparison.ends_with("ASC") || comparison.ends_with("asc") {
-                                            true => self.query = format!("{} ASC", self.query),
-                                            false => match comparison.ends_with("DESC") || comparison.ends_with("desc") {
-                                                true => self.query = format!("{} DESC", self.query),
-                                                false => ()
-                                            }
-                                        }
-                                    },
-                                    None => ()
-                                }
-                            },
-                            _ => ()
-                        }
-                    },
-                    KeywordList::Count => {
-                        let mut split_the_query = self.query.split(" COUNT");
+     /// it adds the `LEFT JOIN` keyword with it's synthax.
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){ 
+    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                             .table("students s")
+    ///                             .left_join("grades g", "s.id", "=", "g.student_id")
+    ///                             .where_("id", "=", ValueType::Int32(10))
+    ///                             .finish();
+    ///
+    ///    assert_eq!(query, "SELECT * FROM students s LEFT JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+    /// }
+    /// 
+    /// ```
+    pub fn left_join(&mut self, table: &str, left: &str, mark: &str, right: &str) -> &mut Self {
+        self.join("LEFT JOIN", table, Some(format!("{} {} {}", left, mark, right)), KeywordList::LeftJoin)
+    }
 
-                        let string_for_put = match _as {
-                            Some(_as) => format!("JSON_EXTRACT({}, '${}') AS {}", haystack, needle, _as),
-                            None => format!("JSON_EXTRACT({}, '${}')", haystack, needle)
-                        };
+    /// it adds the `RIGHT JOIN` keyword with it's synthax.
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){ 
+    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                             .table("students s")
+    ///                             .right_join("grades g", "s.id", "=", "g.student_id")
+    ///                             .where_("id", "=", ValueType::Int32(10))
+    ///                             .finish();
+    ///
+    ///    assert_eq!(query, "SELECT * FROM students s RIGHT JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+    /// }
+    /// 
+    /// ```
+    pub fn right_join(&mut self, table: &str, left: &str, mark: &str, right: &str) -> &mut Self {
+        self.join("RIGHT JOIN", table, Some(format!("{} {} {}", left, mark, right)), KeywordList::RightJoin)
+    }
 
-                        self.query = format!("SELECT {}, COUNT{}", string_for_put, split_the_query.nth(1).unwrap())
-                    },
-                    KeywordList::JsonExtract => {
-                        let mut split_the_query = self.query.split(" FROM");
+    /// it adds the `CROSS JOIN` keyword with it's synthax.
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){ 
+    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                             .table("students s")
+    ///                             .cross_join("grades g")
+    ///                             .where_("id", "=", ValueType::Int32(10))
+    ///                             .finish();
+    ///
+    ///    assert_eq!(query, "SELECT * FROM students s RIGHT JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+    /// }
+    /// 
+    /// ```
+    pub fn cross_join(&mut self, table: &str) -> &mut Self {
+        self.join("CROSS JOIN", table, None, KeywordList::CrossJoin)
+    }
 
-                        match _as {
-                            Some(_as) => self.query = format!("{}, JSON_EXTRACT({}, '${}') AS {} FROM", split_the_query.nth(0).unwrap(), haystack, needle, _as),
-                            None => panic!("If you want to chain .json_extract() methods, you have to give them a tag.")
-                        }
-                    }
-                    _ => ()
-                }
-            },
-            None => ()
-        }
-        
-        self.list.push(KeywordList::JsonExtract);
-        self
+    /// it adds the `NATURAL JOIN` keyword with it's synthax.
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){ 
+    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                             .table("students s")
+    ///                             .natural_join("grades g")
+    ///                             .where_("id", "=", ValueType::Int32(10))
+    ///                             .finish();
+    ///
+    ///    assert_eq!(query, "SELECT * FROM students s RIGHT JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+    /// }
+    /// 
+    /// ```
+    pub fn natural_join(&mut self, table: &str) -> &mut Self {
+        self.join("NATURAL JOIN", table, None, KeywordList::NaturalJoin)
     }
 
-    /// It applies "JSON_CONTAINS()" mysql function with it's Synthax. If you encounter any syntactic bugs or deficiencies about that function, please report it via opening an issue.
+    /// it adds a `JSON_TABLE()` source, which turns a json array into rows. It's used as the table of the query if the table is not defined yet, otherwise it's cross joined with the previous tables, so it can refer to their columns. It panics on sqlite.
     /// 
     /// ```rust
     /// 
-    /// use qubl::{QueryBuilder, ValueType, JsonValue};
+    /// use qubl::{QueryBuilder, JsonTable, JsonTableColumn, JsonPath, ValueType};
     /// 
-    /// fn main(){
-    ///     let value = ValueType::String("blablabla.jpg".to_string());
-    ///     let prop = vec![("name", &value)];
+    /// fn main(){ 
+    ///    let items = JsonTable::new("o.items", "[*]", vec![
+    ///        JsonTableColumn::ordinality("position"),
+    ///        JsonTableColumn::path("sku", "VARCHAR(32)", "sku"),
+    ///        JsonTableColumn::path("quantity", "INT", "qty").default_on_empty(ValueType::Int32(1)),
+    ///    ], "i");
+    /// 
+    ///    let query = QueryBuilder::select(vec!["o.id", "i.sku", "i.quantity"]).unwrap()
+    ///                             .table("orders o")
+    ///                             .json_table(&items)
+    ///                             .where_("i.quantity", ">", 5)
+    ///                             .finish();
+    ///
+    ///    assert_eq!(query, "SELECT o.id, i.sku, i.quantity FROM orders o CROSS JOIN JSON_TABLE(o.items, '$[*]' COLUMNS (position FOR ORDINALITY, sku VARCHAR(32) PATH '$.sku', quantity INT PATH '$.qty' DEFAULT '1' ON EMPTY)) AS i WHERE i.quantity > 5;");
+    /// }
     /// 
-    ///     let object = JsonValue::MysqlJsonObject(&prop);
+    /// ```
+    pub fn json_table(&mut self, table: &JsonTable) -> &mut Self {
+        let source = table.to_sql(&self.dialect);
+
+        match self.list.contains(&KeywordList::Table) {
+            true => self.join("CROSS JOIN", &source, None, KeywordList::CrossJoin),
+            false => self.table(&source)
+        }
+    }
+
+    /// it adds a `JSON_TABLE()` source with `LEFT JOIN ... ON TRUE`, so the rows of the previous tables are kept even if their json arrays are empty. It panics on sqlite.
     /// 
-    ///     let query = QueryBuilder::select(["*"].to_vec()).unwrap()
-    ///                              .table("users")
-    ///                              .where_("pic", "=", ValueType::String("".to_string()))
-    ///                              .json_contains("pic", object, Some(".name"))
-    ///                              .finish();
+    /// ```rust
     /// 
-    ///     assert_eq!(query, "SELECT * FROM users WHERE JSON_CONTAINS(pic, JSON_OBJECT('name', 'blablabla.jpg'), '$.name');")
+    /// use qubl::{QueryBuilder, JsonTable, JsonTableColumn};
+    /// 
+    /// fn main(){ 
+    ///    let tags = JsonTable::new("p.tags", "[*]", vec![JsonTableColumn::path("tag", "VARCHAR(50)", "")], "t");
+    /// 
+    ///    let query = QueryBuilder::select(vec!["p.id", "t.tag"]).unwrap()
+    ///                             .table("posts p")
+    ///                             .left_join_json_table(&tags)
+    ///                             .finish();
+    ///
+    ///    assert_eq!(query, "SELECT p.id, t.tag FROM posts p LEFT JOIN JSON_TABLE(p.tags, '$[*]' COLUMNS (tag VARCHAR(50) PATH '$')) AS t ON TRUE;");
     /// }
     /// 
     /// ```
-    pub fn json_contains(&mut self, column: &str, needle: JsonValue, path: Option<&str>) -> &mut Self {
-        match self.list.last().unwrap() {
-            KeywordList::Select => match path {
-                Some(path) => match needle {
-                    JsonValue::Initial(initial) => match initial {
-                        ValueType::JsonString(needle) => self.query = format!("SELECT JSON_CONTAINS({}, '\"{}\"', '${}') FROM", column, needle, path),
-                        ValueType::String(needle) => self.query = format!("SELECT JSON_CONTAINS({}, '{}', '${}') FROM", column, needle, path),
-                        ValueType::Datetime(needle) => self.query = format!("SELECT JSON_CONTAINS({}, '{}', '${}') FROM", column, needle, path),
-                        _ => self.query = format!("SELECT JSON_CONTAINS({}, {}, '${}') FROM", column, needle, path),
-                    },
-                    _ => self.query = format!("SELECT JSON_CONTAINS({}, {}, '${}') FROM", column, needle, path),
-                }
-                None => match needle {
-                    JsonValue::Initial(initial) => match initial {
-                        ValueType::JsonString(needle) => self.query = format!("SELECT JSON_CONTAINS({}, '\"{}\"') FROM", column, needle),
-                        ValueType::String(needle) => self.query = format!("SELECT JSON_CONTAINS({}, '{}') FROM", column, needle),
-                        ValueType::Datetime(needle) => self.query = format!("SELECT JSON_CONTAINS({}, '{}') FROM", column, needle),
-                        _ => self.query = format!("SELECT JSON_CONTAINS({}, {}) FROM", column, needle)
-                    },
-                    _ => self.query = format!("SELECT JSON_CONTAINS({}, {}) FROM", column, needle)
-                }
-            },
-            KeywordList::Where => match path {
-                Some(path) => {
-                    let mut split_the_query = self.query.split(" WHERE ");
+    pub fn left_join_json_table(&mut self, table: &JsonTable) -> &mut Self {
+        let source = table.to_sql(&self.dialect);
 
-                    let first_half = split_the_query.nth(0);
+        self.join("LEFT JOIN", &source, Some("TRUE".to_string()), KeywordList::LeftJoin)
+    }
 
-                    match needle {
-                        JsonValue::Initial(initial) => match initial {
-                            ValueType::JsonString(needle) => self.query = format!("{} WHERE JSON_CONTAINS({}, '\"{}\"', '${}')", first_half.unwrap(), column, needle, path),
-                            ValueType::String(needle) => self.query = format!("{} WHERE JSON_CONTAINS({}, '{}', '${}')", first_half.unwrap(), column, needle, path),
-                            ValueType::Datetime(needle) => self.query = format!("{} WHERE JSON_CONTAINS({}, '{}', '${}')", first_half.unwrap(), column, needle, path),
-                            _ => self.query = format!("{} WHERE JSON_CONTAINS({}, {}, '${}')", first_half.unwrap(), column, needle, path)
-                        },
-                        _ => self.query = format!("{} WHERE JSON_CONTAINS({}, {}, '${}')", first_half.unwrap(), column, needle, path)
-                    }
-                },
-                None => {
-                    let mut split_the_query = self.query.split(" WHERE ");
+    /// it names the tables (or their aliases) which rows will be deleted from on multi table delete queries, it's only meaningful with mysql. If you use joins with a delete query and don't call that method, the alias of the table which you passed to `.table()` method will be used.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){ 
+    ///    let query = QueryBuilder::delete().unwrap()
+    ///                             .table("students s")
+    ///                             .inner_join("grades g", "s.id", "=", "g.student_id")
+    ///                             .targets(vec!["s", "g"])
+    ///                             .where_("g.point", "<", ValueType::Int32(10))
+    ///                             .finish();
+    ///
+    ///    assert_eq!(query, "DELETE s, g FROM students s INNER JOIN grades g ON s.id = g.student_id WHERE g.point < 10;");
+    /// }
+    /// 
+    /// ```
+    pub fn targets(&mut self, targets: Vec<&str>) -> &mut Self {
+        match self.qtype {
+            QueryType::Delete => (),
+            _ => panic!("you can only name the target tables on delete queries.")
+        }
 
-                    let first_half = split_the_query.nth(0);
+        match self.dialect {
+            Dialect::Mysql => (),
+            _ => panic!("naming the target tables of a delete query is only supported on mysql.")
+        }
 
-                    match needle {
-                        JsonValue::Initial(initial) => match initial {
-                            ValueType::JsonString(needle) => self.query = format!("{} WHERE JSON_CONTAINS({}, '\"{}\"')", first_half.unwrap(), column, needle),
-                            ValueType::String(needle) => self.query = format!("{} WHERE JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
-                            ValueType::Datetime(needle) => self.query = format!("{} WHERE JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
-                            _ => self.query = format!("{} WHERE JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
-                        },
-                        _ => self.query = format!("{} WHERE JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
-                    }
-                }
-            },
-            KeywordList::LeftBracketWhere => match path {
-                Some(path) => {
-                    let mut split_the_query = self.query.split(" WHERE ");
+        if targets.is_empty() {
+            panic!("you cannot pass an empty vector to the targets argument")
+        }
 
-                    let first_half = split_the_query.nth(0);
+        self.targets = targets.into_iter().map(|target| target.to_string()).collect();
 
-                    match needle {
-                        JsonValue::Initial(initial) => match initial {
-                            ValueType::JsonString(needle) => self.query = format!("{} WHERE (JSON_CONTAINS({}, '\"{}\"', '${}')", first_half.unwrap(), column, needle, path),
-                            ValueType::String(needle) => self.query = format!("{} WHERE (JSON_CONTAINS({}, '{}', '${}')", first_half.unwrap(), column, needle, path),
-                            ValueType::Datetime(needle) => self.query = format!("{} WHERE (JSON_CONTAINS({}, '{}', '${}')", first_half.unwrap(), column, needle, path),
-                            _ => self.query = format!("{} WHERE (JSON_CONTAINS({}, {}, '${}')", first_half.unwrap(), column, needle, path)
-                        },
-                        _ => self.query = format!("{} WHERE (JSON_CONTAINS({}, {}, '${}')", first_half.unwrap(), column, needle, path)
-                    }
-                },
-                None => {
-                    let mut split_the_query = self.query.split(" WHERE ");
+        self
+    }
 
-                    let first_half = split_the_query.nth(0);
+    /// it sets the dialect of the query, so the parts of the query which differ between rdbms's are rendered on corresponding form. Default dialect is mysql.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, Dialect};
+    /// 
+    /// fn main(){ 
+    ///    let query = QueryBuilder::update().unwrap()
+    ///                             .dialect(Dialect::Postgresql)
+    ///                             .table("students")
+    ///                             .inner_join("grades", "students.id", "=", "grades.student_id")
+    ///                             .set("passed", ValueType::Boolean(true))
+    ///                             .where_("grades.point", ">", ValueType::Int32(50))
+    ///                             .finish();
+    ///
+    ///    assert_eq!(query, "UPDATE students SET passed = true FROM grades WHERE students.id = grades.student_id AND (grades.point > 50);");
+    /// }
+    /// 
+    /// ```
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        if let QueryType::Insert = self.qtype {
+            match dialect {
+                Dialect::Mysql => (),
+                _ if self.query.starts_with("INSERT LOW_PRIORITY INTO") => panic!("low priority inserts are only supported on mysql."),
+                Dialect::Postgresql if self.query.starts_with("REPLACE INTO") => panic!("postgresql does not support replace queries, use an insert query with an `ON CONFLICT ... DO UPDATE` clause instead."),
+                _ => ()
+            }
+        }
 
-                    match needle {
-                        JsonValue::Initial(initial) => match initial {
-                            ValueType::JsonString(needle) => self.query = format!("{} WHERE (JSON_CONTAINS({}, '\"{}\"')", first_half.unwrap(), column, needle),
-                            ValueType::String(needle) => self.query = format!("{} WHERE (JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
-                            ValueType::Datetime(needle) => self.query = format!("{} WHERE (JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
-                            _ => self.query = format!("{} WHERE (JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
-                        },
-                        _ => self.query = format!("{} WHERE (JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
-                    }
-                }
-            },
-            KeywordList::And => match path {
-                Some(path) => {
-                    let split_the_query = self.query.split(" AND ").collect::<Vec<&str>>();
+        self.dialect = dialect;
 
-                    let length_of_the_split_the_query = split_the_query.len();
+        self
+    }
 
-                    match split_the_query.len() {
-                        0 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
-                        1 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
-                        2 => match needle {
-                            JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} AND JSON_CONTAINS({}, '\"{}\"', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::String(needle) => self.query = format!("{} AND JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::Datetime(needle) => self.query = format!("{} AND JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                _ => self.query = format!("{} AND JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
-                            },
-                            _ => self.query = format!("{} AND JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
-                        },
-                        _ => {
-                            let mut concatenated_string = String::new();
+    /// it adds the `USE INDEX` hint to the last table reference, which is either the table of the query or the last joined table. Index hints are mysql specific.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, IndexHintScope};
+    /// 
+    /// fn main(){ 
+    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                             .table("students s")
+    ///                             .use_index(vec!["idx_class"], None)
+    ///                             .inner_join("grades g", "s.id", "=", "g.student_id")
+    ///                             .use_index(vec!["idx_student"], Some(IndexHintScope::Join))
+    ///                             .where_("s.class", "=", ValueType::String("10/c".to_string()))
+    ///                             .finish();
+    ///
+    ///    assert_eq!(query, "SELECT * FROM students s USE INDEX (idx_class) INNER JOIN grades g USE INDEX FOR JOIN (idx_student) ON s.id = g.student_id WHERE s.class = '10/c';");
+    /// }
+    /// 
+    /// ```
+    pub fn use_index(&mut self, indexes: Vec<&str>, scope: Option<IndexHintScope>) -> &mut Self {
+        self.index_hint("USE", indexes, scope)
+    }
 
-                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
-                                if index == 0 {
-                                    concatenated_string = chunk.to_string();
-                                } else if index + 1 != length_of_the_split_the_query {
-                                    concatenated_string = format!("{} AND {} ", concatenated_string, chunk)
-                                }
-                            }
+    /// it adds the `FORCE INDEX` hint to the last table reference, which is either the table of the query or the last joined table. Index hints are mysql specific.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, IndexHintScope};
+    /// 
+    /// fn main(){ 
+    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                             .table("users")
+    ///                             .force_index(vec!["idx_created_at"], Some(IndexHintScope::OrderBy))
+    ///                             .order_by("created_at", "DESC")
+    ///                             .finish();
+    ///
+    ///    assert_eq!(query, "SELECT * FROM users FORCE INDEX FOR ORDER BY (idx_created_at) ORDER BY created_at DESC;");
+    /// }
+    /// 
+    /// ```
+    pub fn force_index(&mut self, indexes: Vec<&str>, scope: Option<IndexHintScope>) -> &mut Self {
+        self.index_hint("FORCE", indexes, scope)
+    }
 
-                            match needle {
-                                JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}AND JSON_CONTAINS({}, '\"{}\"', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::String(needle) => self.query = format!("{}AND JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::Datetime(needle) => self.query = format!("{}AND JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    _ => self.query = format!("{}AND JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
-                                },
-                                _ => self.query = format!("{}AND JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
-                            }
-                        }
-                    }
-                },
-                None => {
-                    let split_the_query = self.query.split(" AND ").collect::<Vec<&str>>();
+    /// it adds the `IGNORE INDEX` hint to the last table reference, which is either the table of the query or the last joined table. Index hints are mysql specific.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, IndexHintScope};
+    /// 
+    /// fn main(){ 
+    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                             .table("users")
+    ///                             .ignore_index(vec!["idx_age", "idx_name"], Some(IndexHintScope::GroupBy))
+    ///                             .group_by("age")
+    ///                             .finish();
+    ///
+    ///    assert_eq!(query, "SELECT * FROM users IGNORE INDEX FOR GROUP BY (idx_age, idx_name) GROUP BY age;");
+    /// }
+    /// 
+    /// ```
+    pub fn ignore_index(&mut self, indexes: Vec<&str>, scope: Option<IndexHintScope>) -> &mut Self {
+        self.index_hint("IGNORE", indexes, scope)
+    }
 
-                    let length_of_the_split_the_query = split_the_query.len();
+    /// it adds an optimizer hint comment, such as `/*+ MAX_EXECUTION_TIME(1000) */`, right after the `SELECT`, `UPDATE` or `DELETE` keyword. If you call it more than once, hints are collected in the same comment. Optimizer hints are mysql specific.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, OptimizerHint, Timezone};
+    /// 
+    /// fn main(){ 
+    ///    let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                             .table("users")
+    ///                             .optimizer_hint(OptimizerHint::MaxExecutionTime(1000))
+    ///                             .optimizer_hint(OptimizerHint::TimeZone(Timezone::Istanbul))
+    ///                             .where_("id", "=", ValueType::Int32(1))
+    ///                             .finish();
+    ///
+    ///    assert_eq!(query, "SELECT /*+ MAX_EXECUTION_TIME(1000) SET_VAR(time_zone = 'Europe/Istanbul') */ * FROM users WHERE id = 1;");
+    /// }
+    /// 
+    /// ```
+    pub fn optimizer_hint(&mut self, hint: OptimizerHint) -> &mut Self {
+        match self.qtype {
+            QueryType::Select | QueryType::Count | QueryType::Update | QueryType::Delete => (),
+            _ => panic!("optimizer hints can only be used with select, update and delete queries.")
+        }
 
-                    match split_the_query.len() {
-                        0 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
-                        1 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
-                        2 => match needle {
-                            JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} AND JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
-                                ValueType::String(needle) => self.query = format!("{} AND JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                ValueType::Datetime(needle) => self.query = format!("{} AND JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                _ => self.query = format!("{} AND JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
-                            },
-                            _ => self.query = format!("{} AND JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
-                        },
-                        _ => {
-                            let mut concatenated_string = String::new();
+        match self.dialect {
+            Dialect::Mysql => (),
+            _ => panic!("optimizer hints are only supported on mysql.")
+        }
 
-                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
-                                if index == 0 {
-                                    concatenated_string = chunk.to_string();
-                                } else if index + 1 != length_of_the_split_the_query {
-                                    concatenated_string = format!("{} AND {} ", concatenated_string, chunk)
-                                }
-                            }
+        if self.list.iter().any(|keyword| keyword == &KeywordList::Union || keyword == &KeywordList::UnionAll) {
+            panic!("optimizer hints should be given before the .union() and .union_all() methods.")
+        }
 
-                            match needle {
-                                JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}AND JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
-                                    ValueType::String(needle) => self.query = format!("{}AND JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    ValueType::Datetime(needle) => self.query = format!("{}AND JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    _ => self.query = format!("{}AND JSON_CONTAINS({}, {})", concatenated_string, column, needle)
-                                },
-                                _ => self.query = format!("{}AND JSON_CONTAINS({}, {})", concatenated_string, column, needle)
-                            }
-                        }
-                    }
+        self.hints.push(hint.to_string());
+
+        self
+    }
+
+    fn index_hint(&mut self, action: &str, indexes: Vec<&str>, scope: Option<IndexHintScope>) -> &mut Self {
+        match self.dialect {
+            Dialect::Mysql => (),
+            _ => panic!("index hints are only supported on mysql.")
+        }
+
+        if action != "USE" && indexes.is_empty() {
+            panic!("you cannot pass an empty vector to the indexes argument of {} INDEX hint.", action)
+        }
+
+        let hint = match scope {
+            Some(scope) => format!("{} INDEX FOR {} ({})", action, scope, indexes.join(", ")),
+            None => format!("{} INDEX ({})", action, indexes.join(", "))
+        };
+
+        let reference = self.list.iter().rev().find(|keyword| **keyword != KeywordList::IndexHint);
+
+        match reference {
+            Some(KeywordList::Table) | Some(KeywordList::CrossJoin) | Some(KeywordList::NaturalJoin) => {
+                match self.list.last() {
+                    Some(KeywordList::IndexHint) | Some(KeywordList::Table) | Some(KeywordList::CrossJoin) | Some(KeywordList::NaturalJoin) => self.query = format!("{} {}", self.query, hint),
+                    _ => panic!("index hints should be used right after the table or join methods.")
                 }
             },
-            KeywordList::LeftBracketAnd => match path {
-                Some(path) => {
-                    let split_the_query = self.query.split(" AND ").collect::<Vec<&str>>();
+            Some(KeywordList::InnerJoin) | Some(KeywordList::LeftJoin) | Some(KeywordList::RightJoin) => {
+                let end = self.clause_start(&KeywordList::Where).unwrap_or(self.query.len());
+                let index = self.query[..end].rfind(" ON ").unwrap();
 
-                    let length_of_the_split_the_query = split_the_query.len();
+                self.insert_into_query(index, &format!(" {}", hint))
+            },
+            _ => panic!("index hints should be used right after the table or join methods.")
+        }
 
-                    match split_the_query.len() {
-                        0 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
-                        1 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
-                        2 => match needle {
-                            JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} AND (JSON_CONTAINS({}, '\"{}\"', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::String(needle) => self.query = format!("{} AND 8JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::Datetime(needle) => self.query = format!("{} AND (JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                _ => self.query = format!("{} AND (JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
-                            },
-                            _ => self.query = format!("{} AND (JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
-                        },
-                        _ => {
-                            let mut concatenated_string = String::new();
+        self.list.push(KeywordList::IndexHint);
 
-                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
-                                if index == 0 {
-                                    concatenated_string = chunk.to_string();
-                                } else if index + 1 != length_of_the_split_the_query {
-                                    concatenated_string = format!("{} AND {} ", concatenated_string, chunk)
-                                }
-                            }
+        self
+    }
 
-                            match needle {
-                                JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}AND (JSON_CONTAINS({}, '\"{}\"', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::String(needle) => self.query = format!("{}AND (JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::Datetime(needle) => self.query = format!("{}AND (JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    _ => self.query = format!("{}AND (JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
-                                },
-                                _ => self.query = format!("{}AND (JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
-                            }
+    fn join(&mut self, kind: &str, table: &str, condition: Option<String>, keyword: KeywordList) -> &mut Self {
+        match (&self.qtype, &self.dialect) {
+            (QueryType::Update, Dialect::Postgresql) | (QueryType::Update, Dialect::Sqlite) | (QueryType::Delete, Dialect::Postgresql) => {
+                match self.join_sources.len() {
+                    0 => {
+                        match kind {
+                            "INNER JOIN" | "CROSS JOIN" => (),
+                            _ => panic!("postgresql and sqlite only supports inner and cross joins against the updated or deleted table, {} is not supported.", kind)
                         }
-                    }
-                },
-                None => {
-                    let split_the_query = self.query.split(" AND ").collect::<Vec<&str>>();
-
-                    let length_of_the_split_the_query = split_the_query.len();
-
-                    match split_the_query.len() {
-                        0 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
-                        1 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
-                        2 => match needle {
-                            JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} AND (JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
-                                ValueType::String(needle) => self.query = format!("{} AND (JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                ValueType::Datetime(needle) => self.query = format!("{} AND (JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                _ => self.query = format!("{} AND (JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
-                            },
-                            _ => self.query = format!("{} AND (JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
-                        },
-                        _ => {
-                            let mut concatenated_string = String::new();
 
-                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
-                                if index == 0 {
-                                    concatenated_string = chunk.to_string();
-                                } else if index + 1 != length_of_the_split_the_query {
-                                    concatenated_string = format!("{} AND {} ", concatenated_string, chunk)
-                                }
-                            }
+                        self.join_sources.push(table.to_string());
 
-                            match needle {
-                                JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}AND (JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
-                                    ValueType::String(needle) => self.query = format!("{}AND (JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    ValueType::Datetime(needle) => self.query = format!("{}AND (JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    _ => self.query = format!("{}AND (JSON_CONTAINS({}, {})", concatenated_string, column, needle)
-                                },
-                                _ => self.query = format!("{}AND (JSON_CONTAINS({}, {})", concatenated_string, column, needle)
-                            }
+                        if let Some(condition) = condition {
+                            self.join_conditions.push(condition)
                         }
+                    },
+                    _ => match condition {
+                        Some(condition) => self.join_sources.push(format!("{} {} ON {}", kind, table, condition)),
+                        None => self.join_sources.push(format!("{} {}", kind, table))
                     }
                 }
             },
-            KeywordList::Or => match path {
-                Some(path) => {
-                    let split_the_query = self.query.split(" OR ").collect::<Vec<&str>>();
+            (QueryType::Delete, Dialect::Sqlite) => panic!("sqlite does not support joins on delete queries, use a subquery with `.where_in_custom()` instead."),
+            (QueryType::Update, Dialect::Mysql) => {
+                let join = match condition {
+                    Some(condition) => format!(" {} {} ON {}", kind, table, condition),
+                    None => format!(" {} {}", kind, table)
+                };
+
+                // the joined tables of mysql are a part of the table reference, so they're written in front of the SET clause.
+                match self.clause_start(&KeywordList::Set) {
+                    Some(start) => self.insert_into_query(start, &join),
+                    None => self.query.push_str(&join)
+                }
+            },
+            _ => match condition {
+                Some(condition) => self.query = format!("{} {} {} ON {}", self.query, kind, table, condition),
+                None => self.query = format!("{} {} {}", self.query, kind, table)
+            }
+        }
 
-                    let length_of_the_split_the_query = split_the_query.len();
+        self.list.push(keyword);
 
-                    match split_the_query.len() {
-                        0 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
-                        1 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
-                        2 => match needle {
-                            JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} OR JSON_CONTAINS({}, '\"{}\"', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::String(needle) => self.query = format!("{} OR JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::Datetime(needle) => self.query = format!("{} OR JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                _ => self.query = format!("{} OR JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
-                            },
-                            _ => self.query = format!("{} OR JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
-                        },
-                        _ => {
-                            let mut concatenated_string = String::new();
+        self
+    }
 
-                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
-                                if index == 0 {
-                                    concatenated_string = chunk.to_string();
-                                } else if index + 1 != length_of_the_split_the_query {
-                                    concatenated_string = format!("{} OR {} ", concatenated_string, chunk)
-                                }
-                            }
 
-                            match needle {
-                                JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}OR JSON_CONTAINS({}, '\"{}\"', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::String(needle) => self.query = format!("{}OR JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::Datetime(needle) => self.query = format!("{}OR JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    _ => self.query = format!("{}OR JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
-                                },
-                                _ => self.query = format!("{}OR JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
-                            }
-                        }
-                    }
-                },
-                None => {
-                    let split_the_query = self.query.split(" OR ").collect::<Vec<&str>>();
+    /// it adds the `UNION` keyword and its synthax. You can pass multiple queries to union with:
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){
+    ///     let mut union_1 = QueryBuilder::select(vec!["name", "age", "id"]).unwrap();
+    ///     union_1.table("users").where_("age", ">", ValueType::Int32(7));
+    ///
+    ///     let union_2 = QueryBuilder::select(vec!["name", "age", "id"]).unwrap()
+    ///                                .table("users")
+    ///                                .where_("age", "<", ValueType::Int32(15))
+    ///                                .union(vec![union_1])
+    ///                                .finish();
+    ///
+    ///     assert_eq!(union_2, "(SELECT name, age, id FROM users WHERE age < 15) UNION (SELECT name, age, id FROM users WHERE age > 7);");
+    /// }
+    /// 
+    /// ```
+    pub fn union(&mut self, others: Vec<QueryBuilder<'_>>) -> &mut Self {
+        self.flush_tail();
 
-                    let length_of_the_split_the_query = split_the_query.len();
+        match self.list.last() {
+            Some(keyword) => {
+                match keyword {
+                    KeywordList::Union | KeywordList::UnionAll => {
+                        for other in others {
+                            self.push_union("UNION", &other);
+                        }
+                    },
+                    _ => {
+                        let current = self.clone();
 
-                    match split_the_query.len() {
-                        0 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
-                        1 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
-                        2 => match needle {
-                            JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} OR JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
-                                ValueType::String(needle) => self.query = format!("{} OR JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                ValueType::Datetime(needle) => self.query = format!("{} OR JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                _ => self.query = format!("{} OR JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
-                            },
-                            _ => self.query = format!("{} OR JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
-                        },
-                        _ => {
-                            let mut concatenated_string = String::new();
+                        self.query = String::new();
+                        self.bindings.clear();
+                        self.binding_error = None;
+                        self.push_statement("(", &current, ")");
 
-                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
-                                if index == 0 {
-                                    concatenated_string = chunk.to_string();
-                                } else if index + 1 != length_of_the_split_the_query {
-                                    concatenated_string = format!("{} OR {} ", concatenated_string, chunk)
-                                }
-                            }
+                        self.hints.clear();
+                        self.clauses.clear();
+                        self.mark_clause(KeywordList::Union);
 
-                            match needle {
-                                JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}OR JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
-                                    ValueType::String(needle) => self.query = format!("{}OR JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    ValueType::Datetime(needle) => self.query = format!("{}OR JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    _ => self.query = format!("{}OR JSON_CONTAINS({}, {})", concatenated_string, column, needle)
-                                },
-                                _ => self.query = format!("{}OR JSON_CONTAINS({}, {})", concatenated_string, column, needle)
-                            }
+                        for other in others {
+                            self.push_union("UNION", &other);
                         }
                     }
                 }
             },
-            KeywordList::LeftBracketOr => match path {
-                Some(path) => {
-                    let split_the_query = self.query.split(" OR ").collect::<Vec<&str>>();
+            None => panic!("it's impossible to came here!")
+        }
 
-                    let length_of_the_split_the_query = split_the_query.len();
+        self.list.push(KeywordList::Union);
 
-                    match split_the_query.len() {
-                        0 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
-                        1 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
-                        2 => match needle {
-                            JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} OR (JSON_CONTAINS({}, '\"{}\"', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::String(needle) => self.query = format!("{} OR (JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::Datetime(needle) => self.query = format!("{} OR (JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                _ => self.query = format!("{} OR (JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
-                            },
-                            _ => self.query = format!("{} OR (JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
-                        },
-                        _ => {
-                            let mut concatenated_string = String::new();
+        self
+    }
 
-                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
-                                if index == 0 {
-                                    concatenated_string = chunk.to_string();
-                                } else if index + 1 != length_of_the_split_the_query {
-                                    concatenated_string = format!("{} OR {} ", concatenated_string, chunk)
-                                }
-                            }
 
-                            match needle {
-                                JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}OR (JSON_CONTAINS({}, '\"{}\"', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::String(needle) => self.query = format!("{}OR (JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::Datetime(needle) => self.query = format!("{}OR (JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    _ => self.query = format!("{}OR (JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
-                                },
-                                _ => self.query = format!("{}OR (JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
-                            }
-                        }
-                    }
-                },
-                None => {
-                    let split_the_query = self.query.split(" OR ").collect::<Vec<&str>>();
+    /// it adds the `UNION` keyword and its synthax. You can pass multiple queries to union with:
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){
+    ///     let mut union_1 = QueryBuilder::select(vec!["id", "title", "description", "published"]).unwrap();
+    ///     union_1.table("blogs").like(vec!["title"], "text");
+    ///
+    ///     let mut union_2 = QueryBuilder::select(vec!["id", "title", "description", "published"]).unwrap();
+    ///     union_2.table("blogs").like(vec!["description"], "some text");
+    ///
+    ///     let union_3 = QueryBuilder::select(vec!["id", "title", "description", "published"]).unwrap()
+    ///                                .table("blogs")
+    ///                                .where_("published", "=", ValueType::Boolean(true))
+    ///                                .union_all(vec![union_1, union_2])
+    ///                                .finish();
+    ///
+    ///     assert_eq!(union_3, "(SELECT id, title, description, published FROM blogs WHERE published = true) UNION ALL (SELECT id, title, description, published FROM blogs WHERE title LIKE '%text%') UNION ALL (SELECT id, title, description, published FROM blogs WHERE description LIKE '%some text%');");
+    /// }
+    /// 
+    /// ```
+    /// 
+    pub fn union_all(&mut self, others: Vec<QueryBuilder<'_>>) -> &mut Self {
+        self.flush_tail();
 
-                    let length_of_the_split_the_query = split_the_query.len();
+        match self.list.last() {
+            Some(keyword) => {
+                match keyword {
+                    KeywordList::Union | KeywordList::UnionAll => {
+                        for other in others {
+                            self.push_union("UNION ALL", &other);
+                        }
+                    },
+                    _ => {
+                        let current = self.clone();
 
-                    match split_the_query.len() {
-                        0 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
-                        1 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
-                        2 => match needle {
-                            JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} OR (JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
-                                ValueType::String(needle) => self.query = format!("{} OR (JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                ValueType::Datetime(needle) => self.query = format!("{} OR (JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                _ => self.query = format!("{} OR (JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
-                            },
-                            _ => self.query = format!("{} OR (JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
-                        },
-                        _ => {
-                            let mut concatenated_string = String::new();
+                        self.query = String::new();
+                        self.bindings.clear();
+                        self.binding_error = None;
+                        self.push_statement("(", &current, ")");
 
-                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
-                                if index == 0 {
-                                    concatenated_string = chunk.to_string();
-                                } else if index + 1 != length_of_the_split_the_query {
-                                    concatenated_string = format!("{} OR {} ", concatenated_string, chunk)
-                                }
-                            }
+                        self.hints.clear();
+                        self.clauses.clear();
+                        self.mark_clause(KeywordList::Union);
 
-                            match needle {
-                                JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}OR (JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
-                                    ValueType::String(needle) => self.query = format!("{}OR (JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    ValueType::Datetime(needle) => self.query = format!("{}OR (JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    _ => self.query = format!("{}OR (JSON_CONTAINS({}, {})", concatenated_string, column, needle)
-                                },
-                                _ => self.query = format!("{}OR (JSON_CONTAINS({}, {})", concatenated_string, column, needle)
-                            }
+                        for other in others {
+                            self.push_union("UNION ALL", &other);
                         }
                     }
                 }
             },
-            _ => panic!("Wrong usage of '.json_contains()' method, it should be used later than either SELECT, WHERE, AND, OR keywords.")
+            None => panic!("it's impossible to came here!")
         }
 
-        self.list.push(KeywordList::JsonContains);
+        self.list.push(KeywordList::UnionAll);
 
         self
     }
 
-    /// It applies "NOT JSON_CONTAINS()" mysql function with it's Synthax. If you encounter any syntactic bugs or deficiencies about that function, please report it via opening an issue.
+    /// A wildcard method that gives you the chance to write a part of your query. Warning, it does not add any keyword to builder, i'll encourage to add proper keyword to it with `.append_keyword()` method for your custom query, otherwise you should continue building your query by yourself with that function, or you've to be prepared to encounter bugs.  
     /// 
     /// ```rust
     /// 
-    /// use qubl::{QueryBuilder, ValueType, JsonValue};
+    /// use qubl::{QueryBuilder, ValueType};
     /// 
     /// fn main(){
-    ///     let value = ValueType::String("blablabla.jpg".to_string());
-    ///     let prop = vec![("name", &value)];
+    ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                              .table("users")
+    ///                              .append_custom("WHERE age > 25 ORDER BY FIELD(role, 'admin', 'member', 'observer') LIMIT 5 OFFSET 0")
+    ///                              .finish();
     /// 
-    ///     let object = JsonValue::MysqlJsonObject(&prop);
+    ///     assert_eq!(query, "SELECT * FROM users WHERE age > 25 ORDER BY FIELD(role, 'admin', 'member', 'observer') LIMIT 5 OFFSET 0;")
+    /// }
+    /// ```
     /// 
-    ///     let query = QueryBuilder::select(["*"].to_vec()).unwrap()
-    ///                              .table("users")
-    ///                              .where_("pic", "=", ValueType::String("".to_string()))
-    ///                              .not_json_contains("pic", object, Some(".name"))
+    pub fn append_custom(&mut self, query: &str) -> &mut Self {
+        self.flush_tail();
+
+        self.query = format!("{} {}", self.query, query);
+
+        self
+    }
+
+    /// It adds an expression to the selected fields of a select query, with an optional alias. It's useful for the computed fields such as formatted dates.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, Expr};
+    /// 
+    /// fn main(){
+    ///     let query = QueryBuilder::select(vec!["id"]).unwrap()
+    ///                              .select_expr(Expr::date_format(Expr::col("created_at"), "%Y-%m-%d"), Some("day"))
+    ///                              .table("orders")
     ///                              .finish();
     /// 
-    ///     assert_eq!(query, "SELECT * FROM users WHERE NOT JSON_CONTAINS(pic, JSON_OBJECT('name', 'blablabla.jpg'), '$.name');")
+    ///     assert_eq!(query, "SELECT id, DATE_FORMAT(created_at, '%Y-%m-%d') AS day FROM orders;")
     /// }
     /// 
     /// ```
-    pub fn not_json_contains(&mut self, column: &str, needle: JsonValue, path: Option<&str>) -> &mut Self {
-        match self.list.last().unwrap() {
-            KeywordList::Select => match path {
-                Some(path) => match needle {
-                    JsonValue::Initial(initial) => match initial {
-                        ValueType::JsonString(needle) => self.query = format!("SELECT NOT JSON_CONTAINS({}, '\"{}\"', '${}') FROM", column, needle, path),
-                        ValueType::String(needle) => self.query = format!("SELECT NOT JSON_CONTAINS({}, '{}', '${}') FROM", column, needle, path),
-                        ValueType::Datetime(needle) => self.query = format!("SELECT NOT JSON_CONTAINS({}, '{}', '${}') FROM", column, needle, path),
-                        _ => self.query = format!("SELECT NOT JSON_CONTAINS({}, {}, '${}') FROM", column, needle, path),
-                    },
-                    _ => self.query = format!("SELECT NOT JSON_CONTAINS({}, {}, '${}') FROM", column, needle, path),
-                }
-                None => match needle {
-                    JsonValue::Initial(initial) => match initial {
-                        ValueType::JsonString(needle) => self.query = format!("SELECT NOT JSON_CONTAINS({}, '\"{}\"') FROM", column, needle),
-                        ValueType::String(needle) => self.query = format!("SELECT NOT JSON_CONTAINS({}, '{}') FROM", column, needle),
-                        ValueType::Datetime(needle) => self.query = format!("SELECT NOT JSON_CONTAINS({}, '{}') FROM", column, needle),
-                        _ => self.query = format!("SELECT NOT JSON_CONTAINS({}, {}) FROM", column, needle)
-                    },
-                    _ => self.query = format!("SELECT NOT JSON_CONTAINS({}, {}) FROM", column, needle)
-                }
-            },
-            KeywordList::Where => match path {
-                Some(path) => {
-                    let mut split_the_query = self.query.split(" WHERE ");
+    pub fn select_expr<T: Into<Expr>>(&mut self, expr: T, alias: Option<&str>) -> &mut Self {
+        let expr: Expr = expr.into();
 
-                    let first_half = split_the_query.nth(0);
+        match self.qtype {
+            QueryType::Select => (),
+            _ => panic!(".select_expr() method can only be used with select queries.")
+        }
 
-                    match needle {
-                        JsonValue::Initial(initial) => match initial {
-                            ValueType::JsonString(needle) => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, '\"{}\"', '${}')", first_half.unwrap(), column, needle, path),
-                            ValueType::String(needle) => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, '{}', '${}')", first_half.unwrap(), column, needle, path),
-                            ValueType::Datetime(needle) => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, '{}', '${}')", first_half.unwrap(), column, needle, path),
-                            _ => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, {}, '${}')", first_half.unwrap(), column, needle, path)
-                        },
-                        _ => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, {}, '${}')", first_half.unwrap(), column, needle, path)
-                    }
-                },
-                None => {
-                    let mut split_the_query = self.query.split(" WHERE ");
+        match self.hq {
+            Some(_) => (),
+            None => self.hq = Some(Self::load_hqs())
+        }
 
-                    let first_half = split_the_query.nth(0);
+        match self.sanitize_expr(&expr) {
+            Ok(_) => (),
+            Err(error) => panic!("{}", error)
+        }
 
-                    match needle {
-                        JsonValue::Initial(initial) => match initial {
-                            ValueType::JsonString(needle) => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, '\"{}\"')", first_half.unwrap(), column, needle),
-                            ValueType::String(needle) => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
-                            ValueType::Datetime(needle) => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
-                            _ => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
-                        },
-                        _ => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
-                    }
-                }
-            },
-            KeywordList::LeftBracketWhere => match path {
-                Some(path) => {
-                    let mut split_the_query = self.query.split(" WHERE ");
+        let field = match alias {
+            Some(alias) => format!("{} AS {}", expr.to_sql(&self.dialect), alias),
+            None => expr.to_sql(&self.dialect)
+        };
 
-                    let first_half = split_the_query.nth(0);
+        let index = match self.clause_start(&KeywordList::Table) {
+            Some(start) => start - " FROM".len(),
+            None => self.query.len() - " FROM".len()
+        };
 
-                    match needle {
+        self.insert_into_query(index, &format!(", {}", field));
+
+        self
+    }
+
+    /// A wildcard method that benefits you to append a keyword to the keyword list, so the QueryBuilder can build your queries properly, later than you appended your custom string to your query. It should be used with `.append_custom()` method. 
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, KeywordList};
+    /// 
+    /// fn main(){
+    ///     let query = QueryBuilder::select(vec!["*"]).unwrap()
+    ///                              .table("users")
+    ///                              .append_custom("WHERE age > 25 ORDER BY FIELD(role, 'admin', 'member', 'observer') LIMIT 5 OFFSET 0")
+    ///                              .append_keyword(KeywordList::Where)
+    ///                              .append_keyword(KeywordList::Field)
+    ///                              .append_keyword(KeywordList::Limit)
+    ///                              .append_keyword(KeywordList::Offset)
+    ///                              .finish();
+    /// 
+    ///     assert_eq!(query, "SELECT * FROM users WHERE age > 25 ORDER BY FIELD(role, 'admin', 'member', 'observer') LIMIT 5 OFFSET 0;")
+    /// }
+    /// ```
+    /// 
+    pub fn append_keyword(&mut self, keyword: KeywordList) -> &mut Self {
+        self.list.push(keyword);
+
+        self
+    }
+    
+    /// It applies "JSON_EXTRACT()" mysql function with it's Synthax. If you encounter any syntactic bugs or deficiencies about that function, please report it via opening an issue.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){
+    ///     let query = QueryBuilder::select(["*"].to_vec()).unwrap()
+    ///                              .json_extract("articles", "[0]", Some("blog1"))
+    ///                              .json_extract("articles", "[1]", Some("blog2"))
+    ///                              .json_extract("articles", "[2]", Some("blog3"))
+    ///                              .table("users")
+    ///                              .where_("published", "=", ValueType::Int32(1))
+    ///                              .finish();
+    /// 
+    ///     assert_eq!(query, "SELECT JSON_EXTRACT(articles, '$[0]') AS blog1, JSON_EXTRACT(articles, '$[1]') AS blog2, JSON_EXTRACT(articles, '$[2]') AS blog3 FROM users WHERE published = 1;")
+    /// }
+    /// 
+    /// ```
+    /// 
+    /// Inside of a parenthesis it can only be used just after the `.open_parenthesis()` method, it writes the function as the start of the first condition. To open a parenthesis with a condition on a json value, use the `.open_parenthesis_predicate()` method with `Expr::json_extract()`:
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, Expr, BracketType};
+    /// 
+    /// fn main(){
+    ///     let query = QueryBuilder::select(vec!["id"]).unwrap()
+    ///                              .table("users")
+    ///                              .where_("active", "=", true)
+    ///                              .open_parenthesis_predicate(BracketType::Or, Expr::compare(Expr::json_extract(Expr::col("profile"), "lang"), "=", String::from("rust")))
+    ///                              .close_parenthesis()
+    ///                              .finish();
+    /// 
+    ///     assert_eq!(query, "SELECT id FROM users WHERE active = true OR (JSON_EXTRACT(profile, '$.lang') = 'rust');")
+    /// }
+    /// 
+    /// ```
+    pub fn json_extract<P: Into<JsonPath>>(&mut self, haystack: &str, needle: P, _as: Option<&str>) -> &mut Self {
+        let extracted = Expr::json_extract(Expr::col(haystack), needle).to_sql(&self.dialect);
+
+        match self.list.last() {
+            Some(keyword) => {
+                match keyword {
+                    KeywordList::LeftBracketWhere | KeywordList::LeftBracketAnd | KeywordList::LeftBracketOr => {
+                        if _as.is_some() {
+                            println!("Warning: You've gave _as value to some variant and used it inside of a parenthesis on .json_extract() method. In that usage, that value has no effect, you should gave it none value.");
+                        }
+
+                        match self.query.ends_with('(') {
+                            true => self.query = format!("{}{}", self.query, extracted),
+                            false => panic!("the .json_extract() method can be used just after the .open_parenthesis() method inside of a parenthesis, use the .open_parenthesis_predicate() method with Expr::json_extract() to open a parenthesis with a condition on a json value.")
+                        }
+                    },
+                    KeywordList::Where => {
+                        if _as.is_some() {
+                            println!("Warning: You've gave _as value to some variant and used it later than 'WHERE' keyword on .json_extract() method. In that usage, that value has no effect, you should gave it none value.");
+                        }
+
+                        self.extract_in_condition("WHERE", haystack, &extracted)
+                    },
+                    KeywordList::And => {
+                        if _as.is_some() {
+                            println!("Warning: You've gave _as value to some variant and used it later than 'AND' keyword on .json_extract() method. In that usage, that value has no effect, you should gave it none value.");
+                        }
+
+                        self.extract_in_condition("AND", haystack, &extracted)
+                    },
+                    KeywordList::Or => {
+                        if _as.is_some() {
+                            println!("Warning: You've gave _as value to some variant and used it later than 'OR' keyword on .json_extract() method. In that usage, that value has no effect, you should gave it none value.");
+                        }
+
+                        self.extract_in_condition("OR", haystack, &extracted)
+                    },
+                    KeywordList::Select => {
+                        match _as {
+                            Some(_as) => self.query = format!("SELECT {} AS {} FROM", extracted, _as),
+                            None => self.query = format!("SELECT {} FROM", extracted),
+                        }
+                    },
+                    KeywordList::Table => {
+                        match _as {
+                            Some(_as) => self.query = format!("SELECT {} AS {} FROM {}", extracted, _as, self.table),
+                            None => self.query = format!("SELECT {} FROM {}", extracted, self.table),
+                        }
+
+                        self.clauses = vec![(KeywordList::Table, self.query.len() - self.table.len() - 1)];
+                    },
+                    KeywordList::OrderBy => {
+                        if _as.is_some() {
+                            println!("Warning: You've gave _as value to some variant and used it later than 'ORDER BY' operator on .json_extract() method. In that usage, that value has no effect, you should gave it none value.");
+                        }
+
+                        if let Some(ordering) = self.orderings.pop() {
+                            match ordering.rsplit_once(' ') {
+                                Some((_, direction)) if direction == "ASC" || direction == "DESC" => self.orderings.push(format!("{} {}", extracted, direction)),
+                                _ => self.orderings.push(extracted)
+                            }
+                        }
+                    },
+                    KeywordList::Count => {
+                        let mut split_the_query = self.query.split(" COUNT");
+
+                        let string_for_put = match _as {
+                            Some(_as) => format!("{} AS {}", extracted, _as),
+                            None => extracted
+                        };
+
+                        self.query = format!("SELECT {}, COUNT{}", string_for_put, split_the_query.nth(1).unwrap())
+                    },
+                    KeywordList::JsonExtract => {
+                        let mut split_the_query = self.query.split(" FROM");
+
+                        match _as {
+                            Some(_as) => self.query = format!("{}, {} AS {} FROM", split_the_query.nth(0).unwrap(), extracted, _as),
+                            None => panic!("If you want to chain .json_extract() methods, you have to give them a tag.")
+                        }
+                    }
+                    _ => ()
+                }
+            },
+            None => ()
+        }
+        
+        self.list.push(KeywordList::JsonExtract);
+        self
+    }
+
+    /// It applies "JSON_CONTAINS()" mysql function with it's Synthax. The path can be a `JsonPath` or a text which is parsed as a `JsonPath`, the root path `"$"` searches the whole document. It panics on the other dialects. If you encounter any syntactic bugs or deficiencies about that function, please report it via opening an issue.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, JsonValue};
+    /// 
+    /// fn main(){
+    ///     let value = ValueType::String("blablabla.jpg".to_string());
+    ///     let prop = vec![("name", &value)];
+    /// 
+    ///     let object = JsonValue::MysqlJsonObject(&prop);
+    /// 
+    ///     let query = QueryBuilder::select(["*"].to_vec()).unwrap()
+    ///                              .table("users")
+    ///                              .where_("pic", "=", ValueType::String("".to_string()))
+    ///                              .json_contains("pic", object, ".name")
+    ///                              .finish();
+    /// 
+    ///     assert_eq!(query, "SELECT * FROM users WHERE JSON_CONTAINS(pic, JSON_OBJECT('name', 'blablabla.jpg'), '$.name');")
+    /// }
+    /// 
+    /// ```
+    pub fn json_contains<'v, V: Into<JsonValue<'v>>, P: Into<JsonPath>>(&mut self, column: &str, needle: V, path: P) -> &mut Self {
+        if !matches!(self.dialect, Dialect::Mysql) {
+            panic!("JSON_CONTAINS() is mysql specific.")
+        }
+
+        let path = Self::contained_path(path.into());
+        let needle = needle.into();
+        let previous = self.query.clone();
+        let document;
+
+        let needle = match needle {
+            JsonValue::Owned(json) => {
+                let quoted = json.quoted(&Dialect::Mysql);
+
+                document = ValueType::String(quoted[1..quoted.len() - 1].to_string());
+
+                JsonValue::Initial(&document)
+            },
+            needle => needle
+        };
+
+        match self.list.last().unwrap() {
+            KeywordList::Select => match path {
+                Some(path) => match needle {
+                    JsonValue::Initial(initial) => match initial {
+                        ValueType::JsonString(needle) => self.query = format!("SELECT JSON_CONTAINS({}, '\"{}\"', '{}') FROM", column, needle, path),
+                        ValueType::String(needle) => self.query = format!("SELECT JSON_CONTAINS({}, '{}', '{}') FROM", column, needle, path),
+                        ValueType::Datetime(needle) => self.query = format!("SELECT JSON_CONTAINS({}, '{}', '{}') FROM", column, needle, path),
+                        _ => self.query = format!("SELECT JSON_CONTAINS({}, {}, '{}') FROM", column, needle, path),
+                    },
+                    _ => self.query = format!("SELECT JSON_CONTAINS({}, {}, '{}') FROM", column, needle, path),
+                }
+                None => match needle {
+                    JsonValue::Initial(initial) => match initial {
+                        ValueType::JsonString(needle) => self.query = format!("SELECT JSON_CONTAINS({}, '\"{}\"') FROM", column, needle),
+                        ValueType::String(needle) => self.query = format!("SELECT JSON_CONTAINS({}, '{}') FROM", column, needle),
+                        ValueType::Datetime(needle) => self.query = format!("SELECT JSON_CONTAINS({}, '{}') FROM", column, needle),
+                        _ => self.query = format!("SELECT JSON_CONTAINS({}, {}) FROM", column, needle)
+                    },
+                    _ => self.query = format!("SELECT JSON_CONTAINS({}, {}) FROM", column, needle)
+                }
+            },
+            KeywordList::Where => match path {
+                Some(path) => {
+                    let mut split_the_query = self.query.split(" WHERE ");
+
+                    let first_half = split_the_query.nth(0);
+
+                    match needle {
                         JsonValue::Initial(initial) => match initial {
-                            ValueType::JsonString(needle) => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, '\"{}\"', '${}')", first_half.unwrap(), column, needle, path),
-                            ValueType::String(needle) => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, '{}', '${}')", first_half.unwrap(), column, needle, path),
-                            ValueType::Datetime(needle) => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, '{}', '${}')", first_half.unwrap(), column, needle, path),
-                            _ => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, {}, '${}')", first_half.unwrap(), column, needle, path)
+                            ValueType::JsonString(needle) => self.query = format!("{} WHERE JSON_CONTAINS({}, '\"{}\"', '{}')", first_half.unwrap(), column, needle, path),
+                            ValueType::String(needle) => self.query = format!("{} WHERE JSON_CONTAINS({}, '{}', '{}')", first_half.unwrap(), column, needle, path),
+                            ValueType::Datetime(needle) => self.query = format!("{} WHERE JSON_CONTAINS({}, '{}', '{}')", first_half.unwrap(), column, needle, path),
+                            _ => self.query = format!("{} WHERE JSON_CONTAINS({}, {}, '{}')", first_half.unwrap(), column, needle, path)
                         },
-                        _ => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, {}, '${}')", first_half.unwrap(), column, needle, path)
+                        _ => self.query = format!("{} WHERE JSON_CONTAINS({}, {}, '{}')", first_half.unwrap(), column, needle, path)
                     }
                 },
                 None => {
@@ -2262,12 +2831,44 @@ impl<'a> QueryBuilder<'a> {
 
                     match needle {
                         JsonValue::Initial(initial) => match initial {
-                            ValueType::JsonString(needle) => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, '\"{}\"')", first_half.unwrap(), column, needle),
-                            ValueType::String(needle) => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
-                            ValueType::Datetime(needle) => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
-                            _ => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
+                            ValueType::JsonString(needle) => self.query = format!("{} WHERE JSON_CONTAINS({}, '\"{}\"')", first_half.unwrap(), column, needle),
+                            ValueType::String(needle) => self.query = format!("{} WHERE JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
+                            ValueType::Datetime(needle) => self.query = format!("{} WHERE JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
+                            _ => self.query = format!("{} WHERE JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
                         },
-                        _ => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
+                        _ => self.query = format!("{} WHERE JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
+                    }
+                }
+            },
+            KeywordList::LeftBracketWhere => match path {
+                Some(path) => {
+                    let mut split_the_query = self.query.split(" WHERE ");
+
+                    let first_half = split_the_query.nth(0);
+
+                    match needle {
+                        JsonValue::Initial(initial) => match initial {
+                            ValueType::JsonString(needle) => self.query = format!("{} WHERE (JSON_CONTAINS({}, '\"{}\"', '{}')", first_half.unwrap(), column, needle, path),
+                            ValueType::String(needle) => self.query = format!("{} WHERE (JSON_CONTAINS({}, '{}', '{}')", first_half.unwrap(), column, needle, path),
+                            ValueType::Datetime(needle) => self.query = format!("{} WHERE (JSON_CONTAINS({}, '{}', '{}')", first_half.unwrap(), column, needle, path),
+                            _ => self.query = format!("{} WHERE (JSON_CONTAINS({}, {}, '{}')", first_half.unwrap(), column, needle, path)
+                        },
+                        _ => self.query = format!("{} WHERE (JSON_CONTAINS({}, {}, '{}')", first_half.unwrap(), column, needle, path)
+                    }
+                },
+                None => {
+                    let mut split_the_query = self.query.split(" WHERE ");
+
+                    let first_half = split_the_query.nth(0);
+
+                    match needle {
+                        JsonValue::Initial(initial) => match initial {
+                            ValueType::JsonString(needle) => self.query = format!("{} WHERE (JSON_CONTAINS({}, '\"{}\"')", first_half.unwrap(), column, needle),
+                            ValueType::String(needle) => self.query = format!("{} WHERE (JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
+                            ValueType::Datetime(needle) => self.query = format!("{} WHERE (JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
+                            _ => self.query = format!("{} WHERE (JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
+                        },
+                        _ => self.query = format!("{} WHERE (JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
                     }
                 }
             },
@@ -2282,12 +2883,12 @@ impl<'a> QueryBuilder<'a> {
                         1 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
                         2 => match needle {
                             JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} AND NOT JSON_CONTAINS({}, '\"{}\"', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::String(needle) => self.query = format!("{} AND NOT JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::Datetime(needle) => self.query = format!("{} AND NOT JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                _ => self.query = format!("{} AND NOT JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
+                                ValueType::JsonString(needle) => self.query = format!("{} AND JSON_CONTAINS({}, '\"{}\"', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::String(needle) => self.query = format!("{} AND JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::Datetime(needle) => self.query = format!("{} AND JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                _ => self.query = format!("{} AND JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
                             },
-                            _ => self.query = format!("{} AND NOT JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
+                            _ => self.query = format!("{} AND JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
                         },
                         _ => {
                             let mut concatenated_string = String::new();
@@ -2302,12 +2903,12 @@ impl<'a> QueryBuilder<'a> {
 
                             match needle {
                                 JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}AND NOT JSON_CONTAINS({}, '\"{}\"', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::String(needle) => self.query = format!("{}AND NOT JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::Datetime(needle) => self.query = format!("{}AND NOT JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    _ => self.query = format!("{}AND NOT JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
+                                    ValueType::JsonString(needle) => self.query = format!("{}AND JSON_CONTAINS({}, '\"{}\"', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::String(needle) => self.query = format!("{}AND JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::Datetime(needle) => self.query = format!("{}AND JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    _ => self.query = format!("{}AND JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
                                 },
-                                _ => self.query = format!("{}AND NOT JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
+                                _ => self.query = format!("{}AND JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
                             }
                         }
                     }
@@ -2322,12 +2923,12 @@ impl<'a> QueryBuilder<'a> {
                         1 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
                         2 => match needle {
                             JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} AND NOT JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
-                                ValueType::String(needle) => self.query = format!("{} AND NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                ValueType::Datetime(needle) => self.query = format!("{} AND NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                _ => self.query = format!("{} AND NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                                ValueType::JsonString(needle) => self.query = format!("{} AND JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
+                                ValueType::String(needle) => self.query = format!("{} AND JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                ValueType::Datetime(needle) => self.query = format!("{} AND JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                _ => self.query = format!("{} AND JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
                             },
-                            _ => self.query = format!("{} AND NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                            _ => self.query = format!("{} AND JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
                         },
                         _ => {
                             let mut concatenated_string = String::new();
@@ -2342,12 +2943,12 @@ impl<'a> QueryBuilder<'a> {
 
                             match needle {
                                 JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}AND NOT JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
-                                    ValueType::String(needle) => self.query = format!("{}AND NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    ValueType::Datetime(needle) => self.query = format!("{}AND NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    _ => self.query = format!("{}AND NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                                    ValueType::JsonString(needle) => self.query = format!("{}AND JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
+                                    ValueType::String(needle) => self.query = format!("{}AND JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    ValueType::Datetime(needle) => self.query = format!("{}AND JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    _ => self.query = format!("{}AND JSON_CONTAINS({}, {})", concatenated_string, column, needle)
                                 },
-                                _ => self.query = format!("{}AND NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                                _ => self.query = format!("{}AND JSON_CONTAINS({}, {})", concatenated_string, column, needle)
                             }
                         }
                     }
@@ -2364,12 +2965,12 @@ impl<'a> QueryBuilder<'a> {
                         1 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
                         2 => match needle {
                             JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} AND (NOT JSON_CONTAINS({}, '\"{}\"', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::String(needle) => self.query = format!("{} AND (NOT JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::Datetime(needle) => self.query = format!("{} AND (NOT JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                _ => self.query = format!("{} AND (NOT JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
+                                ValueType::JsonString(needle) => self.query = format!("{} AND (JSON_CONTAINS({}, '\"{}\"', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::String(needle) => self.query = format!("{} AND 8JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::Datetime(needle) => self.query = format!("{} AND (JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                _ => self.query = format!("{} AND (JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
                             },
-                            _ => self.query = format!("{} AND (NOT JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
+                            _ => self.query = format!("{} AND (JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
                         },
                         _ => {
                             let mut concatenated_string = String::new();
@@ -2384,12 +2985,12 @@ impl<'a> QueryBuilder<'a> {
 
                             match needle {
                                 JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}AND (NOT JSON_CONTAINS({}, '\"{}\"', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::String(needle) => self.query = format!("{}AND (NOT JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::Datetime(needle) => self.query = format!("{}AND (NOT JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    _ => self.query = format!("{}AND (NOT JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
+                                    ValueType::JsonString(needle) => self.query = format!("{}AND (JSON_CONTAINS({}, '\"{}\"', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::String(needle) => self.query = format!("{}AND (JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::Datetime(needle) => self.query = format!("{}AND (JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    _ => self.query = format!("{}AND (JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
                                 },
-                                _ => self.query = format!("{}AND (NOT JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
+                                _ => self.query = format!("{}AND (JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
                             }
                         }
                     }
@@ -2404,12 +3005,12 @@ impl<'a> QueryBuilder<'a> {
                         1 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
                         2 => match needle {
                             JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} AND (NOT JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
-                                ValueType::String(needle) => self.query = format!("{} AND (NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                ValueType::Datetime(needle) => self.query = format!("{} AND (NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                _ => self.query = format!("{} AND (NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                                ValueType::JsonString(needle) => self.query = format!("{} AND (JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
+                                ValueType::String(needle) => self.query = format!("{} AND (JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                ValueType::Datetime(needle) => self.query = format!("{} AND (JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                _ => self.query = format!("{} AND (JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
                             },
-                            _ => self.query = format!("{} AND (NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                            _ => self.query = format!("{} AND (JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
                         },
                         _ => {
                             let mut concatenated_string = String::new();
@@ -2424,13 +3025,13 @@ impl<'a> QueryBuilder<'a> {
 
                             match needle {
                                 JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}AND (NOT JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
-                                    ValueType::String(needle) => self.query = format!("{}AND (NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    ValueType::Datetime(needle) => self.query = format!("{}AND (NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    _ => self.query = format!("{}AND (NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
-                                },
-                                _ => self.query = format!("{}AND (NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
-                            }
+                                    ValueType::JsonString(needle) => self.query = format!("{}AND (JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
+                                    ValueType::String(needle) => self.query = format!("{}AND (JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    ValueType::Datetime(needle) => self.query = format!("{}AND (JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    _ => self.query = format!("{}AND (JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                                },
+                                _ => self.query = format!("{}AND (JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                            }
                         }
                     }
                 }
@@ -2446,12 +3047,12 @@ impl<'a> QueryBuilder<'a> {
                         1 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
                         2 => match needle {
                             JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} OR NOT JSON_CONTAINS({}, '\"{}\"', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::String(needle) => self.query = format!("{} OR NOT JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::Datetime(needle) => self.query = format!("{} OR NOT JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                _ => self.query = format!("{} OR NOT JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
+                                ValueType::JsonString(needle) => self.query = format!("{} OR JSON_CONTAINS({}, '\"{}\"', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::String(needle) => self.query = format!("{} OR JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::Datetime(needle) => self.query = format!("{} OR JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                _ => self.query = format!("{} OR JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
                             },
-                            _ => self.query = format!("{} OR NOT JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
+                            _ => self.query = format!("{} OR JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
                         },
                         _ => {
                             let mut concatenated_string = String::new();
@@ -2466,12 +3067,12 @@ impl<'a> QueryBuilder<'a> {
 
                             match needle {
                                 JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}OR NOT JSON_CONTAINS({}, '\"{}\"', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::String(needle) => self.query = format!("{}OR NOT JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::Datetime(needle) => self.query = format!("{}OR NOT JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    _ => self.query = format!("{}OR NOT JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
+                                    ValueType::JsonString(needle) => self.query = format!("{}OR JSON_CONTAINS({}, '\"{}\"', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::String(needle) => self.query = format!("{}OR JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::Datetime(needle) => self.query = format!("{}OR JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    _ => self.query = format!("{}OR JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
                                 },
-                                _ => self.query = format!("{}OR NOT JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
+                                _ => self.query = format!("{}OR JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
                             }
                         }
                     }
@@ -2486,12 +3087,12 @@ impl<'a> QueryBuilder<'a> {
                         1 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
                         2 => match needle {
                             JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} OR NOT JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
-                                ValueType::String(needle) => self.query = format!("{} OR NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                ValueType::Datetime(needle) => self.query = format!("{} OR NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                _ => self.query = format!("{} OR NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                                ValueType::JsonString(needle) => self.query = format!("{} OR JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
+                                ValueType::String(needle) => self.query = format!("{} OR JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                ValueType::Datetime(needle) => self.query = format!("{} OR JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                _ => self.query = format!("{} OR JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
                             },
-                            _ => self.query = format!("{} OR NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                            _ => self.query = format!("{} OR JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
                         },
                         _ => {
                             let mut concatenated_string = String::new();
@@ -2506,12 +3107,12 @@ impl<'a> QueryBuilder<'a> {
 
                             match needle {
                                 JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}OR NOT JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
-                                    ValueType::String(needle) => self.query = format!("{}OR NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    ValueType::Datetime(needle) => self.query = format!("{}OR NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    _ => self.query = format!("{}OR NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                                    ValueType::JsonString(needle) => self.query = format!("{}OR JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
+                                    ValueType::String(needle) => self.query = format!("{}OR JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    ValueType::Datetime(needle) => self.query = format!("{}OR JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    _ => self.query = format!("{}OR JSON_CONTAINS({}, {})", concatenated_string, column, needle)
                                 },
-                                _ => self.query = format!("{}OR NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                                _ => self.query = format!("{}OR JSON_CONTAINS({}, {})", concatenated_string, column, needle)
                             }
                         }
                     }
@@ -2528,12 +3129,12 @@ impl<'a> QueryBuilder<'a> {
                         1 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
                         2 => match needle {
                             JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} OR (NOT JSON_CONTAINS({}, '\"{}\"', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::String(needle) => self.query = format!("{} OR (NOT JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                ValueType::Datetime(needle) => self.query = format!("{} OR (NOT JSON_CONTAINS({}, '{}', '${}')", split_the_query[0], column, needle, path),
-                                _ => self.query = format!("{} OR (NOT JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
+                                ValueType::JsonString(needle) => self.query = format!("{} OR (JSON_CONTAINS({}, '\"{}\"', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::String(needle) => self.query = format!("{} OR (JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::Datetime(needle) => self.query = format!("{} OR (JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                _ => self.query = format!("{} OR (JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
                             },
-                            _ => self.query = format!("{} OR (NOT JSON_CONTAINS({}, {}, '${}')", split_the_query[0], column, needle, path)
+                            _ => self.query = format!("{} OR (JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
                         },
                         _ => {
                             let mut concatenated_string = String::new();
@@ -2548,12 +3149,12 @@ impl<'a> QueryBuilder<'a> {
 
                             match needle {
                                 JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}OR (NOT JSON_CONTAINS({}, '\"{}\"', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::String(needle) => self.query = format!("{}OR (NOT JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    ValueType::Datetime(needle) => self.query = format!("{}OR (NOT JSON_CONTAINS({}, '{}', '${}')", concatenated_string, column, needle, path),
-                                    _ => self.query = format!("{}OR (NOT JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
+                                    ValueType::JsonString(needle) => self.query = format!("{}OR (JSON_CONTAINS({}, '\"{}\"', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::String(needle) => self.query = format!("{}OR (JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::Datetime(needle) => self.query = format!("{}OR (JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    _ => self.query = format!("{}OR (JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
                                 },
-                                _ => self.query = format!("{}OR (NOT JSON_CONTAINS({}, {}, '${}')", concatenated_string, column, needle, path)
+                                _ => self.query = format!("{}OR (JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
                             }
                         }
                     }
@@ -2568,12 +3169,12 @@ impl<'a> QueryBuilder<'a> {
                         1 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
                         2 => match needle {
                             JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(needle) => self.query = format!("{} OR (NOT JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
-                                ValueType::String(needle) => self.query = format!("{} OR (NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                ValueType::Datetime(needle) => self.query = format!("{} OR (NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
-                                _ => self.query = format!("{} OR (NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                                ValueType::JsonString(needle) => self.query = format!("{} OR (JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
+                                ValueType::String(needle) => self.query = format!("{} OR (JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                ValueType::Datetime(needle) => self.query = format!("{} OR (JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                _ => self.query = format!("{} OR (JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
                             },
-                            _ => self.query = format!("{} OR (NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                            _ => self.query = format!("{} OR (JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
                         },
                         _ => {
                             let mut concatenated_string = String::new();
@@ -2588,2121 +3189,9551 @@ impl<'a> QueryBuilder<'a> {
 
                             match needle {
                                 JsonValue::Initial(initial) => match initial {
-                                    ValueType::JsonString(needle) => self.query = format!("{}OR (NOT JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
-                                    ValueType::String(needle) => self.query = format!("{}OR (NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    ValueType::Datetime(needle) => self.query = format!("{}OR (NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
-                                    _ => self.query = format!("{}OR (NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                                    ValueType::JsonString(needle) => self.query = format!("{}OR (JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
+                                    ValueType::String(needle) => self.query = format!("{}OR (JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    ValueType::Datetime(needle) => self.query = format!("{}OR (JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    _ => self.query = format!("{}OR (JSON_CONTAINS({}, {})", concatenated_string, column, needle)
                                 },
-                                _ => self.query = format!("{}OR (NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                                _ => self.query = format!("{}OR (JSON_CONTAINS({}, {})", concatenated_string, column, needle)
                             }
                         }
                     }
                 }
             },
-            _ => panic!("Wrong usage of '.not_json_contains()' method, it should be used later than either SELECT, WHERE, AND, OR keywords.")
+            _ => panic!("Wrong usage of '.json_contains()' method, it should be used later than either SELECT, WHERE, AND, OR keywords.")
         }
 
-        self.list.push(KeywordList::NotJsonContains);
+        self.drop_rewritten_bindings(&previous);
+
+        self.list.push(KeywordList::JsonContains);
 
         self
     }
 
-    /// it adds `JSON_ARRAY_APPEND()` mysql function with it's synthax. It's intended to used with only update constructor, don't use it with any other kind of query.
+    /// It applies "NOT JSON_CONTAINS()" mysql function with it's Synthax. The path can be a `JsonPath` or a text which is parsed as a `JsonPath`, the root path `"$"` searches the whole document. It panics on the other dialects. If you encounter any syntactic bugs or deficiencies about that function, please report it via opening an issue.
     /// 
     /// ```rust
     /// 
     /// use qubl::{QueryBuilder, ValueType, JsonValue};
     /// 
-    /// fn main () {
-    ///     let lesson = ("lesson", &ValueType::String("math".to_string()));
-    ///     let point = ("point", &ValueType::Int32(100));
-    ///
-    ///     let values = vec![lesson, point];
-    ///
-    ///     let object = JsonValue::MysqlJsonObject(&values);
-    ///
-    ///     let query = QueryBuilder::update().unwrap()
-    ///                                 .table("users")
-    ///                                 .json_array_append("points", Some(""), object.clone())
-    ///                                 .where_("id", "=", ValueType::Int8(1))
-    ///                                 .finish();
-    ///
-    ///     assert_eq!("UPDATE users SET points = JSON_ARRAY_APPEND(points, '$', JSON_OBJECT('lesson', 'math', 'point', 100)) WHERE id = 1;", query);
-    /// }
-    /// 
-    /// ```
-    pub fn json_array_append(&mut self, column: &str, path: Option<&str>, object: JsonValue) -> &mut Self {
-        match self.list.last() {
-            Some(keyword) => match keyword {
-                KeywordList::Set => {
-                    match path {
-                        Some(path) => match object {
-                            JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(object) => self.query = format!("{}, {} = JSON_ARRAY_APPEND({}, '${}', '\"{}\"')", self.query, column, column, path, object),
-                                ValueType::String(object) => self.query = format!("{}, {} = JSON_ARRAY_APPEND({}, '${}', '{}')", self.query, column, column, path, object),
-                                ValueType::Datetime(object) => self.query = format!("{}, {} = JSON_ARRAY_APPEND({}, '${}', '{}')", self.query, column, column, path, object),
-                                _ => self.query = format!("{}, {} = JSON_ARRAY_APPEND({}, '${}', {})", self.query, column, column, path, object),
-                            },
-                            _ => self.query = format!("{}, {} = JSON_ARRAY_APPEND({}, '${}', {})", self.query, column, column, path, object),
-                        }
-                        None => match object {
-                            JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(object) => self.query = format!("{}, {} = JSON_ARRAY_APPEND({}, '$', '\"{}\"')", self.query, column, column, object),
-                                ValueType::String(object) => self.query = format!("{}, {} = JSON_ARRAY_APPEND({}, '$', '{}')", self.query, column, column, object),
-                                ValueType::Datetime(object) => self.query = format!("{}, {} = JSON_ARRAY_APPEND({}, '$', '{}')", self.query, column, column, object),
-                                _ => self.query = format!("{}, {} = JSON_ARRAY_APPEND({}, '$', {})", self.query, column, column, object)
-                            },
-                            _ => self.query = format!("{}, {} = JSON_ARRAY_APPEND({}, '$', {})", self.query, column, column, object)
-                        }
-                    }
-                },
-                _ => {
-                    match path {
-                        Some(path) => match object {
-                            JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(object) => self.query = format!("{} SET {} = JSON_ARRAY_APPEND({}, '${}', '\"{}\"')", self.query, column, column, path, object),
-                                ValueType::String(object) => self.query = format!("{} SET {} = JSON_ARRAY_APPEND({}, '${}', '{}')", self.query, column, column, path, object),
-                                ValueType::Datetime(object) => self.query = format!("{} SET {} = JSON_ARRAY_APPEND({}, '${}', '{}')", self.query, column, column, path, object),
-                                _ => self.query = format!("{} SET {} = JSON_ARRAY_APPEND({}, '${}', {})", self.query, column, column, path, object),
-                            },
-                            _ => self.query = format!("{} SET {} = JSON_ARRAY_APPEND({}, '${}', {})", self.query, column, column, path, object),
-                        }
-                        None => match object {
-                            JsonValue::Initial(initial) => match initial {
-                                ValueType::JsonString(object) => self.query = format!("{} SET {} = JSON_ARRAY_APPEND({}, '$', '\"{}\"')", self.query, column, column, object),
-                                ValueType::String(object) => self.query = format!("{} SET {} = JSON_ARRAY_APPEND({}, '$', '{}')", self.query, column, column, object),
-                                ValueType::Datetime(object) => self.query = format!("{} SET {} = JSON_ARRAY_APPEND({}, '$', '{}')", self.query, column, column, object),
-                                _ => self.query = format!("{} SET {} = JSON_ARRAY_APPEND({}, '$', {})", self.query, column, column, object)
-                            },
-                            _ => self.query = format!("{} SET {} = JSON_ARRAY_APPEND({}, '$', {})", self.query, column, column, object)
-                        }
-                    }
-                }
-            },
-            None => panic!("it's impossible to came here!")
-        }
-
-        self.list.push(KeywordList::JsonArrayAppend);
-        self
-    }
-
-    /// it adds "JSON_REMOVE()" function with it's synthax. You cannot pass empty strings to paths.
+    /// fn main(){
+    ///     let value = ValueType::String("blablabla.jpg".to_string());
+    ///     let prop = vec![("name", &value)];
     /// 
-    /// ```rust
+    ///     let object = JsonValue::MysqlJsonObject(&prop);
     /// 
-    /// use qubl::{QueryBuilder, ValueType};
+    ///     let query = QueryBuilder::select(["*"].to_vec()).unwrap()
+    ///                              .table("users")
+    ///                              .where_("pic", "=", ValueType::String("".to_string()))
+    ///                              .not_json_contains("pic", object, ".name")
+    ///                              .finish();
     /// 
-    /// fn main () {
-    ///   let query = QueryBuilder::update().unwrap()
-    ///                            .table("blogs")
-    ///                            .json_remove("likes", vec!["[10]"])
-    ///                            .where_("blog_id", "=", ValueType::Int32(20))
-    ///                            .finish();
-    ///
-    ///   assert_eq!(query, "UPDATE blogs SET likes = JSON_REMOVE(likes, '$[10]') WHERE blog_id = 20;");
+    ///     assert_eq!(query, "SELECT * FROM users WHERE NOT JSON_CONTAINS(pic, JSON_OBJECT('name', 'blablabla.jpg'), '$.name');")
     /// }
     /// 
     /// ```
-    pub fn json_remove(&mut self, column: &str, paths: Vec<&str>) -> &mut Self {
-        match paths.iter().any(|path| *path == "") {
-            true => panic!("Error: a value in the paths cannot be empty string, panicking..."),
-            false => ()
+    pub fn not_json_contains<'v, V: Into<JsonValue<'v>>, P: Into<JsonPath>>(&mut self, column: &str, needle: V, path: P) -> &mut Self {
+        if !matches!(self.dialect, Dialect::Mysql) {
+            panic!("NOT JSON_CONTAINS() is mysql specific.")
         }
 
-        match self.list.last() {
-            Some(keyword) => match keyword {
-                KeywordList::Set => {
-                    self.query = format!("{}, {} = JSON_REMOVE({}", self.query, column, column);
-
-                    for path in paths {
-                        if path.starts_with("$") {
-                            self.query = format!("{}, '${}'", self.query, path.replace("$", ""))
-                        } else {
-                            self.query = format!("{}, '${}'", self.query, path)
-                        }
-                    }
-
-                    self.query = format!("{})", self.query)
-                },
-                _ => {
-                    self.query = format!("{} SET {} = JSON_REMOVE({}", self.query, column, column);
-
-                    for path in paths {
-                        if path.starts_with("$") {
-                            self.query = format!("{}, '${}'", self.query, path.replace("$", ""))
-                        } else {
-                            self.query = format!("{}, '${}'", self.query, path)
-                        }
-                    }
+        let path = Self::contained_path(path.into());
+        let needle = needle.into();
+        let previous = self.query.clone();
+        let document;
 
-                    self.query = format!("{})", self.query)
-                }
-            },
-            None => panic!("it's impossible to came here!")
-        }
+        let needle = match needle {
+            JsonValue::Owned(json) => {
+                let quoted = json.quoted(&Dialect::Mysql);
 
-        self.list.push(KeywordList::JsonRemove);
-        self
-    }
+                document = ValueType::String(quoted[1..quoted.len() - 1].to_string());
 
-    /// It adds `JSON_SET()` function with it's synthax. It updates values with the specified path.
-    /// 
-    /// ```rust
-    /// 
-    /// use qubl::{QueryBuilder, ValueType, JsonValue};
-    /// 
-    /// fn main () {
-    /// 
-    /// let lesson = ("lesson", &ValueType::String("math".to_string()));
-    /// let point = ("point", &ValueType::Int32(100));
-    ///
-    /// let values = vec![lesson, point];
-    ///
-    /// let object = JsonValue::MysqlJsonObject(&values);
-    ///
-    /// let query = QueryBuilder::update().unwrap()
-    ///                          .table("users")
-    ///                          .json_set("points", "[0]", object)
-    ///                          .where_("id", "=", ValueType::Int32(1))
-    ///                          .finish();
-    ///
-    /// assert_eq!("UPDATE users SET points = JSON_SET(points, '$[0]', JSON_OBJECT('lesson', 'math', 'point', 100)) WHERE id = 1;", query);
-    /// 
-    /// }
-    /// 
-    /// ```
-    pub fn json_set(&mut self, column: &str, path: &str, value: JsonValue) -> &mut Self {
-        match self.list.last() {
-            Some(keyword) => match keyword {
-                KeywordList::Set => match value {
-                    JsonValue::Initial(initial) => match initial {
-                        ValueType::JsonString(value) => self.query = format!("{}, {} = JSON_SET({}, '${}', '\"{}\"')", self.query, column, column, path, value),
-                        ValueType::String(value) => self.query = format!("{}, {} = JSON_SET({}, '${}', '{}')", self.query, column, column, path, value),
-                        ValueType::Datetime(value) => self.query = format!("{}, {} = JSON_SET({}, '${}', '{}')", self.query, column, column, path, value),
-                        _ => self.query = format!("{}, {} = JSON_SET({}, '${}', {})", self.query, column, column, path, value),
-                    },
-                    _ => self.query = format!("{}, {} = JSON_SET({}, '${}', {})", self.query, column, column, path, value),
-                }
-                _ => match value {
-                    JsonValue::Initial(initial) => match initial {
-                        ValueType::JsonString(value) => self.query = format!("{} SET {} = JSON_SET({}, '${}', '\"{}\"')", self.query, column, column, path, value),
-                        ValueType::String(value) => self.query = format!("{} SET {} = JSON_SET({}, '${}', '{}')", self.query, column, column, path, value),
-                        ValueType::Datetime(value) => self.query = format!("{} SET {} = JSON_SET({}, '${}', '{}')", self.query, column, column, path, value),
-                        _ => self.query = format!("{} SET {} = JSON_SET({}, '${}', {})", self.query, column, column, path, value)
-                    },
-                    _ => self.query = format!("{} SET {} = JSON_SET({}, '${}', {})", self.query, column, column, path, value)
-                }
+                JsonValue::Initial(&document)
             },
-            None => panic!("it's impossible to came here!")
-        }
-
-        self.list.push(KeywordList::JsonSet);
-        self
-    }
+            needle => needle
+        };
 
-    /// It adds `JSON_REPLACE()` function with it's synthax. It updates values with the specified path.
-    /// 
-    /// ```rust
-    /// 
-    /// use qubl::{QueryBuilder, ValueType, JsonValue};
-    /// 
-    /// fn main () {
-    /// 
-    /// let value = ValueType::Int32(100);
-    /// let value = JsonValue::Initial(&value);
-    ///
-    /// let query = QueryBuilder::update().unwrap()
-    ///                          .table("users")
-    ///                          .json_replace("points", "[0].point", value)
-    ///                          .where_("id", "=", ValueType::Int32(1))
-    ///                          .finish();
-    ///
-    /// assert_eq!("UPDATE users SET points = JSON_REPLACE(points, '$[0].point', 100) WHERE id = 1;", query)
-    /// 
-    /// }
-    /// 
-    /// ```
-    pub fn json_replace(&mut self, column: &str, path: &str, value: JsonValue) -> &mut Self {
-        match self.list.last() {
-            Some(keyword) => match keyword {
-                KeywordList::Set => match value {
+        match self.list.last().unwrap() {
+            KeywordList::Select => match path {
+                Some(path) => match needle {
                     JsonValue::Initial(initial) => match initial {
-                        ValueType::JsonString(value) => self.query = format!("{}, {} = JSON_REPLACE({}, '${}', '\"{}\"')", self.query, column, column, path, value),
-                        ValueType::String(value) => self.query = format!("{}, {} = JSON_REPLACE({}, '${}', '{}')", self.query, column, column, path, value),
-                        ValueType::Datetime(value) => self.query = format!("{}, {} = JSON_REPLACE({}, '${}', '{}')", self.query, column, column, path, value),
-                        _ => self.query = format!("{}, {} = JSON_REPLACE({}, '${}', {})", self.query, column, column, path, value),
+                        ValueType::JsonString(needle) => self.query = format!("SELECT NOT JSON_CONTAINS({}, '\"{}\"', '{}') FROM", column, needle, path),
+                        ValueType::String(needle) => self.query = format!("SELECT NOT JSON_CONTAINS({}, '{}', '{}') FROM", column, needle, path),
+                        ValueType::Datetime(needle) => self.query = format!("SELECT NOT JSON_CONTAINS({}, '{}', '{}') FROM", column, needle, path),
+                        _ => self.query = format!("SELECT NOT JSON_CONTAINS({}, {}, '{}') FROM", column, needle, path),
                     },
-                    _ => self.query = format!("{}, {} = JSON_REPLACE({}, '${}', {})", self.query, column, column, path, value),
+                    _ => self.query = format!("SELECT NOT JSON_CONTAINS({}, {}, '{}') FROM", column, needle, path),
                 }
-                _ => match value {
+                None => match needle {
                     JsonValue::Initial(initial) => match initial {
-                        ValueType::JsonString(value) => self.query = format!("{} SET {} = JSON_REPLACE({}, '${}', '\"{}\"')", self.query, column, column, path, value),
-                        ValueType::String(value) => self.query = format!("{} SET {} = JSON_REPLACE({}, '${}', '{}')", self.query, column, column, path, value),
-                        ValueType::Datetime(value) => self.query = format!("{} SET {} = JSON_REPLACE({}, '${}', '{}')", self.query, column, column, path, value),
-                        _ => self.query = format!("{} SET {} = JSON_REPLACE({}, '${}', {})", self.query, column, column, path, value)
+                        ValueType::JsonString(needle) => self.query = format!("SELECT NOT JSON_CONTAINS({}, '\"{}\"') FROM", column, needle),
+                        ValueType::String(needle) => self.query = format!("SELECT NOT JSON_CONTAINS({}, '{}') FROM", column, needle),
+                        ValueType::Datetime(needle) => self.query = format!("SELECT NOT JSON_CONTAINS({}, '{}') FROM", column, needle),
+                        _ => self.query = format!("SELECT NOT JSON_CONTAINS({}, {}) FROM", column, needle)
                     },
-                    _ => self.query = format!("{} SET {} = JSON_REPLACE({}, '${}', {})", self.query, column, column, path, value)
+                    _ => self.query = format!("SELECT NOT JSON_CONTAINS({}, {}) FROM", column, needle)
                 }
             },
-            None => panic!("it's impossible to came here!")
-        }
-
-        self.list.push(KeywordList::JsonSet);
-        self
-    }
+            KeywordList::Where => match path {
+                Some(path) => {
+                    let mut split_the_query = self.query.split(" WHERE ");
 
-    /// finishes the query and returns the result as string.
-    pub fn finish(&self) -> String {
-        return format!("{};", self.query);
-    }
+                    let first_half = split_the_query.nth(0);
 
-    /// gives you an immutable copy of that instance, just for case if you need to share and potentially mutate it across threads.
-    pub fn copy(&mut self) -> Self {
-        Self {
-            query: self.query.clone(),
-            table: self.table.clone(),
-            qtype: self.qtype.clone(),
-            list: self.list.clone(),
-            hq: self.hq
-        }
-    }
+                    match needle {
+                        JsonValue::Initial(initial) => match initial {
+                            ValueType::JsonString(needle) => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, '\"{}\"', '{}')", first_half.unwrap(), column, needle, path),
+                            ValueType::String(needle) => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, '{}', '{}')", first_half.unwrap(), column, needle, path),
+                            ValueType::Datetime(needle) => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, '{}', '{}')", first_half.unwrap(), column, needle, path),
+                            _ => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, {}, '{}')", first_half.unwrap(), column, needle, path)
+                        },
+                        _ => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, {}, '{}')", first_half.unwrap(), column, needle, path)
+                    }
+                },
+                None => {
+                    let mut split_the_query = self.query.split(" WHERE ");
 
-    fn load_hqs() -> [&'a str; 26] {
-        [";", "; drop", "admin' #", "admin'/*", "; union", "or 1 = 1",
-        "or 1 = 1#", "or 1 = 1/*", "or true = true", "or false = false", "or '1' = '1'", "or '1' = '1'#",
-        "or '1' = '1'/*", "; sleep(", "--", "drop table", "drop schema", "select if", "union select",
-        "union all", "exec", "master..", "masters..", "information_schema", "load_file", "alter user"]
-    }
+                    let first_half = split_the_query.nth(0);
 
-    fn sanitize_column(&mut self, column: &str)  -> std::result::Result<(), std::io::Error>  {
-        match self.hq {
-            Some(hqs) => {
-                for _hq in hqs.iter() {
-                    if &column == _hq {
-                        return Err(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "You cannot run arbitrary queries"))
+                    match needle {
+                        JsonValue::Initial(initial) => match initial {
+                            ValueType::JsonString(needle) => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, '\"{}\"')", first_half.unwrap(), column, needle),
+                            ValueType::String(needle) => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
+                            ValueType::Datetime(needle) => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
+                            _ => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
+                        },
+                        _ => self.query = format!("{} WHERE NOT JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
                     }
                 }
             },
-            None => return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "Your input is invalid."))
-        }
+            KeywordList::LeftBracketWhere => match path {
+                Some(path) => {
+                    let mut split_the_query = self.query.split(" WHERE ");
 
-        Ok(())
-    }
+                    let first_half = split_the_query.nth(0);
 
-    /// checks the inputs for potential sql injection patterns and throws error if they exist.
-    fn sanitize_columns(columns: &Vec<&str>, hqs: [&'a str; 26]) -> std::result::Result<(), std::io::Error> {
-        if columns.len() == 1 && columns[0] == "" {
-            return Ok(());
-        };
+                    match needle {
+                        JsonValue::Initial(initial) => match initial {
+                            ValueType::JsonString(needle) => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, '\"{}\"', '{}')", first_half.unwrap(), column, needle, path),
+                            ValueType::String(needle) => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, '{}', '{}')", first_half.unwrap(), column, needle, path),
+                            ValueType::Datetime(needle) => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, '{}', '{}')", first_half.unwrap(), column, needle, path),
+                            _ => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, {}, '{}')", first_half.unwrap(), column, needle, path)
+                        },
+                        _ => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, {}, '{}')", first_half.unwrap(), column, needle, path)
+                    }
+                },
+                None => {
+                    let mut split_the_query = self.query.split(" WHERE ");
 
-        for column in columns.iter() {
-            for hq in hqs.iter() {
-                if column == hq {
-                    return Err(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "You cannot run arbitrary queries"))
+                    let first_half = split_the_query.nth(0);
+
+                    match needle {
+                        JsonValue::Initial(initial) => match initial {
+                            ValueType::JsonString(needle) => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, '\"{}\"')", first_half.unwrap(), column, needle),
+                            ValueType::String(needle) => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
+                            ValueType::Datetime(needle) => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, '{}')", first_half.unwrap(), column, needle),
+                            _ => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
+                        },
+                        _ => self.query = format!("{} WHERE (NOT JSON_CONTAINS({}, {})", first_half.unwrap(), column, needle)
+                    }
                 }
-            }
-        }
+            },
+            KeywordList::And => match path {
+                Some(path) => {
+                    let split_the_query = self.query.split(" AND ").collect::<Vec<&str>>();
 
-        return Ok(())
-    }
+                    let length_of_the_split_the_query = split_the_query.len();
 
-    fn sanitize_inputs(inputs: &Vec<ValueType>, hqs: [&'a str; 26]) -> std::result::Result<(), std::io::Error> {
-        for input in inputs.iter() {
-            match input {
-                ValueType::String(string) | ValueType::Datetime(string) => {
-                    for hq in hqs.iter() {
-                        if &string == hq {
-                            return Err(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "You cannot run arbitrary queries"))
+                    match split_the_query.len() {
+                        0 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
+                        1 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
+                        2 => match needle {
+                            JsonValue::Initial(initial) => match initial {
+                                ValueType::JsonString(needle) => self.query = format!("{} AND NOT JSON_CONTAINS({}, '\"{}\"', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::String(needle) => self.query = format!("{} AND NOT JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::Datetime(needle) => self.query = format!("{} AND NOT JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                _ => self.query = format!("{} AND NOT JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
+                            },
+                            _ => self.query = format!("{} AND NOT JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
+                        },
+                        _ => {
+                            let mut concatenated_string = String::new();
+
+                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
+                                if index == 0 {
+                                    concatenated_string = chunk.to_string();
+                                } else if index + 1 != length_of_the_split_the_query {
+                                    concatenated_string = format!("{} AND {} ", concatenated_string, chunk)
+                                }
+                            }
+
+                            match needle {
+                                JsonValue::Initial(initial) => match initial {
+                                    ValueType::JsonString(needle) => self.query = format!("{}AND NOT JSON_CONTAINS({}, '\"{}\"', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::String(needle) => self.query = format!("{}AND NOT JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::Datetime(needle) => self.query = format!("{}AND NOT JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    _ => self.query = format!("{}AND NOT JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
+                                },
+                                _ => self.query = format!("{}AND NOT JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
+                            }
                         }
                     }
                 },
-                _ => continue
-            }
-        }
+                None => {
+                    let split_the_query = self.query.split(" AND ").collect::<Vec<&str>>();
 
-        return Ok(())
-    }
+                    let length_of_the_split_the_query = split_the_query.len();
 
-    fn sanitize_input(&mut self, input: &ValueType) -> std::result::Result<(), std::io::Error> {
-        match input {
-            ValueType::String(string) | ValueType::Datetime(string) => {
-                match self.hq {
-                    Some(hqs) => {
-                        for hq in hqs.iter() {
-                            if &string == hq {
-                                return Err(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "You cannot run arbitrary queries"))
+                    match split_the_query.len() {
+                        0 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
+                        1 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
+                        2 => match needle {
+                            JsonValue::Initial(initial) => match initial {
+                                ValueType::JsonString(needle) => self.query = format!("{} AND NOT JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
+                                ValueType::String(needle) => self.query = format!("{} AND NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                ValueType::Datetime(needle) => self.query = format!("{} AND NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                _ => self.query = format!("{} AND NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                            },
+                            _ => self.query = format!("{} AND NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                        },
+                        _ => {
+                            let mut concatenated_string = String::new();
+
+                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
+                                if index == 0 {
+                                    concatenated_string = chunk.to_string();
+                                } else if index + 1 != length_of_the_split_the_query {
+                                    concatenated_string = format!("{} AND {} ", concatenated_string, chunk)
+                                }
+                            }
+
+                            match needle {
+                                JsonValue::Initial(initial) => match initial {
+                                    ValueType::JsonString(needle) => self.query = format!("{}AND NOT JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
+                                    ValueType::String(needle) => self.query = format!("{}AND NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    ValueType::Datetime(needle) => self.query = format!("{}AND NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    _ => self.query = format!("{}AND NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                                },
+                                _ => self.query = format!("{}AND NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
                             }
                         }
-                    },
-                    None => return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "Your input is invalid."))
+                    }
                 }
             },
-            _ => return Ok(())
-        };
+            KeywordList::LeftBracketAnd => match path {
+                Some(path) => {
+                    let split_the_query = self.query.split(" AND ").collect::<Vec<&str>>();
 
-        Ok(())
-    }
+                    let length_of_the_split_the_query = split_the_query.len();
 
-    fn sanitize_mark(input: &str) -> std::result::Result<(), std::io::Error> {
-        return match input {
-            "=" | "<" | ">" | "<=" | ">=" | "!=" | "<>" => Ok(()),
-            _ => Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "comparison operators cannot be other than =, <, >, <=,  >=, != or <>."))
-        }
-    }
+                    match split_the_query.len() {
+                        0 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
+                        1 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
+                        2 => match needle {
+                            JsonValue::Initial(initial) => match initial {
+                                ValueType::JsonString(needle) => self.query = format!("{} AND (NOT JSON_CONTAINS({}, '\"{}\"', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::String(needle) => self.query = format!("{} AND (NOT JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::Datetime(needle) => self.query = format!("{} AND (NOT JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                _ => self.query = format!("{} AND (NOT JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
+                            },
+                            _ => self.query = format!("{} AND (NOT JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
+                        },
+                        _ => {
+                            let mut concatenated_string = String::new();
 
-    fn sanitize_str(&mut self, input: &str) -> std::result::Result<(), std::io::Error> {
-        match self.hq {
-            Some(hqs) => {
-                for hq in hqs.iter() {
-                    if *hq == input {
-                        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "comparison operators cannot be other than =, <, >, <=,  >=, != or <>."))
+                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
+                                if index == 0 {
+                                    concatenated_string = chunk.to_string();
+                                } else if index + 1 != length_of_the_split_the_query {
+                                    concatenated_string = format!("{} AND {} ", concatenated_string, chunk)
+                                }
+                            }
+
+                            match needle {
+                                JsonValue::Initial(initial) => match initial {
+                                    ValueType::JsonString(needle) => self.query = format!("{}AND (NOT JSON_CONTAINS({}, '\"{}\"', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::String(needle) => self.query = format!("{}AND (NOT JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::Datetime(needle) => self.query = format!("{}AND (NOT JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    _ => self.query = format!("{}AND (NOT JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
+                                },
+                                _ => self.query = format!("{}AND (NOT JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
+                            }
+                        }
+                    }
+                },
+                None => {
+                    let split_the_query = self.query.split(" AND ").collect::<Vec<&str>>();
+
+                    let length_of_the_split_the_query = split_the_query.len();
+
+                    match split_the_query.len() {
+                        0 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
+                        1 => panic!("There Is No AND query in QueryBuilder but The AND keyword exist in keyword list, panicking."),
+                        2 => match needle {
+                            JsonValue::Initial(initial) => match initial {
+                                ValueType::JsonString(needle) => self.query = format!("{} AND (NOT JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
+                                ValueType::String(needle) => self.query = format!("{} AND (NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                ValueType::Datetime(needle) => self.query = format!("{} AND (NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                _ => self.query = format!("{} AND (NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                            },
+                            _ => self.query = format!("{} AND (NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                        },
+                        _ => {
+                            let mut concatenated_string = String::new();
+
+                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
+                                if index == 0 {
+                                    concatenated_string = chunk.to_string();
+                                } else if index + 1 != length_of_the_split_the_query {
+                                    concatenated_string = format!("{} AND {} ", concatenated_string, chunk)
+                                }
+                            }
+
+                            match needle {
+                                JsonValue::Initial(initial) => match initial {
+                                    ValueType::JsonString(needle) => self.query = format!("{}AND (NOT JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
+                                    ValueType::String(needle) => self.query = format!("{}AND (NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    ValueType::Datetime(needle) => self.query = format!("{}AND (NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    _ => self.query = format!("{}AND (NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                                },
+                                _ => self.query = format!("{}AND (NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                            }
+                        }
                     }
                 }
             },
-            None => return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "comparison operators cannot be other than =, <, >, <=,  >=, != or <>."))
-        }
+            KeywordList::Or => match path {
+                Some(path) => {
+                    let split_the_query = self.query.split(" OR ").collect::<Vec<&str>>();
 
-        Ok(())
-    }
-}
+                    let length_of_the_split_the_query = split_the_query.len();
 
-/// Struct that benefits you to create and use schema's.
-#[derive(Debug, Clone)]
-pub struct SchemaBuilder {
-    pub query: String,
-    pub schema: String,
-    pub list: Vec<KeywordList>
-}
+                    match split_the_query.len() {
+                        0 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
+                        1 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
+                        2 => match needle {
+                            JsonValue::Initial(initial) => match initial {
+                                ValueType::JsonString(needle) => self.query = format!("{} OR NOT JSON_CONTAINS({}, '\"{}\"', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::String(needle) => self.query = format!("{} OR NOT JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::Datetime(needle) => self.query = format!("{} OR NOT JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                _ => self.query = format!("{} OR NOT JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
+                            },
+                            _ => self.query = format!("{} OR NOT JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
+                        },
+                        _ => {
+                            let mut concatenated_string = String::new();
 
-/// implementations fon SchemaBuilder
-impl SchemaBuilder {
-    pub fn create(name: &str) -> std::result::Result<Self, std::io::Error> {
-        if name.contains("!") ||
-           name.contains("-") ||
-           name.contains("=") ||
-           name.contains("+") ||
-           name.contains("%") ||
-           name.contains("$") ||
-           name.contains("&") ||
-           name.contains("#") ||
-           name.contains("[") ||
-           name.contains("]") ||
-           name.contains("{") ||
-           name.contains("}") ||
-           name.contains(":") ||
-           name.contains(";") ||
-           name.contains("'") ||
-           name.contains("\"") ||
-           name.contains(",") ||
-           name.contains(".") {
-                return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "Schame name cannot include these characters: '!', '-', '=', '+', '%', '$', '&', '#', '[', ']', '{', '}', ':', ';', '\"', \"'\", '.', ','"))
-        }
+                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
+                                if index == 0 {
+                                    concatenated_string = chunk.to_string();
+                                } else if index + 1 != length_of_the_split_the_query {
+                                    concatenated_string = format!("{} OR {} ", concatenated_string, chunk)
+                                }
+                            }
 
-        Ok(Self {
-            query: format!("CREATE DATABASE {}", name),
-            schema: name.to_string(),
-            list: vec![KeywordList::Create]
-        })
-    }
-
-    pub fn use_another_schema(name: &str) -> std::result::Result<Self, std::io::Error> {
-        if name.contains("!") ||
-        name.contains("-") ||
-        name.contains("=") ||
-        name.contains("+") ||
-        name.contains("%") ||
-        name.contains("$") ||
-        name.contains("&") ||
-        name.contains("#") ||
-        name.contains("[") ||
-        name.contains("]") ||
-        name.contains("{") ||
-        name.contains("}") ||
-        name.contains(":") ||
-        name.contains(";") ||
-        name.contains("'") ||
-        name.contains("\"") ||
-        name.contains(",") ||
-        name.contains(".") {
-             return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "Schame name cannot include these characters: '!', '-', '=', '+', '%', '$', '&', '#', '[', ']', '{', '}', ':', ';', '\"', \"'\", '.', ','"))
-        }
-
-        Ok(Self {
-            query: format!("USE {}", name),
-            schema: name.to_string(),
-            list: vec![KeywordList::Use, KeywordList::Create]
-        })
-    }
+                            match needle {
+                                JsonValue::Initial(initial) => match initial {
+                                    ValueType::JsonString(needle) => self.query = format!("{}OR NOT JSON_CONTAINS({}, '\"{}\"', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::String(needle) => self.query = format!("{}OR NOT JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::Datetime(needle) => self.query = format!("{}OR NOT JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    _ => self.query = format!("{}OR NOT JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
+                                },
+                                _ => self.query = format!("{}OR NOT JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
+                            }
+                        }
+                    }
+                },
+                None => {
+                    let split_the_query = self.query.split(" OR ").collect::<Vec<&str>>();
 
-    pub fn if_not_exists(&mut self) -> &mut Self {
-        match self.list[0] {
-            KeywordList::Create => (),
-            KeywordList::Table => (),
-            _ => panic!("if_not_exists method cannot be used without Create or Table queries")
-        }
+                    let length_of_the_split_the_query = split_the_query.len();
 
-        let split_the_query =  self.query.split(" DATABASE ").collect::<Vec<&str>>();
-        self.query = format!("{} DATABASE IF NOT EXISTS {}", split_the_query[0], split_the_query[1]);
+                    match split_the_query.len() {
+                        0 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
+                        1 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
+                        2 => match needle {
+                            JsonValue::Initial(initial) => match initial {
+                                ValueType::JsonString(needle) => self.query = format!("{} OR NOT JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
+                                ValueType::String(needle) => self.query = format!("{} OR NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                ValueType::Datetime(needle) => self.query = format!("{} OR NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                _ => self.query = format!("{} OR NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                            },
+                            _ => self.query = format!("{} OR NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                        },
+                        _ => {
+                            let mut concatenated_string = String::new();
 
-        self.list.insert(0, KeywordList::IfNotExist);
-        self
-    }
+                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
+                                if index == 0 {
+                                    concatenated_string = chunk.to_string();
+                                } else if index + 1 != length_of_the_split_the_query {
+                                    concatenated_string = format!("{} OR {} ", concatenated_string, chunk)
+                                }
+                            }
 
-    pub fn use_schema(&mut self, name: Option<&str>) -> &mut Self {
-        match name {
-            Some(schema_name) => {
-                self.query = format!("USE {}", schema_name)
+                            match needle {
+                                JsonValue::Initial(initial) => match initial {
+                                    ValueType::JsonString(needle) => self.query = format!("{}OR NOT JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
+                                    ValueType::String(needle) => self.query = format!("{}OR NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    ValueType::Datetime(needle) => self.query = format!("{}OR NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    _ => self.query = format!("{}OR NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                                },
+                                _ => self.query = format!("{}OR NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                            }
+                        }
+                    }
+                }
             },
-            None => {
-                self.query = format!("USE {}", self.schema);
-            }
-        }
-
-        self
-    }
-
-    pub fn finish(&self) -> String {
-        return format!("{};", self.query)
-    }
-}
-
-/// Struct that benefits you to create Tables. Currently incomplete thoug.
-#[derive(Debug, Clone)]
-pub struct TableBuilder {
-    pub query: String,
-    pub name: String,
-    pub schema: String,
-    pub all: Vec<String>,
-}
+            KeywordList::LeftBracketOr => match path {
+                Some(path) => {
+                    let split_the_query = self.query.split(" OR ").collect::<Vec<&str>>();
 
-/// Struct that benefits to define a foreign key.
-#[derive(Debug, Clone)]
-pub struct ForeignKey {
-    pub first: ForeignKeyItem,
-    pub second: ForeignKeyItem,
-    pub on_delete: Option<ForeignKeyActions>,
-    pub on_update: Option<ForeignKeyActions>,
-    pub constraint: Option<String>
-}
+                    let length_of_the_split_the_query = split_the_query.len();
 
-/// Struct that benefits you to add a foreign key item to a foreign key.
-#[derive(Debug, Clone)]
-pub struct ForeignKeyItem {
-    pub table: String,
-    pub column: String
-}
+                    match split_the_query.len() {
+                        0 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
+                        1 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
+                        2 => match needle {
+                            JsonValue::Initial(initial) => match initial {
+                                ValueType::JsonString(needle) => self.query = format!("{} OR (NOT JSON_CONTAINS({}, '\"{}\"', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::String(needle) => self.query = format!("{} OR (NOT JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                ValueType::Datetime(needle) => self.query = format!("{} OR (NOT JSON_CONTAINS({}, '{}', '{}')", split_the_query[0], column, needle, path),
+                                _ => self.query = format!("{} OR (NOT JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
+                            },
+                            _ => self.query = format!("{} OR (NOT JSON_CONTAINS({}, {}, '{}')", split_the_query[0], column, needle, path)
+                        },
+                        _ => {
+                            let mut concatenated_string = String::new();
 
-/// implementations for TableBuilder
-impl TableBuilder {
-    pub fn create(schema_name: &str, table_name: &str) -> Self {
-        return Self {
-            query: format!("CREATE TABLE {} (", table_name),
-            schema: schema_name.to_string(),
-            name: table_name.to_string(),
-            all: vec![]
-        }
-    }
+                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
+                                if index == 0 {
+                                    concatenated_string = chunk.to_string();
+                                } else if index + 1 != length_of_the_split_the_query {
+                                    concatenated_string = format!("{} OR {} ", concatenated_string, chunk)
+                                }
+                            }
 
-    pub fn if_not_exists(&mut self) -> &mut Self {
-        self.query = format!("{}IF NOT EXISTS (", self.query.replace("(", ""));
+                            match needle {
+                                JsonValue::Initial(initial) => match initial {
+                                    ValueType::JsonString(needle) => self.query = format!("{}OR (NOT JSON_CONTAINS({}, '\"{}\"', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::String(needle) => self.query = format!("{}OR (NOT JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    ValueType::Datetime(needle) => self.query = format!("{}OR (NOT JSON_CONTAINS({}, '{}', '{}')", concatenated_string, column, needle, path),
+                                    _ => self.query = format!("{}OR (NOT JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
+                                },
+                                _ => self.query = format!("{}OR (NOT JSON_CONTAINS({}, {}, '{}')", concatenated_string, column, needle, path)
+                            }
+                        }
+                    }
+                },
+                None => {
+                    let split_the_query = self.query.split(" OR ").collect::<Vec<&str>>();
 
-        self
-    }
+                    let length_of_the_split_the_query = split_the_query.len();
 
-    pub fn add_column(&mut self, column_name: &str) -> &mut Self {
-        if self.query.ends_with("(") {
-            self.query = format!("{}{}", self.query, column_name)
-        } else {
-            self.query = format!("{}, {}", self.query, column_name)
-        }
+                    match split_the_query.len() {
+                        0 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
+                        1 => panic!("There Is No OR query in QueryBuilder but The OR keyword exist in keyword list, panicking."),
+                        2 => match needle {
+                            JsonValue::Initial(initial) => match initial {
+                                ValueType::JsonString(needle) => self.query = format!("{} OR (NOT JSON_CONTAINS({}, '\"{}\"')",  split_the_query[0], column, needle),
+                                ValueType::String(needle) => self.query = format!("{} OR (NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                ValueType::Datetime(needle) => self.query = format!("{} OR (NOT JSON_CONTAINS({}, '{}')",  split_the_query[0], column, needle),
+                                _ => self.query = format!("{} OR (NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                            },
+                            _ => self.query = format!("{} OR (NOT JSON_CONTAINS({}, {})",  split_the_query[0], column, needle)
+                        },
+                        _ => {
+                            let mut concatenated_string = String::new();
 
-        self
-    }
+                            for (index, chunk) in  split_the_query.into_iter().enumerate() {
+                                if index == 0 {
+                                    concatenated_string = chunk.to_string();
+                                } else if index + 1 != length_of_the_split_the_query {
+                                    concatenated_string = format!("{} OR {} ", concatenated_string, chunk)
+                                }
+                            }
 
-    pub fn col_type(&mut self, type_name: &str) -> &mut Self {
-        if self.query.ends_with("(") {
-            panic!("Cannot add type before defining a column name.")
+                            match needle {
+                                JsonValue::Initial(initial) => match initial {
+                                    ValueType::JsonString(needle) => self.query = format!("{}OR (NOT JSON_CONTAINS({}, '\"{}\"')", concatenated_string, column, needle),
+                                    ValueType::String(needle) => self.query = format!("{}OR (NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    ValueType::Datetime(needle) => self.query = format!("{}OR (NOT JSON_CONTAINS({}, '{}')", concatenated_string, column, needle),
+                                    _ => self.query = format!("{}OR (NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                                },
+                                _ => self.query = format!("{}OR (NOT JSON_CONTAINS({}, {})", concatenated_string, column, needle)
+                            }
+                        }
+                    }
+                }
+            },
+            _ => panic!("Wrong usage of '.not_json_contains()' method, it should be used later than either SELECT, WHERE, AND, OR keywords.")
         }
 
-        self.query = format!("{} {}", self.query, type_name);
-
-        self
-    }
+        self.drop_rewritten_bindings(&previous);
 
-    pub fn null(&mut self) -> &mut Self {
-        self.query = format!("{} NULL", self.query);
+        self.list.push(KeywordList::NotJsonContains);
 
         self
     }
 
-    pub fn not_null(&mut self) -> &mut Self {
-        self.query = format!("{} NOT NULL", self.query);
+    /// it adds `JSON_ARRAY_APPEND()` mysql function with it's synthax, it appends the values to the arrays on the paths. It's intended to used with only update constructor, don't use it with any other kind of query. Use the `"$"` path for the root of the document. On sqlite and postgresql it's written with the equivalent functions, like `Expr::json_array_append()`.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, JsonValue};
+    /// 
+    /// fn main () {
+    ///     let lesson = ("lesson", &ValueType::String("math".to_string()));
+    ///     let point = ("point", &ValueType::Int32(100));
+    ///
+    ///     let values = vec![lesson, point];
+    ///
+    ///     let object = JsonValue::MysqlJsonObject(&values);
+    ///
+    ///     let query = QueryBuilder::update().unwrap()
+    ///                                 .table("users")
+    ///                                 .json_array_append("points", vec![("$", object.clone()), ("$[0].lessons", JsonValue::Initial(&ValueType::Int32(3)))])
+    ///                                 .where_("id", "=", ValueType::Int8(1))
+    ///                                 .finish();
+    ///
+    ///     assert_eq!("UPDATE users SET points = JSON_ARRAY_APPEND(points, '$', JSON_OBJECT('lesson', 'math', 'point', 100), '$[0].lessons', 3) WHERE id = 1;", query);
+    /// }
+    /// 
+    /// ```
+    pub fn json_array_append<'v, P: Into<JsonPath>, V: Into<JsonValue<'v>>>(&mut self, column: &str, pairs: Vec<(P, V)>) -> &mut Self {
+        let document = Expr::json_array_append(Expr::col(column), Self::json_pairs(pairs));
 
-        self
+        self.json_modification(column, document, KeywordList::JsonArrayAppend)
     }
 
-    pub fn auto_increment(&mut self) -> &mut Self {
-        self.query = format!("{} AUTO_INCREMENT", self.query);
+    /// it adds `JSON_ARRAY_INSERT()` mysql function with it's synthax, it inserts the values into the arrays on the positions of the paths and shifts the following elements. Sqlite doesn't support it.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, JsonValue};
+    /// 
+    /// fn main () {
+    ///     let first = ValueType::String("new".to_string());
+    ///     let second = ValueType::Int32(7);
+    ///
+    ///     let query = QueryBuilder::update().unwrap()
+    ///                                 .table("posts")
+    ///                                 .json_array_insert("tags", vec![("[0]", JsonValue::Initial(&first)), ("[2]", JsonValue::Initial(&second))])
+    ///                                 .where_("id", "=", ValueType::Int8(1))
+    ///                                 .finish();
+    ///
+    ///     assert_eq!(query, "UPDATE posts SET tags = JSON_ARRAY_INSERT(tags, '$[0]', 'new', '$[2]', 7) WHERE id = 1;");
+    /// }
+    /// 
+    /// ```
+    pub fn json_array_insert<'v, P: Into<JsonPath>, V: Into<JsonValue<'v>>>(&mut self, column: &str, pairs: Vec<(P, V)>) -> &mut Self {
+        let document = Expr::json_array_insert(Expr::col(column), Self::json_pairs(pairs));
 
-        self
+        self.json_modification(column, document, KeywordList::JsonArrayInsert)
     }
 
-    pub fn primary_key(&mut self) -> &mut Self {
-        if self.query.contains("PRIMARY KEY") {
-            panic!("A table cannot have two primary keys.")
-        }
-
-        self.query = format!("{} PRIMARY KEY", self.query);
-
-        self
-    }
-
-    pub fn default(&mut self, value: ValueType) -> &mut Self {
-        let split_the_query = self.query.clone();
-        let split_the_query = split_the_query.split(", ").collect::<Vec<&str>>();
-
-        let last_query = split_the_query[split_the_query.len() - 1];
-
-        if last_query.contains("INT") || 
-           last_query.contains("TINYINT") ||
-           last_query.contains("SMALLINT") ||
-           last_query.contains("MEDIUMINT") ||
-           last_query.contains("BIGINT") ||
-           last_query.contains("BIT") ||
-           last_query.contains("SERIAL") {
-            match value {
-                ValueType::Int8(int) => self.query = format!("{} DEFAULT {}", self.query, int),
-                ValueType::Int16(int) => self.query = format!("{} DEFAULT {}", self.query, int),
-                ValueType::Int32(int) => self.query = format!("{} DEFAULT {}", self.query, int),
-                ValueType::Int64(int) => self.query = format!("{} DEFAULT {}", self.query, int),
-                ValueType::Uint8(int) => self.query = format!("{} DEFAULT {}", self.query, int),
-                ValueType::Uint16(int) => self.query = format!("{} DEFAULT {}", self.query, int),
-                ValueType::Uint32(int) => self.query = format!("{} DEFAULT {}", self.query, int),
-                ValueType::Uint64(int) => self.query = format!("{} DEFAULT {}", self.query, int),
-                ValueType::Usize(int) => self.query = format!("{} DEFAULT {}", self.query, int),
-                ValueType::Float32(int) => self.query = format!("{} DEFAULT {}", self.query, int),
-                ValueType::Float64(int) => self.query = format!("{} DEFAULT {}", self.query, int),
-                _ => panic!("Error: If your column has the of the types of INT, TINYINT, SMALLINT, MEDIUMINT, BIGINT, BIT or SERIAL, it has to be an i8, i16, i32, i64, i128, usize, u8,  u16, u32, u64.")
-            }
-        }
+    /// it adds "JSON_REMOVE()" function with it's synthax. You cannot pass empty strings or root paths to paths. It's written with the `#-` operator on postgresql.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main () {
+    ///   let query = QueryBuilder::update().unwrap()
+    ///                            .table("blogs")
+    ///                            .json_remove("likes", vec!["[10]"])
+    ///                            .where_("blog_id", "=", ValueType::Int32(20))
+    ///                            .finish();
+    ///
+    ///   assert_eq!(query, "UPDATE blogs SET likes = JSON_REMOVE(likes, '$[10]') WHERE blog_id = 20;");
+    /// }
+    /// 
+    /// ```
+    pub fn json_remove<P: Into<JsonPath>>(&mut self, column: &str, paths: Vec<P>) -> &mut Self {
+        let paths = paths.into_iter().map(|path| path.into()).collect::<Vec<JsonPath>>();
 
-        if last_query.contains("BOOL") || 
-           last_query.contains("BOOLEAN") {
-            match value {
-                ValueType::Boolean(boolean) => self.query = format!("{} DEFAULT {}", self.query, boolean),
-                _ => panic!("If your column type is BOOLEAN, you have to write either true or false.")
-            }    
-        }
-
-        if last_query.contains("CHAR") ||
-           last_query.contains("VARCHAR") ||
-           last_query.contains("TEXT") ||
-           last_query.contains("TINYTEXT") ||
-           last_query.contains("MEDIUMTEXT") ||
-           last_query.contains("LONGTEXT") ||
-           last_query.contains("BINARY") ||
-           last_query.contains("VARBINARY") {
-            match value {
-                ValueType::String(ref text) => self.query = format!("{} DEFAULT '{}'", self.query, text),
-                _ => panic!("Error: if your column type is one of the types of CHAR, VARCHAR, TEXT, TINYTEXT, MEDIUMTEXT, LONGTEXT, BINARY or VARBINARY, your value type has to be String.")
-            }
+        match paths.iter().any(|path| path.steps.is_empty()) {
+            true => panic!("Error: a value in the paths cannot be empty string, panicking..."),
+            false => ()
         }
 
-        if last_query.contains("DATETIME") ||
-           last_query.contains("TIMESTAMP") {
-            match value {
-                ValueType::Datetime(datetime) => self.query = format!("{} DEFAULT {}", self.query, datetime),
-                _ => panic!("Error: if your column type is one of the types of CHAR, VARCHAR, TEXT, TINYTEXT, MEDIUMTEXT, LONGTEXT, BINARY or VARBINARY, your value type has to be String.")
-            }
-        }
+        let document = Expr::json_remove(Expr::col(column), paths);
 
-        self
+        self.json_modification(column, document, KeywordList::JsonRemove)
     }
 
-    pub fn unique(&mut self) -> &mut Self {
-        self.query = format!("{} UNIQUE", self.query);
+    /// It adds `JSON_SET()` function with it's synthax with the path & value pairs. It updates the values on the paths and adds them if they don't exist. It's written as nested `jsonb_set()` calls on postgresql.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, JsonValue};
+    /// 
+    /// fn main () {
+    /// 
+    /// let lesson = ("lesson", &ValueType::String("math".to_string()));
+    /// let point = ("point", &ValueType::Int32(100));
+    ///
+    /// let values = vec![lesson, point];
+    ///
+    /// let object = JsonValue::MysqlJsonObject(&values);
+    ///
+    /// let query = QueryBuilder::update().unwrap()
+    ///                          .table("users")
+    ///                          .json_set("points", vec![("[0]", object), ("[1].point", JsonValue::Initial(&ValueType::Int32(50)))])
+    ///                          .where_("id", "=", ValueType::Int32(1))
+    ///                          .finish();
+    ///
+    /// assert_eq!("UPDATE users SET points = JSON_SET(points, '$[0]', JSON_OBJECT('lesson', 'math', 'point', 100), '$[1].point', 50) WHERE id = 1;", query)
+    /// 
+    /// }
+    /// 
+    /// ```
+    pub fn json_set<'v, P: Into<JsonPath>, V: Into<JsonValue<'v>>>(&mut self, column: &str, pairs: Vec<(P, V)>) -> &mut Self {
+        let document = Expr::json_set(Expr::col(column), Self::json_pairs(pairs));
 
-        self
+        self.json_modification(column, document, KeywordList::JsonSet)
     }
 
-    pub fn check(&mut self, condition: &str) -> &mut Self {
-        self.query = format!("{} CHECK({})", self.query, condition);
+    /// It adds `JSON_INSERT()` function with it's synthax with the path & value pairs. It adds the values on the paths which don't exist, the existing values are kept. Postgresql doesn't support it.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, JsonValue};
+    /// 
+    /// fn main () {
+    /// let theme = ValueType::String("dark".to_string());
+    /// let size = ValueType::Int32(14);
+    ///
+    /// let query = QueryBuilder::update().unwrap()
+    ///                          .table("users")
+    ///                          .set("name", ValueType::String("arda".to_string()))
+    ///                          .json_insert("settings", vec![("theme", JsonValue::Initial(&theme)), ("font.size", JsonValue::Initial(&size))])
+    ///                          .where_("id", "=", ValueType::Int32(1))
+    ///                          .finish();
+    ///
+    /// assert_eq!(query, "UPDATE users SET name = 'arda', settings = JSON_INSERT(settings, '$.theme', 'dark', '$.font.size', 14) WHERE id = 1;")
+    /// }
+    /// 
+    /// ```
+    pub fn json_insert<'v, P: Into<JsonPath>, V: Into<JsonValue<'v>>>(&mut self, column: &str, pairs: Vec<(P, V)>) -> &mut Self {
+        let document = Expr::json_insert(Expr::col(column), Self::json_pairs(pairs));
 
-        self
+        self.json_modification(column, document, KeywordList::JsonInsert)
     }
 
-    pub fn character_set(&mut self, character_set: &str) -> &mut Self {
-        self.query = format!("{} CHARACTER SET {}", self.query, character_set);
+    /// It adds `JSON_REPLACE()` function with it's synthax with the path & value pairs. It updates the values on the paths which exist, the missing paths are ignored.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, JsonValue};
+    /// 
+    /// fn main () {
+    /// 
+    /// let value = ValueType::Int32(100);
+    /// let value = JsonValue::Initial(&value);
+    ///
+    /// let query = QueryBuilder::update().unwrap()
+    ///                          .table("users")
+    ///                          .json_replace("points", vec![("[0].point", value), ("[1].point", JsonValue::Initial(&ValueType::Int32(0)))])
+    ///                          .where_("id", "=", ValueType::Int32(1))
+    ///                          .finish();
+    ///
+    /// assert_eq!("UPDATE users SET points = JSON_REPLACE(points, '$[0].point', 100, '$[1].point', 0) WHERE id = 1;", query)
+    /// 
+    /// }
+    /// 
+    /// ```
+    pub fn json_replace<'v, P: Into<JsonPath>, V: Into<JsonValue<'v>>>(&mut self, column: &str, pairs: Vec<(P, V)>) -> &mut Self {
+        let document = Expr::json_replace(Expr::col(column), Self::json_pairs(pairs));
 
-        self
+        self.json_modification(column, document, KeywordList::JsonReplace)
     }
 
-    pub fn foreign_key(&mut self, opts: ForeignKey) -> &mut Self {
-        if self.query.starts_with("ALTER TABLE") {
-            match opts.constraint {
-                Some(constraint) => self.query = format!("{}, ADD CONSTRAINT {} FOREIGN KEY ({})", self.query, constraint, opts.first.column),
-                None => self.query = format!("{}, ADD FOREIGN KEY ({})", self.query, opts.first.column)
-            }
-            
-        } else {
-            match opts.constraint {
-                Some(constraint) => self.query = format!("{}, CONSTRAINT {} FOREIGN KEY ({})", self.query, constraint, opts.first.column),
-                None => self.query = format!("{}, FOREIGN KEY ({})", self.query, opts.first.column)
-            }
-        }
-
-        self.query = format!("{} REFERENCES {}({})", self.query, opts.second.table, opts.second.column);
+    /// It adds `JSON_MERGE_PATCH()` function with it's synthax. It merges the patches into the document by the rfc 7396, the null values in the patches remove the keys.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, JsonValue};
+    /// 
+    /// fn main () {
+    /// let theme = ValueType::String("light".to_string());
+    /// let props = vec![("theme", &theme), ("beta", &ValueType::Null)];
+    ///
+    /// let query = QueryBuilder::update().unwrap()
+    ///                          .table("users")
+    ///                          .json_merge_patch("settings", vec![JsonValue::MysqlJsonObject(&props)])
+    ///                          .where_("id", "=", ValueType::Int32(1))
+    ///                          .finish();
+    ///
+    /// assert_eq!(query, "UPDATE users SET settings = JSON_MERGE_PATCH(settings, JSON_OBJECT('theme', 'light', 'beta', NULL)) WHERE id = 1;")
+    /// }
+    /// 
+    /// ```
+    pub fn json_merge_patch<'v, V: Into<JsonValue<'v>>>(&mut self, column: &str, patches: Vec<V>) -> &mut Self {
+        let patches = patches.into_iter().map(|patch| patch.into()).collect::<Vec<JsonValue>>();
 
-        match opts.on_delete {
-            Some(on_delete_opt) => self.query = format!("{} ON DELETE {}", self.query, on_delete_opt),
-            None => ()
+        if patches.is_empty() {
+            panic!("you should pass at least one patch to the .json_merge_patch() method.")
         }
 
-        match opts.on_update {
-            Some(on_update_opt) => self.query = format!("{} ON UPDATE {}", self.query, on_update_opt),
-            None => ()
-        }
+        let mut documents = vec![Expr::col(column)];
+        documents.extend(patches.into_iter().map(Self::json_expr));
 
-        self
+        self.json_modification(column, Expr::json_merge_patch(documents), KeywordList::JsonMergePatch)
     }
 
-    pub fn unsigned(&mut self) -> &mut Self {
-        self.query = format!("{} UNSIGNED", self.query);
+    /// It adds `JSON_MERGE_PRESERVE()` function with it's synthax. It merges the documents into the column, the values of the same keys are combined into arrays.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType, JsonValue};
+    /// 
+    /// fn main () {
+    /// let tag = ValueType::String("sale".to_string());
+    /// let props = vec![("tags", &tag)];
+    ///
+    /// let query = QueryBuilder::update().unwrap()
+    ///                          .table("products")
+    ///                          .json_merge_preserve("meta", vec![JsonValue::MysqlJsonObject(&props)])
+    ///                          .finish();
+    ///
+    /// assert_eq!(query, "UPDATE products SET meta = JSON_MERGE_PRESERVE(meta, JSON_OBJECT('tags', 'sale'));")
+    /// }
+    /// 
+    /// ```
+    pub fn json_merge_preserve<'v, V: Into<JsonValue<'v>>>(&mut self, column: &str, documents: Vec<V>) -> &mut Self {
+        let documents = documents.into_iter().map(|document| document.into()).collect::<Vec<JsonValue>>();
 
-        self
-    }
+        if documents.is_empty() {
+            panic!("you should pass at least one document to the .json_merge_preserve() method.")
+        }
 
-    pub fn zerofill(&mut self) -> &mut Self {
-        self.query = format!("{} ZEROFILL", self.query);
+        let mut merged = vec![Expr::col(column)];
+        merged.extend(documents.into_iter().map(Self::json_expr));
 
-        self
+        self.json_modification(column, Expr::json_merge_preserve(merged), KeywordList::JsonMergePreserve)
     }
 
-    pub fn enum_sql(&mut self, enum_vec: Vec<&str>) -> &mut Self {
-        match enum_vec.len() {
-            0 => panic!("enum_vec argument cannot be an empty vector"),
-            _ => ()
+    /// the root path is left out of the "JSON_CONTAINS()" function, it searches the whole document.
+    fn contained_path(path: JsonPath) -> Option<String> {
+        match path.steps.is_empty() {
+            true => None,
+            false => Some(path.to_sql(&Dialect::Mysql).replace("'", "''"))
         }
-        
-        self.query = format!("{} ENUM(", self.query);
-
-        let length_of_enum_vec = enum_vec.len();
-        for (index, item) in enum_vec.into_iter().enumerate() {
-            if index + 1 == length_of_enum_vec {
-                self.query = format!("{}'{}'", self.query, item)
-            } else {
-                self.query = format!("{}'{}', ", self.query, item)
-            }
-        }
-
-        self
     }
 
-    pub fn generated_always(&mut self, condition: &str) -> &mut Self {
-        self.query = format!("{} GENERATED ALWAYS AS {}", self.query, condition);
+    fn json_modification(&mut self, column: &str, document: Expr, keyword: KeywordList) -> &mut Self {
+        let assignment = format!("{} = {}", column, document.to_sql(&self.dialect));
+
+        match self.list.last() {
+            Some(_) => match self.in_set_clause() {
+                true => self.query = format!("{}, {}", self.query, assignment),
+                false => {
+                    self.mark_clause(KeywordList::Set);
+                    self.query = format!("{} SET {}", self.query, assignment)
+                }
+            },
+            None => panic!("it's impossible to came here!")
+        }
 
+        self.list.push(keyword);
         self
     }
 
-    pub fn virtual_sql(&mut self) -> &mut Self {
-        self.query = format!("{} VIRTUAL", self.query);
-
-        self
+    /// the assignments are added to the SET clause if it's the last keyword, or if the tables which are joined after it are written in front of it.
+    fn in_set_clause(&self) -> bool {
+        match self.list.last() {
+            Some(KeywordList::Set) | Some(KeywordList::JsonArrayAppend) | Some(KeywordList::JsonArrayInsert) | Some(KeywordList::JsonRemove) |
+            Some(KeywordList::JsonSet) | Some(KeywordList::JsonInsert) | Some(KeywordList::JsonReplace) | Some(KeywordList::JsonMergePatch) |
+            Some(KeywordList::JsonMergePreserve) => true,
+            Some(KeywordList::InnerJoin) | Some(KeywordList::LeftJoin) | Some(KeywordList::RightJoin) | Some(KeywordList::CrossJoin) |
+            Some(KeywordList::NaturalJoin) | Some(KeywordList::IndexHint) => self.clause_start(&KeywordList::Set).is_some(),
+            _ => false
+        }
     }
 
-    pub fn stored(&mut self) -> &mut Self {
-        self.query = format!("{} STORED", self.query);
+    fn json_pairs<'v, P: Into<JsonPath>, V: Into<JsonValue<'v>>>(pairs: Vec<(P, V)>) -> Vec<(JsonPath, Expr)> {
+        if pairs.is_empty() {
+            panic!("you should pass at least one path & value pair to the json methods.")
+        }
 
-        self
+        pairs.into_iter().map(|(path, value)| (path.into(), Self::json_expr(value.into()))).collect()
     }
 
-    pub fn spatial(&mut self) -> &mut Self {
-        self.query = format!("{} SPATIAL", self.query);
+    /// converts the json value into an expression, so it's written for the dialect of the query.
+    fn json_expr(value: JsonValue) -> Expr {
+        let object = |props: &Vec<(&str, &ValueType)>| Expr::json_object(props.iter().map(|(key, value)| (*key, Expr::Value((*value).clone()))).collect());
 
-        self
+        match value {
+            JsonValue::Owned(json) => Expr::from(json),
+            JsonValue::Initial(ValueType::JsonString(value)) => Expr::Value(ValueType::String(format!("\"{}\"", value))),
+            JsonValue::Initial(value) => Expr::Value(value.clone()),
+            JsonValue::Object(props) | JsonValue::MysqlJsonObject(props) => object(props),
+            JsonValue::Array(values) => Expr::json_array(values.iter().map(|value| Expr::Value(value.clone())).collect()),
+            JsonValue::ObjectArray(objects) => Expr::json_array(objects.iter().map(object).collect())
+        }
     }
 
-    pub fn generated(&mut self) -> &mut Self {
-        self.query = format!("{} GENERATED", self.query);
+    /// It derives a count query from a select query, which gives you the total count of rows for offset pagination. It keeps the table, joins, WHERE and GROUP BY parts of the query and drops the ORDER BY, LIMIT and OFFSET parts. If the query is grouped, distinct or a union, it wraps the query with a subquery.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){
+    ///     let mut query = QueryBuilder::select(vec!["s.name", "g.point"]).unwrap();
+    ///     query.table("students s")
+    ///          .inner_join("grades g", "s.id", "=", "g.student_id")
+    ///          .where_("g.point", ">", ValueType::Int32(50))
+    ///          .order_by("g.point", "DESC")
+    ///          .limit(20)
+    ///          .offset(40);
+    /// 
+    ///     assert_eq!(query.to_count_query().finish(), "SELECT COUNT(*) FROM students s INNER JOIN grades g ON s.id = g.student_id WHERE g.point > 50;");
+    /// 
+    ///     let mut grouped = QueryBuilder::select(vec!["class", "COUNT(*)"]).unwrap();
+    ///     grouped.table("students").group_by("class");
+    /// 
+    ///     assert_eq!(grouped.to_count_query().finish(), "SELECT COUNT(*) FROM (SELECT class, COUNT(*) FROM students GROUP BY class) AS counted_rows;");
+    /// }
+    /// 
+    /// ```
+    pub fn to_count_query(&self) -> Self {
+        match self.qtype {
+            QueryType::Select => (),
+            _ => panic!("you can only derive a count query from a select query.")
+        }
 
-        self
-    }
+        let from = self.clause_start(&KeywordList::Table).map(|start| start - " FROM".len());
 
-    pub fn index(&mut self, indexes: Vec<&str>) -> &mut Self {
-        let length_of_indexes = indexes.len();
+        let needs_subquery = self.query["SELECT ".len()..from.unwrap_or("SELECT ".len())].starts_with("DISTINCT ") ||
+                             [KeywordList::GroupBy, KeywordList::Having, KeywordList::Union].iter().any(|keyword| self.clause_start(keyword).is_some());
 
-        match length_of_indexes {
-            0 => panic!("There is no index here."),
-            1 => self.query = format!("{}, INDEX({})", self.query, indexes[0]),
+        let (query, clauses, hints, bindings, binding_error) = match (needs_subquery, from) {
+            (false, Some(from)) => {
+                let query = format!("SELECT COUNT(*){}", &self.query[from..]);
+                let shift = query.len() - (self.query.len() - from);
+                let clauses = self.clauses.iter().map(|(keyword, start)| (keyword.clone(), start - from + shift)).collect();
+                let bindings = self.bindings.iter().filter(|(start, _)| *start >= from).map(|(start, literal)| (start - from + shift, literal.clone())).collect();
+
+                (query, clauses, self.hints.clone(), bindings, self.binding_error.clone())
+            },
             _ => {
-                for (i, index) in indexes.into_iter().enumerate() {
-                    if i + 1 == length_of_indexes {
-                        self.query = format!("{}{}", self.query, index);
+                let mut statement = self.clone();
 
-                        continue;
-                    }
+                statement.orderings.clear();
+                statement.row_limit = None;
+                statement.row_offset = None;
+                statement.into.clear();
 
-                    if i == 0 {
-                        self.query = format!("{}, INDEX ({}, ", self.query, index);
+                let mut count = statement.clone();
 
-                        continue;
-                    }
+                count.query.clear();
+                count.bindings.clear();
+                count.binding_error = None;
+                count.push_statement("SELECT COUNT(*) FROM (", &statement, ") AS counted_rows");
 
-                    self.query = format!("{}{}, ", self.query, index)
-                }
+                (count.query, vec![], vec![], count.bindings, count.binding_error)
             }
-        }
+        };
 
-        self
-    }
+        let mut list = vec![KeywordList::Count];
 
-    pub fn comment(&mut self, comment: &str) -> &mut Self {
-        self.query = format!("{} COMMENT '{}'", self.query, comment);
+        for keyword in self.list.iter().skip(1) {
+            match keyword {
+                KeywordList::OrderBy | KeywordList::Field | KeywordList::Limit | KeywordList::Offset => (),
+                _ => list.push(keyword.clone())
+            }
+        }
 
-        self
+        Self {
+            query,
+            table: self.table.clone(),
+            qtype: QueryType::Count,
+            list,
+            hq: self.hq,
+            dialect: self.dialect.clone(),
+            targets: vec![],
+            join_sources: vec![],
+            join_conditions: vec![],
+            orderings: vec![],
+            row_limit: None,
+            row_offset: None,
+            into: vec![],
+            clauses,
+            statements: self.statements.clone(),
+            hints,
+            bindings,
+            binding_error
+        }
     }
 
-    pub fn default_on_null(&mut self, value: ValueType) -> &mut Self {
-        match value {
-            ValueType::String(text) => self.query = format!("{} DEFAULT {} ON NULL", self.query, text),
-            _ => self.query = format!("{} DEFAULT {} ON NULL", self.query, value),
+    /// It returns the finished select query of the given page, which starts from 1, with it's LIMIT and OFFSET parts and the finished count query for the total count of the rows, in that order.
+    /// 
+    /// ```rust
+    /// 
+    /// use qubl::{QueryBuilder, ValueType};
+    /// 
+    /// fn main(){
+    ///     let mut query = QueryBuilder::select(vec!["*"]).unwrap();
+    ///     query.table("users").where_("age", ">", ValueType::Int32(25)).order_by("id", "ASC");
+    /// 
+    ///     let (select, count) = query.page(3, 20);
+    /// 
+    ///     assert_eq!(select, "SELECT * FROM users WHERE age > 25 ORDER BY id ASC LIMIT 20 OFFSET 40;");
+    ///     assert_eq!(count, "SELECT COUNT(*) FROM users WHERE age > 25;");
+    /// }
+    /// 
+    /// ```
+    pub fn page(&self, page: i32, per_page: i32) -> (String, String) {
+        if page < 1 {
+            panic!("pages start from 1, you cannot pass {} to the page argument.", page)
         }
 
-        self
-    }
+        if self.list.iter().any(|keyword| keyword == &KeywordList::Limit || keyword == &KeywordList::Offset) {
+            panic!("page method adds it's own LIMIT and OFFSET keywords, you cannot use it with .limit() or .offset() methods.")
+        }
 
-    pub fn invisible(&mut self) -> &mut Self {
-        self.query = format!("{} INVISIBLE", self.query);
+        let mut select = self.clone();
+        select.limit(per_page).offset((page - 1) * per_page);
 
-        self
+        (select.finish(), self.to_count_query().finish())
     }
 
-    pub fn custom_query(&mut self, query: &str) -> &mut Self {
-        self.query = format!("{} {}", self.query, query);
-
-        self
+    /// finishes the query and returns the result as string.
+    pub fn finish(&self) -> String {
+        format!("{};", self.render())
     }
 
-    pub fn finish(&mut self) -> String {
-        return format!("{});", self.query)
+    fn render(&self) -> String {
+        self.statements.iter().map(|statement| format!("{}; ", statement)).chain(std::iter::once(self.render_statement())).collect()
     }
-}
 
-/// KeywordList enum. It helps to syntactically correcting the queries. 
-#[derive(Debug, Clone, PartialEq)]
-pub enum KeywordList {
-    Select, Update, Delete, Insert, Count, Table, Where, Or, And, Set, 
-    Finish, OrderBy, GroupBy, Having, Like, Limit, Offset, IfNotExist, Create, Use, WhereIn, 
-    WhereNotIn, AndIn, AndNotIn, OrIn, OrNotIn, JsonExtract, JsonContains, NotJsonContains, JsonArrayAppend, JsonRemove, JsonSet, JsonReplace, 
-    Field, Union, UnionAll, Timezone, GlobalTimezone, InnerJoin, LeftJoin, RightJoin, LeftBracketWhere, LeftBracketAnd, LeftBracketOr, RightBracket
-}
+    /// renders the query without the statements which are put in front of it, such as `SET time_zone = ...`.
+    fn render_statement(&self) -> String {
+        self.render_bound().0
+    }
 
-/// QueryType enum. It helps to detect the type of a query with more optimized way when is needed.
-#[derive(Debug, Clone)]
-pub enum QueryType {
-    Select, Update, Delete, Insert, Null, Create, Count
-}
+    /// renders the statement and moves the places of the bound values to their places in it. The parts of the query are copied into the statement with `.copy_bound()`, the other parts are written between them.
+    fn render_bound(&self) -> (String, Vec<(usize, String)>) {
+        let mut text = String::new();
+        let mut bindings = vec![];
 
-/// BracketType enum. It helps you to open brackets with Corresponding keyword of it's variant on sql queries.
-#[derive(Debug, Clone)]
-pub enum BracketType {
-    Where, And, Or
-}
+        let mut targets = String::new();
 
-impl std::fmt::Display for BracketType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            BracketType::Where => write!(f, "WHERE"),
-            BracketType::And => write!(f, "AND"),
-            BracketType::Or => write!(f, "OR")
+        if let (QueryType::Delete, Dialect::Mysql) = (&self.qtype, &self.dialect) {
+            if !self.targets.is_empty() {
+                targets = format!(" {}", self.targets.join(", "));
+            } else if self.has_joins() {
+                targets = format!(" {}", self.table.split_whitespace().last().unwrap_or(""));
+            }
         }
-    }
-}
 
-/// ValueType enum. It benefits to detect and format the value with optimized way when you have to work with exact column values. 
-#[derive(Debug, Clone)]
-pub enum ValueType {
-    String(String), Datetime(String), Null, Boolean(bool), Int32(i32), Int16(i16), Int8(i8), Int64(i64), Int128(i128),
-    Uint8(u8), Uint16(u16), Uint32(u32), Uint64(u64), Usize(usize), Float32(f32), Float64(f64),
-    EpochTime(i64), JsonString(String)
-}
+        // the optimizer hints and the targets of the delete queries are put right after the first keyword of the query.
+        let keyword = match self.qtype {
+            QueryType::Update => "UPDATE",
+            QueryType::Delete => "DELETE",
+            _ => "SELECT"
+        };
 
-impl std::fmt::Display for ValueType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ValueType::String(string) => write!(f, "'{}'", string),
-            ValueType::JsonString(string) => write!(f, "\"{}\"", string),
-            ValueType::Datetime(datetime) => match datetime.as_str() {
-                "CURRENT_TIMESTAMP" | "UNIX_TIMESTAMP" | "CURRENT_DATE" | "CURRENT_TIME" | "NOW()" | "CURDATE()" | "CURTIME()" => write!(f, "{}", datetime),
-                _ => write!(f, "'{}'", datetime)
+        let head = match self.hints.is_empty() && targets.is_empty() {
+            true => 0,
+            false => keyword.len()
+        };
+
+        // the mysql specific insert keywords are written with the form of the dialect.
+        let (start, suffix) = match self.insert_keyword() {
+            Some((keyword, replacement, suffix)) => {
+                text.push_str(replacement);
+
+                (keyword.len(), suffix)
             },
-            ValueType::Null => write!(f, "NULL"),
-            ValueType::Boolean(val) => write!(f, "{}", val),
-            ValueType::Int8(val) => write!(f, "{}", val),
-            ValueType::Int16(val) => write!(f, "{}", val),
-            ValueType::Int32(val) => write!(f, "{}", val),
-            ValueType::Int64(val) => write!(f, "{}", val),
-            ValueType::Int128(val) => write!(f, "{}", val),
-            ValueType::Usize(val) => write!(f, "{}", val),
-            ValueType::Uint8(val) => write!(f, "{}", val),
-            ValueType::Uint16(val) => write!(f, "{}", val),
-            ValueType::Uint32(val) => write!(f, "{}", val),
-            ValueType::Uint64(val) => write!(f, "{}", val),
-            ValueType::Float32(val) => write!(f, "{}", val),
-            ValueType::Float64(val) => write!(f, "{}", val),
-            ValueType::EpochTime(val) => write!(f, "FROM_UNIXTIME({})", val),
-        }
-    }
-}
+            None => (0, "")
+        };
 
-impl From<String> for ValueType { fn from(value: String) -> Self { ValueType::String(value) } }
-impl From<bool> for ValueType { fn from(value: bool) -> Self { ValueType::Boolean(value) } }
-impl From<i8> for ValueType { fn from(value: i8) -> Self { ValueType::Int8(value) } }
-impl From<i16> for ValueType { fn from(value: i16) -> Self { ValueType::Int16(value) } }
-impl From<i32> for ValueType { fn from(value: i32) -> Self { ValueType::Int32(value) } }
-impl From<i64> for ValueType { fn from(value: i64) -> Self { ValueType::Int64(value) } }
-impl From<i128> for ValueType { fn from(value: i128) -> Self { ValueType::Int128(value) } }
-impl From<usize> for ValueType { fn from(value: usize) -> Self { ValueType::Usize(value) } }
-impl From<u8> for ValueType { fn from(value: u8) -> Self { ValueType::Uint8(value) } }
-impl From<u16> for ValueType { fn from(value: u16) -> Self { ValueType::Uint16(value) } }
-impl From<u32> for ValueType { fn from(value: u32) -> Self { ValueType::Uint32(value) } }
-impl From<u64> for ValueType { fn from(value: u64) -> Self { ValueType::Uint64(value) } }
-impl From<f32> for ValueType { fn from(value: f32) -> Self { ValueType::Float32(value) } }
-impl From<f64> for ValueType { fn from(value: f64) -> Self { ValueType::Float64(value) } }
+        let head = head.max(start);
 
+        self.copy_bound(&mut text, &mut bindings, start, head);
 
-impl Into<String> for ValueType {
-    fn into(self) -> String {
-        match self {
-            ValueType::String(text) => text,
-            ValueType::Datetime(datetime) => datetime,
-            _ => panic!("you cannot convert a ValueType to string unless it's not a String or Datetime variant.")
+        if !self.hints.is_empty() {
+            text.push_str(&format!(" /*+ {} */", self.hints.join(" ")));
         }
-    }
-}
 
-impl Into<bool> for ValueType {
-    fn into(self) -> bool {
-        match self {
-            ValueType::Boolean(val) => val,
-            ValueType::String(text) => match text.as_str() {
-                "false" | "" | "\0" | "0" => false,
-                _ => true,
+        text.push_str(&targets);
+
+        match self.join_sources.is_empty() {
+            true => self.copy_bound(&mut text, &mut bindings, head, self.query.len()),
+            false => {
+                let keyword = match self.qtype {
+                    QueryType::Update => "FROM",
+                    _ => "USING"
+                };
+
+                let sources = self.join_sources.join(" ");
+                let conditions = self.join_conditions.join(" AND ");
+
+                match self.clause_start(&KeywordList::Where) {
+                    Some(start) => {
+                        let end = self.clause_end(start);
+
+                        self.copy_bound(&mut text, &mut bindings, head, start);
+
+                        match conditions.as_str() {
+                            "" => {
+                                text.push_str(&format!(" {} {} WHERE ", keyword, sources));
+                                self.copy_bound(&mut text, &mut bindings, start + " WHERE ".len(), end);
+                            },
+                            _ => {
+                                text.push_str(&format!(" {} {} WHERE {} AND (", keyword, sources, conditions));
+                                self.copy_bound(&mut text, &mut bindings, start + " WHERE ".len(), end);
+                                text.push(')');
+                            }
+                        }
+
+                        self.copy_bound(&mut text, &mut bindings, end, self.query.len());
+                    },
+                    None => {
+                        self.copy_bound(&mut text, &mut bindings, head, self.query.len());
+
+                        match conditions.as_str() {
+                            "" => text.push_str(&format!(" {} {}", keyword, sources)),
+                            _ => text.push_str(&format!(" {} {} WHERE {}", keyword, sources, conditions))
+                        }
+                    }
+                }
             }
-            ValueType::Null => false,
-            ValueType::Int8(val) => match val == 0 {
-                false => true,
-                true => false
-            },
-            ValueType::Int16(val) => match val == 0 {
-                false => true,
-                true => false
-            },
-            ValueType::Int32(val) => match val == 0 {
-                false => true,
-                true => false
-            },
-            ValueType::Int64(val) => match val == 0 {
-                false => true,
-                true => false
-            },
-            ValueType::Int128(val) => match val == 0 {
-                false => true,
-                true => false
-            },
-            ValueType::Uint8(val) => match val == 0 {
-                false => true,
-                true => false
-            },
-            ValueType::Uint16(val) => match val == 0 {
-                false => true,
-                true => false
-            },
-            ValueType::Uint32(val) => match val == 0 {
-                false => true,
-                true => false
-            },
-            ValueType::Uint64(val) => match val == 0 {
-                false => true,
-                true => false
-            },
-            ValueType::Float32(val) => match val == 0.0 {
-                false => true,
-                true => false
-            },
-            ValueType::Float64(val) => match val == 0.0 {
-                false => true,
-                true => false
-            },
-            _ => panic!("invalid conversion")
         }
+
+        text.push_str(suffix);
+        text.push_str(&self.render_tail());
+
+        (text, bindings)
     }
-}
 
-impl Into<f32> for ValueType {
-    fn into(self) -> f32 {
-        match self {
-            ValueType::Float32(num) => num,
-            ValueType::Float64(num) => num as f32,
-            _ => panic!("invalid conversion")
+    /// returns the mysql keyword of the insert query, it's replacement on the dialect and the clause that is added to the end of the query.
+    fn insert_keyword(&self) -> Option<(&'static str, &'static str, &'static str)> {
+        match (&self.qtype, &self.dialect) {
+            (QueryType::Insert, Dialect::Sqlite) if self.query.starts_with("INSERT IGNORE INTO") => Some(("INSERT IGNORE INTO", "INSERT OR IGNORE INTO", "")),
+            (QueryType::Insert, Dialect::Sqlite) if self.query.starts_with("REPLACE INTO") => Some(("REPLACE INTO", "INSERT OR REPLACE INTO", "")),
+            (QueryType::Insert, Dialect::Postgresql) if self.query.starts_with("INSERT IGNORE INTO") => Some(("INSERT IGNORE INTO", "INSERT INTO", " ON CONFLICT DO NOTHING")),
+            _ => None
         }
     }
-}
 
-impl Into<f64> for ValueType {
-    fn into(self) -> f64 {
-        match self {
-            ValueType::Float32(num) => num as f64,
-            ValueType::Float64(num) => num,
-            _ => panic!("invalid conversion")
-        }
+    /// copies the given part of the query into the text, with the bound values in it.
+    fn copy_bound(&self, text: &mut String, bindings: &mut Vec<(usize, String)>, start: usize, end: usize) {
+        let shift = text.len() as isize - start as isize;
+
+        bindings.extend(self.bindings.iter().filter(|(offset, _)| *offset >= start && *offset < end).map(|(offset, literal)| ((*offset as isize + shift) as usize, literal.clone())));
+        text.push_str(&self.query[start..end]);
     }
-}
 
-impl Into<i8> for ValueType {
-    fn into(self) -> i8 {
-        match self {
-            ValueType::Int8(num) => num,
-            ValueType::Int16(num) => match num > 128 || num < -128 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i8
-            },
-            ValueType::Int32(num) => match num > 128 || num < -128 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i8
-            },
-            ValueType::Int64(num) => match num > 128 || num < -128 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i8
+    /// adds the condition to the WHERE clause with "AND", the existing condition is wrapped in parenthesis to keep it's precedence. If there isn't a WHERE clause yet, it's put in front of the GROUP BY and HAVING clauses. The bindings are the places of the bound values in the condition.
+    fn and_where(&mut self, condition: &str, bindings: Vec<(usize, String)>) {
+        if self.clause_start(&KeywordList::Union).is_some() {
+            panic!("conditions cannot be added to a union query, add them to the queries that are passed to the .union() method.")
+        }
+
+        let position = match self.clause_start(&KeywordList::Where) {
+            Some(start) => {
+                let end = self.clause_end(start);
+
+                self.insert_into_query(start + " WHERE ".len(), "(");
+                self.insert_into_query(end + 1, &format!(") AND {}", condition));
+                self.list.push(KeywordList::And);
+
+                end + 1 + ") AND ".len()
             },
-            ValueType::Int128(num) => match num > 128 || num < -128 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i8
+            None => {
+                let index = [KeywordList::GroupBy, KeywordList::Having].iter().filter_map(|keyword| self.clause_start(keyword)).min().unwrap_or(self.query.len());
+
+                self.insert_into_query(index, &format!(" WHERE {}", condition));
+                self.clauses.push((KeywordList::Where, index));
+                self.list.push(KeywordList::Where);
+
+                index + " WHERE ".len()
             }
-            ValueType::Uint8(num) => match num > 128 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i8
-            },
-            ValueType::Uint16(num) => match num > 128 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i8
-            },
-            ValueType::Uint32(num) => match num > 128 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i8
-            },
-            ValueType::Usize(num) => match num > 128 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i8
+        };
+
+        self.bindings.extend(bindings.into_iter().map(|(offset, literal)| (position + offset, literal)));
+    }
+
+    /// renders the expression with the places of it's literal values in it, for the prepared statements. The values inside of the function calls are written into the text.
+    fn bind_expr(&self, expr: &Expr) -> (String, Vec<(usize, String)>) {
+        match expr {
+            Expr::Value(value) if Self::bindable(value) => {
+                let text = expr.to_sql(&self.dialect);
+
+                (text.clone(), vec![(0, text)])
             },
-            ValueType::Uint64(num) => match num > 128 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i8
+            Expr::Binary(left, operator, right) => {
+                let (mut text, mut bindings) = self.bind_operand(left);
+                let (right, right_bindings) = self.bind_operand(right);
+
+                text = format!("{} {} ", text, operator);
+                bindings.extend(right_bindings.into_iter().map(|(offset, literal)| (text.len() + offset, literal)));
+                text.push_str(&right);
+
+                (text, bindings)
             },
-            _ => panic!("you cannot convert non numeric values into numeric ones.")
+            _ => (expr.to_sql(&self.dialect), vec![])
         }
     }
-}
 
-impl Into<i16> for ValueType {
-    fn into(self) -> i16 {
-        match self {
-            ValueType::Int8(num) => num as i16,
-            ValueType::Int16(num) => num,
-            ValueType::Int32(num) => match num > 32_768 || num < -32_768 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i16
-            },
-            ValueType::Int64(num) => match num > 32_768 || num < -32_768 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i16
-            },
-            ValueType::Int128(num) => match num > 32_768 || num < -32_768 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i16
-            }
-            ValueType::Uint8(num) => num as i16,
-            ValueType::Uint16(num) => match num > 32_768 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i16
-            },
-            ValueType::Uint32(num) => match num > 32_768 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i16
-            },
-            ValueType::Usize(num) => match num > 32_768 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i16
-            },
-            ValueType::Uint64(num) => match num > 32_768 {
-                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i16
+    fn bind_operand(&self, expr: &Expr) -> (String, Vec<(usize, String)>) {
+        match expr {
+            Expr::Binary(_, _, _) => {
+                let (text, bindings) = self.bind_expr(expr);
+
+                (format!("({})", text), bindings.into_iter().map(|(offset, literal)| (offset + 1, literal)).collect())
             },
-            _ => panic!("you cannot convert non numeric values into numeric ones.")
+            _ => self.bind_expr(expr)
         }
     }
-}
 
-impl Into<i32> for ValueType {
-    fn into(self) -> i32 {
-        match self {
-            ValueType::Int8(num) => num as i32,
-            ValueType::Int16(num) => num as i32,
-            ValueType::Int32(num) => num,
-            ValueType::Int64(num) => match num > 2_147_483_647 || num < -2_147_483_647 {
-                true => panic!("you cannot convert i32's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i32
-            },
-            ValueType::Int128(num) => match num > 2_147_483_647 || num < -2_147_483_647 {
-                true => panic!("you cannot convert i32's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i32
+    fn push_union(&mut self, keyword: &str, other: &QueryBuilder) {
+        self.push_statement(&format!(" {} (", keyword), other, ")");
+    }
+
+    /// appends the statement of the other query between the prefix and the suffix with it's bound values. If the bound values of the other query are lost, the error is kept for `.bound_statement()`.
+    fn push_statement(&mut self, prefix: &str, other: &QueryBuilder, suffix: &str) {
+        match other.bound_statement() {
+            Ok((text, bindings)) => self.push_bound(&format!("{}{}{}", prefix, text, suffix), bindings.into_iter().map(|(offset, literal)| (prefix.len() + offset, literal)).collect()),
+            Err(error) => {
+                self.query = format!("{}{}{}{}", self.query, prefix, other.render_statement(), suffix);
+                self.binding_error = Some(error);
             }
-            ValueType::Uint8(num) => num as i32,
-            ValueType::Uint16(num) => num as i32,
-            ValueType::Uint32(num) => match num > 2_147_483_647 {
-                true => panic!("you cannot convert i32's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i32
-            },
-            ValueType::Usize(num) => match num > 2_147_483_647 {
-                true => panic!("you cannot convert i32's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i32
-            },
-            ValueType::Uint64(num) => match num > 2_147_483_647 {
-                true => panic!("you cannot convert i32's into a value which is bigger than the capacity of 32 bit values."),
-                false => num as i32
-            }
-            _ => panic!("you cannot convert non numeric values into numeric ones.")
         }
     }
-}
 
-impl Into<i64> for ValueType {
-    fn into(self) -> i64 {
-        match self {
-            ValueType::EpochTime(epoch) => epoch as i64,
-            ValueType::Int8(num) => num as i64,
-            ValueType::Int16(num) => num as i64,
-            ValueType::Int32(num) => num as i64,
-            ValueType::Int64(num) => num,
-            ValueType::Usize(num) => num as i64,
-            ValueType::Uint8(num) => num as i64,
-            ValueType::Uint16(num) => num as i64,
-            ValueType::Uint32(num) => num as i64,
-            ValueType::Uint64(num) => num as i64,
-            _ => panic!("you cannot convert non numeric values into numeric ones.")
+    fn push_like(&mut self, operand: &str) {
+        let pattern = format!("'%{}%'", operand);
+
+        self.push_bound(&pattern, vec![(0, pattern.clone())]);
+    }
+
+    fn bind_value(value: &ValueType) -> (String, Vec<(usize, String)>) {
+        match Self::bindable(value) {
+            true => (value.to_string(), vec![(0, value.to_string())]),
+            false => (value.to_string(), vec![])
         }
     }
-}
 
-impl Into<u8> for ValueType {
-    fn into(self) -> u8 {
-        match self {
-            ValueType::Uint8(num) => num,
-            ValueType::Uint16(num) => match num > 255 {
-                true => panic!("you cannot convert u16's if it's value is bigger than capacity of 8 bit values"),
-                false => num as u8
-            },
-            ValueType::Uint32(num) => match num > 255 {
-                true => panic!("you cannot convert u32's if it's value is bigger than capacity of 8 bit values"),
-                false => num as u8
-            },
-            ValueType::Uint64(num) => match num > 255 {
-                true => panic!("you cannot convert u64's if it's value is bigger than capacity of 8 bit values"),
-                false => num as u8
-            },
-            ValueType::Usize(num) => match num > 255 {
-                true => panic!("you cannot convert usizes if it's value is bigger than capacity of 8 bit values"),
-                false => num as u8
-            },
-            ValueType::Int8(num) => match num < 0 {
-                true => panic!("you cannot convert i8's if it's value is lower than 0"),
-                false => num as u8
-            },
-            ValueType::Int16(num) => match num < 0 || num > 255 {
-                true => panic!("you cannot convert i16's if it's value is lower than 0 or has a value which is bigger than capacity of 8 bit values."),
-                false => num as u8
-            },
-            ValueType::Int32(num) => match num < 0 || num > 255 {
-                true => panic!("you cannot convert i32's if it's value is lower than 0 or has a value which is bigger than capacity of 8 bit values."),
-                false => num as u8
-            },
-            ValueType::Int64(num) => match num < 0 || num > 255 {
-                true => panic!("you cannot convert 64's if it's value is lower than 0 or has a value which is bigger than capacity of 8 bit values."),
-                false => num as u8
-            },
-            ValueType::Int128(num) => match num < 0 || num > 255 {
-                true => panic!("you cannot convert i128's if it's value is lower than 0 or has a value which is bigger than capacity of 8 bit values."),
-                false => num as u8
+    /// NULL values, epoch times and the date keywords are written into the statement, the others are bound.
+    fn bindable(value: &ValueType) -> bool {
+        match value {
+            ValueType::Null | ValueType::EpochTime(_) => false,
+            ValueType::Datetime(datetime) => SqlKeyword::from_name(datetime).is_none(),
+            _ => true
+        }
+    }
+
+    /// appends the text into the query and remembers the places of the bound values in it.
+    fn push_bound(&mut self, text: &str, bindings: Vec<(usize, String)>) {
+        let start = self.query.len();
+
+        self.query.push_str(text);
+        self.bindings.extend(bindings.into_iter().map(|(offset, literal)| (start + offset, literal)));
+    }
+
+    /// renders the statement like `.render_statement()` does, and returns the places of the bound values in it. It returns an error if a bound value isn't in it's remembered place anymore, that means the part of the query which holds it is rewritten.
+    fn bound_statement(&self) -> std::result::Result<(String, Vec<(usize, String)>), String> {
+        if let Some(error) = &self.binding_error {
+            return Err(error.clone())
+        }
+
+        let mut bindings = self.bindings.clone();
+        bindings.sort_by_key(|(start, _)| *start);
+
+        let mut end = 0;
+
+        for (start, literal) in bindings.iter() {
+            if *start < end || self.query.get(*start..start + literal.len()) != Some(literal.as_str()) {
+                return Err(format!("the bound value {} is not in it's place in the query, the query is rewritten after it's added.", literal))
             }
-            _ => panic!("you cannot convert non numeric values into numeric ones.")
+
+            end = start + literal.len();
         }
+
+        Ok(self.render_bound())
     }
-}
 
-impl Into<u16> for ValueType {
-    fn into(self) -> u16 {
-        match self {
-            ValueType::Uint8(num) => num as u16,
-            ValueType::Uint16(num) => num,
-            ValueType::Uint32(num) => match num > 65_535 {
-                true => panic!("you cannot convert u32's if it's value is bigger than capacity of 32 bit values"),
-                false => num as u16
-            },
-            ValueType::Uint64(num) => match num > 65_535 {
-                true => panic!("you cannot convert u64's if it's value is bigger than capacity of 32 bit values"),
-                false => num as u16
-            },
-            ValueType::Usize(num) => match num > 65_535 {
-                true => panic!("you cannot convert usizes if it's value is bigger than capacity of 32 bit values"),
-                false => num as u16
-            },
-            ValueType::Int8(num) => match num < 0 {
-                true => panic!("you cannot convert i8's if it's value is lower than 0"),
-                false => num as u16
-            },
-            ValueType::Int16(num) => match num < 0 {
-                true => panic!("you cannot convert i16's if it's value is lower than 0"),
-                false => num as u16
-            },
-            ValueType::Int32(num) => match num < 0 || num > 65_535 {
-                true => panic!("you cannot convert i32's if it's value is lower than 0 or has a value which is bigger than capacity of 16 bit values."),
-                false => num as u16
-            },
-            ValueType::Int64(num) => match num < 0 || num > 65_535 {
-                true => panic!("you cannot convert i64's if it's value is lower than 0 or has a value which is bigger than capacity of 16 bit values."),
-                false => num as u16
-            },
-            ValueType::Int128(num) => match num < 0 || num > 65_535 {
-                true => panic!("you cannot convert i128's if it's value is lower than 0 or has a value which is bigger than capacity of 16 bit values."),
-                false => num as u16
+    /// remembers where the given clause starts in the query. The rendering and the derived queries find the clauses with it instead of searching them in the text, which may contain the same words in it's values.
+    fn mark_clause(&mut self, keyword: KeywordList) {
+        if self.clause_start(&keyword).is_none() {
+            self.clauses.push((keyword, self.query.len()));
+        }
+    }
+
+    fn clause_start(&self, keyword: &KeywordList) -> Option<usize> {
+        self.clauses.iter().find(|(clause, _)| clause == keyword).map(|(_, start)| *start)
+    }
+
+    /// the end of the clause which starts from the given index, that is the start of the next clause or the end of the query.
+    fn clause_end(&self, start: usize) -> usize {
+        self.clauses.iter().map(|(_, index)| *index).filter(|index| *index > start).min().unwrap_or(self.query.len())
+    }
+
+    /// inserts the text into the given index of the query and shifts the clauses and the bound values which start later than it.
+    fn insert_into_query(&mut self, index: usize, text: &str) {
+        self.query.insert_str(index, text);
+
+        for (_, start) in self.clauses.iter_mut() {
+            if *start >= index {
+                *start += text.len();
+            }
+        }
+
+        for (start, _) in self.bindings.iter_mut() {
+            if *start >= index {
+                *start += text.len();
             }
-            _ => panic!("you cannot convert non numeric values into numeric ones.")
         }
     }
-}
 
-impl Into<u32> for ValueType {
-    fn into(self) -> u32 {
-        match self {
-            ValueType::Uint8(num) => num as u32,
-            ValueType::Uint16(num) => num as u32,
-            ValueType::Uint32(num) => num,
-            ValueType::Uint64(num) => match num > 4_294_967_295 {
-                true => panic!("you cannot convert u64's if it's value is bigger than capacity of 32 bit values"),
-                false => num as u32
-            },
-            ValueType::Usize(num) => match num > 4_294_967_295 {
-                true => panic!("you cannot convert usizes if it's value is bigger than capacity of 32 bit values"),
-                false => num as u32
-            },
-            ValueType::Int8(num) => match num < 0 {
-                true => panic!("you cannot convert i8's if it's value is lower than 0"),
-                false => num as u32
-            },
-            ValueType::Int16(num) => match num < 0 {
-                true => panic!("you cannot convert i16's if it's value is lower than 0"),
-                false => num as u32
-            },
-            ValueType::Int32(num) => match num < 0 {
-                true => panic!("you cannot convert i32's if it's value is lower than 0"),
-                false => num as u32
-            },
-            ValueType::Int64(num) => match num < 0 || num > 4_294_967_295 {
-                true => panic!("you cannot convert i64's if it's value is lower than 0 or has a value which is bigger than capacity of 32 bit values."),
-                false => num as u32
-            },
-            ValueType::Int128(num) => match num < 0 || num > 4_294_967_295 {
-                true => panic!("you cannot convert i128's if it's value is lower than 0 or has a value which is bigger than capacity of 32 bit values."),
-                false => num as u32
+    /// replaces the part of the query between the positions, the clauses and the bound values after it are moved and the bound values inside of it are dropped.
+    fn replace_in_query(&mut self, start: usize, end: usize, text: &str) {
+        self.query.replace_range(start..end, text);
+        self.bindings.retain(|(offset, literal)| *offset >= end || offset + literal.len() <= start);
+
+        for (_, position) in self.clauses.iter_mut() {
+            if *position >= end {
+                *position = *position - (end - start) + text.len();
+            }
+        }
+
+        for (position, _) in self.bindings.iter_mut() {
+            if *position >= end {
+                *position = *position - (end - start) + text.len();
             }
-            _ => panic!("you cannot convert non numeric values into numeric ones.")
         }
     }
-}
 
-impl Into<u64> for ValueType {
-    fn into(self) -> u64 {
-        match self {
-            ValueType::Usize(num) => num as u64,
-            ValueType::Uint8(num) => num as u64,
-            ValueType::Uint16(num) => num as u64,
-            ValueType::Uint32(num) => num as u64,
-            ValueType::Uint64(num) => num,
-            ValueType::Int8(num) => match num < 0 {
-                true => panic!("you cannot turn a negative value into u64"),
-                false => num as u64
-            },
-            ValueType::Int16(num) => match num < 0 {
-                true => panic!("you cannot turn a negative value into u64"),
-                false => num as u64
-            },
-            ValueType::Int32(num) => match num < 0 {
-                true => panic!("you cannot turn a negative value into u64"),
-                false => num as u64
-            },
-            ValueType::Int64(num) => match num < 0 {
-                true => panic!("you cannot turn a negative value into u64"),
-                false => num as u64
+    /// writes the json extraction in place of the column of the last condition, so the value of the condition keeps it's binding.
+    fn extract_in_condition(&mut self, keyword: &str, column: &str, extracted: &str) {
+        if let Some(index) = self.query.rfind(&format!(" {} {} ", keyword, column)) {
+            let start = index + keyword.len() + 2;
+
+            self.replace_in_query(start, start + column.len(), extracted);
+        }
+    }
+
+    /// drops the bound values which are not in their place after the end of the query is rewritten, they're kept in the text.
+    fn drop_rewritten_bindings(&mut self, previous: &str) {
+        let kept = previous.bytes().zip(self.query.bytes()).take_while(|(a, b)| a == b).count();
+
+        self.bindings.retain(|(offset, literal)| offset + literal.len() <= kept);
+    }
+
+    /// renders the "ORDER BY", "LIMIT", "OFFSET" and "INTO" parts, which are always put on the end of the query.
+    fn render_tail(&self) -> String {
+        let mut tail = String::new();
+
+        if !self.orderings.is_empty() {
+            tail = format!(" ORDER BY {}", self.orderings.join(", "));
+        }
+
+        if let Some(limit) = self.row_limit {
+            tail = format!("{} LIMIT {}", tail, limit);
+        }
+
+        if let Some(offset) = self.row_offset {
+            tail = format!("{} OFFSET {}", tail, offset);
+        }
+
+        if !self.into.is_empty() {
+            tail = format!("{} INTO {}", tail, self.into.join(", "));
+        }
+
+        tail
+    }
+
+    /// writes the end parts of the query into it, for the methods which should put something after them.
+    fn flush_tail(&mut self) {
+        self.query = format!("{}{}", self.query, self.render_tail());
+
+        self.orderings.clear();
+        self.row_limit = None;
+        self.row_offset = None;
+        self.into.clear();
+    }
+
+    /// gives you an immutable copy of that instance, just for case if you need to share and potentially mutate it across threads.
+    pub fn copy(&mut self) -> Self {
+        Self {
+            query: self.query.clone(),
+            table: self.table.clone(),
+            qtype: self.qtype.clone(),
+            list: self.list.clone(),
+            hq: self.hq,
+            dialect: self.dialect.clone(),
+            targets: self.targets.clone(),
+            join_sources: self.join_sources.clone(),
+            join_conditions: self.join_conditions.clone(),
+            orderings: self.orderings.clone(),
+            row_limit: self.row_limit,
+            row_offset: self.row_offset,
+            into: self.into.clone(),
+            clauses: self.clauses.clone(),
+            statements: self.statements.clone(),
+            hints: self.hints.clone(),
+            bindings: self.bindings.clone(),
+            binding_error: self.binding_error.clone()
+        }
+    }
+
+    fn has_joins(&self) -> bool {
+        self.list.iter().any(|keyword| matches!(keyword, KeywordList::InnerJoin | KeywordList::LeftJoin | KeywordList::RightJoin | KeywordList::CrossJoin | KeywordList::NaturalJoin))
+    }
+
+    fn check_order_and_limit(&self, keyword: &str) {
+        match self.qtype {
+            QueryType::Select | QueryType::Count => (),
+            QueryType::Update | QueryType::Delete => {
+                if self.dialect != Dialect::Mysql {
+                    panic!("{} can only be used with update and delete queries on mysql.", keyword)
+                }
+
+                if self.has_joins() {
+                    panic!("mysql does not support {} on multi table update and delete queries.", keyword)
+                }
+
+                if keyword == "OFFSET" {
+                    panic!("mysql does not support OFFSET on update and delete queries, only ORDER BY and LIMIT.")
+                }
             },
-            ValueType::Int128(num) => match num < 0 {
-                true => panic!("you cannot turn a negative value into u64"),
-                false => num as u64
+            _ => panic!("{} can only be used with select, update and delete queries.", keyword)
+        }
+    }
+
+    fn load_hqs() -> [&'a str; 26] {
+        [";", "; drop", "admin' #", "admin'/*", "; union", "or 1 = 1",
+        "or 1 = 1#", "or 1 = 1/*", "or true = true", "or false = false", "or '1' = '1'", "or '1' = '1'#",
+        "or '1' = '1'/*", "; sleep(", "--", "drop table", "drop schema", "select if", "union select",
+        "union all", "exec", "master..", "masters..", "information_schema", "load_file", "alter user"]
+    }
+
+    fn sanitize_column(&mut self, column: &str)  -> std::result::Result<(), std::io::Error>  {
+        match self.hq {
+            Some(hqs) => {
+                for _hq in hqs.iter() {
+                    if &column == _hq {
+                        return Err(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "You cannot run arbitrary queries"))
+                    }
+                }
             },
-            _ => panic!("you cannot convert non numeric values into numeric ones.")
+            None => return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "Your input is invalid."))
         }
+
+        Ok(())
     }
-}
 
-impl Into<usize> for ValueType {
-    fn into(self) -> usize {
-        match self {
-            ValueType::Int8(num) => match num < 0 {
-                true => panic!("you cannot convert negative numbers to usize"),
-                false => num as usize
-            },
-            ValueType::Int16(num) => match num < 0 {
-                true => panic!("you cannot convert negative numbers to usize"),
-                false => num as usize
-            },
-            ValueType::Int32(num) => match num < 0 {
-                true => panic!("you cannot convert negative numbers to usize"),
-                false => num as usize
-            },
-            ValueType::Int64(num) => match num < 0 {
-                true => panic!("you cannot convert negative numbers to usize"),
-                false => num as usize
-            },
-            ValueType::Int128(num) => match num < 0 {
-                true => panic!("you cannot convert negative numbers to usize"),
-                false => num as usize
-            },
-            ValueType::Usize(num) => num,
-            ValueType::Uint8(num) => num as usize,
-            ValueType::Uint16(num) => num as usize,
-            ValueType::Uint32(num) => num as usize,
-            ValueType::Uint64(num) => num as usize,
-            _ => panic!("you cannot convert non numeric values into numeric ones.")
-        }
+    /// checks the inputs for potential sql injection patterns and throws error if they exist.
+    fn sanitize_columns(columns: &Vec<&str>, hqs: [&'a str; 26]) -> std::result::Result<(), std::io::Error> {
+        if columns.len() == 1 && columns[0] == "" {
+            return Ok(());
+        };
+
+        for column in columns.iter() {
+            for hq in hqs.iter() {
+                if column == hq {
+                    return Err(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "You cannot run arbitrary queries"))
+                }
+            }
+        }
+
+        return Ok(())
+    }
+
+    fn sanitize_inputs(inputs: &Vec<ValueType>, hqs: [&'a str; 26]) -> std::result::Result<(), std::io::Error> {
+        for input in inputs.iter() {
+            match input {
+                ValueType::String(string) | ValueType::Datetime(string) => {
+                    for hq in hqs.iter() {
+                        if &string == hq {
+                            return Err(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "You cannot run arbitrary queries"))
+                        }
+                    }
+                },
+                _ => continue
+            }
+        }
+
+        return Ok(())
+    }
+
+    fn sanitize_input(&mut self, input: &ValueType) -> std::result::Result<(), std::io::Error> {
+        match input {
+            ValueType::String(string) | ValueType::Datetime(string) => {
+                match self.hq {
+                    Some(hqs) => {
+                        for hq in hqs.iter() {
+                            if &string == hq {
+                                return Err(std::io::Error::new(std::io::ErrorKind::PermissionDenied, "You cannot run arbitrary queries"))
+                            }
+                        }
+                    },
+                    None => return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "Your input is invalid."))
+                }
+            },
+            _ => return Ok(())
+        };
+
+        Ok(())
+    }
+
+    fn sanitize_expr(&mut self, input: &Expr) -> std::result::Result<(), std::io::Error> {
+        match input {
+            Expr::Value(value) => self.sanitize_input(value),
+            Expr::Column(column) => self.sanitize_str(column),
+            Expr::Keyword(_) => Ok(()),
+            Expr::Binary(left, _, right) => {
+                self.sanitize_expr(left)?;
+                self.sanitize_expr(right)
+            },
+            Expr::Date(date) => match date.as_ref() {
+                DateExpr::Add(expr, _, _) | DateExpr::Sub(expr, _, _) | DateExpr::Date(expr) | DateExpr::ConvertTz(expr, _, _) |
+                DateExpr::UnixTimestamp(expr) | DateExpr::Extract(_, expr) => self.sanitize_expr(expr),
+                DateExpr::Format(expr, format) => {
+                    self.sanitize_str(format)?;
+                    self.sanitize_expr(expr)
+                },
+                DateExpr::TimestampDiff(_, start, end) => {
+                    self.sanitize_expr(start)?;
+                    self.sanitize_expr(end)
+                }
+            },
+            Expr::Function(function) => match function.as_ref() {
+                FunctionExpr::Call(name, args) => {
+                    self.sanitize_str(name)?;
+
+                    for arg in args.iter() {
+                        self.sanitize_expr(arg)?;
+                    }
+
+                    Ok(())
+                },
+                FunctionExpr::Concat(args) | FunctionExpr::Coalesce(args) => {
+                    for arg in args.iter() {
+                        self.sanitize_expr(arg)?;
+                    }
+
+                    Ok(())
+                },
+                FunctionExpr::ConcatWs(separator, args) => {
+                    self.sanitize_expr(separator)?;
+
+                    for arg in args.iter() {
+                        self.sanitize_expr(arg)?;
+                    }
+
+                    Ok(())
+                },
+                FunctionExpr::Lower(expr) | FunctionExpr::Upper(expr) | FunctionExpr::Trim(expr) | FunctionExpr::Substring(expr, _, _) |
+                FunctionExpr::Length(expr) | FunctionExpr::Cast(expr, _) => self.sanitize_expr(expr),
+                FunctionExpr::IfNull(expr, other) | FunctionExpr::NullIf(expr, other) => {
+                    self.sanitize_expr(expr)?;
+                    self.sanitize_expr(other)
+                }
+            },
+            Expr::Spatial(spatial) => match spatial.as_ref() {
+                SpatialExpr::Geometry(Geometry::Wkt(wkt), _) => self.sanitize_str(wkt),
+                SpatialExpr::Geometry(_, _) => Ok(()),
+                SpatialExpr::AsText(geometry) => self.sanitize_expr(geometry),
+                SpatialExpr::Contains(a, b) | SpatialExpr::Within(a, b) | SpatialExpr::Intersects(a, b) | SpatialExpr::MbrContains(a, b) |
+                SpatialExpr::Distance(a, b) | SpatialExpr::DistanceSphere(a, b) => {
+                    self.sanitize_expr(a)?;
+                    self.sanitize_expr(b)
+                }
+            },
+            Expr::Json(json) => match json.as_ref() {
+                JsonExpr::Extract(document, _) | JsonExpr::ArrayAgg(document) | JsonExpr::Remove(document, _) | JsonExpr::ContainsPath(document, _, _) |
+                JsonExpr::Keys(document, _) | JsonExpr::Length(document, _) | JsonExpr::Type(document) | JsonExpr::Valid(document) |
+                JsonExpr::Get(document, _) | JsonExpr::GetText(document, _) => self.sanitize_expr(document),
+                JsonExpr::Literal(_) => Ok(()),
+                JsonExpr::Search(a, _, b, _) | JsonExpr::MemberOf(a, b) | JsonExpr::Overlaps(a, b) => {
+                    self.sanitize_expr(a)?;
+                    self.sanitize_expr(b)
+                },
+                JsonExpr::Modify(_, document, pairs) => {
+                    self.sanitize_expr(document)?;
+
+                    for (_, value) in pairs.iter() {
+                        self.sanitize_expr(value)?;
+                    }
+
+                    Ok(())
+                },
+                JsonExpr::Merge(_, documents) => {
+                    for document in documents.iter() {
+                        self.sanitize_expr(document)?;
+                    }
+
+                    Ok(())
+                },
+                JsonExpr::Object(pairs) => {
+                    for (_, value) in pairs.iter() {
+                        self.sanitize_expr(value)?;
+                    }
+
+                    Ok(())
+                },
+                JsonExpr::Array(values) => {
+                    for value in values.iter() {
+                        self.sanitize_expr(value)?;
+                    }
+
+                    Ok(())
+                },
+                JsonExpr::ObjectAgg(key, value) => {
+                    self.sanitize_expr(key)?;
+                    self.sanitize_expr(value)
+                }
+            },
+            Expr::Subquery(_) => Ok(())
+        }
+    }
+
+    fn sanitize_mark(input: &str) -> std::result::Result<(), std::io::Error> {
+        return match input {
+            "=" | "<" | ">" | "<=" | ">=" | "!=" | "<>" => Ok(()),
+            _ => Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "comparison operators cannot be other than =, <, >, <=,  >=, != or <>."))
+        }
+    }
+
+    fn sanitize_str(&mut self, input: &str) -> std::result::Result<(), std::io::Error> {
+        match self.hq {
+            Some(hqs) => {
+                for hq in hqs.iter() {
+                    if *hq == input {
+                        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "comparison operators cannot be other than =, <, >, <=,  >=, != or <>."))
+                    }
+                }
+            },
+            None => return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "comparison operators cannot be other than =, <, >, <=,  >=, != or <>."))
+        }
+
+        Ok(())
+    }
+}
+
+/// Struct that benefits you to create and use schema's.
+#[derive(Debug, Clone)]
+pub struct SchemaBuilder {
+    pub query: String,
+    pub schema: String,
+    pub list: Vec<KeywordList>
+}
+
+/// implementations fon SchemaBuilder
+impl SchemaBuilder {
+    pub fn create(name: &str) -> std::result::Result<Self, std::io::Error> {
+        if name.contains("!") ||
+           name.contains("-") ||
+           name.contains("=") ||
+           name.contains("+") ||
+           name.contains("%") ||
+           name.contains("$") ||
+           name.contains("&") ||
+           name.contains("#") ||
+           name.contains("[") ||
+           name.contains("]") ||
+           name.contains("{") ||
+           name.contains("}") ||
+           name.contains(":") ||
+           name.contains(";") ||
+           name.contains("'") ||
+           name.contains("\"") ||
+           name.contains(",") ||
+           name.contains(".") {
+                return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "Schame name cannot include these characters: '!', '-', '=', '+', '%', '$', '&', '#', '[', ']', '{', '}', ':', ';', '\"', \"'\", '.', ','"))
+        }
+
+        Ok(Self {
+            query: format!("CREATE DATABASE {}", name),
+            schema: name.to_string(),
+            list: vec![KeywordList::Create]
+        })
+    }
+
+    pub fn use_another_schema(name: &str) -> std::result::Result<Self, std::io::Error> {
+        if name.contains("!") ||
+        name.contains("-") ||
+        name.contains("=") ||
+        name.contains("+") ||
+        name.contains("%") ||
+        name.contains("$") ||
+        name.contains("&") ||
+        name.contains("#") ||
+        name.contains("[") ||
+        name.contains("]") ||
+        name.contains("{") ||
+        name.contains("}") ||
+        name.contains(":") ||
+        name.contains(";") ||
+        name.contains("'") ||
+        name.contains("\"") ||
+        name.contains(",") ||
+        name.contains(".") {
+             return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "Schame name cannot include these characters: '!', '-', '=', '+', '%', '$', '&', '#', '[', ']', '{', '}', ':', ';', '\"', \"'\", '.', ','"))
+        }
+
+        Ok(Self {
+            query: format!("USE {}", name),
+            schema: name.to_string(),
+            list: vec![KeywordList::Use, KeywordList::Create]
+        })
+    }
+
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        match self.list[0] {
+            KeywordList::Create => (),
+            KeywordList::Table => (),
+            _ => panic!("if_not_exists method cannot be used without Create or Table queries")
+        }
+
+        let split_the_query =  self.query.split(" DATABASE ").collect::<Vec<&str>>();
+        self.query = format!("{} DATABASE IF NOT EXISTS {}", split_the_query[0], split_the_query[1]);
+
+        self.list.insert(0, KeywordList::IfNotExist);
+        self
+    }
+
+    pub fn use_schema(&mut self, name: Option<&str>) -> &mut Self {
+        match name {
+            Some(schema_name) => {
+                self.query = format!("USE {}", schema_name)
+            },
+            None => {
+                self.query = format!("USE {}", self.schema);
+            }
+        }
+
+        self
+    }
+
+    pub fn finish(&self) -> String {
+        return format!("{};", self.query)
+    }
+}
+
+/// Struct that benefits you to create Tables. The columns are kept as `ColumnDef`s and the statement is rendered when `.finish()` method is called, so the modifier methods such as `.not_null()` and `.default()` apply to the last added column.
+/// 
+/// The table name is qualified with the schema name unless the schema name is empty, and the table options are rendered after the column list.
+/// 
+/// ```rust
+/// 
+/// use qubl::{TableBuilder, ColumnType, ValueType};
+/// 
+/// fn main(){
+///     let mut table = TableBuilder::create("shop", "products");
+/// 
+///     table.if_not_exists()
+///          .add_column("id").column_type(ColumnType::Int(None)).unsigned().auto_increment().primary_key()
+///          .add_column("name").col_type("VARCHAR(100)").not_null()
+///          .add_column("price").column_type(ColumnType::Decimal(10, 2)).default(ValueType::Int32(0))
+///          .add_column("status").column_type(ColumnType::Enum(vec!["draft".to_string(), "live".to_string()])).default(ValueType::String("draft".to_string()))
+///          .add_column("created_at").column_type(ColumnType::Datetime(Some(3))).default(ValueType::Datetime("CURRENT_TIMESTAMP(3)".to_string()));
+/// 
+///     assert_eq!(table.finish(), "CREATE TABLE IF NOT EXISTS shop.products (id INT UNSIGNED AUTO_INCREMENT PRIMARY KEY, name VARCHAR(100) NOT NULL, price DECIMAL(10, 2) DEFAULT 0, status ENUM('draft', 'live') DEFAULT 'draft', created_at DATETIME(3) DEFAULT CURRENT_TIMESTAMP(3));");
+/// 
+///     let mut sessions = TableBuilder::create("", "sessions");
+/// 
+///     sessions.temporary()
+///             .add_column("token").col_type("CHAR(64)").primary_key()
+///             .engine("MEMORY")
+///             .default_charset("ascii")
+///             .table_comment("it's dropped when the connection is closed");
+/// 
+///     assert_eq!(sessions.finish(), "CREATE TEMPORARY TABLE sessions (token CHAR(64) PRIMARY KEY) ENGINE = MEMORY DEFAULT CHARSET = ascii COMMENT = 'it''s dropped when the connection is closed';");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub struct TableBuilder {
+    pub name: String,
+    pub schema: String,
+    pub all: Vec<String>,
+    pub columns: Vec<ColumnDef>,
+    pub constraints: Vec<TableConstraint>,
+    pub definitions: Vec<String>,
+    pub if_not_exists: bool,
+    pub temporary: bool,
+    pub options: Vec<TableOption>
+}
+
+/// Struct that represents a column of a `TableBuilder`.
+/// 
+/// ```rust
+/// 
+/// use qubl::{ColumnDef, ColumnType, ValueType};
+/// 
+/// fn main(){
+///     let column = ColumnDef::new("visits", ColumnType::BigInt(None)).unsigned().not_null().default(ValueType::Int32(0)).clone();
+/// 
+///     assert_eq!(column.to_string(), "visits BIGINT UNSIGNED NOT NULL DEFAULT 0");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub struct ColumnDef {
+    pub name: String,
+    pub ty: Option<ColumnType>,
+    pub unsigned: bool,
+    pub zerofill: bool,
+    pub character_set: Option<String>,
+    pub generated: Option<(String, Option<GeneratedKind>)>,
+    pub nullable: Option<bool>,
+    pub default: Option<ValueType>,
+    pub auto_increment: bool,
+    pub unique: bool,
+    pub primary_key: bool,
+    pub comment: Option<String>,
+    pub check: Option<String>,
+    pub invisible: bool,
+    pub extra: Vec<String>
+}
+
+/// GeneratedKind enum. It's the storage of a generated column, `VIRTUAL` or `STORED`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeneratedKind {
+    Virtual, Stored
+}
+
+/// ColumnType enum. It's the data type of a column. The integer types take an optional display width and the time types take an optional fractional seconds precision.
+/// 
+/// The types that are not listed can be written with the `Custom` variant. `ColumnType::parse()` function reads the type names such as `"VARCHAR(40)"` and `"DECIMAL(10, 2)"` and it falls back to `Custom`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnType {
+    TinyInt(Option<u8>), SmallInt(Option<u8>), MediumInt(Option<u8>), Int(Option<u8>), BigInt(Option<u8>), Bit(u8), Boolean, Serial,
+    Decimal(u8, u8), Float, Double,
+    Char(u32), Varchar(u32), Binary(u32), Varbinary(u32),
+    TinyText, Text, MediumText, LongText, TinyBlob, Blob, MediumBlob, LongBlob,
+    Date, Time(Option<u8>), Datetime(Option<u8>), Timestamp(Option<u8>), Year,
+    Json, Enum(Vec<String>), Set(Vec<String>),
+    Geometry, Point, LineString, Polygon, MultiPoint, MultiLineString, MultiPolygon, GeometryCollection,
+    Custom(String)
+}
+
+impl ColumnType {
+    /// It reads a type name, the unknown names are kept as `Custom`.
+    pub fn parse(type_name: &str) -> Self {
+        let normalized = type_name.trim().to_uppercase();
+
+        let (name, arguments) = match normalized.split_once('(') {
+            Some((name, rest)) if rest.ends_with(')') => (name.trim().to_string(), Some(rest[..rest.len() - 1].to_string())),
+            Some(_) => return ColumnType::Custom(type_name.trim().to_string()),
+            None => (normalized.clone(), None)
+        };
+
+        let numbers = arguments.as_ref().map(|arguments| arguments.split(',').map(|number| number.trim().parse::<u32>()).collect::<Vec<std::result::Result<u32, std::num::ParseIntError>>>());
+
+        let single = match &numbers {
+            Some(numbers) if numbers.len() == 1 => numbers[0].clone().ok(),
+            _ => None
+        };
+
+        let small = single.and_then(|number| u8::try_from(number).ok());
+
+        let members = || {
+            let arguments = type_name.trim();
+            let inner = &arguments[arguments.find('(').unwrap_or(0) + 1..arguments.len() - 1];
+
+            inner.split(',').map(|member| member.trim().trim_matches('\'').to_string()).collect::<Vec<String>>()
+        };
+
+        let parsed = match (name.as_str(), arguments.is_some()) {
+            ("TINYINT", _) => Some(ColumnType::TinyInt(small)),
+            ("SMALLINT", _) => Some(ColumnType::SmallInt(small)),
+            ("MEDIUMINT", _) => Some(ColumnType::MediumInt(small)),
+            ("INT", _) | ("INTEGER", _) => Some(ColumnType::Int(small)),
+            ("BIGINT", _) => Some(ColumnType::BigInt(small)),
+            ("BIT", true) => small.map(ColumnType::Bit),
+            ("BOOL", false) | ("BOOLEAN", false) => Some(ColumnType::Boolean),
+            ("SERIAL", false) => Some(ColumnType::Serial),
+            ("DECIMAL", _) | ("NUMERIC", _) => match &numbers {
+                Some(numbers) if numbers.len() == 2 => match (&numbers[0], &numbers[1]) {
+                    (Ok(precision), Ok(scale)) => match (u8::try_from(*precision), u8::try_from(*scale)) {
+                        (Ok(precision), Ok(scale)) => Some(ColumnType::Decimal(precision, scale)),
+                        _ => None
+                    },
+                    _ => None
+                },
+                None => Some(ColumnType::Decimal(10, 0)),
+                _ => None
+            },
+            ("FLOAT", false) => Some(ColumnType::Float),
+            ("DOUBLE", false) => Some(ColumnType::Double),
+            ("CHAR", true) => single.map(ColumnType::Char),
+            ("VARCHAR", true) => single.map(ColumnType::Varchar),
+            ("BINARY", true) => single.map(ColumnType::Binary),
+            ("VARBINARY", true) => single.map(ColumnType::Varbinary),
+            ("TINYTEXT", false) => Some(ColumnType::TinyText),
+            ("TEXT", false) => Some(ColumnType::Text),
+            ("MEDIUMTEXT", false) => Some(ColumnType::MediumText),
+            ("LONGTEXT", false) => Some(ColumnType::LongText),
+            ("TINYBLOB", false) => Some(ColumnType::TinyBlob),
+            ("BLOB", false) => Some(ColumnType::Blob),
+            ("MEDIUMBLOB", false) => Some(ColumnType::MediumBlob),
+            ("LONGBLOB", false) => Some(ColumnType::LongBlob),
+            ("DATE", false) => Some(ColumnType::Date),
+            ("TIME", _) => Some(ColumnType::Time(small)),
+            ("DATETIME", _) => Some(ColumnType::Datetime(small)),
+            ("TIMESTAMP", _) => Some(ColumnType::Timestamp(small)),
+            ("YEAR", false) => Some(ColumnType::Year),
+            ("JSON", false) => Some(ColumnType::Json),
+            ("ENUM", true) => Some(ColumnType::Enum(members())),
+            ("SET", true) => Some(ColumnType::Set(members())),
+            ("GEOMETRY", false) => Some(ColumnType::Geometry),
+            ("POINT", false) => Some(ColumnType::Point),
+            ("LINESTRING", false) => Some(ColumnType::LineString),
+            ("POLYGON", false) => Some(ColumnType::Polygon),
+            ("MULTIPOINT", false) => Some(ColumnType::MultiPoint),
+            ("MULTILINESTRING", false) => Some(ColumnType::MultiLineString),
+            ("MULTIPOLYGON", false) => Some(ColumnType::MultiPolygon),
+            ("GEOMETRYCOLLECTION", false) => Some(ColumnType::GeometryCollection),
+            _ => None
+        };
+
+        match (parsed, &arguments, small) {
+            (Some(ColumnType::TinyInt(_)), Some(_), None) | (Some(ColumnType::SmallInt(_)), Some(_), None) | (Some(ColumnType::MediumInt(_)), Some(_), None) |
+            (Some(ColumnType::Int(_)), Some(_), None) | (Some(ColumnType::BigInt(_)), Some(_), None) | (Some(ColumnType::Time(_)), Some(_), None) |
+            (Some(ColumnType::Datetime(_)), Some(_), None) | (Some(ColumnType::Timestamp(_)), Some(_), None) | (None, _, _) => ColumnType::Custom(type_name.trim().to_string()),
+            (Some(parsed), _, _) => parsed
+        }
+    }
+
+    /// It returns true for the integer, decimal and floating point types.
+    pub fn is_numeric(&self) -> bool {
+        matches!(self, ColumnType::TinyInt(_) | ColumnType::SmallInt(_) | ColumnType::MediumInt(_) | ColumnType::Int(_) | ColumnType::BigInt(_) |
+                       ColumnType::Serial | ColumnType::Decimal(_, _) | ColumnType::Float | ColumnType::Double)
+    }
+
+    /// It returns true for the spatial types.
+    pub fn is_spatial(&self) -> bool {
+        matches!(self, ColumnType::Geometry | ColumnType::Point | ColumnType::LineString | ColumnType::Polygon | ColumnType::MultiPoint |
+                       ColumnType::MultiLineString | ColumnType::MultiPolygon | ColumnType::GeometryCollection)
+    }
+
+    /// It returns true for the types that only take expression defaults on mysql, which are the text, blob and json types.
+    pub fn needs_expression_default(&self) -> bool {
+        matches!(self, ColumnType::TinyText | ColumnType::Text | ColumnType::MediumText | ColumnType::LongText |
+                       ColumnType::TinyBlob | ColumnType::Blob | ColumnType::MediumBlob | ColumnType::LongBlob | ColumnType::Json)
+    }
+
+    /// It checks if the value can be the default value of a column with that type and returns the reason if it can't.
+    pub fn check_default(&self, value: &ValueType) -> std::result::Result<(), String> {
+        let is_integer = matches!(value, ValueType::Int8(_) | ValueType::Int16(_) | ValueType::Int32(_) | ValueType::Int64(_) | ValueType::Int128(_) |
+                                         ValueType::Uint8(_) | ValueType::Uint16(_) | ValueType::Uint32(_) | ValueType::Uint64(_) | ValueType::Usize(_));
+        let is_float = matches!(value, ValueType::Float32(_) | ValueType::Float64(_));
+
+        let accepted = match (self, value) {
+            (_, ValueType::Null) => true,
+            (ColumnType::Custom(_), _) => true,
+            (ColumnType::Boolean, ValueType::Boolean(_)) | (ColumnType::TinyInt(_), ValueType::Boolean(_)) | (ColumnType::Bit(_), ValueType::Boolean(_)) => true,
+            (ColumnType::Decimal(_, _), _) | (ColumnType::Float, _) | (ColumnType::Double, _) => is_integer || is_float,
+            (ColumnType::Year, _) | (ColumnType::Bit(_), _) | (ColumnType::Boolean, _) => is_integer,
+            (column_type, _) if column_type.is_numeric() => is_integer,
+            (ColumnType::Enum(members), ValueType::String(text)) => members.contains(text),
+            (ColumnType::Set(members), ValueType::String(text)) => text.is_empty() || text.split(',').all(|member| members.iter().any(|item| item == member)),
+            (ColumnType::Date, ValueType::Datetime(_)) | (ColumnType::Date, ValueType::String(_)) | (ColumnType::Time(_), ValueType::Datetime(_)) | (ColumnType::Time(_), ValueType::String(_)) |
+            (ColumnType::Datetime(_), ValueType::Datetime(_)) | (ColumnType::Datetime(_), ValueType::String(_)) |
+            (ColumnType::Timestamp(_), ValueType::Datetime(_)) | (ColumnType::Timestamp(_), ValueType::String(_)) => true,
+            (ColumnType::Char(_), ValueType::String(_)) | (ColumnType::Varchar(_), ValueType::String(_)) | (ColumnType::Binary(_), ValueType::String(_)) | (ColumnType::Varbinary(_), ValueType::String(_)) => true,
+            (ColumnType::Json, ValueType::String(_)) | (ColumnType::Json, ValueType::JsonString(_)) => true,
+            (column_type, ValueType::String(_)) if column_type.needs_expression_default() => true,
+            _ => false
+        };
+
+        match accepted {
+            true => match (self, value) {
+                (ColumnType::Char(length), ValueType::String(text)) | (ColumnType::Varchar(length), ValueType::String(text)) if text.chars().count() > *length as usize => {
+                    Err(format!("the default value '{}' is longer than the length of the {} column.", text, self))
+                },
+                (ColumnType::Datetime(fsp), ValueType::Datetime(datetime)) | (ColumnType::Timestamp(fsp), ValueType::Datetime(datetime)) => match Self::current_timestamp_precision(datetime) {
+                    Some(precision) if precision != fsp.unwrap_or(0) => Err(format!("the precision of the default value {} doesn't match the precision of the {} column.", datetime, self)),
+                    _ => Ok(())
+                },
+                _ => Ok(())
+            },
+            false => Err(format!("{:?} cannot be the default value of a {} column.", value, self))
+        }
+    }
+
+    /// It returns the fractional seconds precision of the `CURRENT_TIMESTAMP` and `NOW()` defaults, which is 0 when they're written without a precision. It returns `None` for the other values.
+    fn current_timestamp_precision(datetime: &str) -> Option<u8> {
+        match datetime {
+            "CURRENT_TIMESTAMP" | "CURRENT_TIMESTAMP()" | "NOW()" => Some(0),
+            _ => datetime.strip_prefix("CURRENT_TIMESTAMP(").or_else(|| datetime.strip_prefix("NOW("))
+                         .and_then(|rest| rest.strip_suffix(')'))
+                         .and_then(|precision| precision.trim().parse::<u8>().ok())
+        }
+    }
+
+    /// It renders the type for the given dialect. On postgresql the mysql types are written with their equivalents, such as `DOUBLE PRECISION` for `DOUBLE`, `TIMESTAMP` for `DATETIME` and `BYTEA` for the blobs, and the display widths are dropped. It panics for the types that have no equivalent on postgresql, use the `Custom` variant for them.
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        let with_precision = |name: &str, fsp: &Option<u8>| match fsp {
+            Some(fsp) => format!("{}({})", name, fsp),
+            None => name.to_string()
+        };
+
+        match dialect {
+            Dialect::Postgresql => match self {
+                ColumnType::TinyInt(_) | ColumnType::SmallInt(_) => "SMALLINT".to_string(),
+                ColumnType::MediumInt(_) | ColumnType::Int(_) => "INTEGER".to_string(),
+                ColumnType::BigInt(_) => "BIGINT".to_string(),
+                ColumnType::Serial => "BIGSERIAL".to_string(),
+                ColumnType::Float => "REAL".to_string(),
+                ColumnType::Double => "DOUBLE PRECISION".to_string(),
+                ColumnType::Binary(_) | ColumnType::Varbinary(_) | ColumnType::TinyBlob | ColumnType::Blob | ColumnType::MediumBlob | ColumnType::LongBlob => "BYTEA".to_string(),
+                ColumnType::TinyText | ColumnType::Text | ColumnType::MediumText | ColumnType::LongText => "TEXT".to_string(),
+                ColumnType::Time(fsp) => with_precision("TIME", fsp),
+                ColumnType::Datetime(fsp) | ColumnType::Timestamp(fsp) => with_precision("TIMESTAMP", fsp),
+                ColumnType::Json => "JSONB".to_string(),
+                ColumnType::Year | ColumnType::Enum(_) | ColumnType::Set(_) => panic!("{} type is not supported on postgresql, use ColumnType::Custom for an equivalent type.", self),
+                column_type if column_type.is_spatial() => panic!("{} type is not supported on postgresql, use ColumnType::Custom for the types of postgis.", self),
+                column_type => column_type.to_string()
+            },
+            _ => self.to_string()
+        }
+    }
+}
+
+impl std::fmt::Display for ColumnType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let with_width = |f: &mut std::fmt::Formatter<'_>, name: &str, width: &Option<u8>| match width {
+            Some(width) => write!(f, "{}({})", name, width),
+            None => write!(f, "{}", name)
+        };
+
+        let with_members = |f: &mut std::fmt::Formatter<'_>, name: &str, members: &Vec<String>| {
+            write!(f, "{}({})", name, members.iter().map(|member| format!("'{}'", member.replace('\'', "''"))).collect::<Vec<String>>().join(", "))
+        };
+
+        match self {
+            ColumnType::TinyInt(width) => with_width(f, "TINYINT", width),
+            ColumnType::SmallInt(width) => with_width(f, "SMALLINT", width),
+            ColumnType::MediumInt(width) => with_width(f, "MEDIUMINT", width),
+            ColumnType::Int(width) => with_width(f, "INT", width),
+            ColumnType::BigInt(width) => with_width(f, "BIGINT", width),
+            ColumnType::Bit(length) => write!(f, "BIT({})", length),
+            ColumnType::Boolean => write!(f, "BOOLEAN"),
+            ColumnType::Serial => write!(f, "SERIAL"),
+            ColumnType::Decimal(precision, scale) => write!(f, "DECIMAL({}, {})", precision, scale),
+            ColumnType::Float => write!(f, "FLOAT"),
+            ColumnType::Double => write!(f, "DOUBLE"),
+            ColumnType::Char(length) => write!(f, "CHAR({})", length),
+            ColumnType::Varchar(length) => write!(f, "VARCHAR({})", length),
+            ColumnType::Binary(length) => write!(f, "BINARY({})", length),
+            ColumnType::Varbinary(length) => write!(f, "VARBINARY({})", length),
+            ColumnType::TinyText => write!(f, "TINYTEXT"),
+            ColumnType::Text => write!(f, "TEXT"),
+            ColumnType::MediumText => write!(f, "MEDIUMTEXT"),
+            ColumnType::LongText => write!(f, "LONGTEXT"),
+            ColumnType::TinyBlob => write!(f, "TINYBLOB"),
+            ColumnType::Blob => write!(f, "BLOB"),
+            ColumnType::MediumBlob => write!(f, "MEDIUMBLOB"),
+            ColumnType::LongBlob => write!(f, "LONGBLOB"),
+            ColumnType::Date => write!(f, "DATE"),
+            ColumnType::Time(fsp) => with_width(f, "TIME", fsp),
+            ColumnType::Datetime(fsp) => with_width(f, "DATETIME", fsp),
+            ColumnType::Timestamp(fsp) => with_width(f, "TIMESTAMP", fsp),
+            ColumnType::Year => write!(f, "YEAR"),
+            ColumnType::Json => write!(f, "JSON"),
+            ColumnType::Enum(members) => with_members(f, "ENUM", members),
+            ColumnType::Set(members) => with_members(f, "SET", members),
+            ColumnType::Geometry => write!(f, "GEOMETRY"),
+            ColumnType::Point => write!(f, "POINT"),
+            ColumnType::LineString => write!(f, "LINESTRING"),
+            ColumnType::Polygon => write!(f, "POLYGON"),
+            ColumnType::MultiPoint => write!(f, "MULTIPOINT"),
+            ColumnType::MultiLineString => write!(f, "MULTILINESTRING"),
+            ColumnType::MultiPolygon => write!(f, "MULTIPOLYGON"),
+            ColumnType::GeometryCollection => write!(f, "GEOMETRYCOLLECTION"),
+            ColumnType::Custom(name) => write!(f, "{}", name)
+        }
+    }
+}
+
+impl From<&str> for ColumnType { fn from(value: &str) -> Self { ColumnType::parse(value) } }
+
+/// implementations of ColumnDef
+impl ColumnDef {
+    /// It creates a column with the given type.
+    pub fn new<T: Into<ColumnType>>(name: &str, ty: T) -> Self {
+        let mut column = Self::untyped(name);
+        column.ty = Some(ty.into());
+
+        column
+    }
+
+    fn untyped(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ty: None,
+            unsigned: false,
+            zerofill: false,
+            character_set: None,
+            generated: None,
+            nullable: None,
+            default: None,
+            auto_increment: false,
+            unique: false,
+            primary_key: false,
+            comment: None,
+            check: None,
+            invisible: false,
+            extra: vec![]
+        }
+    }
+
+    /// It makes the column `NULL` or `NOT NULL`.
+    pub fn nullable(&mut self, nullable: bool) -> &mut Self {
+        if !nullable && matches!(self.default, Some(ValueType::Null)) {
+            panic!("the column {} has a NULL default value, it cannot be NOT NULL.", self.name)
+        }
+
+        self.nullable = Some(nullable);
+
+        self
+    }
+
+    /// It makes the column `NOT NULL`.
+    pub fn not_null(&mut self) -> &mut Self {
+        self.nullable(false)
+    }
+
+    /// It makes the column `UNSIGNED`, it panics if the type of the column is not numeric.
+    pub fn unsigned(&mut self) -> &mut Self {
+        match &self.ty {
+            Some(ty) if ty.is_numeric() || matches!(ty, ColumnType::Custom(_)) => self.unsigned = true,
+            _ => panic!("only the numeric columns can be unsigned.")
+        }
+
+        self
+    }
+
+    /// It adds the default value of the column. It panics if the value doesn't fit the type of the column.
+    pub fn default(&mut self, value: ValueType) -> &mut Self {
+        let ty = match &self.ty {
+            Some(ty) => ty,
+            None => panic!("Cannot add a default value before defining the type of the column {}.", self.name)
+        };
+
+        if let Err(error) = ty.check_default(&value) {
+            panic!("Error: {}", error)
+        }
+
+        if let (ValueType::Null, Some(false)) = (&value, self.nullable) {
+            panic!("the column {} is NOT NULL, it's default value cannot be NULL.", self.name)
+        }
+
+        if ty.is_spatial() || self.auto_increment {
+            panic!("the column {} cannot have a default value.", self.name)
+        }
+
+        self.default = Some(value);
+
+        self
+    }
+
+    fn render_default(&self, value: &ValueType) -> String {
+        let literal = match value {
+            ValueType::Datetime(datetime) => match SqlKeyword::from_name(datetime).is_some() || ColumnType::current_timestamp_precision(datetime).is_some() {
+                true => datetime.to_string(),
+                false => format!("'{}'", datetime)
+            },
+            ValueType::String(text) | ValueType::JsonString(text) => format!("'{}'", text.replace('\'', "''")),
+            value => value.to_string()
+        };
+
+        match &self.ty {
+            Some(ty) if ty.needs_expression_default() && !matches!(value, ValueType::Null) => format!("({})", literal),
+            _ => literal
+        }
+    }
+
+    /// It renders the column definition for the given dialect, the type is rendered with `ColumnType::to_sql()`. It panics if the column has an attribute which is only supported on mysql, check it with `.check_dialect()` first.
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        if let Err(error) = self.check_dialect(dialect) {
+            panic!("{}", error)
+        }
+
+        let mut column = self.name.to_string();
+
+        if let Some(ty) = &self.ty {
+            column = format!("{} {}", column, ty.to_sql(dialect));
+        }
+
+        if self.unsigned {
+            column = format!("{} UNSIGNED", column);
+        }
+
+        if self.zerofill {
+            column = format!("{} ZEROFILL", column);
+        }
+
+        if let Some(character_set) = &self.character_set {
+            column = format!("{} CHARACTER SET {}", column, character_set);
+        }
+
+        if let Some((expression, kind)) = &self.generated {
+            column = format!("{} GENERATED ALWAYS AS {}", column, expression);
+
+            match kind {
+                Some(GeneratedKind::Virtual) => column = format!("{} VIRTUAL", column),
+                Some(GeneratedKind::Stored) => column = format!("{} STORED", column),
+                None => ()
+            }
+        }
+
+        match self.nullable {
+            Some(true) => column = format!("{} NULL", column),
+            Some(false) => column = format!("{} NOT NULL", column),
+            None => ()
+        }
+
+        if let Some(value) = &self.default {
+            column = format!("{} DEFAULT {}", column, self.render_default(value));
+        }
+
+        if self.invisible {
+            column = format!("{} INVISIBLE", column);
+        }
+
+        if self.auto_increment {
+            column = format!("{} AUTO_INCREMENT", column);
+        }
+
+        if self.unique {
+            column = format!("{} UNIQUE", column);
+        }
+
+        if self.primary_key {
+            column = format!("{} PRIMARY KEY", column);
+        }
+
+        if let Some(comment) = &self.comment {
+            column = format!("{} COMMENT '{}'", column, comment.replace('\'', "''"));
+        }
+
+        if let Some(check) = &self.check {
+            column = format!("{} CHECK({})", column, check);
+        }
+
+        for extra in self.extra.iter() {
+            column = format!("{} {}", column, extra);
+        }
+
+        column
+    }
+
+    /// It checks if the column can be written on the given dialect. `UNSIGNED`, `ZEROFILL`, `CHARACTER SET`, `INVISIBLE`, `AUTO_INCREMENT` and `COMMENT` attributes are only supported on mysql, and the generated columns have to be `STORED` on postgresql.
+    pub fn check_dialect(&self, dialect: &Dialect) -> std::result::Result<(), String> {
+        if *dialect == Dialect::Mysql {
+            return Ok(())
+        }
+
+        let attributes = [(self.unsigned, "UNSIGNED"), (self.zerofill, "ZEROFILL"), (self.character_set.is_some(), "CHARACTER SET"), (self.invisible, "INVISIBLE"),
+                          (self.auto_increment, "AUTO_INCREMENT"), (self.comment.is_some(), "COMMENT")];
+
+        if let Some((_, attribute)) = attributes.iter().find(|(used, _)| *used) {
+            return Err(format!("the {} attribute of the column {} is only supported on mysql.", attribute, self.name))
+        }
+
+        match (dialect, &self.generated) {
+            (Dialect::Postgresql, Some((_, kind))) if *kind != Some(GeneratedKind::Stored) => Err(format!("the generated column {} has to be STORED on postgresql.", self.name)),
+            _ => Ok(())
+        }
+    }
+}
+
+impl std::fmt::Display for ColumnDef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_sql(&Dialect::Mysql))
+    }
+}
+
+/// TableOption enum. It's an option of a mysql table that is written after the column list or changed with `AlterTableBuilder`. The options that are not listed can be written with the `Custom` variant.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableOption {
+    Engine(String), DefaultCharset(String), Collate(String), Comment(String), AutoIncrement(u64), RowFormat(RowFormat), KeyBlockSize(u8), Custom(String, String)
+}
+
+impl TableOption {
+    fn name(&self) -> String {
+        match self {
+            TableOption::Engine(_) => "ENGINE".to_string(),
+            TableOption::DefaultCharset(_) => "DEFAULT CHARSET".to_string(),
+            TableOption::Collate(_) => "COLLATE".to_string(),
+            TableOption::Comment(_) => "COMMENT".to_string(),
+            TableOption::AutoIncrement(_) => "AUTO_INCREMENT".to_string(),
+            TableOption::RowFormat(_) => "ROW_FORMAT".to_string(),
+            TableOption::KeyBlockSize(_) => "KEY_BLOCK_SIZE".to_string(),
+            TableOption::Custom(name, _) => name.to_uppercase()
+        }
+    }
+
+    fn check(&self) {
+        let name = match self {
+            TableOption::Engine(name) | TableOption::DefaultCharset(name) | TableOption::Collate(name) | TableOption::Custom(name, _) => name,
+            TableOption::KeyBlockSize(size) if ![1, 2, 4, 8, 16].contains(size) => panic!("KEY_BLOCK_SIZE has to be 1, 2, 4, 8 or 16."),
+            _ => return
+        };
+
+        if let Err(error) = RoutineBuilder::check_name(name) {
+            panic!("{}", error)
+        }
+
+        if let TableOption::Custom(_, value) = self {
+            if value.contains('\\') {
+                panic!("the values of the table options cannot include backslashes.")
+            }
+        }
+    }
+
+    /// the values of the custom options are written as they are if they're words, numbers or a single quoted string, otherwise they're quoted.
+    fn custom_value(value: &str) -> String {
+        let word = !value.is_empty() && value.chars().all(|character| character.is_ascii_alphanumeric() || character == '_' || character == '.');
+        let quoted = value.len() > 1 && value.starts_with('\'') && value.ends_with('\'') && !value[1..value.len() - 1].replace("''", "").contains('\'');
+
+        match word || quoted {
+            true => value.to_string(),
+            false => format!("'{}'", value.replace('\'', "''"))
+        }
+    }
+}
+
+impl std::fmt::Display for TableOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TableOption::Engine(value) | TableOption::DefaultCharset(value) | TableOption::Collate(value) => write!(f, "{} = {}", self.name(), value),
+            TableOption::Comment(comment) => write!(f, "COMMENT = '{}'", comment.replace('\'', "''")),
+            TableOption::AutoIncrement(start) => write!(f, "AUTO_INCREMENT = {}", start),
+            TableOption::RowFormat(row_format) => write!(f, "ROW_FORMAT = {}", row_format),
+            TableOption::KeyBlockSize(size) => write!(f, "KEY_BLOCK_SIZE = {}", size),
+            TableOption::Custom(name, value) => write!(f, "{} = {}", name.to_uppercase(), Self::custom_value(value))
+        }
+    }
+}
+
+/// RowFormat enum. It's the physical format that mysql stores the rows of a table.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RowFormat {
+    Default, Dynamic, Fixed, Compressed, Redundant, Compact
+}
+
+impl std::fmt::Display for RowFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RowFormat::Default => write!(f, "DEFAULT"),
+            RowFormat::Dynamic => write!(f, "DYNAMIC"),
+            RowFormat::Fixed => write!(f, "FIXED"),
+            RowFormat::Compressed => write!(f, "COMPRESSED"),
+            RowFormat::Redundant => write!(f, "REDUNDANT"),
+            RowFormat::Compact => write!(f, "COMPACT")
+        }
+    }
+}
+
+/// Struct that benefits to define a foreign key on a single column. The composite foreign keys are created with `TableConstraint::foreign_key()`.
+#[derive(Debug, Clone)]
+pub struct ForeignKey {
+    pub first: ForeignKeyItem,
+    pub second: ForeignKeyItem,
+    pub on_delete: Option<ForeignKeyActions>,
+    pub on_update: Option<ForeignKeyActions>,
+    pub constraint: Option<String>
+}
+
+/// Struct that benefits you to add a foreign key item to a foreign key.
+#[derive(Debug, Clone)]
+pub struct ForeignKeyItem {
+    pub table: String,
+    pub column: String
+}
+
+/// implementations of ForeignKey
+impl ForeignKey {
+    fn render(&self) -> String {
+        TableConstraint::from(self.clone()).to_sql(&Dialect::Mysql)
+    }
+}
+
+/// TableConstraint enum. It's a table level constraint, the names are optional except the `Custom` constraints which are written as `CONSTRAINT name definition`.
+/// 
+/// ```rust
+/// 
+/// use qubl::{TableConstraint, Dialect, ForeignKeyActions};
+/// 
+/// fn main(){
+///     let unique = TableConstraint::unique(Some("email_tenant_uq"), vec!["email", "tenant_id"]);
+/// 
+///     assert_eq!(unique.to_sql(&Dialect::Mysql), "UNIQUE KEY email_tenant_uq (email, tenant_id)");
+///     assert_eq!(unique.to_sql(&Dialect::Postgresql), "CONSTRAINT email_tenant_uq UNIQUE (email, tenant_id)");
+/// 
+///     let check = TableConstraint::check(Some("dates_check"), "ends_at > starts_at");
+/// 
+///     assert_eq!(check.to_sql(&Dialect::Mysql), "CONSTRAINT dates_check CHECK(ends_at > starts_at)");
+/// 
+///     let foreign_key = TableConstraint::foreign_key(Some("line_fk"), vec!["order_id", "product_id"], "order_items", vec!["order_id", "product_id"])
+///                                      .on_delete(ForeignKeyActions::Cascade);
+/// 
+///     assert_eq!(foreign_key.to_sql(&Dialect::Mysql), "CONSTRAINT line_fk FOREIGN KEY (order_id, product_id) REFERENCES order_items(order_id, product_id) ON DELETE CASCADE");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub enum TableConstraint {
+    PrimaryKey(Option<String>, Vec<String>), Unique(Option<String>, Vec<String>), Check(Option<String>, String), Custom(String, String),
+    ForeignKey { name: Option<String>, columns: Vec<String>, ref_table: String, ref_columns: Vec<String>, on_delete: Option<ForeignKeyActions>, on_update: Option<ForeignKeyActions> }
+}
+
+/// implementations of TableConstraint
+impl TableConstraint {
+    /// It creates a primary key, which can be composite.
+    pub fn primary_key(name: Option<&str>, columns: Vec<&str>) -> Self {
+        TableConstraint::PrimaryKey(name.map(|name| name.to_string()), Self::column_list(columns))
+    }
+
+    /// It creates a unique key.
+    pub fn unique(name: Option<&str>, columns: Vec<&str>) -> Self {
+        TableConstraint::Unique(name.map(|name| name.to_string()), Self::column_list(columns))
+    }
+
+    /// It creates a check constraint.
+    pub fn check(name: Option<&str>, condition: &str) -> Self {
+        TableConstraint::Check(name.map(|name| name.to_string()), condition.to_string())
+    }
+
+    /// It creates a foreign key, which can be composite. The columns and the referenced columns have to have the same length.
+    pub fn foreign_key(name: Option<&str>, columns: Vec<&str>, ref_table: &str, ref_columns: Vec<&str>) -> Self {
+        if columns.len() != ref_columns.len() {
+            panic!("a foreign key needs the same number of columns and referenced columns.")
+        }
+
+        TableConstraint::ForeignKey {
+            name: name.map(|name| name.to_string()),
+            columns: Self::column_list(columns),
+            ref_table: ref_table.to_string(),
+            ref_columns: Self::column_list(ref_columns),
+            on_delete: None,
+            on_update: None
+        }
+    }
+
+    /// It sets the action of a foreign key on the deletion of the referenced row.
+    pub fn on_delete(mut self, action: ForeignKeyActions) -> Self {
+        match &mut self {
+            TableConstraint::ForeignKey { on_delete, .. } => *on_delete = Some(action),
+            _ => panic!("only the foreign keys have ON DELETE actions.")
+        }
+
+        self
+    }
+
+    /// It sets the action of a foreign key on the update of the referenced row.
+    pub fn on_update(mut self, action: ForeignKeyActions) -> Self {
+        match &mut self {
+            TableConstraint::ForeignKey { on_update, .. } => *on_update = Some(action),
+            _ => panic!("only the foreign keys have ON UPDATE actions.")
+        }
+
+        self
+    }
+
+    fn column_list(columns: Vec<&str>) -> Vec<String> {
+        if columns.is_empty() {
+            panic!("a key needs at least one column.")
+        }
+
+        columns.iter().map(|column| column.to_string()).collect()
+    }
+
+    /// It returns the name of the constraint, if it has one.
+    pub fn name(&self) -> Option<String> {
+        match self {
+            TableConstraint::PrimaryKey(name, _) | TableConstraint::Unique(name, _) | TableConstraint::Check(name, _) | TableConstraint::ForeignKey { name, .. } => name.clone(),
+            TableConstraint::Custom(name, _) => Some(name.to_string())
+        }
+    }
+
+    /// It returns the columns of the constraint, it's empty for the check and custom constraints.
+    pub fn columns(&self) -> Vec<String> {
+        match self {
+            TableConstraint::PrimaryKey(_, columns) | TableConstraint::Unique(_, columns) | TableConstraint::ForeignKey { columns, .. } => columns.clone(),
+            _ => vec![]
+        }
+    }
+
+    fn check_names(&self) {
+        for name in self.name().iter().chain(self.columns().iter()) {
+            if let Err(error) = RoutineBuilder::check_name(name) {
+                panic!("{}", error)
+            }
+        }
+
+        match self {
+            TableConstraint::Check(_, definition) | TableConstraint::Custom(_, definition) => Self::check_definition(definition),
+            TableConstraint::ForeignKey { ref_table, ref_columns, .. } => {
+                for name in ref_columns.iter().chain([ref_table]) {
+                    if let Err(error) = RoutineBuilder::check_name(name) {
+                        panic!("{}", error)
+                    }
+                }
+            },
+            _ => ()
+        }
+    }
+
+    /// checks the raw sql of the check and custom constraints, the ';' character and the comments are only allowed inside of the quoted strings.
+    fn check_definition(definition: &str) {
+        let mut quoted = false;
+        let characters = definition.chars().collect::<Vec<char>>();
+
+        for (index, character) in characters.iter().enumerate() {
+            let next = characters.get(index + 1);
+
+            match (quoted, character, next) {
+                (_, '\\', _) => panic!("the constraint definitions cannot include backslashes."),
+                (_, '\'', _) => quoted = !quoted,
+                (false, ';', _) | (false, '#', _) | (false, '-', Some('-')) | (false, '/', Some('*')) => panic!("the constraint definitions cannot include ';' character or comments."),
+                _ => ()
+            }
+        }
+
+        if quoted {
+            panic!("the constraint definitions cannot include unclosed quotes.")
+        }
+    }
+
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        let named = |name: &Option<String>, definition: String| match name {
+            Some(name) => format!("CONSTRAINT {} {}", name, definition),
+            None => definition
+        };
+
+        match (self, dialect) {
+            (TableConstraint::PrimaryKey(name, columns), _) => named(name, format!("PRIMARY KEY ({})", columns.join(", "))),
+            (TableConstraint::Unique(Some(name), columns), Dialect::Mysql) => format!("UNIQUE KEY {} ({})", name, columns.join(", ")),
+            (TableConstraint::Unique(None, columns), Dialect::Mysql) => format!("UNIQUE KEY ({})", columns.join(", ")),
+            (TableConstraint::Unique(name, columns), _) => named(name, format!("UNIQUE ({})", columns.join(", "))),
+            (TableConstraint::Check(name, condition), _) => named(name, format!("CHECK({})", condition)),
+            (TableConstraint::ForeignKey { name, columns, ref_table, ref_columns, on_delete, on_update }, _) => {
+                let mut definition = named(name, format!("FOREIGN KEY ({}) REFERENCES {}({})", columns.join(", "), ref_table, ref_columns.join(", ")));
+
+                if let Some(on_delete) = on_delete {
+                    definition = format!("{} ON DELETE {}", definition, on_delete);
+                }
+
+                if let Some(on_update) = on_update {
+                    definition = format!("{} ON UPDATE {}", definition, on_update);
+                }
+
+                definition
+            },
+            (TableConstraint::Custom(name, definition), _) => format!("CONSTRAINT {} {}", name, definition)
+        }
+    }
+}
+
+impl From<ForeignKey> for TableConstraint {
+    fn from(foreign_key: ForeignKey) -> Self {
+        TableConstraint::ForeignKey {
+            name: foreign_key.constraint,
+            columns: vec![foreign_key.first.column],
+            ref_table: foreign_key.second.table,
+            ref_columns: vec![foreign_key.second.column],
+            on_delete: foreign_key.on_delete,
+            on_update: foreign_key.on_update
+        }
+    }
+}
+
+/// implementations for TableBuilder
+impl TableBuilder {
+    pub fn create(schema_name: &str, table_name: &str) -> Self {
+        return Self {
+            schema: schema_name.to_string(),
+            name: table_name.to_string(),
+            all: vec![],
+            columns: vec![],
+            constraints: vec![],
+            definitions: vec![],
+            if_not_exists: false,
+            temporary: false,
+            options: vec![]
+        }
+    }
+
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+
+        self
+    }
+
+    pub fn add_column(&mut self, column_name: &str) -> &mut Self {
+        self.all.push(column_name.to_string());
+        self.columns.push(ColumnDef::untyped(column_name));
+
+        self
+    }
+
+    /// It adds a column that is already defined.
+    pub fn column(&mut self, column: ColumnDef) -> &mut Self {
+        self.all.push(column.name.clone());
+        self.columns.push(column);
+
+        self
+    }
+
+    fn last_column(&mut self) -> &mut ColumnDef {
+        match self.columns.last_mut() {
+            Some(column) => column,
+            None => panic!("There is no column in the table {} yet, add a column first.", self.name)
+        }
+    }
+
+    /// It sets the type of the last column, the name of the type is read by `ColumnType::parse()`.
+    pub fn col_type<T: Into<ColumnType>>(&mut self, type_name: T) -> &mut Self {
+        if self.columns.is_empty() {
+            panic!("Cannot add type before defining a column name.")
+        }
+
+        self.column_type(type_name.into())
+    }
+
+    pub fn column_type(&mut self, column_type: ColumnType) -> &mut Self {
+        let column = self.last_column();
+
+        if column.ty.is_some() {
+            panic!("The column {} already has a type.", column.name)
+        }
+
+        column.ty = Some(column_type);
+
+        self
+    }
+
+    pub fn null(&mut self) -> &mut Self {
+        self.last_column().nullable(true);
+
+        self
+    }
+
+    pub fn not_null(&mut self) -> &mut Self {
+        self.last_column().not_null();
+
+        self
+    }
+
+    pub fn auto_increment(&mut self) -> &mut Self {
+        let column = self.last_column();
+
+        if column.default.is_some() {
+            panic!("The column {} has a default value, it cannot be AUTO_INCREMENT.", column.name)
+        }
+
+        column.auto_increment = true;
+
+        self
+    }
+
+    pub fn primary_key(&mut self) -> &mut Self {
+        if self.has_primary_key() {
+            panic!("A table cannot have two primary keys.")
+        }
+
+        self.last_column().primary_key = true;
+
+        self
+    }
+
+    /// It adds the default value of the last column. It panics if the column has no type yet or the value doesn't fit the type of the column, such as a string for an `INT` column or a value that is not a member of an `ENUM`.
+    pub fn default(&mut self, value: ValueType) -> &mut Self {
+        self.last_column().default(value);
+
+        self
+    }
+
+    pub fn unique(&mut self) -> &mut Self {
+        self.last_column().unique = true;
+
+        self
+    }
+
+    pub fn check(&mut self, condition: &str) -> &mut Self {
+        TableConstraint::check_definition(condition);
+
+        self.last_column().check = Some(condition.to_string());
+
+        self
+    }
+
+    pub fn character_set(&mut self, character_set: &str) -> &mut Self {
+        self.last_column().character_set = Some(character_set.to_string());
+
+        self
+    }
+
+    pub fn foreign_key(&mut self, opts: ForeignKey) -> &mut Self {
+        self.constraint(opts.into())
+    }
+
+    fn has_primary_key(&self) -> bool {
+        self.columns.iter().any(|column| column.primary_key) || self.constraints.iter().any(|constraint| matches!(constraint, TableConstraint::PrimaryKey(_, _)))
+    }
+
+    /// It adds a table level constraint. It panics if the constraint refers to a column that is not in the table, if it's name is already used or if it's a second primary key.
+    pub fn constraint(&mut self, constraint: TableConstraint) -> &mut Self {
+        constraint.check_names();
+
+        if matches!(constraint, TableConstraint::PrimaryKey(_, _)) && self.has_primary_key() {
+            panic!("A table cannot have two primary keys.")
+        }
+
+        if let Some(column) = constraint.columns().iter().find(|column| !self.all.contains(column)) {
+            panic!("There is no column named {} in the table {}.", column, self.name)
+        }
+
+        if let Some(name) = constraint.name() {
+            if self.constraints.iter().any(|item| item.name() == Some(name.to_string())) {
+                panic!("There is already a constraint named {} in the table {}.", name, self.name)
+            }
+        }
+
+        self.constraints.push(constraint);
+
+        self
+    }
+
+    /// It adds a primary key on one or more columns.
+    pub fn primary_key_on(&mut self, name: Option<&str>, columns: Vec<&str>) -> &mut Self {
+        self.constraint(TableConstraint::primary_key(name, columns))
+    }
+
+    /// It adds a unique key on one or more columns.
+    pub fn unique_key(&mut self, name: Option<&str>, columns: Vec<&str>) -> &mut Self {
+        self.constraint(TableConstraint::unique(name, columns))
+    }
+
+    /// It adds a table level check constraint, the check constraint of a column is added with `.check()` method.
+    pub fn check_constraint(&mut self, name: Option<&str>, condition: &str) -> &mut Self {
+        self.constraint(TableConstraint::check(name, condition))
+    }
+
+    pub fn unsigned(&mut self) -> &mut Self {
+        self.last_column().unsigned();
+
+        self
+    }
+
+    pub fn zerofill(&mut self) -> &mut Self {
+        self.last_column().zerofill = true;
+
+        self
+    }
+
+    pub fn enum_sql(&mut self, enum_vec: Vec<&str>) -> &mut Self {
+        match enum_vec.len() {
+            0 => panic!("enum_vec argument cannot be an empty vector"),
+            _ => ()
+        }
+
+        self.column_type(ColumnType::Enum(enum_vec.into_iter().map(|item| item.to_string()).collect()))
+    }
+
+    pub fn generated_always(&mut self, condition: &str) -> &mut Self {
+        self.last_column().generated = Some((condition.to_string(), None));
+
+        self
+    }
+
+    fn generated_kind(&mut self, kind: GeneratedKind) -> &mut Self {
+        let column = self.last_column();
+
+        match &mut column.generated {
+            Some((_, generated_kind)) => *generated_kind = Some(kind),
+            None => panic!("The column {} is not a generated column, use .generated_always() method first.", column.name)
+        }
+
+        self
+    }
+
+    pub fn virtual_sql(&mut self) -> &mut Self {
+        self.generated_kind(GeneratedKind::Virtual)
+    }
+
+    pub fn stored(&mut self) -> &mut Self {
+        self.generated_kind(GeneratedKind::Stored)
+    }
+
+    pub fn spatial(&mut self) -> &mut Self {
+        self.last_column().extra.push("SPATIAL".to_string());
+
+        self
+    }
+
+    pub fn generated(&mut self) -> &mut Self {
+        self.last_column().extra.push("GENERATED".to_string());
+
+        self
+    }
+
+    pub fn index(&mut self, indexes: Vec<&str>) -> &mut Self {
+        match indexes.len() {
+            0 => panic!("There is no index here."),
+            1 => self.definitions.push(format!("INDEX({})", indexes[0])),
+            _ => self.definitions.push(format!("INDEX ({})", indexes.join(", ")))
+        }
+
+        self
+    }
+
+    pub fn comment(&mut self, comment: &str) -> &mut Self {
+        self.last_column().comment = Some(comment.to_string());
+
+        self
+    }
+
+    pub fn default_on_null(&mut self, value: ValueType) -> &mut Self {
+        let extra = match value {
+            ValueType::String(text) => format!("DEFAULT {} ON NULL", text),
+            _ => format!("DEFAULT {} ON NULL", value),
+        };
+
+        self.last_column().extra.push(extra);
+
+        self
+    }
+
+    pub fn invisible(&mut self) -> &mut Self {
+        self.last_column().invisible = true;
+
+        self
+    }
+
+    /// It makes the table a temporary table, which is dropped when the session ends.
+    pub fn temporary(&mut self) -> &mut Self {
+        self.temporary = true;
+
+        self
+    }
+
+    /// It adds a table option, the option replaces the earlier option of the same kind.
+    pub fn table_option(&mut self, option: TableOption) -> &mut Self {
+        option.check();
+
+        match self.options.iter().position(|item| item.name() == option.name()) {
+            Some(index) => self.options[index] = option,
+            None => self.options.push(option)
+        }
+
+        self
+    }
+
+    /// It sets the storage engine of the table, such as `InnoDB`.
+    pub fn engine(&mut self, engine: &str) -> &mut Self {
+        self.table_option(TableOption::Engine(engine.to_string()))
+    }
+
+    /// It sets the default character set of the table.
+    pub fn default_charset(&mut self, charset: &str) -> &mut Self {
+        self.table_option(TableOption::DefaultCharset(charset.to_string()))
+    }
+
+    /// It sets the default collation of the table.
+    pub fn collate(&mut self, collation: &str) -> &mut Self {
+        self.table_option(TableOption::Collate(collation.to_string()))
+    }
+
+    /// It sets the comment of the table, the comment of a column is set with `.comment()` method.
+    pub fn table_comment(&mut self, comment: &str) -> &mut Self {
+        self.table_option(TableOption::Comment(comment.to_string()))
+    }
+
+    /// It sets the first value of the AUTO_INCREMENT column.
+    pub fn auto_increment_start(&mut self, start: u64) -> &mut Self {
+        self.table_option(TableOption::AutoIncrement(start))
+    }
+
+    /// It sets the row format of the table.
+    pub fn row_format(&mut self, row_format: RowFormat) -> &mut Self {
+        self.table_option(TableOption::RowFormat(row_format))
+    }
+
+    /// It sets the page size of the compressed tables in kilobytes, it has to be 1, 2, 4, 8 or 16.
+    pub fn key_block_size(&mut self, size: u8) -> &mut Self {
+        self.table_option(TableOption::KeyBlockSize(size))
+    }
+
+    /// It adds a custom text to the last column, or to the table definitions if there is no column yet. If the last column has no type yet, the text is read as it's type by `ColumnType::parse()`, so the modifiers that are called later are written after it.
+    pub fn custom_query(&mut self, query: &str) -> &mut Self {
+        match self.columns.last_mut() {
+            Some(column) if column.ty.is_none() => column.ty = Some(ColumnType::parse(query)),
+            Some(column) => column.extra.push(query.to_string()),
+            None => self.definitions.push(query.to_string())
+        }
+
+        self
+    }
+
+    /// It returns the name of the table, qualified with the schema name if there is one.
+    pub fn qualified_name(&self) -> String {
+        match self.schema.is_empty() {
+            true => self.name.to_string(),
+            false => format!("{}.{}", self.schema, self.name)
+        }
+    }
+
+    pub fn finish(&mut self) -> String {
+        format!("{};", self.render())
+    }
+
+    fn render(&self) -> String {
+        let mut query = match self.temporary {
+            true => "CREATE TEMPORARY TABLE".to_string(),
+            false => "CREATE TABLE".to_string()
+        };
+
+        if self.if_not_exists {
+            query = format!("{} IF NOT EXISTS", query);
+        }
+
+        let definitions = self.columns.iter().map(|column| column.to_string())
+                                             .chain(self.constraints.iter().map(|constraint| constraint.to_sql(&Dialect::Mysql)))
+                                             .chain(self.definitions.iter().cloned()).collect::<Vec<String>>();
+
+        query = format!("{} {} ({})", query, self.qualified_name(), definitions.join(", "));
+
+        for option in self.options.iter() {
+            query = format!("{} {}", query, option);
+        }
+
+        query
+    }
+}
+
+/// Struct that benefits you to alter tables. On mysql the actions are rendered as a single statement with the online ddl options, on postgresql and sqlite the actions which cannot be combined are rendered as separate statements.
+/// 
+/// The columns are rendered with `ColumnDef::to_sql()` on postgresql and sqlite, so the mysql only attributes such as `UNSIGNED` and `AUTO_INCREMENT` panic. Sqlite can only add, drop and rename the columns, rename the table and add or drop the indexes; for the other actions sqlite rebuilds the table from it's current definition, which is given to the `AlterTableBuilder::from_table()` constructor. The rebuild creates the altered table as `<table>_new`, copies the rows into it, drops the old table and renames the new one, the indexes of the old table are dropped with it. The other actions panic on sqlite if the builder doesn't have the definition of the table.
+/// 
+/// ```rust
+/// 
+/// use qubl::{AlterTableBuilder, AlterAlgorithm, AlterLock, ColumnDef, ColumnType, Dialect, TableBuilder, ValueType};
+/// 
+/// fn main(){
+///     let query = AlterTableBuilder::alter("users").unwrap()
+///                                   .add_column(ColumnDef::new("age", ColumnType::TinyInt(None)).unsigned().not_null().default(ValueType::Int32(0)).clone())
+///                                   .after("name")
+///                                   .rename_column("mail", "email")
+///                                   .add_index("users_email_idx", vec!["email"])
+///                                   .algorithm(AlterAlgorithm::Inplace)
+///                                   .lock(AlterLock::None)
+///                                   .finish();
+/// 
+///     assert_eq!(query, "ALTER TABLE users ADD COLUMN age TINYINT UNSIGNED NOT NULL DEFAULT 0 AFTER name, RENAME COLUMN mail TO email, ADD INDEX users_email_idx (email), ALGORITHM = INPLACE, LOCK = NONE;");
+/// 
+///     let query = AlterTableBuilder::alter("users").unwrap()
+///                                   .dialect(Dialect::Sqlite)
+///                                   .add_column(ColumnDef::new("age", ColumnType::Int(None)))
+///                                   .rename_to("members")
+///                                   .add_index("members_age_idx", vec!["age"])
+///                                   .finish();
+/// 
+///     assert_eq!(query, "ALTER TABLE users ADD COLUMN age INT; ALTER TABLE users RENAME TO members; CREATE INDEX members_age_idx ON members (age);");
+/// 
+///     let table = TableBuilder::create("", "users").add_column("id").col_type("INT").primary_key().add_column("age").col_type("INT").clone();
+/// 
+///     let query = AlterTableBuilder::from_table(&table).unwrap()
+///                                   .dialect(Dialect::Sqlite)
+///                                   .modify_column(ColumnDef::new("age", ColumnType::Int(None)).not_null().default(ValueType::Int32(0)).clone())
+///                                   .finish();
+/// 
+///     assert_eq!(query, "CREATE TABLE users_new (id INT PRIMARY KEY, age INT NOT NULL DEFAULT 0); INSERT INTO users_new (id, age) SELECT id, age FROM users; DROP TABLE users; ALTER TABLE users_new RENAME TO users;");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub struct AlterTableBuilder {
+    pub name: String,
+    pub dialect: Dialect,
+    pub actions: Vec<AlterTableAction>,
+    pub algorithm: Option<AlterAlgorithm>,
+    pub lock: Option<AlterLock>,
+    pub table: Option<TableBuilder>
+}
+
+/// AlterTableAction enum. It's an action of an `AlterTableBuilder`.
+#[derive(Debug, Clone)]
+pub enum AlterTableAction {
+    AddColumn(ColumnDef, Option<ColumnPosition>), DropColumn(String), ModifyColumn(ColumnDef, Option<ColumnPosition>), ChangeColumn(String, ColumnDef, Option<ColumnPosition>),
+    RenameColumn(String, String), AddIndex(String, Vec<String>), DropIndex(String), AddForeignKey(ForeignKey), DropForeignKey(String),
+    AddConstraint(TableConstraint), DropConstraint(String), DropPrimaryKey(Option<String>), DropUnique(String), DropCheck(String), RenameTo(String), TableOption(TableOption)
+}
+
+/// ColumnPosition enum. It's the position of an added or modified column on mysql, `FIRST` or `AFTER column`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ColumnPosition {
+    First, After(String)
+}
+
+impl std::fmt::Display for ColumnPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnPosition::First => write!(f, "FIRST"),
+            ColumnPosition::After(column) => write!(f, "AFTER {}", column)
+        }
+    }
+}
+
+/// AlterAlgorithm enum. It's the algorithm that mysql uses to alter a table.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlterAlgorithm {
+    Default, Instant, Inplace, Copy
+}
+
+impl std::fmt::Display for AlterAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlterAlgorithm::Default => write!(f, "DEFAULT"),
+            AlterAlgorithm::Instant => write!(f, "INSTANT"),
+            AlterAlgorithm::Inplace => write!(f, "INPLACE"),
+            AlterAlgorithm::Copy => write!(f, "COPY")
+        }
+    }
+}
+
+/// AlterLock enum. It's the level of the concurrent access that mysql allows while a table is altered.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlterLock {
+    Default, None, Shared, Exclusive
+}
+
+impl std::fmt::Display for AlterLock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AlterLock::Default => write!(f, "DEFAULT"),
+            AlterLock::None => write!(f, "NONE"),
+            AlterLock::Shared => write!(f, "SHARED"),
+            AlterLock::Exclusive => write!(f, "EXCLUSIVE")
+        }
+    }
+}
+
+/// implementations of AlterTableBuilder
+impl AlterTableBuilder {
+    /// AlterTableBuilder constructor. The default dialect is mysql.
+    pub fn alter(table_name: &str) -> std::result::Result<Self, std::io::Error> {
+        RoutineBuilder::check_name(table_name)?;
+
+        Ok(Self {
+            name: table_name.to_string(),
+            dialect: Dialect::Mysql,
+            actions: vec![],
+            algorithm: None,
+            lock: None,
+            table: None
+        })
+    }
+
+    /// AlterTableBuilder constructor with the current definition of the table, sqlite rebuilds the table with it for the actions it cannot run with "ALTER TABLE". The default dialect is mysql.
+    pub fn from_table(table: &TableBuilder) -> std::result::Result<Self, std::io::Error> {
+        let mut builder = Self::alter(&table.qualified_name())?;
+        builder.table = Some(table.clone());
+
+        Ok(builder)
+    }
+
+    /// It sets the dialect of the statement. It panics if one of the actions that are already added is not supported on that dialect.
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        self.dialect = dialect;
+
+        for action in self.actions.clone().iter() {
+            self.check_action(action);
+        }
+
+        if self.dialect != Dialect::Mysql && (self.algorithm.is_some() || self.lock.is_some()) {
+            panic!("ALGORITHM and LOCK options are only supported on mysql.")
+        }
+
+        self
+    }
+
+    fn check_column_name(name: &str) {
+        if let Err(error) = RoutineBuilder::check_name(name) {
+            panic!("{}", error)
+        }
+    }
+
+    fn check_action(&self, action: &AlterTableAction) {
+        match action {
+            AlterTableAction::AddColumn(column, _) | AlterTableAction::ModifyColumn(column, _) | AlterTableAction::ChangeColumn(_, column, _) => {
+                if let Err(error) = column.check_dialect(&self.dialect) {
+                    panic!("{}", error)
+                }
+            },
+            _ => ()
+        }
+
+        match (&self.dialect, action) {
+            (Dialect::Mysql, _) => (),
+            (_, AlterTableAction::AddColumn(_, Some(_))) | (_, AlterTableAction::ModifyColumn(_, Some(_))) | (_, AlterTableAction::ChangeColumn(_, _, Some(_))) => {
+                panic!("FIRST and AFTER column positions are only supported on mysql.")
+            },
+            (_, AlterTableAction::TableOption(_)) => panic!("table options are only supported on mysql."),
+            (Dialect::Sqlite, action) if Self::sqlite_rebuilds(action) && self.table.is_none() => {
+                panic!("sqlite cannot modify the columns and the constraints of a table without rebuilding it, create the builder with AlterTableBuilder::from_table() to rebuild the table.")
+            },
+            (Dialect::Postgresql, AlterTableAction::ModifyColumn(column, _)) | (Dialect::Postgresql, AlterTableAction::ChangeColumn(_, column, _)) if column.ty.is_none() => {
+                panic!("the modified column {} needs a type on postgresql.", column.name)
+            },
+            (Dialect::Postgresql, AlterTableAction::ModifyColumn(column, _)) | (Dialect::Postgresql, AlterTableAction::ChangeColumn(_, column, _)) if column.generated.is_some() || !column.extra.is_empty() => {
+                panic!("the generated columns and the custom texts of the column {} cannot be modified on postgresql, drop and add the column instead.", column.name)
+            },
+            _ => ()
+        }
+    }
+
+    /// checks if sqlite has to rebuild the table for the action.
+    fn sqlite_rebuilds(action: &AlterTableAction) -> bool {
+        match action {
+            AlterTableAction::AddColumn(column, _) => column.primary_key || column.unique,
+            AlterTableAction::DropColumn(_) | AlterTableAction::RenameColumn(_, _) | AlterTableAction::AddIndex(_, _) | AlterTableAction::DropIndex(_) |
+            AlterTableAction::RenameTo(_) | AlterTableAction::TableOption(_) => false,
+            _ => true
+        }
+    }
+
+    fn push_action(&mut self, action: AlterTableAction) -> &mut Self {
+        self.check_action(&action);
+        self.actions.push(action);
+
+        self
+    }
+
+    /// It adds a column.
+    pub fn add_column(&mut self, column: ColumnDef) -> &mut Self {
+        Self::check_column_name(&column.name);
+
+        self.push_action(AlterTableAction::AddColumn(column, None))
+    }
+
+    /// It drops a column.
+    pub fn drop_column(&mut self, column: &str) -> &mut Self {
+        Self::check_column_name(column);
+
+        self.push_action(AlterTableAction::DropColumn(column.to_string()))
+    }
+
+    /// It redefines a column without renaming it. On postgresql it's rendered as "ALTER COLUMN" actions that change the type, the nullability and the default value of the column, and the unique, primary key and check attributes are added as constraints.
+    pub fn modify_column(&mut self, column: ColumnDef) -> &mut Self {
+        Self::check_column_name(&column.name);
+
+        self.push_action(AlterTableAction::ModifyColumn(column, None))
+    }
+
+    /// It renames and redefines a column. On postgresql the column is renamed with a separate statement.
+    pub fn change_column(&mut self, old_name: &str, column: ColumnDef) -> &mut Self {
+        Self::check_column_name(old_name);
+        Self::check_column_name(&column.name);
+
+        self.push_action(AlterTableAction::ChangeColumn(old_name.to_string(), column, None))
+    }
+
+    /// It renames a column.
+    pub fn rename_column(&mut self, old_name: &str, new_name: &str) -> &mut Self {
+        Self::check_column_name(old_name);
+        Self::check_column_name(new_name);
+
+        self.push_action(AlterTableAction::RenameColumn(old_name.to_string(), new_name.to_string()))
+    }
+
+    fn position(&mut self, position: ColumnPosition) -> &mut Self {
+        if self.dialect != Dialect::Mysql {
+            panic!("FIRST and AFTER column positions are only supported on mysql.")
+        }
+
+        match self.actions.last_mut() {
+            Some(AlterTableAction::AddColumn(_, column_position)) | Some(AlterTableAction::ModifyColumn(_, column_position)) | Some(AlterTableAction::ChangeColumn(_, _, column_position)) => {
+                *column_position = Some(position)
+            },
+            _ => panic!("FIRST and AFTER can only be used after adding, modifying or changing a column.")
+        }
+
+        self
+    }
+
+    /// It puts the last added, modified or changed column to the first place, it's only supported on mysql.
+    pub fn first(&mut self) -> &mut Self {
+        self.position(ColumnPosition::First)
+    }
+
+    /// It puts the last added, modified or changed column after the given column, it's only supported on mysql.
+    pub fn after(&mut self, column: &str) -> &mut Self {
+        Self::check_column_name(column);
+
+        self.position(ColumnPosition::After(column.to_string()))
+    }
+
+    /// It adds an index. On postgresql and sqlite it's rendered as a "CREATE INDEX" statement.
+    pub fn add_index(&mut self, name: &str, columns: Vec<&str>) -> &mut Self {
+        Self::check_column_name(name);
+
+        if columns.is_empty() {
+            panic!("There is no index here.")
+        }
+
+        self.push_action(AlterTableAction::AddIndex(name.to_string(), columns.iter().map(|column| column.to_string()).collect()))
+    }
+
+    /// It drops an index. On postgresql and sqlite it's rendered as a "DROP INDEX" statement.
+    pub fn drop_index(&mut self, name: &str) -> &mut Self {
+        Self::check_column_name(name);
+
+        self.push_action(AlterTableAction::DropIndex(name.to_string()))
+    }
+
+    /// It adds a foreign key.
+    pub fn add_foreign_key(&mut self, foreign_key: ForeignKey) -> &mut Self {
+        self.push_action(AlterTableAction::AddForeignKey(foreign_key))
+    }
+
+    /// It drops a foreign key with it's constraint name. On postgresql it's rendered as "DROP CONSTRAINT".
+    pub fn drop_foreign_key(&mut self, name: &str) -> &mut Self {
+        Self::check_column_name(name);
+
+        self.push_action(AlterTableAction::DropForeignKey(name.to_string()))
+    }
+
+    /// It adds a named constraint, such as `add_constraint("positive_price", "CHECK(price > 0)")`. The definition cannot include ';' character, comments, backslashes or unclosed quotes.
+    pub fn add_constraint(&mut self, name: &str, definition: &str) -> &mut Self {
+        let constraint = TableConstraint::Custom(name.to_string(), definition.to_string());
+
+        constraint.check_names();
+
+        self.push_action(AlterTableAction::AddConstraint(constraint))
+    }
+
+    /// It adds a table level constraint such as a composite primary key, a unique key or a check constraint.
+    pub fn add_table_constraint(&mut self, constraint: TableConstraint) -> &mut Self {
+        constraint.check_names();
+
+        self.push_action(AlterTableAction::AddConstraint(constraint))
+    }
+
+    /// It drops the primary key. Postgresql drops it with the name of the constraint, it's `<table>_pkey` if the name is not given. Mysql doesn't need the name, it's ignored on mysql.
+    pub fn drop_primary_key(&mut self, name: Option<&str>) -> &mut Self {
+        if let Some(name) = name {
+            Self::check_column_name(name);
+        }
+
+        self.push_action(AlterTableAction::DropPrimaryKey(name.map(|name| name.to_string())))
+    }
+
+    /// It drops a unique key. On mysql it's rendered as "DROP INDEX".
+    pub fn drop_unique(&mut self, name: &str) -> &mut Self {
+        Self::check_column_name(name);
+
+        self.push_action(AlterTableAction::DropUnique(name.to_string()))
+    }
+
+    /// It drops a check constraint. On mysql it's rendered as "DROP CHECK".
+    pub fn drop_check(&mut self, name: &str) -> &mut Self {
+        Self::check_column_name(name);
+
+        self.push_action(AlterTableAction::DropCheck(name.to_string()))
+    }
+
+    /// It drops a constraint.
+    pub fn drop_constraint(&mut self, name: &str) -> &mut Self {
+        Self::check_column_name(name);
+
+        self.push_action(AlterTableAction::DropConstraint(name.to_string()))
+    }
+
+    /// It renames the table. On postgresql and sqlite it's rendered as a separate statement and the statements after it use the new name.
+    pub fn rename_to(&mut self, new_name: &str) -> &mut Self {
+        Self::check_column_name(new_name);
+
+        self.push_action(AlterTableAction::RenameTo(new_name.to_string()))
+    }
+
+    /// It changes a table option of mysql, such as `option("ENGINE", "InnoDB")`.
+    pub fn option(&mut self, name: &str, value: &str) -> &mut Self {
+        self.table_option(TableOption::Custom(name.to_string(), value.to_string()))
+    }
+
+    /// It changes a table option of mysql.
+    pub fn table_option(&mut self, option: TableOption) -> &mut Self {
+        option.check();
+
+        self.push_action(AlterTableAction::TableOption(option))
+    }
+
+    /// It sets the ALGORITHM option of mysql, `INSTANT` and `INPLACE` algorithms alter the table without copying it.
+    pub fn algorithm(&mut self, algorithm: AlterAlgorithm) -> &mut Self {
+        if self.dialect != Dialect::Mysql {
+            panic!("ALGORITHM and LOCK options are only supported on mysql.")
+        }
+
+        self.algorithm = Some(algorithm);
+
+        self
+    }
+
+    /// It sets the LOCK option of mysql, `LOCK = NONE` allows the reads and the writes while the table is altered.
+    pub fn lock(&mut self, lock: AlterLock) -> &mut Self {
+        if self.dialect != Dialect::Mysql {
+            panic!("ALGORITHM and LOCK options are only supported on mysql.")
+        }
+
+        self.lock = Some(lock);
+
+        self
+    }
+
+    /// Finishes the statements and returns them as one string.
+    pub fn finish(&self) -> String {
+        self.statements().iter().map(|statement| format!("{};", statement)).collect::<Vec<String>>().join(" ")
+    }
+
+    /// Returns the statements one by one, without the trailing ";" characters. On mysql there is always one statement.
+    pub fn statements(&self) -> Vec<String> {
+        if self.actions.is_empty() {
+            panic!("There is no action to alter the table {}.", self.name)
+        }
+
+        match self.dialect {
+            Dialect::Mysql => {
+                let mut clauses = self.actions.iter().map(|action| self.mysql_clause(action)).collect::<Vec<String>>();
+
+                if let Some(algorithm) = &self.algorithm {
+                    clauses.push(format!("ALGORITHM = {}", algorithm));
+                }
+
+                if let Some(lock) = &self.lock {
+                    clauses.push(format!("LOCK = {}", lock));
+                }
+
+                vec![format!("ALTER TABLE {} {}", self.name, clauses.join(", "))]
+            },
+            _ => {
+                let rebuilt = self.dialect == Dialect::Sqlite && self.actions.iter().any(Self::sqlite_rebuilds);
+                let mut statements = match rebuilt {
+                    true => self.sqlite_rebuild(),
+                    false => vec![]
+                };
+                let mut clauses: Vec<String> = vec![];
+                let mut table = self.name.clone();
+
+                for action in self.actions.iter() {
+                    let combinable = match (&self.dialect, action) {
+                        (Dialect::Postgresql, AlterTableAction::AddColumn(column, _)) => vec![format!("ADD COLUMN {}", column.to_sql(&self.dialect))],
+                        (Dialect::Postgresql, AlterTableAction::DropColumn(column)) => vec![format!("DROP COLUMN {}", column)],
+                        (Dialect::Postgresql, AlterTableAction::ModifyColumn(column, _)) => Self::pg_alter_column(&column.name, column),
+                        (Dialect::Postgresql, AlterTableAction::AddForeignKey(foreign_key)) => vec![format!("ADD {}", foreign_key.render())],
+                        (Dialect::Postgresql, AlterTableAction::DropForeignKey(name)) | (Dialect::Postgresql, AlterTableAction::DropConstraint(name)) |
+                        (Dialect::Postgresql, AlterTableAction::DropUnique(name)) | (Dialect::Postgresql, AlterTableAction::DropCheck(name)) |
+                        (Dialect::Postgresql, AlterTableAction::DropPrimaryKey(Some(name))) => vec![format!("DROP CONSTRAINT {}", name)],
+                        (Dialect::Postgresql, AlterTableAction::DropPrimaryKey(None)) => vec![format!("DROP CONSTRAINT {}_pkey", table.rsplit('.').next().unwrap_or(&table))],
+                        (Dialect::Postgresql, AlterTableAction::AddConstraint(constraint)) => vec![format!("ADD {}", constraint.to_sql(&self.dialect))],
+                        _ => vec![]
+                    };
+
+                    if !combinable.is_empty() {
+                        clauses.extend(combinable);
+
+                        continue;
+                    }
+
+                    if !clauses.is_empty() {
+                        statements.push(format!("ALTER TABLE {} {}", table, clauses.join(", ")));
+                        clauses.clear();
+                    }
+
+                    match action {
+                        AlterTableAction::AddIndex(_, _) | AlterTableAction::DropIndex(_) | AlterTableAction::RenameTo(_) => (),
+                        _ if rebuilt => continue,
+                        _ => ()
+                    }
+
+                    match action {
+                        AlterTableAction::AddColumn(column, _) => statements.push(format!("ALTER TABLE {} ADD COLUMN {}", table, column.to_sql(&self.dialect))),
+                        AlterTableAction::DropColumn(column) => statements.push(format!("ALTER TABLE {} DROP COLUMN {}", table, column)),
+                        AlterTableAction::RenameColumn(old_name, new_name) => statements.push(format!("ALTER TABLE {} RENAME COLUMN {} TO {}", table, old_name, new_name)),
+                        AlterTableAction::ChangeColumn(old_name, column, _) => {
+                            if *old_name != column.name {
+                                statements.push(format!("ALTER TABLE {} RENAME COLUMN {} TO {}", table, old_name, column.name));
+                            }
+
+                            statements.push(format!("ALTER TABLE {} {}", table, Self::pg_alter_column(&column.name, column).join(", ")));
+                        },
+                        AlterTableAction::AddIndex(name, columns) => statements.push(format!("CREATE INDEX {} ON {} ({})", name, table, columns.join(", "))),
+                        AlterTableAction::DropIndex(name) => statements.push(format!("DROP INDEX {}", name)),
+                        AlterTableAction::RenameTo(new_name) => {
+                            statements.push(format!("ALTER TABLE {} RENAME TO {}", table, new_name));
+                            table = new_name.to_string();
+                        },
+                        _ => ()
+                    }
+                }
+
+                if !clauses.is_empty() {
+                    statements.push(format!("ALTER TABLE {} {}", table, clauses.join(", ")));
+                }
+
+                statements
+            }
+        }
+    }
+
+    fn mysql_clause(&self, action: &AlterTableAction) -> String {
+        let with_position = |clause: String, position: &Option<ColumnPosition>| match position {
+            Some(position) => format!("{} {}", clause, position),
+            None => clause
+        };
+
+        match action {
+            AlterTableAction::AddColumn(column, position) => with_position(format!("ADD COLUMN {}", column), position),
+            AlterTableAction::DropColumn(column) => format!("DROP COLUMN {}", column),
+            AlterTableAction::ModifyColumn(column, position) => with_position(format!("MODIFY COLUMN {}", column), position),
+            AlterTableAction::ChangeColumn(old_name, column, position) => with_position(format!("CHANGE COLUMN {} {}", old_name, column), position),
+            AlterTableAction::RenameColumn(old_name, new_name) => format!("RENAME COLUMN {} TO {}", old_name, new_name),
+            AlterTableAction::AddIndex(name, columns) => format!("ADD INDEX {} ({})", name, columns.join(", ")),
+            AlterTableAction::DropIndex(name) => format!("DROP INDEX {}", name),
+            AlterTableAction::AddForeignKey(foreign_key) => format!("ADD {}", foreign_key.render()),
+            AlterTableAction::DropForeignKey(name) => format!("DROP FOREIGN KEY {}", name),
+            AlterTableAction::AddConstraint(constraint) => format!("ADD {}", constraint.to_sql(&self.dialect)),
+            AlterTableAction::DropConstraint(name) => format!("DROP CONSTRAINT {}", name),
+            AlterTableAction::DropPrimaryKey(_) => "DROP PRIMARY KEY".to_string(),
+            AlterTableAction::DropUnique(name) => format!("DROP INDEX {}", name),
+            AlterTableAction::DropCheck(name) => format!("DROP CHECK {}", name),
+            AlterTableAction::RenameTo(new_name) => format!("RENAME TO {}", new_name),
+            AlterTableAction::TableOption(option) => option.to_string()
+        }
+    }
+
+    /// rebuilds the table on sqlite, the column and constraint actions are applied to the definition of the table and the rows are copied to the new table with the columns that exist in both tables.
+    fn sqlite_rebuild(&self) -> Vec<String> {
+        let table = match &self.table {
+            Some(table) => table,
+            None => panic!("sqlite cannot rebuild the table {} without it's definition, create the builder with AlterTableBuilder::from_table().", self.name)
+        };
+
+        let mut columns = table.columns.iter().map(|column| (column.clone(), Some(column.name.clone()))).collect::<Vec<(ColumnDef, Option<String>)>>();
+        let mut constraints = table.constraints.clone();
+
+        let position = |columns: &Vec<(ColumnDef, Option<String>)>, name: &str| match columns.iter().position(|(column, _)| column.name == name) {
+            Some(position) => position,
+            None => panic!("There is no column {} in the table {}.", name, self.name)
+        };
+
+        let rename = |constraints: &mut Vec<TableConstraint>, old_name: &str, new_name: &str| {
+            for constraint in constraints.iter_mut() {
+                if let TableConstraint::PrimaryKey(_, columns) | TableConstraint::Unique(_, columns) | TableConstraint::ForeignKey { columns, .. } = constraint {
+                    for column in columns.iter_mut().filter(|column| *column == old_name) {
+                        *column = new_name.to_string();
+                    }
+                }
+            }
+        };
+
+        for action in self.actions.iter() {
+            match action {
+                AlterTableAction::AddColumn(column, _) => columns.push((column.clone(), None)),
+                AlterTableAction::DropColumn(name) => {
+                    columns.remove(position(&columns, name));
+                },
+                AlterTableAction::ModifyColumn(column, _) => {
+                    let index = position(&columns, &column.name);
+                    columns[index].0 = column.clone();
+                },
+                AlterTableAction::ChangeColumn(old_name, column, _) => {
+                    let index = position(&columns, old_name);
+                    columns[index].0 = column.clone();
+                    rename(&mut constraints, old_name, &column.name);
+                },
+                AlterTableAction::RenameColumn(old_name, new_name) => {
+                    let index = position(&columns, old_name);
+                    columns[index].0.name = new_name.to_string();
+                    rename(&mut constraints, old_name, new_name);
+                },
+                AlterTableAction::AddForeignKey(foreign_key) => constraints.push(foreign_key.clone().into()),
+                AlterTableAction::AddConstraint(constraint) => constraints.push(constraint.clone()),
+                AlterTableAction::DropForeignKey(name) | AlterTableAction::DropConstraint(name) | AlterTableAction::DropUnique(name) | AlterTableAction::DropCheck(name) => {
+                    match constraints.iter().position(|constraint| constraint.name().as_deref() == Some(name.as_str())) {
+                        Some(index) => {
+                            constraints.remove(index);
+                        },
+                        None => panic!("There is no constraint {} in the table {}.", name, self.name)
+                    }
+                },
+                AlterTableAction::DropPrimaryKey(_) => {
+                    constraints.retain(|constraint| !matches!(constraint, TableConstraint::PrimaryKey(_, _)));
+
+                    for (column, _) in columns.iter_mut() {
+                        column.primary_key = false;
+                    }
+                },
+                _ => ()
+            }
+        }
+
+        let new_table = format!("{}_new", self.name);
+        let definitions = columns.iter().map(|(column, _)| column.to_sql(&self.dialect))
+                                 .chain(constraints.iter().map(|constraint| constraint.to_sql(&self.dialect)))
+                                 .chain(table.definitions.iter().cloned()).collect::<Vec<String>>();
+
+        let copied = columns.iter().filter(|(column, _)| column.generated.is_none())
+                            .filter_map(|(column, source)| source.as_ref().map(|source| (column.name.clone(), source.clone()))).collect::<Vec<(String, String)>>();
+
+        let mut statements = vec![format!("CREATE TABLE {} ({})", new_table, definitions.join(", "))];
+
+        if !copied.is_empty() {
+            let (targets, sources): (Vec<String>, Vec<String>) = copied.into_iter().unzip();
+
+            statements.push(format!("INSERT INTO {} ({}) SELECT {} FROM {}", new_table, targets.join(", "), sources.join(", "), self.name));
+        }
+
+        statements.push(format!("DROP TABLE {}", self.name));
+        statements.push(format!("ALTER TABLE {} RENAME TO {}", new_table, self.name.rsplit('.').next().unwrap_or(&self.name)));
+
+        statements
+    }
+
+    fn pg_alter_column(name: &str, column: &ColumnDef) -> Vec<String> {
+        let mut clauses = vec![];
+
+        if let Some(ty) = &column.ty {
+            clauses.push(format!("ALTER COLUMN {} TYPE {}", name, ty.to_sql(&Dialect::Postgresql)));
+        }
+
+        match column.nullable {
+            Some(true) => clauses.push(format!("ALTER COLUMN {} DROP NOT NULL", name)),
+            Some(false) => clauses.push(format!("ALTER COLUMN {} SET NOT NULL", name)),
+            None => ()
+        }
+
+        if let Some(value) = &column.default {
+            clauses.push(format!("ALTER COLUMN {} SET DEFAULT {}", name, column.render_default(value)));
+        }
+
+        if column.unique {
+            clauses.push(format!("ADD UNIQUE ({})", name));
+        }
+
+        if column.primary_key {
+            clauses.push(format!("ADD PRIMARY KEY ({})", name));
+        }
+
+        if let Some(check) = &column.check {
+            clauses.push(format!("ADD CHECK({})", check));
+        }
+
+        clauses
+    }
+}
+
+/// Struct that benefits you to create stored procedures and functions. The body can be built from raw statements and the output of `QueryBuilder`.
+/// 
+/// ```rust
+/// 
+/// use qubl::{RoutineBuilder, RoutineCharacteristic, ParamMode, QueryBuilder, Expr};
+/// 
+/// fn main(){
+///     let count = QueryBuilder::count("*", None).table("orders").where_("user_id", "=", Expr::col("uid")).clone();
+/// 
+///     let query = RoutineBuilder::procedure("count_orders").unwrap()
+///                                .param(ParamMode::In, "uid", "INT")
+///                                .param(ParamMode::Out, "total", "INT")
+///                                .characteristic(RoutineCharacteristic::ReadsSqlData)
+///                                .query_into(&count, vec!["total"])
+///                                .finish();
+/// 
+///     assert_eq!(query, "CREATE PROCEDURE count_orders(IN uid INT, OUT total INT) READS SQL DATA BEGIN SELECT COUNT(*) FROM orders WHERE user_id = uid INTO total; END;");
+/// 
+///     let query = RoutineBuilder::function("full_name").unwrap()
+///                                .param(ParamMode::In, "first", "VARCHAR(50)")
+///                                .param(ParamMode::In, "last", "VARCHAR(50)")
+///                                .returns("VARCHAR(101)")
+///                                .characteristic(RoutineCharacteristic::Deterministic)
+///                                .return_value(Expr::concat_ws(String::from(" "), vec![Expr::col("first"), Expr::col("last")]))
+///                                .delimiter("//")
+///                                .finish();
+/// 
+///     assert_eq!(query, "DELIMITER //\nCREATE FUNCTION full_name(first VARCHAR(50), last VARCHAR(50)) RETURNS VARCHAR(101) DETERMINISTIC BEGIN RETURN CONCAT_WS(' ', first, last); END//\nDELIMITER ;");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub struct RoutineBuilder {
+    pub kind: RoutineKind,
+    pub name: String,
+    pub or_replace: bool,
+    pub parameters: Vec<String>,
+    pub returns: Option<String>,
+    pub characteristics: Vec<RoutineCharacteristic>,
+    pub body: Vec<String>,
+    pub delimiter: Option<String>
+}
+
+/// implementations of RoutineBuilder
+impl RoutineBuilder {
+    /// Procedure constructor. Use it if you want to create a stored procedure.
+    pub fn procedure(name: &str) -> std::result::Result<Self, std::io::Error> {
+        Self::new(RoutineKind::Procedure, name)
+    }
+
+    /// Function constructor. Use it if you want to create a stored function, it requires a return type with `.returns()` method.
+    pub fn function(name: &str) -> std::result::Result<Self, std::io::Error> {
+        Self::new(RoutineKind::Function, name)
+    }
+
+    fn new(kind: RoutineKind, name: &str) -> std::result::Result<Self, std::io::Error> {
+        Self::check_name(name)?;
+
+        Ok(Self {
+            kind,
+            name: name.to_string(),
+            or_replace: false,
+            parameters: vec![],
+            returns: None,
+            characteristics: vec![],
+            body: vec![],
+            delimiter: None
+        })
+    }
+
+    /// It adds the "OR REPLACE" keyword.
+    pub fn or_replace(&mut self) -> &mut Self {
+        self.or_replace = true;
+
+        self
+    }
+
+    /// It adds a parameter. Functions can only have `IN` parameters, their mode is not written.
+    pub fn param(&mut self, mode: ParamMode, name: &str, type_name: &str) -> &mut Self {
+        if let Err(error) = Self::check_name(name) {
+            panic!("{}", error)
+        }
+
+        if type_name.contains(';') {
+            panic!("parameter types cannot include ';' character.")
+        }
+
+        match (&self.kind, mode) {
+            (RoutineKind::Function, ParamMode::In) => self.parameters.push(format!("{} {}", name, type_name)),
+            (RoutineKind::Function, _) => panic!("functions can only have IN parameters."),
+            (RoutineKind::Procedure, mode) => self.parameters.push(format!("{} {} {}", mode, name, type_name))
+        }
+
+        self
+    }
+
+    /// It sets the return type of a function.
+    pub fn returns(&mut self, type_name: &str) -> &mut Self {
+        match self.kind {
+            RoutineKind::Function => (),
+            RoutineKind::Procedure => panic!("procedures cannot have a return type.")
+        }
+
+        if type_name.contains(';') {
+            panic!("return types cannot include ';' character.")
+        }
+
+        self.returns = Some(type_name.to_string());
+
+        self
+    }
+
+    /// It adds a characteristic such as `DETERMINISTIC` or `SQL SECURITY INVOKER`.
+    pub fn characteristic(&mut self, characteristic: RoutineCharacteristic) -> &mut Self {
+        self.characteristics.push(characteristic);
+
+        self
+    }
+
+    /// It adds a local variable declaration to the body, with the optional default value. The quotes and the backslashes of the string defaults are escaped.
+    pub fn declare(&mut self, name: &str, type_name: &str, default: Option<ValueType>) -> &mut Self {
+        if let Err(error) = Self::check_name(name) {
+            panic!("{}", error)
+        }
+
+        if type_name.contains(';') {
+            panic!("variable types cannot include ';' character.")
+        }
+
+        match default {
+            Some(mut value) => {
+                SetBuilder::escape(&mut value);
+
+                self.body.push(format!("DECLARE {} {} DEFAULT {}", name, type_name, value))
+            },
+            None => self.body.push(format!("DECLARE {} {}", name, type_name))
+        }
+
+        self
+    }
+
+    /// It adds a raw statement to the body.
+    pub fn statement(&mut self, statement: &str) -> &mut Self {
+        self.body.push(statement.trim_end_matches(';').to_string());
+
+        self
+    }
+
+    /// It adds a query to the body.
+    pub fn query(&mut self, query: &QueryBuilder) -> &mut Self {
+        self.body.push(query.finish().trim_end_matches(';').to_string());
+
+        self
+    }
+
+    /// It adds a select query to the body which stores it's values into the given variables or parameters with the `INTO` keyword.
+    pub fn query_into(&mut self, query: &QueryBuilder, variables: Vec<&str>) -> &mut Self {
+        if variables.is_empty() {
+            panic!("you cannot pass an empty vector to the variables argument.")
+        }
+
+        for variable in variables.iter() {
+            if let Err(error) = Self::check_name(variable) {
+                panic!("{}", error)
+            }
+        }
+
+        self.body.push(format!("{} INTO {}", query.finish().trim_end_matches(';'), variables.join(", ")));
+
+        self
+    }
+
+    /// It adds the "RETURN" statement of a function to the body.
+    pub fn return_value<T: Into<Expr>>(&mut self, value: T) -> &mut Self {
+        match self.kind {
+            RoutineKind::Function => (),
+            RoutineKind::Procedure => panic!("procedures cannot return a value, use OUT parameters instead.")
+        }
+
+        self.body.push(format!("RETURN {}", value.into().to_sql(&Dialect::Mysql)));
+
+        self
+    }
+
+    /// It wraps the routine with `DELIMITER` statements for the mysql client scripts.
+    pub fn delimiter(&mut self, delimiter: &str) -> &mut Self {
+        if delimiter.is_empty() || delimiter.contains(';') || delimiter.contains(char::is_whitespace) {
+            panic!("delimiter cannot be empty and cannot include ';' or whitespace characters.")
+        }
+
+        self.delimiter = Some(delimiter.to_string());
+
+        self
+    }
+
+    /// Finishes the routine and returns the result as string.
+    pub fn finish(&self) -> String {
+        match &self.delimiter {
+            Some(delimiter) => format!("DELIMITER {}\n{}{}\nDELIMITER ;", delimiter, self.render(), delimiter),
+            None => format!("{};", self.render())
+        }
+    }
+
+    fn render(&self) -> String {
+        if self.body.is_empty() {
+            panic!("routines cannot have an empty body.")
+        }
+
+        let mut query = match self.or_replace {
+            true => format!("CREATE OR REPLACE {} {}({})", self.kind, self.name, self.parameters.join(", ")),
+            false => format!("CREATE {} {}({})", self.kind, self.name, self.parameters.join(", "))
+        };
+
+        match (&self.kind, &self.returns) {
+            (RoutineKind::Function, Some(returns)) => query = format!("{} RETURNS {}", query, returns),
+            (RoutineKind::Function, None) => panic!("functions must have a return type, use .returns() method."),
+            (RoutineKind::Procedure, _) => ()
+        }
+
+        for characteristic in self.characteristics.iter() {
+            query = format!("{} {}", query, characteristic);
+        }
+
+        format!("{} BEGIN {}; END", query, self.body.join("; "))
+    }
+
+    /// It returns the "DROP PROCEDURE" or "DROP FUNCTION" statement.
+    pub fn drop(kind: RoutineKind, name: &str, if_exists: bool) -> std::result::Result<String, std::io::Error> {
+        Self::check_name(name)?;
+
+        match if_exists {
+            true => Ok(format!("DROP {} IF EXISTS {};", kind, name)),
+            false => Ok(format!("DROP {} {};", kind, name))
+        }
+    }
+
+    /// It returns the "CALL" statement of a procedure. Use `Expr::col("@name")` for the user variables of the `OUT` parameters. The quotes and the backslashes of the string arguments are escaped.
+    pub fn call(name: &str, args: Vec<Expr>) -> std::result::Result<String, std::io::Error> {
+        Self::check_name(name)?;
+
+        let args = args.into_iter().map(|mut arg| {
+            arg.escape_values();
+
+            arg.to_sql(&Dialect::Mysql)
+        }).collect::<Vec<String>>();
+
+        Ok(format!("CALL {}({});", name, args.join(", ")))
+    }
+
+    fn check_name(name: &str) -> std::result::Result<(), std::io::Error> {
+        if name.is_empty() || !name.chars().all(|character| character.is_ascii_alphanumeric() || character == '_' || character == '.') {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "routine, parameter and variable names can only include letters, digits, '_' and '.' characters."))
+        }
+
+        Ok(())
+    }
+}
+
+/// Struct that benefits you to create triggers. The body can be built from raw statements and the output of `QueryBuilder`, `NEW.` and `OLD.` column references are created with `.new_col()` and `.old_col()` methods.
+/// 
+/// ```rust
+/// 
+/// use qubl::{TriggerBuilder, TriggerTiming, TriggerEvent, TriggerRow, TableBuilder, QueryBuilder, Expr, ValueType};
+/// 
+/// fn main(){
+///     let mut users = TableBuilder::create("shop", "users");
+///     users.add_column("id").col_type("INT").add_column("name").col_type("VARCHAR(50)");
+/// 
+///     let query = TriggerBuilder::for_table("users_audit", TriggerTiming::After, TriggerEvent::Update, &users).unwrap()
+///                                .insert_row("users_history", TriggerRow::Old)
+///                                .finish();
+/// 
+///     assert_eq!(query, "CREATE TRIGGER users_audit AFTER UPDATE ON shop.users FOR EACH ROW INSERT INTO users_history (id, name) VALUES (OLD.id, OLD.name);");
+/// 
+///     let mut trigger = TriggerBuilder::create("count_posts", TriggerTiming::After, TriggerEvent::Insert, "posts").unwrap();
+///     let author = trigger.new_col("author_id");
+/// 
+///     let update = QueryBuilder::update().unwrap().table("authors").set("posts", Expr::col("posts") + 1).where_("id", "=", author).clone();
+/// 
+///     assert_eq!(trigger.query(&update).finish(), "CREATE TRIGGER count_posts AFTER INSERT ON posts FOR EACH ROW UPDATE authors SET posts = posts + 1 WHERE id = NEW.author_id;");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub struct TriggerBuilder {
+    pub name: String,
+    pub timing: TriggerTiming,
+    pub event: TriggerEvent,
+    pub table: String,
+    pub columns: Vec<String>,
+    pub order: Option<String>,
+    pub body: Vec<String>,
+    pub delimiter: Option<String>
+}
+
+/// implementations of TriggerBuilder
+impl TriggerBuilder {
+    /// Trigger constructor. Use it if you want to create a trigger on a table.
+    pub fn create(name: &str, timing: TriggerTiming, event: TriggerEvent, table: &str) -> std::result::Result<Self, std::io::Error> {
+        RoutineBuilder::check_name(name)?;
+        RoutineBuilder::check_name(table)?;
+
+        Ok(Self {
+            name: name.to_string(),
+            timing,
+            event,
+            table: table.to_string(),
+            columns: vec![],
+            order: None,
+            body: vec![],
+            delimiter: None
+        })
+    }
+
+    /// Trigger constructor that takes the table name, qualified with it's schema, and the columns from a `TableBuilder`. The columns are used to validate the column references and to build `.insert_row()` statements.
+    pub fn for_table(name: &str, timing: TriggerTiming, event: TriggerEvent, table: &TableBuilder) -> std::result::Result<Self, std::io::Error> {
+        let mut trigger = Self::create(name, timing, event, &table.qualified_name())?;
+        trigger.columns = table.all.clone();
+
+        Ok(trigger)
+    }
+
+    /// It returns the `NEW.column` reference, it panics on delete triggers.
+    pub fn new_col(&self, column: &str) -> Expr {
+        if let TriggerEvent::Delete = self.event {
+            panic!("delete triggers cannot reference the NEW row.")
+        }
+
+        self.check_column(column);
+
+        Expr::col(&format!("NEW.{}", column))
+    }
+
+    /// It returns the `OLD.column` reference, it panics on insert triggers.
+    pub fn old_col(&self, column: &str) -> Expr {
+        if let TriggerEvent::Insert = self.event {
+            panic!("insert triggers cannot reference the OLD row.")
+        }
+
+        self.check_column(column);
+
+        Expr::col(&format!("OLD.{}", column))
+    }
+
+    /// It adds the "FOLLOWS" keyword, the trigger runs after the given trigger of the same table and event.
+    pub fn follows(&mut self, trigger: &str) -> &mut Self {
+        if let Err(error) = RoutineBuilder::check_name(trigger) {
+            panic!("{}", error)
+        }
+
+        self.order = Some(format!("FOLLOWS {}", trigger));
+
+        self
+    }
+
+    /// It adds the "PRECEDES" keyword, the trigger runs before the given trigger of the same table and event.
+    pub fn precedes(&mut self, trigger: &str) -> &mut Self {
+        if let Err(error) = RoutineBuilder::check_name(trigger) {
+            panic!("{}", error)
+        }
+
+        self.order = Some(format!("PRECEDES {}", trigger));
+
+        self
+    }
+
+    /// It adds a raw statement to the body.
+    pub fn statement(&mut self, statement: &str) -> &mut Self {
+        self.body.push(statement.trim_end_matches(';').to_string());
+
+        self
+    }
+
+    /// It adds a query to the body.
+    pub fn query(&mut self, query: &QueryBuilder) -> &mut Self {
+        self.body.push(query.finish().trim_end_matches(';').to_string());
+
+        self
+    }
+
+    /// It changes a column of the new row with `SET NEW.column = value` statement, it can only be used with the before insert and before update triggers.
+    pub fn set_new<T: Into<Expr>>(&mut self, column: &str, value: T) -> &mut Self {
+        match (&self.timing, &self.event) {
+            (TriggerTiming::Before, TriggerEvent::Insert) | (TriggerTiming::Before, TriggerEvent::Update) => (),
+            _ => panic!("the NEW row can only be changed on the before insert and before update triggers.")
+        }
+
+        let reference = self.new_col(column);
+        self.body.push(format!("SET {} = {}", reference, value.into().to_sql(&Dialect::Mysql)));
+
+        self
+    }
+
+    /// It adds an insert statement that copies the new or old row into another table, with the columns of the `TableBuilder` which the trigger was created from.
+    pub fn insert_row(&mut self, table: &str, row: TriggerRow) -> &mut Self {
+        if self.columns.is_empty() {
+            panic!(".insert_row() method requires the columns of the table, create the trigger with TriggerBuilder::for_table().")
+        }
+
+        if let Err(error) = RoutineBuilder::check_name(table) {
+            panic!("{}", error)
+        }
+
+        let values = self.columns.iter().map(|column| match row {
+            TriggerRow::New => self.new_col(column).to_string(),
+            TriggerRow::Old => self.old_col(column).to_string()
+        }).collect::<Vec<String>>();
+
+        self.body.push(format!("INSERT INTO {} ({}) VALUES ({})", table, self.columns.join(", "), values.join(", ")));
+
+        self
+    }
+
+    /// It wraps the trigger with `DELIMITER` statements for the mysql client scripts.
+    pub fn delimiter(&mut self, delimiter: &str) -> &mut Self {
+        if delimiter.is_empty() || delimiter.contains(';') || delimiter.contains(char::is_whitespace) {
+            panic!("delimiter cannot be empty and cannot include ';' or whitespace characters.")
+        }
+
+        self.delimiter = Some(delimiter.to_string());
+
+        self
+    }
+
+    /// Finishes the trigger and returns the result as string.
+    pub fn finish(&self) -> String {
+        match &self.delimiter {
+            Some(delimiter) => format!("DELIMITER {}\n{}{}\nDELIMITER ;", delimiter, self.render(), delimiter),
+            None => format!("{};", self.render())
+        }
+    }
+
+    fn render(&self) -> String {
+        let mut query = format!("CREATE TRIGGER {} {} {} ON {} FOR EACH ROW", self.name, self.timing, self.event, self.table);
+
+        if let Some(order) = &self.order {
+            query = format!("{} {}", query, order);
+        }
+
+        match self.body.len() {
+            0 => panic!("triggers cannot have an empty body."),
+            1 => format!("{} {}", query, self.body[0]),
+            _ => format!("{} BEGIN {}; END", query, self.body.join("; "))
+        }
+    }
+
+    /// It returns the "DROP TRIGGER" statement.
+    pub fn drop(name: &str, if_exists: bool) -> std::result::Result<String, std::io::Error> {
+        RoutineBuilder::check_name(name)?;
+
+        match if_exists {
+            true => Ok(format!("DROP TRIGGER IF EXISTS {};", name)),
+            false => Ok(format!("DROP TRIGGER {};", name))
+        }
+    }
+
+    fn check_column(&self, column: &str) {
+        if let Err(error) = RoutineBuilder::check_name(column) {
+            panic!("{}", error)
+        }
+
+        if !self.columns.is_empty() && !self.columns.iter().any(|known| known == column) {
+            panic!("{} table has no column named {}.", self.table, column)
+        }
+    }
+}
+
+/// TriggerTiming enum. It's the time that a trigger runs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TriggerTiming {
+    Before, After
+}
+
+impl std::fmt::Display for TriggerTiming {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TriggerTiming::Before => write!(f, "BEFORE"),
+            TriggerTiming::After => write!(f, "AFTER")
+        }
+    }
+}
+
+/// TriggerEvent enum. It's the event that fires a trigger.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TriggerEvent {
+    Insert, Update, Delete
+}
+
+impl std::fmt::Display for TriggerEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TriggerEvent::Insert => write!(f, "INSERT"),
+            TriggerEvent::Update => write!(f, "UPDATE"),
+            TriggerEvent::Delete => write!(f, "DELETE")
+        }
+    }
+}
+
+/// TriggerRow enum. It's the row that a trigger refers, `NEW` or `OLD`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TriggerRow {
+    New, Old
+}
+
+/// Struct that benefits you to create views and postgresql materialized views from select queries. The view is rendered with the dialect of the query.
+/// 
+/// ```rust
+/// 
+/// use qubl::{ViewBuilder, ViewAlgorithm, ViewSecurity, QueryBuilder, ValueType};
+/// 
+/// fn main(){
+///     let select = QueryBuilder::select(vec!["id", "name"]).unwrap().table("users").where_("active", "=", ValueType::Boolean(true)).clone();
+/// 
+///     let query = ViewBuilder::create("active_users", &select).unwrap()
+///                             .or_replace()
+///                             .algorithm(ViewAlgorithm::Merge)
+///                             .sql_security(ViewSecurity::Invoker)
+///                             .columns(vec!["user_id", "user_name"])
+///                             .with_check_option()
+///                             .finish();
+/// 
+///     assert_eq!(query, "CREATE OR REPLACE ALGORITHM = MERGE SQL SECURITY INVOKER VIEW active_users (user_id, user_name) AS SELECT id, name FROM users WHERE active = true WITH CHECK OPTION;");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub struct ViewBuilder {
+    pub name: String,
+    pub select: String,
+    pub dialect: Dialect,
+    pub materialized: bool,
+    pub or_replace: bool,
+    pub algorithm: Option<ViewAlgorithm>,
+    pub security: Option<ViewSecurity>,
+    pub columns: Vec<String>,
+    pub check_option: bool,
+    pub with_data: bool
+}
+
+/// implementations of ViewBuilder
+impl ViewBuilder {
+    /// View constructor. The query has to be a select query without the statements in front of it such as `SET time_zone = ...`.
+    pub fn create(name: &str, query: &QueryBuilder) -> std::result::Result<Self, std::io::Error> {
+        RoutineBuilder::check_name(name)?;
+
+        match query.qtype {
+            QueryType::Select | QueryType::Count => (),
+            _ => return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "views can only be created from select queries."))
+        }
+
+        let select = query.render_statement();
+
+        if !query.statements.is_empty() {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "views cannot be created from the queries which have statements in front of them."))
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            select,
+            dialect: query.dialect.clone(),
+            materialized: false,
+            or_replace: false,
+            algorithm: None,
+            security: None,
+            columns: vec![],
+            check_option: false,
+            with_data: true
+        })
+    }
+
+    /// Materialized view constructor, it's only supported on postgresql.
+    pub fn materialized(name: &str, query: &QueryBuilder) -> std::result::Result<Self, std::io::Error> {
+        if query.dialect != Dialect::Postgresql {
+            return Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "materialized views are only supported on postgresql."))
+        }
+
+        let mut view = Self::create(name, query)?;
+        view.materialized = true;
+
+        Ok(view)
+    }
+
+    /// It adds the "OR REPLACE" keyword.
+    pub fn or_replace(&mut self) -> &mut Self {
+        if self.materialized || self.dialect == Dialect::Sqlite {
+            panic!("OR REPLACE keyword is not supported on materialized views and sqlite.")
+        }
+
+        self.or_replace = true;
+
+        self
+    }
+
+    /// It sets the algorithm of a mysql view.
+    pub fn algorithm(&mut self, algorithm: ViewAlgorithm) -> &mut Self {
+        if self.dialect != Dialect::Mysql {
+            panic!("view algorithms are only supported on mysql.")
+        }
+
+        self.algorithm = Some(algorithm);
+
+        self
+    }
+
+    /// It sets the sql security of a mysql view.
+    pub fn sql_security(&mut self, security: ViewSecurity) -> &mut Self {
+        if self.dialect != Dialect::Mysql {
+            panic!("SQL SECURITY keyword is only supported on mysql.")
+        }
+
+        self.security = Some(security);
+
+        self
+    }
+
+    /// It sets the column names of the view.
+    pub fn columns(&mut self, columns: Vec<&str>) -> &mut Self {
+        for column in columns.iter() {
+            if let Err(error) = RoutineBuilder::check_name(column) {
+                panic!("{}", error)
+            }
+        }
+
+        self.columns = columns.iter().map(|column| column.to_string()).collect();
+
+        self
+    }
+
+    /// It adds the "WITH CHECK OPTION" keyword, which rejects the changes that wouldn't be visible through the view.
+    pub fn with_check_option(&mut self) -> &mut Self {
+        if self.materialized || self.dialect == Dialect::Sqlite {
+            panic!("WITH CHECK OPTION keyword is not supported on materialized views and sqlite.")
+        }
+
+        self.check_option = true;
+
+        self
+    }
+
+    /// It adds the "WITH NO DATA" keyword to a materialized view, so it's not filled until it's refreshed.
+    pub fn with_no_data(&mut self) -> &mut Self {
+        if !self.materialized {
+            panic!("WITH NO DATA keyword can only be used with materialized views.")
+        }
+
+        self.with_data = false;
+
+        self
+    }
+
+    /// Finishes the view and returns the result as string.
+    pub fn finish(&self) -> String {
+        format!("{};", self.render())
+    }
+
+    fn render(&self) -> String {
+        let mut query = "CREATE".to_string();
+
+        if self.or_replace {
+            query = format!("{} OR REPLACE", query);
+        }
+
+        if let Some(algorithm) = &self.algorithm {
+            query = format!("{} ALGORITHM = {}", query, algorithm);
+        }
+
+        if let Some(security) = &self.security {
+            query = format!("{} SQL SECURITY {}", query, security);
+        }
+
+        match self.materialized {
+            true => query = format!("{} MATERIALIZED VIEW {}", query, self.name),
+            false => query = format!("{} VIEW {}", query, self.name)
+        }
+
+        if !self.columns.is_empty() {
+            query = format!("{} ({})", query, self.columns.join(", "));
+        }
+
+        query = format!("{} AS {}", query, self.select);
+
+        if self.check_option {
+            query = format!("{} WITH CHECK OPTION", query);
+        }
+
+        if !self.with_data {
+            query = format!("{} WITH NO DATA", query);
+        }
+
+        query
+    }
+
+    /// It returns the "DROP VIEW" statement.
+    pub fn drop(name: &str, if_exists: bool) -> std::result::Result<String, std::io::Error> {
+        RoutineBuilder::check_name(name)?;
+
+        match if_exists {
+            true => Ok(format!("DROP VIEW IF EXISTS {};", name)),
+            false => Ok(format!("DROP VIEW {};", name))
+        }
+    }
+
+    /// It returns the "DROP MATERIALIZED VIEW" statement of postgresql.
+    pub fn drop_materialized(name: &str, if_exists: bool) -> std::result::Result<String, std::io::Error> {
+        RoutineBuilder::check_name(name)?;
+
+        match if_exists {
+            true => Ok(format!("DROP MATERIALIZED VIEW IF EXISTS {};", name)),
+            false => Ok(format!("DROP MATERIALIZED VIEW {};", name))
+        }
+    }
+
+    /// It returns the "REFRESH MATERIALIZED VIEW" statement of postgresql, concurrent refreshes don't lock the selects on the view.
+    pub fn refresh(name: &str, concurrently: bool) -> std::result::Result<String, std::io::Error> {
+        RoutineBuilder::check_name(name)?;
+
+        match concurrently {
+            true => Ok(format!("REFRESH MATERIALIZED VIEW CONCURRENTLY {};", name)),
+            false => Ok(format!("REFRESH MATERIALIZED VIEW {};", name))
+        }
+    }
+}
+
+/// ViewAlgorithm enum. It's the algorithm that mysql uses to process a view.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ViewAlgorithm {
+    Undefined, Merge, TempTable
+}
+
+impl std::fmt::Display for ViewAlgorithm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ViewAlgorithm::Undefined => write!(f, "UNDEFINED"),
+            ViewAlgorithm::Merge => write!(f, "MERGE"),
+            ViewAlgorithm::TempTable => write!(f, "TEMPTABLE")
+        }
+    }
+}
+
+/// ViewSecurity enum. It's the user whose privileges are checked when a mysql view is used.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ViewSecurity {
+    Definer, Invoker
+}
+
+impl std::fmt::Display for ViewSecurity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ViewSecurity::Definer => write!(f, "DEFINER"),
+            ViewSecurity::Invoker => write!(f, "INVOKER")
+        }
+    }
+}
+
+/// RoutineKind enum. It's the kind of a stored routine.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RoutineKind {
+    Procedure, Function
+}
+
+impl std::fmt::Display for RoutineKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoutineKind::Procedure => write!(f, "PROCEDURE"),
+            RoutineKind::Function => write!(f, "FUNCTION")
+        }
+    }
+}
+
+/// ParamMode enum. It's the mode of a procedure parameter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamMode {
+    In, Out, InOut
+}
+
+impl std::fmt::Display for ParamMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParamMode::In => write!(f, "IN"),
+            ParamMode::Out => write!(f, "OUT"),
+            ParamMode::InOut => write!(f, "INOUT")
+        }
+    }
+}
+
+/// RoutineCharacteristic enum. It's the characteristics of stored routines.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RoutineCharacteristic {
+    Deterministic, NotDeterministic, ContainsSql, NoSql, ReadsSqlData, ModifiesSqlData, SqlSecurityDefiner, SqlSecurityInvoker, Comment(String)
+}
+
+impl std::fmt::Display for RoutineCharacteristic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RoutineCharacteristic::Deterministic => write!(f, "DETERMINISTIC"),
+            RoutineCharacteristic::NotDeterministic => write!(f, "NOT DETERMINISTIC"),
+            RoutineCharacteristic::ContainsSql => write!(f, "CONTAINS SQL"),
+            RoutineCharacteristic::NoSql => write!(f, "NO SQL"),
+            RoutineCharacteristic::ReadsSqlData => write!(f, "READS SQL DATA"),
+            RoutineCharacteristic::ModifiesSqlData => write!(f, "MODIFIES SQL DATA"),
+            RoutineCharacteristic::SqlSecurityDefiner => write!(f, "SQL SECURITY DEFINER"),
+            RoutineCharacteristic::SqlSecurityInvoker => write!(f, "SQL SECURITY INVOKER"),
+            RoutineCharacteristic::Comment(comment) => write!(f, "COMMENT '{}'", comment.replace("'", "''"))
+        }
+    }
+}
+
+/// Struct that benefits you to turn a query into mysql's server side prepared statement synthax for the plain text protocol. The values which are given to the builder are replaced with "?" placeholders and passed with user variables, they're remembered when they're added to the query. NULL values, epoch times, date keywords, `LIMIT` and `OFFSET` numbers, the arguments of the function calls and the values which are written by the json methods are kept in the statement text. If a bound value can't be found in it's place anymore, `.from_query()` returns an error instead of leaving the values in the statement.
+/// 
+/// ```rust
+/// 
+/// use qubl::{QueryBuilder, PreparedStatement, ValueType};
+/// 
+/// fn main(){
+///     let query = QueryBuilder::select(vec!["*"]).unwrap()
+///                              .table("users")
+///                              .where_("name", "=", ValueType::String("necdet".to_string()))
+///                              .and("age", ">", ValueType::Int32(25))
+///                              .clone();
+/// 
+///     let statement = PreparedStatement::from_query("find_users", &query).unwrap();
+/// 
+///     assert_eq!(statement.finish(), "PREPARE find_users FROM 'SELECT * FROM users WHERE name = ? AND age > ?'; SET @p1 = 'necdet', @p2 = 25; EXECUTE find_users USING @p1, @p2; DEALLOCATE PREPARE find_users;")
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub struct PreparedStatement {
+    pub name: String,
+    pub statement: String,
+    pub parameters: Vec<String>,
+    pub prefix: Vec<String>
+}
+
+/// implementations of PreparedStatement
+impl PreparedStatement {
+    /// Creates a prepared statement from a query. The statements in front of the query such as `SET time_zone = ...` are kept as they are and run before the `PREPARE` statement.
+    pub fn from_query(name: &str, query: &QueryBuilder) -> std::result::Result<Self, std::io::Error> {
+        if name.is_empty() || !name.chars().all(|character| character.is_ascii_alphanumeric() || character == '_') {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "prepared statement names can only include letters, digits and '_' character."))
+        }
+
+        let (mut statement, bindings) = query.bound_statement().map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+
+        if statement.is_empty() {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "the query is empty."))
+        }
+
+        for (start, literal) in bindings.iter().rev() {
+            statement.replace_range(*start..start + literal.len(), "?");
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            statement,
+            parameters: bindings.into_iter().map(|(_, literal)| literal).collect(),
+            prefix: query.statements.clone()
+        })
+    }
+
+    /// Returns the `PREPARE` statement, the statement text is escaped.
+    pub fn prepare(&self) -> String {
+        format!("PREPARE {} FROM '{}'", self.name, self.statement.replace("\\", "\\\\").replace("'", "''"))
+    }
+
+    /// Returns the `EXECUTE` statement, it uses the user variables which are assigned by `.assignments()`.
+    pub fn execute(&self) -> String {
+        match self.parameters.is_empty() {
+            true => format!("EXECUTE {}", self.name),
+            false => format!("EXECUTE {} USING {}", self.name, (1..=self.parameters.len()).map(|i| format!("@p{}", i)).collect::<Vec<String>>().join(", "))
+        }
+    }
+
+    /// Returns the `SET` statement that assigns the parameters to the user variables, `None` if the query has no literal values.
+    pub fn assignments(&self) -> Option<String> {
+        match self.parameters.is_empty() {
+            true => None,
+            false => Some(format!("SET {}", self.parameters.iter().enumerate().map(|(i, parameter)| format!("@p{} = {}", i + 1, parameter)).collect::<Vec<String>>().join(", ")))
+        }
+    }
+
+    /// Returns the `DEALLOCATE PREPARE` statement.
+    pub fn deallocate(&self) -> String {
+        format!("DEALLOCATE PREPARE {}", self.name)
+    }
+
+    /// Returns all of the statements one by one, without the trailing ";" characters.
+    pub fn statements(&self) -> Vec<String> {
+        let mut statements = self.prefix.clone();
+
+        statements.push(self.prepare());
+
+        if let Some(assignments) = self.assignments() {
+            statements.push(assignments);
+        }
+
+        statements.push(self.execute());
+        statements.push(self.deallocate());
+
+        statements
+    }
+
+    /// Finishes the prepared statement and returns all of it's statements as one string.
+    pub fn finish(&self) -> String {
+        self.statements().iter().map(|statement| format!("{};", statement)).collect::<Vec<String>>().join(" ")
+    }
+}
+
+/// Struct that benefits you to build mysql `SET` statements for the system variables with session or global scope, character sets and user variables.
+/// 
+/// ```rust
+/// 
+/// use qubl::{SetBuilder, SessionVariable, SqlMode, IsolationLevel, ValueType};
+/// 
+/// fn main(){
+///     let query = SetBuilder::new()
+///                            .names("utf8mb4", Some("utf8mb4_unicode_ci"))
+///                            .session(SessionVariable::SqlMode(vec![SqlMode::StrictTransTables, SqlMode::OnlyFullGroupBy]))
+///                            .global(SessionVariable::TransactionIsolation(IsolationLevel::ReadCommitted))
+///                            .user_variable("limit", ValueType::Int32(10))
+///                            .finish();
+/// 
+///     assert_eq!(query, "SET NAMES 'utf8mb4' COLLATE 'utf8mb4_unicode_ci', sql_mode = 'STRICT_TRANS_TABLES,ONLY_FULL_GROUP_BY', GLOBAL transaction_isolation = 'READ-COMMITTED', @limit = 10;")
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub struct SetBuilder {
+    pub assignments: Vec<String>
+}
+
+/// implementations of SetBuilder
+impl SetBuilder {
+    /// Creates an empty set statement.
+    pub fn new() -> Self {
+        Self {
+            assignments: vec![]
+        }
+    }
+
+    /// It assigns a system variable for the current session.
+    pub fn session(&mut self, variable: SessionVariable) -> &mut Self {
+        self.assignments.push(variable.assignment());
+
+        self
+    }
+
+    /// It assigns a system variable globally.
+    pub fn global(&mut self, variable: SessionVariable) -> &mut Self {
+        self.assignments.push(format!("GLOBAL {}", variable.assignment()));
+
+        self
+    }
+
+    /// It sets the character set and optionally the collation of the connection with "NAMES" keyword.
+    pub fn names(&mut self, charset: &str, collation: Option<&str>) -> &mut Self {
+        Self::check_name(charset);
+
+        match collation {
+            Some(collation) => {
+                Self::check_name(collation);
+
+                self.assignments.push(format!("NAMES '{}' COLLATE '{}'", charset, collation))
+            },
+            None => self.assignments.push(format!("NAMES '{}'", charset))
+        }
+
+        self
+    }
+
+    /// It assigns a user variable, the name is written without the "@" character. The quotes and the backslashes of the string values in the expression are escaped.
+    pub fn user_variable<T: Into<Expr>>(&mut self, name: &str, value: T) -> &mut Self {
+        Self::check_name(name);
+
+        let mut value = value.into();
+
+        value.escape_values();
+
+        self.assignments.push(format!("@{} = {}", name, value.to_sql(&Dialect::Mysql)));
+
+        self
+    }
+
+    /// Finishes the statement and returns it as string.
+    pub fn finish(&self) -> String {
+        format!("{};", self.render())
+    }
+
+    fn render(&self) -> String {
+        if self.assignments.is_empty() {
+            panic!("you have to assign at least one variable to build a SET statement.")
+        }
+
+        format!("SET {}", self.assignments.join(", "))
+    }
+
+    fn check_name(name: &str) {
+        if name.is_empty() || !name.chars().all(|character| character.is_ascii_alphanumeric() || character == '_') {
+            panic!("variable, character set and collation names can only include letters, digits and '_' character.")
+        }
+    }
+
+    fn escape(value: &mut ValueType) {
+        match value {
+            ValueType::String(text) => *text = text.replace('\\', "\\\\").replace('\'', "''"),
+            ValueType::Datetime(text) if SqlKeyword::from_name(text).is_none() => *text = text.replace('\\', "\\\\").replace('\'', "''"),
+            ValueType::JsonString(text) => *text = text.replace('\\', "\\\\").replace('"', "\\\""),
+            _ => ()
+        }
+    }
+}
+
+impl Default for SetBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// SessionVariable enum. It's the system variables that `SetBuilder` knows, their values are validated before they're written. Use `Custom` variant for the others.
+#[derive(Debug, Clone)]
+pub enum SessionVariable {
+    TimeZone(Timezone), SqlMode(Vec<SqlMode>), TransactionIsolation(IsolationLevel), TransactionReadOnly(bool), ForeignKeyChecks(bool), UniqueChecks(bool),
+    Autocommit(bool), SqlSafeUpdates(bool), GroupConcatMaxLen(u64), MaxExecutionTime(u64), Custom(String, ValueType)
+}
+
+impl SessionVariable {
+    fn assignment(&self) -> String {
+        let flag = |value: &bool| match value {
+            true => "1",
+            false => "0"
+        };
+
+        match self {
+            SessionVariable::TimeZone(timezone) => format!("time_zone = '{}'", timezone),
+            SessionVariable::SqlMode(modes) => format!("sql_mode = '{}'", modes.iter().map(|mode| mode.to_string()).collect::<Vec<String>>().join(",")),
+            SessionVariable::TransactionIsolation(isolation) => format!("transaction_isolation = '{}'", isolation.to_string().replace(" ", "-")),
+            SessionVariable::TransactionReadOnly(value) => format!("transaction_read_only = {}", flag(value)),
+            SessionVariable::ForeignKeyChecks(value) => format!("foreign_key_checks = {}", flag(value)),
+            SessionVariable::UniqueChecks(value) => format!("unique_checks = {}", flag(value)),
+            SessionVariable::Autocommit(value) => format!("autocommit = {}", flag(value)),
+            SessionVariable::SqlSafeUpdates(value) => format!("sql_safe_updates = {}", flag(value)),
+            SessionVariable::GroupConcatMaxLen(length) => {
+                if *length < 4 {
+                    panic!("group_concat_max_len cannot be less than 4.")
+                }
+
+                format!("group_concat_max_len = {}", length)
+            },
+            SessionVariable::MaxExecutionTime(milliseconds) => format!("max_execution_time = {}", milliseconds),
+            SessionVariable::Custom(name, value) => {
+                SetBuilder::check_name(name);
+
+                let mut value = value.clone();
+
+                SetBuilder::escape(&mut value);
+
+                format!("{} = {}", name, value)
+            }
+        }
+    }
+}
+
+/// SqlMode enum. It's the modes of mysql's `sql_mode` system variable.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlMode {
+    AllowInvalidDates, AnsiQuotes, ErrorForDivisionByZero, HighNotPrecedence, IgnoreSpace, NoAutoValueOnZero, NoBackslashEscapes,
+    NoDirInCreate, NoEngineSubstitution, NoUnsignedSubtraction, NoZeroDate, NoZeroInDate, OnlyFullGroupBy, PadCharToFullLength,
+    PipesAsConcat, RealAsFloat, StrictAllTables, StrictTransTables, TimeTruncateFractional, Ansi, Traditional
+}
+
+impl std::fmt::Display for SqlMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SqlMode::AllowInvalidDates => write!(f, "ALLOW_INVALID_DATES"), SqlMode::AnsiQuotes => write!(f, "ANSI_QUOTES"),
+            SqlMode::ErrorForDivisionByZero => write!(f, "ERROR_FOR_DIVISION_BY_ZERO"), SqlMode::HighNotPrecedence => write!(f, "HIGH_NOT_PRECEDENCE"),
+            SqlMode::IgnoreSpace => write!(f, "IGNORE_SPACE"), SqlMode::NoAutoValueOnZero => write!(f, "NO_AUTO_VALUE_ON_ZERO"),
+            SqlMode::NoBackslashEscapes => write!(f, "NO_BACKSLASH_ESCAPES"), SqlMode::NoDirInCreate => write!(f, "NO_DIR_IN_CREATE"),
+            SqlMode::NoEngineSubstitution => write!(f, "NO_ENGINE_SUBSTITUTION"), SqlMode::NoUnsignedSubtraction => write!(f, "NO_UNSIGNED_SUBTRACTION"),
+            SqlMode::NoZeroDate => write!(f, "NO_ZERO_DATE"), SqlMode::NoZeroInDate => write!(f, "NO_ZERO_IN_DATE"),
+            SqlMode::OnlyFullGroupBy => write!(f, "ONLY_FULL_GROUP_BY"), SqlMode::PadCharToFullLength => write!(f, "PAD_CHAR_TO_FULL_LENGTH"),
+            SqlMode::PipesAsConcat => write!(f, "PIPES_AS_CONCAT"), SqlMode::RealAsFloat => write!(f, "REAL_AS_FLOAT"),
+            SqlMode::StrictAllTables => write!(f, "STRICT_ALL_TABLES"), SqlMode::StrictTransTables => write!(f, "STRICT_TRANS_TABLES"),
+            SqlMode::TimeTruncateFractional => write!(f, "TIME_TRUNCATE_FRACTIONAL"), SqlMode::Ansi => write!(f, "ANSI"),
+            SqlMode::Traditional => write!(f, "TRADITIONAL")
+        }
+    }
+}
+
+/// Struct that benefits you to build multi statement scripts and transactions. It holds an ordered list of statements and renders them as one script with `.finish()`, or one by one with `.statements()` for the drivers which reject multi statements.
+/// 
+/// ```rust
+/// 
+/// use qubl::{Script, QueryBuilder, IsolationLevel, ValueType};
+/// 
+/// fn main(){
+///     let debit = QueryBuilder::update().unwrap().table("accounts").set("balance", ValueType::Int32(50)).where_("id", "=", ValueType::Int32(1)).clone();
+///     let credit = QueryBuilder::update().unwrap().table("accounts").set("balance", ValueType::Int32(150)).where_("id", "=", ValueType::Int32(2)).clone();
+/// 
+///     let script = Script::transaction(Some(IsolationLevel::Serializable), false)
+///                         .query(&debit)
+///                         .savepoint("debited")
+///                         .query(&credit)
+///                         .commit()
+///                         .clone();
+/// 
+///     assert_eq!(script.finish(), "SET TRANSACTION ISOLATION LEVEL SERIALIZABLE; START TRANSACTION; UPDATE accounts SET balance = 50 WHERE id = 1; SAVEPOINT debited; UPDATE accounts SET balance = 150 WHERE id = 2; COMMIT;");
+///     assert_eq!(script.statements()[1], "START TRANSACTION")
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub struct Script {
+    pub items: Vec<ScriptItem>,
+    pub dialect: Dialect
+}
+
+/// implementations of Script
+impl Script {
+    /// Creates an empty script.
+    pub fn new() -> Self {
+        Self {
+            items: vec![],
+            dialect: Dialect::Mysql
+        }
+    }
+
+    /// Creates a script that starts with a transaction.
+    pub fn transaction(isolation: Option<IsolationLevel>, read_only: bool) -> Self {
+        let mut script = Self::new();
+        script.start_transaction(isolation, read_only);
+
+        script
+    }
+
+    /// It sets the dialect of the transaction control statements of the script. It doesn't change the dialect of the added queries.
+    pub fn dialect(&mut self, dialect: Dialect) -> &mut Self {
+        self.dialect = dialect;
+
+        self
+    }
+
+    /// It adds the "START TRANSACTION" statement with the optional isolation level and read only access mode.
+    pub fn start_transaction(&mut self, isolation: Option<IsolationLevel>, read_only: bool) -> &mut Self {
+        self.items.push(ScriptItem::StartTransaction(isolation, read_only));
+
+        self
+    }
+
+    /// It adds the "SAVEPOINT" statement.
+    pub fn savepoint(&mut self, name: &str) -> &mut Self {
+        Self::check_name(name);
+        self.items.push(ScriptItem::Savepoint(name.to_string()));
+
+        self
+    }
+
+    /// It adds the "ROLLBACK TO SAVEPOINT" statement.
+    pub fn rollback_to(&mut self, name: &str) -> &mut Self {
+        Self::check_name(name);
+        self.items.push(ScriptItem::RollbackTo(name.to_string()));
+
+        self
+    }
+
+    /// It adds the "RELEASE SAVEPOINT" statement.
+    pub fn release_savepoint(&mut self, name: &str) -> &mut Self {
+        Self::check_name(name);
+        self.items.push(ScriptItem::ReleaseSavepoint(name.to_string()));
+
+        self
+    }
+
+    /// It adds the "COMMIT" statement.
+    pub fn commit(&mut self) -> &mut Self {
+        self.items.push(ScriptItem::Commit);
+
+        self
+    }
+
+    /// It adds the "ROLLBACK" statement.
+    pub fn rollback(&mut self) -> &mut Self {
+        self.items.push(ScriptItem::Rollback);
+
+        self
+    }
+
+    /// It adds a "SET" statement that assigns a value to a system variable. It's built with `SessionVariable::Custom` of `SetBuilder`, so the name of the variable can only include letters, digits and '_' character.
+    pub fn set<T: Into<ValueType>>(&mut self, variable: &str, value: T) -> &mut Self {
+        self.set_variables(SetBuilder::new().session(SessionVariable::Custom(variable.to_string(), value.into())))
+    }
+
+    /// It adds the `SET` statement of a `SetBuilder`. Sqlite doesn't have the "SET" statement, so it panics on the sqlite dialect.
+    pub fn set_variables(&mut self, variables: &SetBuilder) -> &mut Self {
+        if let Dialect::Sqlite = self.dialect {
+            panic!("sqlite doesn't have the SET statement, use the PRAGMA statements instead.")
+        }
+
+        self.items.push(ScriptItem::Statement(variables.render()));
+
+        self
+    }
+
+    /// It adds the statements of a `PreparedStatement`: `PREPARE`, the assignments of it's parameters, `EXECUTE` and `DEALLOCATE PREPARE`.
+    pub fn prepared(&mut self, statement: &PreparedStatement) -> &mut Self {
+        for item in statement.statements() {
+            self.items.push(ScriptItem::Statement(item));
+        }
+
+        self
+    }
+
+    /// It adds the creation of a stored procedure or function as a single statement, the delimiter of the routine is ignored.
+    pub fn routine(&mut self, routine: &RoutineBuilder) -> &mut Self {
+        self.items.push(ScriptItem::Statement(routine.render()));
+
+        self
+    }
+
+    /// It adds the creation of a trigger as a single statement, the delimiter of the trigger is ignored.
+    pub fn trigger(&mut self, trigger: &TriggerBuilder) -> &mut Self {
+        self.items.push(ScriptItem::Statement(trigger.render()));
+
+        self
+    }
+
+    /// It adds the creation of a view.
+    pub fn view(&mut self, view: &ViewBuilder) -> &mut Self {
+        self.items.push(ScriptItem::Statement(view.render()));
+
+        self
+    }
+
+    /// It adds a query. The statements that are put in front of the query such as `SET time_zone = ...` are kept as separate statements.
+    pub fn query(&mut self, query: &QueryBuilder) -> &mut Self {
+        for statement in query.statements.iter() {
+            self.items.push(ScriptItem::Statement(statement.to_string()));
+        }
+
+        self.items.push(ScriptItem::Statement(query.render_statement()));
+
+        self
+    }
+
+    /// It adds a table creation.
+    pub fn table(&mut self, table: &TableBuilder) -> &mut Self {
+        self.items.push(ScriptItem::Statement(table.render()));
+
+        self
+    }
+
+    /// It adds the statements of an `AlterTableBuilder`.
+    pub fn alter_table(&mut self, table: &AlterTableBuilder) -> &mut Self {
+        for statement in table.statements() {
+            self.items.push(ScriptItem::Statement(statement));
+        }
+
+        self
+    }
+
+    /// It adds a schema creation or selection. Sqlite doesn't have schema's that can be created or selected, so it panics on the sqlite dialect, the database files are attached instead. Postgresql doesn't have the "USE" statement either, it panics for the schema selections on it.
+    pub fn schema(&mut self, schema: &SchemaBuilder) -> &mut Self {
+        match (&self.dialect, schema.list.contains(&KeywordList::Use)) {
+            (Dialect::Sqlite, _) => panic!("sqlite doesn't support creating or selecting a schema, attach the database file instead."),
+            (Dialect::Postgresql, true) => panic!("postgresql doesn't support the USE statement, connect to the database instead."),
+            _ => ()
+        }
+
+        self.items.push(ScriptItem::Statement(schema.query.to_string()));
+
+        self
+    }
+
+    /// Finishes the script and returns it's statements as one string.
+    pub fn finish(&self) -> String {
+        self.statements().iter().map(|statement| format!("{};", statement)).collect::<Vec<String>>().join(" ")
+    }
+
+    /// Returns the statements of the script one by one, without the trailing ";" characters.
+    pub fn statements(&self) -> Vec<String> {
+        let mut statements = vec![];
+
+        for item in self.items.iter() {
+            match item {
+                ScriptItem::StartTransaction(isolation, read_only) => match self.dialect {
+                    Dialect::Mysql => {
+                        if let Some(isolation) = isolation {
+                            statements.push(format!("SET TRANSACTION ISOLATION LEVEL {}", isolation));
+                        }
+
+                        match read_only {
+                            true => statements.push("START TRANSACTION READ ONLY".to_string()),
+                            false => statements.push("START TRANSACTION".to_string())
+                        }
+                    },
+                    Dialect::Postgresql => {
+                        let mut modes = vec![];
+
+                        if let Some(isolation) = isolation {
+                            modes.push(format!("ISOLATION LEVEL {}", isolation));
+                        }
+
+                        if *read_only {
+                            modes.push("READ ONLY".to_string());
+                        }
+
+                        match modes.is_empty() {
+                            true => statements.push("START TRANSACTION".to_string()),
+                            false => statements.push(format!("START TRANSACTION {}", modes.join(", ")))
+                        }
+                    },
+                    Dialect::Sqlite => {
+                        if isolation.is_some() || *read_only {
+                            panic!("sqlite doesn't support isolation levels and read only transactions.")
+                        }
+
+                        statements.push("BEGIN TRANSACTION".to_string())
+                    }
+                },
+                ScriptItem::Savepoint(name) => statements.push(format!("SAVEPOINT {}", name)),
+                ScriptItem::RollbackTo(name) => statements.push(format!("ROLLBACK TO SAVEPOINT {}", name)),
+                ScriptItem::ReleaseSavepoint(name) => statements.push(format!("RELEASE SAVEPOINT {}", name)),
+                ScriptItem::Commit => statements.push("COMMIT".to_string()),
+                ScriptItem::Rollback => statements.push("ROLLBACK".to_string()),
+                ScriptItem::Statement(statement) => statements.push(statement.to_string())
+            }
+        }
+
+        statements
+    }
+
+    fn check_name(name: &str) {
+        if name.is_empty() || !name.chars().all(|character| character.is_ascii_alphanumeric() || character == '_') {
+            panic!("savepoint names can only include letters, digits and '_' character.")
+        }
+    }
+}
+
+impl Default for Script {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// ScriptItem enum. It's a statement of a `Script`, the transaction control statements are rendered with the dialect of the script.
+#[derive(Debug, Clone)]
+pub enum ScriptItem {
+    StartTransaction(Option<IsolationLevel>, bool), Savepoint(String), RollbackTo(String), ReleaseSavepoint(String), Commit, Rollback, Statement(String)
+}
+
+/// IsolationLevel enum. It's the isolation level of the transactions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IsolationLevel {
+    ReadUncommitted, ReadCommitted, RepeatableRead, Serializable
+}
+
+impl std::fmt::Display for IsolationLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IsolationLevel::ReadUncommitted => write!(f, "READ UNCOMMITTED"),
+            IsolationLevel::ReadCommitted => write!(f, "READ COMMITTED"),
+            IsolationLevel::RepeatableRead => write!(f, "REPEATABLE READ"),
+            IsolationLevel::Serializable => write!(f, "SERIALIZABLE")
+        }
+    }
+}
+
+/// KeywordList enum. It helps to syntactically correcting the queries. 
+#[derive(Debug, Clone, PartialEq)]
+pub enum KeywordList {
+    Select, Update, Delete, Insert, Count, Table, Where, Or, And, Set, 
+    Finish, OrderBy, GroupBy, Having, Like, Limit, Offset, IfNotExist, Create, Use, WhereIn, 
+    WhereNotIn, AndIn, AndNotIn, OrIn, OrNotIn, JsonExtract, JsonContains, NotJsonContains, JsonArrayAppend, JsonRemove, JsonSet, JsonReplace, 
+    JsonInsert, JsonArrayInsert, JsonMergePatch, JsonMergePreserve,
+    Field, Union, UnionAll, Timezone, GlobalTimezone, InnerJoin, LeftJoin, RightJoin, CrossJoin, NaturalJoin, LeftBracketWhere, LeftBracketAnd, LeftBracketOr, RightBracket,
+    IndexHint, OptimizerHint, SetVariable, IntoVariables
+}
+
+/// QueryType enum. It helps to detect the type of a query with more optimized way when is needed.
+#[derive(Debug, Clone)]
+pub enum QueryType {
+    Select, Update, Delete, Insert, Null, Create, Count
+}
+
+/// Dialect enum. It helps the builder to render the parts of the queries which are differ between rdbms's.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Dialect {
+    Mysql, Postgresql, Sqlite
+}
+
+/// IndexHintScope enum. It restricts an index hint to the corresponding part of the query with `FOR JOIN`, `FOR ORDER BY` or `FOR GROUP BY`.
+#[derive(Debug, Clone)]
+pub enum IndexHintScope {
+    Join, OrderBy, GroupBy
+}
+
+impl std::fmt::Display for IndexHintScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IndexHintScope::Join => write!(f, "JOIN"),
+            IndexHintScope::OrderBy => write!(f, "ORDER BY"),
+            IndexHintScope::GroupBy => write!(f, "GROUP BY")
+        }
+    }
+}
+
+/// OptimizerHint enum. It represents the mysql 8 optimizer hints which are put in `/*+ ... */` comments.
+#[derive(Debug, Clone)]
+pub enum OptimizerHint {
+    /// It writes `MAX_EXECUTION_TIME(n)`, n is in milliseconds.
+    MaxExecutionTime(u64),
+
+    /// It writes `SET_VAR(name = value)`, it sets a session variable only for the duration of the query.
+    SetVar(String, ValueType),
+
+    /// It writes `SET_VAR(time_zone = '...')`, it's a single statement alternative of the `.time_zone()` method.
+    TimeZone(Timezone),
+
+    /// It writes `INDEX(table index1, index2)`.
+    Index(String, Vec<String>),
+
+    /// It writes `NO_INDEX(table index1, index2)`.
+    NoIndex(String, Vec<String>),
+
+    /// It writes the given hint as it is.
+    Custom(String)
+}
+
+impl std::fmt::Display for OptimizerHint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OptimizerHint::MaxExecutionTime(milliseconds) => write!(f, "MAX_EXECUTION_TIME({})", milliseconds),
+            OptimizerHint::SetVar(name, value) => write!(f, "SET_VAR({} = {})", name, value),
+            OptimizerHint::TimeZone(timezone) => write!(f, "SET_VAR(time_zone = '{}')", timezone),
+            OptimizerHint::Index(table, indexes) => write!(f, "INDEX({} {})", table, indexes.join(", ")),
+            OptimizerHint::NoIndex(table, indexes) => write!(f, "NO_INDEX({} {})", table, indexes.join(", ")),
+            OptimizerHint::Custom(hint) => write!(f, "{}", hint)
+        }
+    }
+}
+
+/// BracketType enum. It helps you to open brackets with Corresponding keyword of it's variant on sql queries.
+#[derive(Debug, Clone)]
+pub enum BracketType {
+    Where, And, Or
+}
+
+impl std::fmt::Display for BracketType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BracketType::Where => write!(f, "WHERE"),
+            BracketType::And => write!(f, "AND"),
+            BracketType::Or => write!(f, "OR")
+        }
+    }
+}
+
+/// SqlKeyword enum. It represents the sql keywords and niladic functions which are written without quotes, such as `CURRENT_TIMESTAMP`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlKeyword {
+    CurrentTimestamp, UnixTimestamp, CurrentDate, CurrentTime, Now, CurDate, CurTime
+}
+
+impl SqlKeyword {
+    /// It detects the keyword from it's sql spelling, returns `None` if the given text is not a keyword.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "CURRENT_TIMESTAMP" => Some(SqlKeyword::CurrentTimestamp),
+            "UNIX_TIMESTAMP" | "UNIX_TIMESTAMP()" => Some(SqlKeyword::UnixTimestamp),
+            "CURRENT_DATE" => Some(SqlKeyword::CurrentDate),
+            "CURRENT_TIME" => Some(SqlKeyword::CurrentTime),
+            "NOW()" => Some(SqlKeyword::Now),
+            "CURDATE()" => Some(SqlKeyword::CurDate),
+            "CURTIME()" => Some(SqlKeyword::CurTime),
+            _ => None
+        }
+    }
+
+    /// It renders the keyword for the given dialect.
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        match (self, dialect) {
+            (SqlKeyword::CurrentTimestamp, _) => "CURRENT_TIMESTAMP".to_string(),
+            (SqlKeyword::CurrentDate, _) => "CURRENT_DATE".to_string(),
+            (SqlKeyword::CurrentTime, _) => "CURRENT_TIME".to_string(),
+            (SqlKeyword::UnixTimestamp, Dialect::Mysql) => "UNIX_TIMESTAMP()".to_string(),
+            (SqlKeyword::UnixTimestamp, Dialect::Postgresql) => "EXTRACT(EPOCH FROM NOW())".to_string(),
+            (SqlKeyword::UnixTimestamp, Dialect::Sqlite) => "UNIXEPOCH()".to_string(),
+            (SqlKeyword::Now, Dialect::Sqlite) => "DATETIME('now')".to_string(),
+            (SqlKeyword::Now, _) => "NOW()".to_string(),
+            (SqlKeyword::CurDate, Dialect::Mysql) => "CURDATE()".to_string(),
+            (SqlKeyword::CurDate, _) => "CURRENT_DATE".to_string(),
+            (SqlKeyword::CurTime, Dialect::Mysql) => "CURTIME()".to_string(),
+            (SqlKeyword::CurTime, _) => "CURRENT_TIME".to_string()
+        }
+    }
+}
+
+impl std::fmt::Display for SqlKeyword {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_sql(&Dialect::Mysql))
+    }
+}
+
+/// Expr enum. It represents the right hand side of the conditions and assignments, which can be a literal value, a column reference, a keyword, a function call or an arithmetic of them.
+/// 
+/// ```rust
+/// 
+/// use qubl::{Expr, SqlKeyword, Dialect};
+/// 
+/// fn main() {
+///     let expr = Expr::col("views") + 1;
+///     assert_eq!(expr.to_sql(&Dialect::Mysql), "views + 1");
+/// 
+///     let expr = (Expr::col("price") - Expr::col("discount")) * 2;
+///     assert_eq!(expr.to_sql(&Dialect::Mysql), "(price - discount) * 2");
+/// 
+///     let expr = Expr::func("COALESCE", vec![Expr::col("nickname"), Expr::val(String::from("anonymous"))]);
+///     assert_eq!(expr.to_sql(&Dialect::Mysql), "COALESCE(nickname, 'anonymous')");
+/// 
+///     let expr = Expr::Keyword(SqlKeyword::Now);
+///     assert_eq!(expr.to_sql(&Dialect::Sqlite), "DATETIME('now')");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Column(String), Value(ValueType), Keyword(SqlKeyword), Binary(Box<Expr>, String, Box<Expr>), Function(Box<FunctionExpr>), Date(Box<DateExpr>), Spatial(Box<SpatialExpr>), Json(Box<JsonExpr>), Subquery(Subquery)
+}
+
+impl Expr {
+    /// It creates a column reference, which is written as is.
+    pub fn col(column: &str) -> Self {
+        Expr::Column(column.to_string())
+    }
+
+    /// It creates a literal value.
+    pub fn val<T: Into<ValueType>>(value: T) -> Self {
+        Expr::Value(value.into())
+    }
+
+    /// It creates a function call with the given arguments.
+    pub fn func(name: &str, args: Vec<Expr>) -> Self {
+        Expr::Function(Box::new(FunctionExpr::Call(name.to_string(), args)))
+    }
+
+    /// It adds an interval to a date expression, `DATE_ADD(x, INTERVAL n UNIT)` on mysql.
+    pub fn date_add<T: Into<Expr>>(date: T, amount: i64, unit: IntervalUnit) -> Self {
+        Expr::Date(Box::new(DateExpr::Add(date.into(), amount, unit)))
+    }
+
+    /// It subtracts an interval from a date expression, `DATE_SUB(x, INTERVAL n UNIT)` on mysql.
+    pub fn date_sub<T: Into<Expr>>(date: T, amount: i64, unit: IntervalUnit) -> Self {
+        Expr::Date(Box::new(DateExpr::Sub(date.into(), amount, unit)))
+    }
+
+    /// It formats a date expression with mysql's `DATE_FORMAT()` specifiers such as `%Y-%m-%d`, they're translated for the other dialects.
+    pub fn date_format<T: Into<Expr>>(date: T, format: &str) -> Self {
+        Expr::Date(Box::new(DateExpr::Format(date.into(), format.to_string())))
+    }
+
+    /// It takes the date part of a datetime expression.
+    pub fn date<T: Into<Expr>>(date: T) -> Self {
+        Expr::Date(Box::new(DateExpr::Date(date.into())))
+    }
+
+    /// It calculates the difference between two date expressions in the given unit, `TIMESTAMPDIFF(UNIT, start, end)` on mysql.
+    pub fn timestamp_diff<S: Into<Expr>, E: Into<Expr>>(unit: IntervalUnit, start: S, end: E) -> Self {
+        Expr::Date(Box::new(DateExpr::TimestampDiff(unit, start.into(), end.into())))
+    }
+
+    /// It converts a datetime expression from a timezone to another one.
+    pub fn convert_tz<T: Into<Expr>>(date: T, from: Timezone, to: Timezone) -> Self {
+        Expr::Date(Box::new(DateExpr::ConvertTz(date.into(), from, to)))
+    }
+
+    /// It converts a date expression to unix timestamp, `UNIX_TIMESTAMP(x)` on mysql.
+    pub fn unix_timestamp<T: Into<Expr>>(date: T) -> Self {
+        Expr::Date(Box::new(DateExpr::UnixTimestamp(date.into())))
+    }
+
+    /// It extracts a part of a date expression, `EXTRACT(UNIT FROM x)` on mysql.
+    pub fn extract<T: Into<Expr>>(unit: IntervalUnit, date: T) -> Self {
+        Expr::Date(Box::new(DateExpr::Extract(unit, date.into())))
+    }
+
+    /// It concatenates the given expressions, `CONCAT(a, b)` on mysql and `(a || b)` on the other dialects.
+    pub fn concat(args: Vec<Expr>) -> Self {
+        if args.is_empty() {
+            panic!("you cannot pass an empty vector to the Expr::concat() function.")
+        }
+
+        Expr::Function(Box::new(FunctionExpr::Concat(args)))
+    }
+
+    /// It concatenates the given expressions with a separator and skips the null ones, `CONCAT_WS(separator, a, b)`. On sqlite it's written with `||` and `SUBSTR()`, since `CONCAT_WS()` is only available on sqlite 3.44 and later.
+    pub fn concat_ws<T: Into<Expr>>(separator: T, args: Vec<Expr>) -> Self {
+        if args.is_empty() {
+            panic!("you cannot pass an empty vector to the Expr::concat_ws() function.")
+        }
+
+        Expr::Function(Box::new(FunctionExpr::ConcatWs(separator.into(), args)))
+    }
+
+    /// It converts an expression to lowercase.
+    pub fn lower<T: Into<Expr>>(expr: T) -> Self {
+        Expr::Function(Box::new(FunctionExpr::Lower(expr.into())))
+    }
+
+    /// It converts an expression to uppercase.
+    pub fn upper<T: Into<Expr>>(expr: T) -> Self {
+        Expr::Function(Box::new(FunctionExpr::Upper(expr.into())))
+    }
+
+    /// It removes the leading and trailing spaces of an expression.
+    pub fn trim<T: Into<Expr>>(expr: T) -> Self {
+        Expr::Function(Box::new(FunctionExpr::Trim(expr.into())))
+    }
+
+    /// It takes a part of an expression, starting from 1 with the optional length.
+    pub fn substring<T: Into<Expr>>(expr: T, start: i64, length: Option<i64>) -> Self {
+        Expr::Function(Box::new(FunctionExpr::Substring(expr.into(), start, length)))
+    }
+
+    /// It returns the length of an expression.
+    pub fn length<T: Into<Expr>>(expr: T) -> Self {
+        Expr::Function(Box::new(FunctionExpr::Length(expr.into())))
+    }
+
+    /// It returns the first non null expression.
+    pub fn coalesce(args: Vec<Expr>) -> Self {
+        if args.is_empty() {
+            panic!("you cannot pass an empty vector to the Expr::coalesce() function.")
+        }
+
+        Expr::Function(Box::new(FunctionExpr::Coalesce(args)))
+    }
+
+    /// It returns the fallback if the expression is null, `IFNULL(x, fallback)` on mysql & sqlite and `COALESCE(x, fallback)` on postgresql.
+    pub fn if_null<T: Into<Expr>, F: Into<Expr>>(expr: T, fallback: F) -> Self {
+        Expr::Function(Box::new(FunctionExpr::IfNull(expr.into(), fallback.into())))
+    }
+
+    /// It returns null if both of the expressions are equal.
+    pub fn null_if<T: Into<Expr>, U: Into<Expr>>(expr: T, other: U) -> Self {
+        Expr::Function(Box::new(FunctionExpr::NullIf(expr.into(), other.into())))
+    }
+
+    /// It converts an expression to the given type.
+    pub fn cast<T: Into<Expr>>(expr: T, to: CastType) -> Self {
+        Expr::Function(Box::new(FunctionExpr::Cast(expr.into(), to)))
+    }
+
+    /// It creates a geometry value, with the optional srid.
+    pub fn geometry(geometry: Geometry, srid: Option<u32>) -> Self {
+        Expr::Spatial(Box::new(SpatialExpr::Geometry(geometry, srid)))
+    }
+
+    /// It checks if the first geometry contains the second one, `ST_Contains(a, b)`.
+    pub fn st_contains<A: Into<Expr>, B: Into<Expr>>(a: A, b: B) -> Self {
+        Expr::Spatial(Box::new(SpatialExpr::Contains(a.into(), b.into())))
+    }
+
+    /// It checks if the first geometry is within the second one, `ST_Within(a, b)`.
+    pub fn st_within<A: Into<Expr>, B: Into<Expr>>(a: A, b: B) -> Self {
+        Expr::Spatial(Box::new(SpatialExpr::Within(a.into(), b.into())))
+    }
+
+    /// It checks if the geometries intersect, `ST_Intersects(a, b)`.
+    pub fn st_intersects<A: Into<Expr>, B: Into<Expr>>(a: A, b: B) -> Self {
+        Expr::Spatial(Box::new(SpatialExpr::Intersects(a.into(), b.into())))
+    }
+
+    /// It checks if the minimum bounding rectangle of the first geometry contains the second one, `MBRContains(a, b)` on mysql and `a ~ b` on postgresql.
+    pub fn mbr_contains<A: Into<Expr>, B: Into<Expr>>(a: A, b: B) -> Self {
+        Expr::Spatial(Box::new(SpatialExpr::MbrContains(a.into(), b.into())))
+    }
+
+    /// It calculates the distance between two geometries in the unit of their srid, `ST_Distance(a, b)`.
+    pub fn st_distance<A: Into<Expr>, B: Into<Expr>>(a: A, b: B) -> Self {
+        Expr::Spatial(Box::new(SpatialExpr::Distance(a.into(), b.into())))
+    }
+
+    /// It calculates the distance between two longitude & latitude points in meters, `ST_Distance_Sphere(a, b)` on mysql and `ST_DistanceSphere(a, b)` on postgresql.
+    pub fn st_distance_sphere<A: Into<Expr>, B: Into<Expr>>(a: A, b: B) -> Self {
+        Expr::Spatial(Box::new(SpatialExpr::DistanceSphere(a.into(), b.into())))
+    }
+
+    /// It converts a geometry into it's well known text form, `ST_AsText(g)`.
+    pub fn st_as_text<T: Into<Expr>>(geometry: T) -> Self {
+        Expr::Spatial(Box::new(SpatialExpr::AsText(geometry.into())))
+    }
+
+    /// It extracts the value on the given path of a json document, `JSON_EXTRACT(doc, '$.a')` on mysql & sqlite and `doc #> '{a}'` on postgresql.
+    pub fn json_extract<T: Into<Expr>, P: Into<JsonPath>>(document: T, path: P) -> Self {
+        Expr::Json(Box::new(JsonExpr::Extract(document.into(), path.into())))
+    }
+
+    /// It creates a json object from the key & value pairs, `JSON_OBJECT('k', v, ...)` on mysql, `json_build_object()` on postgresql and `json_object()` on sqlite.
+    pub fn json_object(pairs: Vec<(&str, Expr)>) -> Self {
+        Expr::Json(Box::new(JsonExpr::Object(pairs.into_iter().map(|(key, value)| (key.to_string(), value)).collect())))
+    }
+
+    /// It creates a json array from the values, `JSON_ARRAY(...)` on mysql, `json_build_array()` on postgresql and `json_array()` on sqlite.
+    pub fn json_array(values: Vec<Expr>) -> Self {
+        Expr::Json(Box::new(JsonExpr::Array(values)))
+    }
+
+    /// It aggregates the values of a group into a json array, `JSON_ARRAYAGG(v)` on mysql, `json_agg(v)` on postgresql and `json_group_array(v)` on sqlite.
+    pub fn json_arrayagg<T: Into<Expr>>(value: T) -> Self {
+        Expr::Json(Box::new(JsonExpr::ArrayAgg(value.into())))
+    }
+
+    /// It aggregates the key & value pairs of a group into a json object, `JSON_OBJECTAGG(k, v)` on mysql, `json_object_agg(k, v)` on postgresql and `json_group_object(k, v)` on sqlite.
+    pub fn json_objectagg<K: Into<Expr>, V: Into<Expr>>(key: K, value: V) -> Self {
+        Expr::Json(Box::new(JsonExpr::ObjectAgg(key.into(), value.into())))
+    }
+
+    /// It sets the values on the paths of a json document, `JSON_SET(doc, path, value, ...)`. It's rendered as nested `jsonb_set()` calls on postgresql.
+    pub fn json_set<T: Into<Expr>, P: Into<JsonPath>>(document: T, pairs: Vec<(P, Expr)>) -> Self {
+        Self::json_modify(JsonModifier::Set, document.into(), pairs)
+    }
+
+    /// It adds the values on the paths of a json document which don't exist, `JSON_INSERT(doc, path, value, ...)`. Postgresql doesn't support it.
+    pub fn json_insert<T: Into<Expr>, P: Into<JsonPath>>(document: T, pairs: Vec<(P, Expr)>) -> Self {
+        Self::json_modify(JsonModifier::Insert, document.into(), pairs)
+    }
+
+    /// It replaces the existing values on the paths of a json document, `JSON_REPLACE(doc, path, value, ...)`.
+    pub fn json_replace<T: Into<Expr>, P: Into<JsonPath>>(document: T, pairs: Vec<(P, Expr)>) -> Self {
+        Self::json_modify(JsonModifier::Replace, document.into(), pairs)
+    }
+
+    /// It appends the values to the arrays on the paths of a json document, `JSON_ARRAY_APPEND(doc, path, value, ...)`.
+    pub fn json_array_append<T: Into<Expr>, P: Into<JsonPath>>(document: T, pairs: Vec<(P, Expr)>) -> Self {
+        Self::json_modify(JsonModifier::ArrayAppend, document.into(), pairs)
+    }
+
+    /// It inserts the values into the arrays on the positions of the paths, `JSON_ARRAY_INSERT(doc, path, value, ...)`. Sqlite doesn't support it.
+    pub fn json_array_insert<T: Into<Expr>, P: Into<JsonPath>>(document: T, pairs: Vec<(P, Expr)>) -> Self {
+        Self::json_modify(JsonModifier::ArrayInsert, document.into(), pairs)
+    }
+
+    /// It removes the values on the paths of a json document, `JSON_REMOVE(doc, path, ...)`. It panics on the root path.
+    pub fn json_remove<T: Into<Expr>, P: Into<JsonPath>>(document: T, paths: Vec<P>) -> Self {
+        let paths = paths.into_iter().map(|path| path.into()).collect::<Vec<JsonPath>>();
+
+        if paths.is_empty() || paths.iter().any(|path| path.steps.is_empty()) {
+            panic!("json_remove needs at least one path and it cannot remove the root of a document.")
+        }
+
+        Expr::Json(Box::new(JsonExpr::Remove(document.into(), paths)))
+    }
+
+    /// It merges the documents by the rfc 7396, `JSON_MERGE_PATCH(a, b, ...)` on mysql and `json_patch(a, b)` on sqlite. Postgresql doesn't support it.
+    pub fn json_merge_patch(documents: Vec<Expr>) -> Self {
+        if documents.len() < 2 {
+            panic!("json_merge_patch needs at least two documents.")
+        }
+
+        Expr::Json(Box::new(JsonExpr::Merge(JsonMerge::Patch, documents)))
+    }
+
+    /// It merges the documents by combining the values of the same keys into arrays, `JSON_MERGE_PRESERVE(a, b, ...)`. It's mysql specific.
+    pub fn json_merge_preserve(documents: Vec<Expr>) -> Self {
+        if documents.len() < 2 {
+            panic!("json_merge_preserve needs at least two documents.")
+        }
+
+        Expr::Json(Box::new(JsonExpr::Merge(JsonMerge::Preserve, documents)))
+    }
+
+    fn json_modify<P: Into<JsonPath>>(modifier: JsonModifier, document: Expr, pairs: Vec<(P, Expr)>) -> Self {
+        if pairs.is_empty() {
+            panic!("json modification functions need at least one path & value pair.")
+        }
+
+        Expr::Json(Box::new(JsonExpr::Modify(modifier, document, pairs.into_iter().map(|(path, value)| (path.into(), value)).collect())))
+    }
+
+    /// It compares two expressions with one of the =, <, >, <=, >=, != or <> operators, so the comparison can be used as a predicate. It panics on the other operators.
+    pub fn compare<L: Into<Expr>, R: Into<Expr>>(left: L, mark: &str, right: R) -> Self {
+        if let Err(error) = QueryBuilder::sanitize_mark(mark) {
+            panic!("{}", error)
+        }
+
+        Expr::Binary(Box::new(left.into()), mark.to_string(), Box::new(right.into()))
+    }
+
+    /// It checks if the expression is null, `expr IS NULL`.
+    pub fn is_null(self) -> Self {
+        Expr::Binary(Box::new(self), "IS".to_string(), Box::new(Expr::Value(ValueType::Null)))
+    }
+
+    /// It checks if the expression is not null, `expr IS NOT NULL`.
+    pub fn is_not_null(self) -> Self {
+        Expr::Binary(Box::new(self), "IS NOT".to_string(), Box::new(Expr::Value(ValueType::Null)))
+    }
+
+    /// It returns the path of the string values which match the pattern, `JSON_SEARCH(doc, 'one', pattern)`. It's mysql specific.
+    pub fn json_search<T: Into<Expr>, S: Into<Expr>>(document: T, mode: JsonSearchMode, pattern: S) -> Self {
+        Expr::Json(Box::new(JsonExpr::Search(document.into(), mode, pattern.into(), None)))
+    }
+
+    /// It's same with `Expr::json_search()`, but it only searches under the given path, `JSON_SEARCH(doc, 'all', pattern, NULL, path)`.
+    pub fn json_search_in<T: Into<Expr>, S: Into<Expr>, P: Into<JsonPath>>(document: T, mode: JsonSearchMode, pattern: S, path: P) -> Self {
+        Expr::Json(Box::new(JsonExpr::Search(document.into(), mode, pattern.into(), Some(path.into()))))
+    }
+
+    /// It checks if one or all of the paths exist in a json document, `JSON_CONTAINS_PATH(doc, 'one', path, ...)`. It's rendered with the `@?` operator on postgresql and `json_type()` on sqlite.
+    pub fn json_contains_path<T: Into<Expr>, P: Into<JsonPath>>(document: T, mode: JsonSearchMode, paths: Vec<P>) -> Self {
+        if paths.is_empty() {
+            panic!("json_contains_path needs at least one path.")
+        }
+
+        Expr::Json(Box::new(JsonExpr::ContainsPath(document.into(), mode, paths.into_iter().map(|path| path.into()).collect())))
+    }
+
+    /// It returns the keys of the object on the path as a json array, `JSON_KEYS(doc[, path])`. Sqlite doesn't support it.
+    pub fn json_keys<T: Into<Expr>, P: Into<JsonPath>>(document: T, path: Option<P>) -> Self {
+        Expr::Json(Box::new(JsonExpr::Keys(document.into(), path.map(|path| path.into()))))
+    }
+
+    /// It returns the length of the value on the path, `JSON_LENGTH(doc[, path])`. Postgresql and sqlite only count the elements of the arrays.
+    pub fn json_length<T: Into<Expr>, P: Into<JsonPath>>(document: T, path: Option<P>) -> Self {
+        Expr::Json(Box::new(JsonExpr::Length(document.into(), path.map(|path| path.into()))))
+    }
+
+    /// It returns the type of a json value, `JSON_TYPE(doc)`. Each database names the types on it's own way.
+    pub fn json_type<T: Into<Expr>>(document: T) -> Self {
+        Expr::Json(Box::new(JsonExpr::Type(document.into())))
+    }
+
+    /// It checks if the value is a valid json, `JSON_VALID(doc)`.
+    pub fn json_valid<T: Into<Expr>>(document: T) -> Self {
+        Expr::Json(Box::new(JsonExpr::Valid(document.into())))
+    }
+
+    /// It checks if the value is an element of the json array, `value MEMBER OF(array)`.
+    pub fn member_of<V: Into<Expr>, A: Into<Expr>>(value: V, array: A) -> Self {
+        Expr::Json(Box::new(JsonExpr::MemberOf(value.into(), array.into())))
+    }
+
+    /// It checks if two json documents have any common key & value pair or array element, `JSON_OVERLAPS(a, b)`. It's mysql specific.
+    pub fn json_overlaps<A: Into<Expr>, B: Into<Expr>>(a: A, b: B) -> Self {
+        Expr::Json(Box::new(JsonExpr::Overlaps(a.into(), b.into())))
+    }
+
+    /// It extracts the value on the path with the `->` operator, `doc->'$.a'`. It's rendered with the `#>` operator on postgresql, the operator needs sqlite 3.38 or later on sqlite.
+    pub fn json_get<T: Into<Expr>, P: Into<JsonPath>>(document: T, path: P) -> Self {
+        Expr::Json(Box::new(JsonExpr::Get(document.into(), path.into())))
+    }
+
+    /// It extracts the value on the path as an unquoted text with the `->>` operator, `doc->>'$.a'`. It's rendered with the `#>>` operator on postgresql, the operator needs sqlite 3.38 or later on sqlite.
+    pub fn json_get_text<T: Into<Expr>, P: Into<JsonPath>>(document: T, path: P) -> Self {
+        Expr::Json(Box::new(JsonExpr::GetText(document.into(), path.into())))
+    }
+
+    /// It wraps a query into parenthesis, so it can be used as a scalar subquery. The query is rendered with it's own dialect.
+    pub fn subquery(query: &QueryBuilder) -> Self {
+        Expr::Subquery(Subquery {
+            statement: query.render_statement()
+        })
+    }
+
+    /// It renders the expression for the given dialect.
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        match self {
+            Expr::Column(column) => column.to_string(),
+            Expr::Value(ValueType::Datetime(datetime)) => match SqlKeyword::from_name(datetime) {
+                Some(keyword) => keyword.to_sql(dialect),
+                None => format!("'{}'", datetime)
+            },
+            Expr::Value(value) => value.to_string(),
+            Expr::Keyword(keyword) => keyword.to_sql(dialect),
+            Expr::Binary(left, operator, right) => format!("{} {} {}", Self::operand(left, dialect), operator, Self::operand(right, dialect)),
+            Expr::Function(function) => function.to_sql(dialect),
+            Expr::Date(date) => date.to_sql(dialect),
+            Expr::Spatial(spatial) => spatial.to_sql(dialect),
+            Expr::Json(json) => json.to_sql(dialect),
+            Expr::Subquery(query) => format!("({})", query.statement)
+        }
+    }
+
+    fn operand(expr: &Expr, dialect: &Dialect) -> String {
+        match expr {
+            Expr::Binary(_, _, _) => format!("({})", expr.to_sql(dialect)),
+            _ => expr.to_sql(dialect)
+        }
+    }
+
+    /// escapes the quotes and the backslashes of the string values in the expression, for the mysql statements which are written without bindings.
+    fn escape_values(&mut self) {
+        let mut values = vec![];
+
+        self.values_mut(&mut values);
+        values.into_iter().for_each(SetBuilder::escape);
+    }
+
+    /// collects the literal values of the expression and the expressions inside of it, so they can be escaped before they're written.
+    fn values_mut<'e>(&'e mut self, values: &mut Vec<&'e mut ValueType>) {
+        match self {
+            Expr::Value(value) => values.push(value),
+            Expr::Column(_) | Expr::Keyword(_) | Expr::Subquery(_) => (),
+            Expr::Binary(left, _, right) => {
+                left.values_mut(values);
+                right.values_mut(values);
+            },
+            Expr::Function(function) => match function.as_mut() {
+                FunctionExpr::Call(_, args) | FunctionExpr::Concat(args) | FunctionExpr::Coalesce(args) => args.iter_mut().for_each(|arg| arg.values_mut(values)),
+                FunctionExpr::ConcatWs(separator, args) => {
+                    separator.values_mut(values);
+                    args.iter_mut().for_each(|arg| arg.values_mut(values));
+                },
+                FunctionExpr::Lower(expr) | FunctionExpr::Upper(expr) | FunctionExpr::Trim(expr) | FunctionExpr::Substring(expr, _, _) |
+                FunctionExpr::Length(expr) | FunctionExpr::Cast(expr, _) => expr.values_mut(values),
+                FunctionExpr::IfNull(expr, other) | FunctionExpr::NullIf(expr, other) => {
+                    expr.values_mut(values);
+                    other.values_mut(values);
+                }
+            },
+            Expr::Date(date) => match date.as_mut() {
+                DateExpr::Add(expr, _, _) | DateExpr::Sub(expr, _, _) | DateExpr::Format(expr, _) | DateExpr::Date(expr) | DateExpr::ConvertTz(expr, _, _) |
+                DateExpr::UnixTimestamp(expr) | DateExpr::Extract(_, expr) => expr.values_mut(values),
+                DateExpr::TimestampDiff(_, start, end) => {
+                    start.values_mut(values);
+                    end.values_mut(values);
+                }
+            },
+            Expr::Spatial(spatial) => match spatial.as_mut() {
+                SpatialExpr::Geometry(_, _) => (),
+                SpatialExpr::AsText(geometry) => geometry.values_mut(values),
+                SpatialExpr::Contains(a, b) | SpatialExpr::Within(a, b) | SpatialExpr::Intersects(a, b) | SpatialExpr::MbrContains(a, b) |
+                SpatialExpr::Distance(a, b) | SpatialExpr::DistanceSphere(a, b) => {
+                    a.values_mut(values);
+                    b.values_mut(values);
+                }
+            },
+            Expr::Json(json) => match json.as_mut() {
+                JsonExpr::Extract(document, _) | JsonExpr::ArrayAgg(document) | JsonExpr::Remove(document, _) | JsonExpr::ContainsPath(document, _, _) |
+                JsonExpr::Keys(document, _) | JsonExpr::Length(document, _) | JsonExpr::Type(document) | JsonExpr::Valid(document) |
+                JsonExpr::Get(document, _) | JsonExpr::GetText(document, _) => document.values_mut(values),
+                JsonExpr::Literal(_) => (),
+                JsonExpr::Search(a, _, b, _) | JsonExpr::MemberOf(a, b) | JsonExpr::Overlaps(a, b) | JsonExpr::ObjectAgg(a, b) => {
+                    a.values_mut(values);
+                    b.values_mut(values);
+                },
+                JsonExpr::Modify(_, document, pairs) => {
+                    document.values_mut(values);
+                    pairs.iter_mut().for_each(|(_, value)| value.values_mut(values));
+                },
+                JsonExpr::Object(pairs) => pairs.iter_mut().for_each(|(_, value)| value.values_mut(values)),
+                JsonExpr::Merge(_, documents) | JsonExpr::Array(documents) => documents.iter_mut().for_each(|document| document.values_mut(values))
+            }
+        }
+    }
+}
+
+impl From<Json> for Expr { fn from(value: Json) -> Self { Expr::Json(Box::new(JsonExpr::Literal(value))) } }
+
+/// Struct that holds the statement of a subquery expression. It can only be created with `Expr::subquery()` from a `QueryBuilder`, so the raw sql texts cannot be passed as subqueries.
+#[derive(Debug, Clone)]
+pub struct Subquery {
+    statement: String
+}
+
+impl Subquery {
+    /// Returns the statement of the subquery, without the parenthesis.
+    pub fn statement(&self) -> &str {
+        &self.statement
+    }
+}
+
+impl std::fmt::Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_sql(&Dialect::Mysql))
+    }
+}
+
+impl<T: Into<ValueType>> From<T> for Expr { fn from(value: T) -> Self { Expr::Value(value.into()) } }
+impl From<SqlKeyword> for Expr { fn from(value: SqlKeyword) -> Self { Expr::Keyword(value) } }
+impl From<Geometry> for Expr { fn from(value: Geometry) -> Self { Expr::geometry(value, None) } }
+
+impl<T: Into<Expr>> std::ops::Add<T> for Expr { type Output = Expr; fn add(self, rhs: T) -> Expr { Expr::Binary(Box::new(self), "+".to_string(), Box::new(rhs.into())) } }
+impl<T: Into<Expr>> std::ops::Sub<T> for Expr { type Output = Expr; fn sub(self, rhs: T) -> Expr { Expr::Binary(Box::new(self), "-".to_string(), Box::new(rhs.into())) } }
+impl<T: Into<Expr>> std::ops::Mul<T> for Expr { type Output = Expr; fn mul(self, rhs: T) -> Expr { Expr::Binary(Box::new(self), "*".to_string(), Box::new(rhs.into())) } }
+impl<T: Into<Expr>> std::ops::Div<T> for Expr { type Output = Expr; fn div(self, rhs: T) -> Expr { Expr::Binary(Box::new(self), "/".to_string(), Box::new(rhs.into())) } }
+impl<T: Into<Expr>> std::ops::Rem<T> for Expr { type Output = Expr; fn rem(self, rhs: T) -> Expr { Expr::Binary(Box::new(self), "%".to_string(), Box::new(rhs.into())) } }
+
+/// Geometry enum. It represents the spatial values, which are created from coordinates or from a well known text. The rings of polygons are closed automatically if they're not.
+/// 
+/// ```rust
+/// 
+/// use qubl::Geometry;
+/// 
+/// fn main() {
+///     let area = Geometry::Polygon(vec![vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]]);
+/// 
+///     assert_eq!(area.to_string(), "POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub enum Geometry {
+    Point(f64, f64), LineString(Vec<(f64, f64)>), Polygon(Vec<Vec<(f64, f64)>>), Wkt(String)
+}
+
+impl Geometry {
+    fn coordinates(points: &[(f64, f64)]) -> String {
+        points.iter().map(|(x, y)| format!("{} {}", x, y)).collect::<Vec<String>>().join(", ")
+    }
+}
+
+impl std::fmt::Display for Geometry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Geometry::Point(x, y) => write!(f, "POINT({} {})", x, y),
+            Geometry::LineString(points) => write!(f, "LINESTRING({})", Self::coordinates(points)),
+            Geometry::Polygon(rings) => {
+                let rings = rings.iter().map(|ring| {
+                    let mut ring = ring.clone();
+
+                    if let (Some(first), Some(last)) = (ring.first().copied(), ring.last().copied()) {
+                        if first != last {
+                            ring.push(first);
+                        }
+                    }
+
+                    format!("({})", Self::coordinates(&ring))
+                }).collect::<Vec<String>>();
+
+                write!(f, "POLYGON({})", rings.join(", "))
+            },
+            Geometry::Wkt(wkt) => write!(f, "{}", wkt)
+        }
+    }
+}
+
+/// SpatialExpr enum. It holds the spatial values and functions of the `Expr` type, use the constructors of `Expr` such as `Expr::st_contains()` to create them.
+/// 
+/// They're rendered with mysql spatial functions or their postgis equivalents on postgresql. They panic on sqlite, since it doesn't support spatial functions.
+/// 
+/// ```rust
+/// 
+/// use qubl::{Expr, Geometry, Dialect};
+/// 
+/// fn main() {
+///     let expr = Expr::st_distance_sphere(Expr::col("location"), Geometry::Point(28.97, 41.01));
+/// 
+///     assert_eq!(expr.to_sql(&Dialect::Mysql), "ST_Distance_Sphere(location, POINT(28.97, 41.01))");
+///     assert_eq!(expr.to_sql(&Dialect::Postgresql), "ST_DistanceSphere(location, ST_MakePoint(28.97, 41.01))");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub enum SpatialExpr {
+    Geometry(Geometry, Option<u32>), Contains(Expr, Expr), Within(Expr, Expr), Intersects(Expr, Expr), MbrContains(Expr, Expr),
+    Distance(Expr, Expr), DistanceSphere(Expr, Expr), AsText(Expr)
+}
+
+impl SpatialExpr {
+    /// It renders the spatial value or function for the given dialect.
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        if let Dialect::Sqlite = dialect {
+            panic!("spatial functions are not supported on sqlite.")
+        }
+
+        match self {
+            SpatialExpr::Geometry(Geometry::Point(x, y), None) => match dialect {
+                Dialect::Postgresql => format!("ST_MakePoint({}, {})", x, y),
+                _ => format!("POINT({}, {})", x, y)
+            },
+            SpatialExpr::Geometry(geometry, None) => format!("ST_GeomFromText('{}')", geometry),
+            SpatialExpr::Geometry(geometry, Some(srid)) => format!("ST_GeomFromText('{}', {})", geometry, srid),
+            SpatialExpr::Contains(a, b) => format!("ST_Contains({}, {})", a.to_sql(dialect), b.to_sql(dialect)),
+            SpatialExpr::Within(a, b) => format!("ST_Within({}, {})", a.to_sql(dialect), b.to_sql(dialect)),
+            SpatialExpr::Intersects(a, b) => format!("ST_Intersects({}, {})", a.to_sql(dialect), b.to_sql(dialect)),
+            SpatialExpr::MbrContains(a, b) => match dialect {
+                Dialect::Postgresql => format!("({} ~ {})", a.to_sql(dialect), b.to_sql(dialect)),
+                _ => format!("MBRContains({}, {})", a.to_sql(dialect), b.to_sql(dialect))
+            },
+            SpatialExpr::Distance(a, b) => format!("ST_Distance({}, {})", a.to_sql(dialect), b.to_sql(dialect)),
+            SpatialExpr::DistanceSphere(a, b) => match dialect {
+                Dialect::Postgresql => format!("ST_DistanceSphere({}, {})", a.to_sql(dialect), b.to_sql(dialect)),
+                _ => format!("ST_Distance_Sphere({}, {})", a.to_sql(dialect), b.to_sql(dialect))
+            },
+            SpatialExpr::AsText(geometry) => format!("ST_AsText({})", geometry.to_sql(dialect))
+        }
+    }
+}
+
+/// JsonExpr enum. It holds the json functions of the `Expr` type, use the constructors of `Expr` such as `Expr::json_extract()` to create them.
+/// 
+/// ```rust
+/// 
+/// use qubl::{Expr, JsonPath, Dialect};
+/// 
+/// fn main() {
+///     let expr = Expr::json_extract(Expr::col("profile"), JsonPath::root().key("tags").index(0));
+/// 
+///     assert_eq!(expr.to_sql(&Dialect::Mysql), "JSON_EXTRACT(profile, '$.tags[0]')");
+///     assert_eq!(expr.to_sql(&Dialect::Postgresql), "profile #> '{tags,0}'");
+///     assert_eq!(expr.to_sql(&Dialect::Sqlite), "json_extract(profile, '$.tags[0]')");
+/// 
+///     let expr = Expr::json_extract(Expr::col("profile"), "tags[*]");
+/// 
+///     assert_eq!(expr.to_sql(&Dialect::Postgresql), "jsonb_path_query_array(profile, '$.tags[*]')");
+/// 
+///     let expr = Expr::json_arrayagg(Expr::json_object(vec![("id", Expr::col("p.id")), ("title", Expr::col("p.title"))]));
+/// 
+///     assert_eq!(expr.to_sql(&Dialect::Mysql), "JSON_ARRAYAGG(JSON_OBJECT('id', p.id, 'title', p.title))");
+///     assert_eq!(expr.to_sql(&Dialect::Postgresql), "json_agg(json_build_object('id', p.id, 'title', p.title))");
+///     assert_eq!(expr.to_sql(&Dialect::Sqlite), "json_group_array(json_object('id', p.id, 'title', p.title))");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub enum JsonExpr {
+    Extract(Expr, JsonPath), Object(Vec<(String, Expr)>), Array(Vec<Expr>), ArrayAgg(Expr), ObjectAgg(Expr, Expr),
+    Modify(JsonModifier, Expr, Vec<(JsonPath, Expr)>), Remove(Expr, Vec<JsonPath>), Merge(JsonMerge, Vec<Expr>),
+    Literal(Json), Search(Expr, JsonSearchMode, Expr, Option<JsonPath>), ContainsPath(Expr, JsonSearchMode, Vec<JsonPath>), Keys(Expr, Option<JsonPath>),
+    Length(Expr, Option<JsonPath>), Type(Expr), Valid(Expr), MemberOf(Expr, Expr), Overlaps(Expr, Expr), Get(Expr, JsonPath), GetText(Expr, JsonPath)
+}
+
+/// JsonSearchMode enum. It's the `'one'` or `'all'` argument of the `JSON_SEARCH()` and `JSON_CONTAINS_PATH()` functions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonSearchMode {
+    One, All
+}
+
+impl std::fmt::Display for JsonSearchMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonSearchMode::One => write!(f, "one"),
+            JsonSearchMode::All => write!(f, "all")
+        }
+    }
+}
+
+/// JsonModifier enum. It's the kind of a json modification function, `JSON_SET()`, `JSON_INSERT()`, `JSON_REPLACE()`, `JSON_ARRAY_APPEND()` or `JSON_ARRAY_INSERT()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonModifier {
+    Set, Insert, Replace, ArrayAppend, ArrayInsert
+}
+
+/// JsonMerge enum. It's the kind of a json merge function, `JSON_MERGE_PATCH()` or `JSON_MERGE_PRESERVE()`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonMerge {
+    Patch, Preserve
+}
+
+impl JsonExpr {
+    /// It renders the json function for the given dialect. The extracted paths with wildcards or ranges are rendered with `jsonb_path_query_array()` on postgresql.
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        match self {
+            JsonExpr::Extract(document, path) => match dialect {
+                Dialect::Mysql => format!("JSON_EXTRACT({}, '{}')", document.to_sql(dialect), path.to_sql(dialect).replace("'", "''")),
+                Dialect::Sqlite => format!("json_extract({}, '{}')", document.to_sql(dialect), path.to_sql(dialect).replace("'", "''")),
+                Dialect::Postgresql => match path.is_multiple() {
+                    true => format!("jsonb_path_query_array({}, '{}')", document.to_sql(dialect), path.to_sql(dialect).replace("'", "''")),
+                    false => format!("{} #> '{}'", Expr::operand(document, dialect), path.to_pg_pointer().replace("'", "''"))
+                }
+            },
+            JsonExpr::Object(pairs) => {
+                let pairs = pairs.iter().map(|(key, value)| format!("'{}', {}", key.replace("'", "''"), value.to_sql(dialect))).collect::<Vec<String>>().join(", ");
+
+                match dialect {
+                    Dialect::Mysql => format!("JSON_OBJECT({})", pairs),
+                    Dialect::Postgresql => format!("json_build_object({})", pairs),
+                    Dialect::Sqlite => format!("json_object({})", pairs)
+                }
+            },
+            JsonExpr::Array(values) => {
+                let values = values.iter().map(|value| value.to_sql(dialect)).collect::<Vec<String>>().join(", ");
+
+                match dialect {
+                    Dialect::Mysql => format!("JSON_ARRAY({})", values),
+                    Dialect::Postgresql => format!("json_build_array({})", values),
+                    Dialect::Sqlite => format!("json_array({})", values)
+                }
+            },
+            JsonExpr::ArrayAgg(value) => match dialect {
+                Dialect::Mysql => format!("JSON_ARRAYAGG({})", value.to_sql(dialect)),
+                Dialect::Postgresql => format!("json_agg({})", value.to_sql(dialect)),
+                Dialect::Sqlite => format!("json_group_array({})", value.to_sql(dialect))
+            },
+            JsonExpr::ObjectAgg(key, value) => match dialect {
+                Dialect::Mysql => format!("JSON_OBJECTAGG({}, {})", key.to_sql(dialect), value.to_sql(dialect)),
+                Dialect::Postgresql => format!("json_object_agg({}, {})", key.to_sql(dialect), value.to_sql(dialect)),
+                Dialect::Sqlite => format!("json_group_object({}, {})", key.to_sql(dialect), value.to_sql(dialect))
+            },
+            JsonExpr::Modify(modifier, document, pairs) => match dialect {
+                Dialect::Postgresql => pairs.iter().fold(document.to_sql(dialect), |document, (path, value)| match modifier {
+                    JsonModifier::Set => format!("jsonb_set({}, '{}', {})", document, path.to_pg_pointer(), JsonExpr::to_jsonb(value)),
+                    JsonModifier::Replace => format!("jsonb_set({}, '{}', {}, false)", document, path.to_pg_pointer(), JsonExpr::to_jsonb(value)),
+                    JsonModifier::ArrayAppend => format!("jsonb_insert({}, '{}', {}, true)", document, path.clone().last().to_pg_pointer(), JsonExpr::to_jsonb(value)),
+                    JsonModifier::ArrayInsert => format!("jsonb_insert({}, '{}', {})", document, path.to_pg_pointer(), JsonExpr::to_jsonb(value)),
+                    JsonModifier::Insert => panic!("postgresql doesn't have an equivalent of JSON_INSERT().")
+                }),
+                _ => {
+                    let function = match (modifier, dialect) {
+                        (JsonModifier::Set, Dialect::Mysql) => "JSON_SET",
+                        (JsonModifier::Insert, Dialect::Mysql) => "JSON_INSERT",
+                        (JsonModifier::Replace, Dialect::Mysql) => "JSON_REPLACE",
+                        (JsonModifier::ArrayAppend, Dialect::Mysql) => "JSON_ARRAY_APPEND",
+                        (JsonModifier::ArrayInsert, Dialect::Mysql) => "JSON_ARRAY_INSERT",
+                        (JsonModifier::Set, _) => "json_set",
+                        (JsonModifier::Insert, _) | (JsonModifier::ArrayAppend, _) => "json_insert",
+                        (JsonModifier::Replace, _) => "json_replace",
+                        (JsonModifier::ArrayInsert, _) => panic!("sqlite doesn't have an equivalent of JSON_ARRAY_INSERT().")
+                    };
+
+                    let pairs = pairs.iter().map(|(path, value)| {
+                        let path = match (modifier, dialect) {
+                            (JsonModifier::ArrayAppend, Dialect::Sqlite) => format!("{}[#]", path.to_sql(dialect)),
+                            _ => path.to_sql(dialect)
+                        };
+
+                        format!("'{}', {}", path.replace("'", "''"), value.to_sql(dialect))
+                    }).collect::<Vec<String>>();
+
+                    format!("{}({}, {})", function, document.to_sql(dialect), pairs.join(", "))
+                }
+            },
+            JsonExpr::Remove(document, paths) => match dialect {
+                Dialect::Postgresql => paths.iter().fold(document.to_sql(dialect), |document, path| format!("{} #- '{}'", document, path.to_pg_pointer())),
+                _ => {
+                    let function = match dialect {
+                        Dialect::Mysql => "JSON_REMOVE",
+                        _ => "json_remove"
+                    };
+
+                    let paths = paths.iter().map(|path| format!("'{}'", path.to_sql(dialect).replace("'", "''"))).collect::<Vec<String>>();
+
+                    format!("{}({}, {})", function, document.to_sql(dialect), paths.join(", "))
+                }
+            },
+            JsonExpr::Merge(merge, documents) => {
+                let documents = documents.iter().map(|document| document.to_sql(dialect)).collect::<Vec<String>>();
+
+                match (merge, dialect) {
+                    (JsonMerge::Patch, Dialect::Mysql) => format!("JSON_MERGE_PATCH({})", documents.join(", ")),
+                    (JsonMerge::Preserve, Dialect::Mysql) => format!("JSON_MERGE_PRESERVE({})", documents.join(", ")),
+                    (JsonMerge::Patch, Dialect::Sqlite) => documents[1..].iter().fold(documents[0].clone(), |document, patch| format!("json_patch({}, {})", document, patch)),
+                    _ => panic!("postgresql doesn't support json merge functions, sqlite only supports json_merge_patch.")
+                }
+            },
+            JsonExpr::Literal(json) => json.to_sql(dialect),
+            JsonExpr::Search(document, mode, pattern, path) => match (dialect, path) {
+                (Dialect::Mysql, Some(path)) => format!("JSON_SEARCH({}, '{}', {}, NULL, '{}')", document.to_sql(dialect), mode, pattern.to_sql(dialect), path.to_sql(dialect).replace("'", "''")),
+                (Dialect::Mysql, None) => format!("JSON_SEARCH({}, '{}', {})", document.to_sql(dialect), mode, pattern.to_sql(dialect)),
+                _ => panic!("JSON_SEARCH() is mysql specific.")
+            },
+            JsonExpr::ContainsPath(document, mode, paths) => {
+                let separator = match mode {
+                    JsonSearchMode::One => " OR ",
+                    JsonSearchMode::All => " AND "
+                };
+
+                match dialect {
+                    Dialect::Mysql => format!("JSON_CONTAINS_PATH({}, '{}', {})", document.to_sql(dialect), mode, paths.iter().map(|path| format!("'{}'", path.to_sql(dialect).replace("'", "''"))).collect::<Vec<String>>().join(", ")),
+                    Dialect::Postgresql => format!("({})", paths.iter().map(|path| format!("{} @? '{}'", Expr::operand(document, dialect), path.to_sql(dialect).replace("'", "''"))).collect::<Vec<String>>().join(separator)),
+                    Dialect::Sqlite => format!("({})", paths.iter().map(|path| format!("json_type({}, '{}') IS NOT NULL", document.to_sql(dialect), path.to_sql(dialect).replace("'", "''"))).collect::<Vec<String>>().join(separator))
+                }
+            },
+            JsonExpr::Keys(document, path) => match (dialect, path) {
+                (Dialect::Mysql, Some(path)) => format!("JSON_KEYS({}, '{}')", document.to_sql(dialect), path.to_sql(dialect).replace("'", "''")),
+                (Dialect::Mysql, None) => format!("JSON_KEYS({})", document.to_sql(dialect)),
+                (Dialect::Postgresql, _) => {
+                    let path = path.clone().unwrap_or(JsonPath::root());
+
+                    format!("jsonb_path_query_array({}, '{}.keyvalue().key')", document.to_sql(dialect), path.to_sql(dialect).replace("'", "''"))
+                },
+                (Dialect::Sqlite, _) => panic!("sqlite doesn't have an equivalent of JSON_KEYS().")
+            },
+            JsonExpr::Length(document, path) => match (dialect, path) {
+                (Dialect::Mysql, Some(path)) => format!("JSON_LENGTH({}, '{}')", document.to_sql(dialect), path.to_sql(dialect).replace("'", "''")),
+                (Dialect::Mysql, None) => format!("JSON_LENGTH({})", document.to_sql(dialect)),
+                (Dialect::Sqlite, Some(path)) => format!("json_array_length({}, '{}')", document.to_sql(dialect), path.to_sql(dialect).replace("'", "''")),
+                (Dialect::Sqlite, None) => format!("json_array_length({})", document.to_sql(dialect)),
+                (Dialect::Postgresql, Some(path)) => format!("jsonb_array_length({} #> '{}')", Expr::operand(document, dialect), path.to_pg_pointer().replace("'", "''")),
+                (Dialect::Postgresql, None) => format!("jsonb_array_length({})", document.to_sql(dialect))
+            },
+            JsonExpr::Type(document) => match dialect {
+                Dialect::Mysql => format!("JSON_TYPE({})", document.to_sql(dialect)),
+                Dialect::Postgresql => format!("jsonb_typeof({})", document.to_sql(dialect)),
+                Dialect::Sqlite => format!("json_type({})", document.to_sql(dialect))
+            },
+            JsonExpr::Valid(document) => match dialect {
+                Dialect::Mysql => format!("JSON_VALID({})", document.to_sql(dialect)),
+                Dialect::Postgresql => format!("({} IS JSON)", Expr::operand(document, dialect)),
+                Dialect::Sqlite => format!("json_valid({})", document.to_sql(dialect))
+            },
+            JsonExpr::MemberOf(value, array) => match dialect {
+                Dialect::Mysql => format!("{} MEMBER OF({})", Expr::operand(value, dialect), array.to_sql(dialect)),
+                Dialect::Postgresql => format!("{} @> {}", Expr::operand(array, dialect), JsonExpr::to_jsonb(value)),
+                Dialect::Sqlite => format!("{} IN (SELECT value FROM json_each({}))", Expr::operand(value, dialect), array.to_sql(dialect))
+            },
+            JsonExpr::Overlaps(a, b) => match dialect {
+                Dialect::Mysql => format!("JSON_OVERLAPS({}, {})", a.to_sql(dialect), b.to_sql(dialect)),
+                _ => panic!("JSON_OVERLAPS() is mysql specific.")
+            },
+            JsonExpr::Get(document, path) => match dialect {
+                Dialect::Postgresql => format!("{} #> '{}'", Expr::operand(document, dialect), path.to_pg_pointer().replace("'", "''")),
+                _ => format!("{}->'{}'", Expr::operand(document, dialect), path.to_sql(dialect).replace("'", "''"))
+            },
+            JsonExpr::GetText(document, path) => match dialect {
+                Dialect::Postgresql => format!("{} #>> '{}'", Expr::operand(document, dialect), path.to_pg_pointer().replace("'", "''")),
+                _ => format!("{}->>'{}'", Expr::operand(document, dialect), path.to_sql(dialect).replace("'", "''"))
+            }
+        }
+    }
+
+    /// wraps the value with `to_jsonb()` on postgresql, the string literals are cast to text because `to_jsonb('x')` cannot resolve the type of an unknown literal.
+    fn to_jsonb(value: &Expr) -> String {
+        match value {
+            Expr::Value(ValueType::String(_)) => format!("to_jsonb({}::text)", value.to_sql(&Dialect::Postgresql)),
+            Expr::Value(ValueType::Datetime(datetime)) if SqlKeyword::from_name(datetime).is_none() => format!("to_jsonb({}::text)", value.to_sql(&Dialect::Postgresql)),
+            _ => format!("to_jsonb({})", value.to_sql(&Dialect::Postgresql))
+        }
+    }
+}
+
+/// CastType enum. It's the target type of the `Expr::cast()` function, rendered with the matching type name of the dialect.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CastType {
+    Text, Integer, Unsigned, Decimal(u8, u8), Float, Date, Datetime, Time, Json, Binary
+}
+
+/// FunctionExpr enum. It holds the function calls of the `Expr` type: the ones which are written as they are with `Expr::func()` and the string and null handling functions which are spelled with the form of the dialect. Use the constructors of `Expr` such as `Expr::concat()` to create them.
+/// 
+/// ```rust
+/// 
+/// use qubl::{Expr, CastType, Dialect};
+/// 
+/// fn main() {
+///     let expr = Expr::concat(vec![Expr::col("name"), Expr::val(String::from(" ")), Expr::col("surname")]);
+/// 
+///     assert_eq!(expr.to_sql(&Dialect::Mysql), "CONCAT(name, ' ', surname)");
+///     assert_eq!(expr.to_sql(&Dialect::Postgresql), "(name || ' ' || surname)");
+/// 
+///     let expr = Expr::cast(Expr::if_null(Expr::col("age"), 0), CastType::Integer);
+/// 
+///     assert_eq!(expr.to_sql(&Dialect::Mysql), "CAST(IFNULL(age, 0) AS SIGNED)");
+///     assert_eq!(expr.to_sql(&Dialect::Postgresql), "CAST(COALESCE(age, 0) AS INTEGER)");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub enum FunctionExpr {
+    Call(String, Vec<Expr>), Concat(Vec<Expr>), ConcatWs(Expr, Vec<Expr>), Lower(Expr), Upper(Expr), Trim(Expr), Substring(Expr, i64, Option<i64>), Length(Expr),
+    Coalesce(Vec<Expr>), IfNull(Expr, Expr), NullIf(Expr, Expr), Cast(Expr, CastType)
+}
+
+impl FunctionExpr {
+    /// It renders the function for the given dialect.
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        match self {
+            FunctionExpr::Call(name, args) => format!("{}({})", name, Self::join(args, dialect, ", ")),
+            FunctionExpr::Concat(args) => match dialect {
+                Dialect::Mysql => format!("CONCAT({})", Self::join(args, dialect, ", ")),
+                _ => format!("({})", Self::join(args, dialect, " || "))
+            },
+            FunctionExpr::ConcatWs(separator, args) => match dialect {
+                Dialect::Sqlite => {
+                    let separator = separator.to_sql(dialect);
+                    let parts = args.iter().map(|arg| format!("COALESCE({} || {}, '')", separator, arg.to_sql(dialect))).collect::<Vec<String>>();
+
+                    format!("SUBSTR({}, LENGTH({}) + 1)", parts.join(" || "), separator)
+                },
+                _ => format!("CONCAT_WS({}, {})", separator.to_sql(dialect), Self::join(args, dialect, ", "))
+            },
+            FunctionExpr::Lower(expr) => format!("LOWER({})", expr.to_sql(dialect)),
+            FunctionExpr::Upper(expr) => format!("UPPER({})", expr.to_sql(dialect)),
+            FunctionExpr::Trim(expr) => format!("TRIM({})", expr.to_sql(dialect)),
+            FunctionExpr::Substring(expr, start, length) => match (dialect, length) {
+                (Dialect::Postgresql, Some(length)) => format!("SUBSTRING({} FROM {} FOR {})", expr.to_sql(dialect), start, length),
+                (Dialect::Postgresql, None) => format!("SUBSTRING({} FROM {})", expr.to_sql(dialect), start),
+                (Dialect::Sqlite, Some(length)) => format!("SUBSTR({}, {}, {})", expr.to_sql(dialect), start, length),
+                (Dialect::Sqlite, None) => format!("SUBSTR({}, {})", expr.to_sql(dialect), start),
+                (Dialect::Mysql, Some(length)) => format!("SUBSTRING({}, {}, {})", expr.to_sql(dialect), start, length),
+                (Dialect::Mysql, None) => format!("SUBSTRING({}, {})", expr.to_sql(dialect), start)
+            },
+            FunctionExpr::Length(expr) => format!("LENGTH({})", expr.to_sql(dialect)),
+            FunctionExpr::Coalesce(args) => format!("COALESCE({})", Self::join(args, dialect, ", ")),
+            FunctionExpr::IfNull(expr, fallback) => match dialect {
+                Dialect::Postgresql => format!("COALESCE({}, {})", expr.to_sql(dialect), fallback.to_sql(dialect)),
+                _ => format!("IFNULL({}, {})", expr.to_sql(dialect), fallback.to_sql(dialect))
+            },
+            FunctionExpr::NullIf(expr, other) => format!("NULLIF({}, {})", expr.to_sql(dialect), other.to_sql(dialect)),
+            FunctionExpr::Cast(expr, to) => {
+                let type_name = match (dialect, to) {
+                    (Dialect::Sqlite, CastType::Date) => return format!("DATE({})", expr.to_sql(dialect)),
+                    (Dialect::Sqlite, CastType::Datetime) => return format!("DATETIME({})", expr.to_sql(dialect)),
+                    (Dialect::Sqlite, CastType::Time) => return format!("TIME({})", expr.to_sql(dialect)),
+                    (Dialect::Sqlite, CastType::Json) => return format!("JSON({})", expr.to_sql(dialect)),
+                    (Dialect::Mysql, CastType::Text) => "CHAR".to_string(),
+                    (_, CastType::Text) => "TEXT".to_string(),
+                    (Dialect::Mysql, CastType::Integer) => "SIGNED".to_string(),
+                    (Dialect::Mysql, CastType::Unsigned) => "UNSIGNED".to_string(),
+                    (Dialect::Postgresql, CastType::Unsigned) => "BIGINT".to_string(),
+                    (_, CastType::Integer) | (_, CastType::Unsigned) => "INTEGER".to_string(),
+                    (Dialect::Sqlite, CastType::Decimal(_, _)) => "NUMERIC".to_string(),
+                    (_, CastType::Decimal(precision, scale)) => format!("DECIMAL({}, {})", precision, scale),
+                    (Dialect::Mysql, CastType::Float) => "DOUBLE".to_string(),
+                    (Dialect::Postgresql, CastType::Float) => "DOUBLE PRECISION".to_string(),
+                    (Dialect::Sqlite, CastType::Float) => "REAL".to_string(),
+                    (_, CastType::Date) => "DATE".to_string(),
+                    (Dialect::Mysql, CastType::Datetime) => "DATETIME".to_string(),
+                    (_, CastType::Datetime) => "TIMESTAMP".to_string(),
+                    (_, CastType::Time) => "TIME".to_string(),
+                    (_, CastType::Json) => "JSON".to_string(),
+                    (Dialect::Mysql, CastType::Binary) => "BINARY".to_string(),
+                    (Dialect::Postgresql, CastType::Binary) => "BYTEA".to_string(),
+                    (Dialect::Sqlite, CastType::Binary) => "BLOB".to_string()
+                };
+
+                format!("CAST({} AS {})", expr.to_sql(dialect), type_name)
+            }
+        }
+    }
+
+    fn join(args: &[Expr], dialect: &Dialect, separator: &str) -> String {
+        args.iter().map(|arg| arg.to_sql(dialect)).collect::<Vec<String>>().join(separator)
+    }
+}
+
+/// IntervalUnit enum. It's the unit of the intervals, `TIMESTAMPDIFF()` and `EXTRACT()` functions.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntervalUnit {
+    Microsecond, Second, Minute, Hour, Day, Week, Month, Quarter, Year
+}
+
+impl IntervalUnit {
+    fn seconds(&self) -> Option<i64> {
+        match self {
+            IntervalUnit::Second => Some(1),
+            IntervalUnit::Minute => Some(60),
+            IntervalUnit::Hour => Some(3600),
+            IntervalUnit::Day => Some(86400),
+            IntervalUnit::Week => Some(604800),
+            _ => None
+        }
+    }
+}
+
+impl std::fmt::Display for IntervalUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IntervalUnit::Microsecond => write!(f, "MICROSECOND"),
+            IntervalUnit::Second => write!(f, "SECOND"),
+            IntervalUnit::Minute => write!(f, "MINUTE"),
+            IntervalUnit::Hour => write!(f, "HOUR"),
+            IntervalUnit::Day => write!(f, "DAY"),
+            IntervalUnit::Week => write!(f, "WEEK"),
+            IntervalUnit::Month => write!(f, "MONTH"),
+            IntervalUnit::Quarter => write!(f, "QUARTER"),
+            IntervalUnit::Year => write!(f, "YEAR")
+        }
+    }
+}
+
+/// DateExpr enum. It holds the date and time functions of the `Expr` type, use the constructors of `Expr` such as `Expr::date_sub()` to create them.
+/// 
+/// They're rendered with the equivalents of the dialect: `x - INTERVAL '7 DAY'` on postgresql and `DATETIME(x, '-7 days')` on sqlite for example. Functions which have no equivalent on a dialect panic, such as `CONVERT_TZ()` on sqlite.
+/// 
+/// ```rust
+/// 
+/// use qubl::{Expr, SqlKeyword, IntervalUnit, Dialect};
+/// 
+/// fn main() {
+///     let expr = Expr::date_sub(SqlKeyword::Now, 7, IntervalUnit::Day);
+/// 
+///     assert_eq!(expr.to_sql(&Dialect::Mysql), "DATE_SUB(NOW(), INTERVAL 7 DAY)");
+///     assert_eq!(expr.to_sql(&Dialect::Postgresql), "NOW() - INTERVAL '7 DAY'");
+///     assert_eq!(expr.to_sql(&Dialect::Sqlite), "DATETIME(DATETIME('now'), '-7 days')");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub enum DateExpr {
+    Add(Expr, i64, IntervalUnit), Sub(Expr, i64, IntervalUnit), Format(Expr, String), Date(Expr), TimestampDiff(IntervalUnit, Expr, Expr),
+    ConvertTz(Expr, Timezone, Timezone), UnixTimestamp(Expr), Extract(IntervalUnit, Expr)
+}
+
+impl DateExpr {
+    /// It renders the function for the given dialect.
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        match self {
+            DateExpr::Add(date, amount, unit) => Self::interval(date, *amount, unit, dialect),
+            DateExpr::Sub(date, amount, unit) => Self::interval(date, -*amount, unit, dialect),
+            DateExpr::Format(date, format) => match dialect {
+                Dialect::Mysql => format!("DATE_FORMAT({}, '{}')", date.to_sql(dialect), format.replace('\'', "''")),
+                Dialect::Postgresql => format!("TO_CHAR({}, '{}')", date.to_sql(dialect), Self::translate_format(format, dialect).replace('\'', "''")),
+                Dialect::Sqlite => format!("STRFTIME('{}', {})", Self::translate_format(format, dialect).replace('\'', "''"), date.to_sql(dialect))
+            },
+            DateExpr::Date(date) => match dialect {
+                Dialect::Postgresql => format!("CAST({} AS DATE)", date.to_sql(dialect)),
+                _ => format!("DATE({})", date.to_sql(dialect))
+            },
+            DateExpr::TimestampDiff(unit, start, end) => match dialect {
+                Dialect::Mysql => format!("TIMESTAMPDIFF({}, {}, {})", unit, start.to_sql(dialect), end.to_sql(dialect)),
+                Dialect::Postgresql => {
+                    let (start, end) = (start.to_sql(dialect), end.to_sql(dialect));
+
+                    match (unit.seconds(), unit) {
+                        (Some(1), _) => format!("FLOOR(EXTRACT(EPOCH FROM ({} - {})))", end, start),
+                        (Some(seconds), _) => format!("FLOOR(EXTRACT(EPOCH FROM ({} - {})) / {})", end, start, seconds),
+                        (None, IntervalUnit::Month) => format!("(EXTRACT(YEAR FROM AGE({}, {})) * 12 + EXTRACT(MONTH FROM AGE({}, {})))", end, start, end, start),
+                        (None, IntervalUnit::Quarter) => format!("FLOOR((EXTRACT(YEAR FROM AGE({}, {})) * 12 + EXTRACT(MONTH FROM AGE({}, {}))) / 3)", end, start, end, start),
+                        (None, IntervalUnit::Year) => format!("EXTRACT(YEAR FROM AGE({}, {}))", end, start),
+                        (None, _) => format!("FLOOR(EXTRACT(EPOCH FROM ({} - {})) * 1000000)", end, start)
+                    }
+                },
+                Dialect::Sqlite => match unit.seconds() {
+                    Some(seconds) => format!("CAST((JULIANDAY({}) - JULIANDAY({})) * 86400 / {} AS INTEGER)", end.to_sql(dialect), start.to_sql(dialect), seconds),
+                    None => panic!("TIMESTAMPDIFF() with {} unit is not supported on sqlite.", unit)
+                }
+            },
+            DateExpr::ConvertTz(date, from, to) => match dialect {
+                Dialect::Mysql => format!("CONVERT_TZ({}, '{}', '{}')", date.to_sql(dialect), from, to),
+                Dialect::Postgresql => format!("(({} AT TIME ZONE '{}') AT TIME ZONE '{}')", date.to_sql(dialect), from, to),
+                Dialect::Sqlite => panic!("CONVERT_TZ() is not supported on sqlite.")
+            },
+            DateExpr::UnixTimestamp(date) => match dialect {
+                Dialect::Mysql => format!("UNIX_TIMESTAMP({})", date.to_sql(dialect)),
+                Dialect::Postgresql => format!("EXTRACT(EPOCH FROM {})", date.to_sql(dialect)),
+                Dialect::Sqlite => format!("UNIXEPOCH({})", date.to_sql(dialect))
+            },
+            DateExpr::Extract(unit, date) => match dialect {
+                Dialect::Mysql | Dialect::Postgresql => format!("EXTRACT({} FROM {})", unit, date.to_sql(dialect)),
+                Dialect::Sqlite => {
+                    let specifier = match unit {
+                        IntervalUnit::Second => "%S", IntervalUnit::Minute => "%M", IntervalUnit::Hour => "%H", IntervalUnit::Day => "%d",
+                        IntervalUnit::Week => "%W", IntervalUnit::Month => "%m", IntervalUnit::Year => "%Y",
+                        IntervalUnit::Quarter => return format!("((CAST(STRFTIME('%m', {}) AS INTEGER) + 2) / 3)", date.to_sql(dialect)),
+                        IntervalUnit::Microsecond => panic!("EXTRACT() with MICROSECOND unit is not supported on sqlite.")
+                    };
+
+                    format!("CAST(STRFTIME('{}', {}) AS INTEGER)", specifier, date.to_sql(dialect))
+                }
+            }
+        }
+    }
+
+    fn interval(date: &Expr, amount: i64, unit: &IntervalUnit, dialect: &Dialect) -> String {
+        match dialect {
+            Dialect::Mysql => match amount < 0 {
+                true => format!("DATE_SUB({}, INTERVAL {} {})", date.to_sql(dialect), -amount, unit),
+                false => format!("DATE_ADD({}, INTERVAL {} {})", date.to_sql(dialect), amount, unit)
+            },
+            Dialect::Postgresql => {
+                let (amount, unit) = match unit {
+                    IntervalUnit::Quarter => (amount * 3, IntervalUnit::Month),
+                    _ => (amount, unit.clone())
+                };
+
+                match amount < 0 {
+                    true => format!("{} - INTERVAL '{} {}'", Expr::operand(date, dialect), -amount, unit),
+                    false => format!("{} + INTERVAL '{} {}'", Expr::operand(date, dialect), amount, unit)
+                }
+            },
+            Dialect::Sqlite => {
+                let (amount, modifier) = match unit {
+                    IntervalUnit::Second => (amount, "seconds"),
+                    IntervalUnit::Minute => (amount, "minutes"),
+                    IntervalUnit::Hour => (amount, "hours"),
+                    IntervalUnit::Day => (amount, "days"),
+                    IntervalUnit::Week => (amount * 7, "days"),
+                    IntervalUnit::Month => (amount, "months"),
+                    IntervalUnit::Quarter => (amount * 3, "months"),
+                    IntervalUnit::Year => (amount, "years"),
+                    IntervalUnit::Microsecond => panic!("intervals with MICROSECOND unit are not supported on sqlite.")
+                };
+
+                format!("DATETIME({}, '{:+} {}')", date.to_sql(dialect), amount, modifier)
+            }
+        }
+    }
+
+    fn translate_format(format: &str, dialect: &Dialect) -> String {
+        let mut translated = String::new();
+        let mut characters = format.chars();
+
+        while let Some(character) = characters.next() {
+            if character != '%' {
+                translated.push(character);
+
+                continue;
+            }
+
+            let specifier = characters.next().unwrap_or('%');
+
+            let replacement = match (dialect, specifier) {
+                (Dialect::Postgresql, 'Y') => "YYYY", (Dialect::Postgresql, 'y') => "YY", (Dialect::Postgresql, 'm') => "MM",
+                (Dialect::Postgresql, 'c') => "FMMM", (Dialect::Postgresql, 'd') => "DD", (Dialect::Postgresql, 'e') => "FMDD",
+                (Dialect::Postgresql, 'H') => "HH24", (Dialect::Postgresql, 'h') => "HH12", (Dialect::Postgresql, 'i') => "MI",
+                (Dialect::Postgresql, 's') | (Dialect::Postgresql, 'S') => "SS", (Dialect::Postgresql, 'p') => "AM",
+                (Dialect::Postgresql, 'M') => "FMMonth", (Dialect::Postgresql, 'b') => "Mon", (Dialect::Postgresql, 'W') => "FMDay",
+                (Dialect::Postgresql, 'a') => "Dy", (Dialect::Postgresql, 'j') => "DDD", (Dialect::Postgresql, 'f') => "US",
+                (Dialect::Postgresql, '%') => "%",
+                (Dialect::Sqlite, 'Y') => "%Y", (Dialect::Sqlite, 'm') => "%m", (Dialect::Sqlite, 'd') => "%d",
+                (Dialect::Sqlite, 'H') => "%H", (Dialect::Sqlite, 'i') => "%M", (Dialect::Sqlite, 's') | (Dialect::Sqlite, 'S') => "%S",
+                (Dialect::Sqlite, 'j') => "%j", (Dialect::Sqlite, '%') => "%%",
+                (Dialect::Mysql, _) => {
+                    translated.push('%');
+                    translated.push(specifier);
+
+                    continue;
+                },
+                _ => panic!("%{} specifier of DATE_FORMAT() has no equivalent on {:?}.", specifier, dialect)
+            };
+
+            translated.push_str(replacement);
+        }
+
+        translated
+    }
+}
+
+/// ValueType enum. It benefits to detect and format the value with optimized way when you have to work with exact column values. 
+#[derive(Debug, Clone)]
+pub enum ValueType {
+    String(String), Datetime(String), Null, Boolean(bool), Int32(i32), Int16(i16), Int8(i8), Int64(i64), Int128(i128),
+    Uint8(u8), Uint16(u16), Uint32(u32), Uint64(u64), Usize(usize), Float32(f32), Float64(f64),
+    EpochTime(i64), JsonString(String)
+}
+
+impl std::fmt::Display for ValueType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValueType::String(string) => write!(f, "'{}'", string),
+            ValueType::JsonString(string) => write!(f, "\"{}\"", string),
+            ValueType::Datetime(datetime) => match SqlKeyword::from_name(datetime) {
+                Some(_) => write!(f, "{}", datetime),
+                None => write!(f, "'{}'", datetime)
+            },
+            ValueType::Null => write!(f, "NULL"),
+            ValueType::Boolean(val) => write!(f, "{}", val),
+            ValueType::Int8(val) => write!(f, "{}", val),
+            ValueType::Int16(val) => write!(f, "{}", val),
+            ValueType::Int32(val) => write!(f, "{}", val),
+            ValueType::Int64(val) => write!(f, "{}", val),
+            ValueType::Int128(val) => write!(f, "{}", val),
+            ValueType::Usize(val) => write!(f, "{}", val),
+            ValueType::Uint8(val) => write!(f, "{}", val),
+            ValueType::Uint16(val) => write!(f, "{}", val),
+            ValueType::Uint32(val) => write!(f, "{}", val),
+            ValueType::Uint64(val) => write!(f, "{}", val),
+            ValueType::Float32(val) => write!(f, "{}", val),
+            ValueType::Float64(val) => write!(f, "{}", val),
+            ValueType::EpochTime(val) => write!(f, "FROM_UNIXTIME({})", val),
+        }
+    }
+}
+
+impl From<String> for ValueType { fn from(value: String) -> Self { ValueType::String(value) } }
+impl From<bool> for ValueType { fn from(value: bool) -> Self { ValueType::Boolean(value) } }
+impl From<i8> for ValueType { fn from(value: i8) -> Self { ValueType::Int8(value) } }
+impl From<i16> for ValueType { fn from(value: i16) -> Self { ValueType::Int16(value) } }
+impl From<i32> for ValueType { fn from(value: i32) -> Self { ValueType::Int32(value) } }
+impl From<i64> for ValueType { fn from(value: i64) -> Self { ValueType::Int64(value) } }
+impl From<i128> for ValueType { fn from(value: i128) -> Self { ValueType::Int128(value) } }
+impl From<usize> for ValueType { fn from(value: usize) -> Self { ValueType::Usize(value) } }
+impl From<u8> for ValueType { fn from(value: u8) -> Self { ValueType::Uint8(value) } }
+impl From<u16> for ValueType { fn from(value: u16) -> Self { ValueType::Uint16(value) } }
+impl From<u32> for ValueType { fn from(value: u32) -> Self { ValueType::Uint32(value) } }
+impl From<u64> for ValueType { fn from(value: u64) -> Self { ValueType::Uint64(value) } }
+impl From<f32> for ValueType { fn from(value: f32) -> Self { ValueType::Float32(value) } }
+impl From<f64> for ValueType { fn from(value: f64) -> Self { ValueType::Float64(value) } }
+
+
+impl Into<String> for ValueType {
+    fn into(self) -> String {
+        match self {
+            ValueType::String(text) => text,
+            ValueType::Datetime(datetime) => datetime,
+            _ => panic!("you cannot convert a ValueType to string unless it's not a String or Datetime variant.")
+        }
+    }
+}
+
+impl Into<bool> for ValueType {
+    fn into(self) -> bool {
+        match self {
+            ValueType::Boolean(val) => val,
+            ValueType::String(text) => match text.as_str() {
+                "false" | "" | "\0" | "0" => false,
+                _ => true,
+            }
+            ValueType::Null => false,
+            ValueType::Int8(val) => match val == 0 {
+                false => true,
+                true => false
+            },
+            ValueType::Int16(val) => match val == 0 {
+                false => true,
+                true => false
+            },
+            ValueType::Int32(val) => match val == 0 {
+                false => true,
+                true => false
+            },
+            ValueType::Int64(val) => match val == 0 {
+                false => true,
+                true => false
+            },
+            ValueType::Int128(val) => match val == 0 {
+                false => true,
+                true => false
+            },
+            ValueType::Uint8(val) => match val == 0 {
+                false => true,
+                true => false
+            },
+            ValueType::Uint16(val) => match val == 0 {
+                false => true,
+                true => false
+            },
+            ValueType::Uint32(val) => match val == 0 {
+                false => true,
+                true => false
+            },
+            ValueType::Uint64(val) => match val == 0 {
+                false => true,
+                true => false
+            },
+            ValueType::Float32(val) => match val == 0.0 {
+                false => true,
+                true => false
+            },
+            ValueType::Float64(val) => match val == 0.0 {
+                false => true,
+                true => false
+            },
+            _ => panic!("invalid conversion")
+        }
+    }
+}
+
+impl Into<f32> for ValueType {
+    fn into(self) -> f32 {
+        match self {
+            ValueType::Float32(num) => num,
+            ValueType::Float64(num) => num as f32,
+            _ => panic!("invalid conversion")
+        }
+    }
+}
+
+impl Into<f64> for ValueType {
+    fn into(self) -> f64 {
+        match self {
+            ValueType::Float32(num) => num as f64,
+            ValueType::Float64(num) => num,
+            _ => panic!("invalid conversion")
+        }
+    }
+}
+
+impl Into<i8> for ValueType {
+    fn into(self) -> i8 {
+        match self {
+            ValueType::Int8(num) => num,
+            ValueType::Int16(num) => match num > 128 || num < -128 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i8
+            },
+            ValueType::Int32(num) => match num > 128 || num < -128 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i8
+            },
+            ValueType::Int64(num) => match num > 128 || num < -128 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i8
+            },
+            ValueType::Int128(num) => match num > 128 || num < -128 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i8
+            }
+            ValueType::Uint8(num) => match num > 128 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i8
+            },
+            ValueType::Uint16(num) => match num > 128 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i8
+            },
+            ValueType::Uint32(num) => match num > 128 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i8
+            },
+            ValueType::Usize(num) => match num > 128 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i8
+            },
+            ValueType::Uint64(num) => match num > 128 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i8
+            },
+            _ => panic!("you cannot convert non numeric values into numeric ones.")
+        }
+    }
+}
+
+impl Into<i16> for ValueType {
+    fn into(self) -> i16 {
+        match self {
+            ValueType::Int8(num) => num as i16,
+            ValueType::Int16(num) => num,
+            ValueType::Int32(num) => match num > 32_768 || num < -32_768 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i16
+            },
+            ValueType::Int64(num) => match num > 32_768 || num < -32_768 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i16
+            },
+            ValueType::Int128(num) => match num > 32_768 || num < -32_768 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i16
+            }
+            ValueType::Uint8(num) => num as i16,
+            ValueType::Uint16(num) => match num > 32_768 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i16
+            },
+            ValueType::Uint32(num) => match num > 32_768 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i16
+            },
+            ValueType::Usize(num) => match num > 32_768 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i16
+            },
+            ValueType::Uint64(num) => match num > 32_768 {
+                true => panic!("you cannot convert i16's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i16
+            },
+            _ => panic!("you cannot convert non numeric values into numeric ones.")
+        }
+    }
+}
+
+impl Into<i32> for ValueType {
+    fn into(self) -> i32 {
+        match self {
+            ValueType::Int8(num) => num as i32,
+            ValueType::Int16(num) => num as i32,
+            ValueType::Int32(num) => num,
+            ValueType::Int64(num) => match num > 2_147_483_647 || num < -2_147_483_647 {
+                true => panic!("you cannot convert i32's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i32
+            },
+            ValueType::Int128(num) => match num > 2_147_483_647 || num < -2_147_483_647 {
+                true => panic!("you cannot convert i32's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i32
+            }
+            ValueType::Uint8(num) => num as i32,
+            ValueType::Uint16(num) => num as i32,
+            ValueType::Uint32(num) => match num > 2_147_483_647 {
+                true => panic!("you cannot convert i32's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i32
+            },
+            ValueType::Usize(num) => match num > 2_147_483_647 {
+                true => panic!("you cannot convert i32's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i32
+            },
+            ValueType::Uint64(num) => match num > 2_147_483_647 {
+                true => panic!("you cannot convert i32's into a value which is bigger than the capacity of 32 bit values."),
+                false => num as i32
+            }
+            _ => panic!("you cannot convert non numeric values into numeric ones.")
+        }
+    }
+}
+
+impl Into<i64> for ValueType {
+    fn into(self) -> i64 {
+        match self {
+            ValueType::EpochTime(epoch) => epoch as i64,
+            ValueType::Int8(num) => num as i64,
+            ValueType::Int16(num) => num as i64,
+            ValueType::Int32(num) => num as i64,
+            ValueType::Int64(num) => num,
+            ValueType::Usize(num) => num as i64,
+            ValueType::Uint8(num) => num as i64,
+            ValueType::Uint16(num) => num as i64,
+            ValueType::Uint32(num) => num as i64,
+            ValueType::Uint64(num) => num as i64,
+            _ => panic!("you cannot convert non numeric values into numeric ones.")
+        }
+    }
+}
+
+impl Into<u8> for ValueType {
+    fn into(self) -> u8 {
+        match self {
+            ValueType::Uint8(num) => num,
+            ValueType::Uint16(num) => match num > 255 {
+                true => panic!("you cannot convert u16's if it's value is bigger than capacity of 8 bit values"),
+                false => num as u8
+            },
+            ValueType::Uint32(num) => match num > 255 {
+                true => panic!("you cannot convert u32's if it's value is bigger than capacity of 8 bit values"),
+                false => num as u8
+            },
+            ValueType::Uint64(num) => match num > 255 {
+                true => panic!("you cannot convert u64's if it's value is bigger than capacity of 8 bit values"),
+                false => num as u8
+            },
+            ValueType::Usize(num) => match num > 255 {
+                true => panic!("you cannot convert usizes if it's value is bigger than capacity of 8 bit values"),
+                false => num as u8
+            },
+            ValueType::Int8(num) => match num < 0 {
+                true => panic!("you cannot convert i8's if it's value is lower than 0"),
+                false => num as u8
+            },
+            ValueType::Int16(num) => match num < 0 || num > 255 {
+                true => panic!("you cannot convert i16's if it's value is lower than 0 or has a value which is bigger than capacity of 8 bit values."),
+                false => num as u8
+            },
+            ValueType::Int32(num) => match num < 0 || num > 255 {
+                true => panic!("you cannot convert i32's if it's value is lower than 0 or has a value which is bigger than capacity of 8 bit values."),
+                false => num as u8
+            },
+            ValueType::Int64(num) => match num < 0 || num > 255 {
+                true => panic!("you cannot convert 64's if it's value is lower than 0 or has a value which is bigger than capacity of 8 bit values."),
+                false => num as u8
+            },
+            ValueType::Int128(num) => match num < 0 || num > 255 {
+                true => panic!("you cannot convert i128's if it's value is lower than 0 or has a value which is bigger than capacity of 8 bit values."),
+                false => num as u8
+            }
+            _ => panic!("you cannot convert non numeric values into numeric ones.")
+        }
+    }
+}
+
+impl Into<u16> for ValueType {
+    fn into(self) -> u16 {
+        match self {
+            ValueType::Uint8(num) => num as u16,
+            ValueType::Uint16(num) => num,
+            ValueType::Uint32(num) => match num > 65_535 {
+                true => panic!("you cannot convert u32's if it's value is bigger than capacity of 32 bit values"),
+                false => num as u16
+            },
+            ValueType::Uint64(num) => match num > 65_535 {
+                true => panic!("you cannot convert u64's if it's value is bigger than capacity of 32 bit values"),
+                false => num as u16
+            },
+            ValueType::Usize(num) => match num > 65_535 {
+                true => panic!("you cannot convert usizes if it's value is bigger than capacity of 32 bit values"),
+                false => num as u16
+            },
+            ValueType::Int8(num) => match num < 0 {
+                true => panic!("you cannot convert i8's if it's value is lower than 0"),
+                false => num as u16
+            },
+            ValueType::Int16(num) => match num < 0 {
+                true => panic!("you cannot convert i16's if it's value is lower than 0"),
+                false => num as u16
+            },
+            ValueType::Int32(num) => match num < 0 || num > 65_535 {
+                true => panic!("you cannot convert i32's if it's value is lower than 0 or has a value which is bigger than capacity of 16 bit values."),
+                false => num as u16
+            },
+            ValueType::Int64(num) => match num < 0 || num > 65_535 {
+                true => panic!("you cannot convert i64's if it's value is lower than 0 or has a value which is bigger than capacity of 16 bit values."),
+                false => num as u16
+            },
+            ValueType::Int128(num) => match num < 0 || num > 65_535 {
+                true => panic!("you cannot convert i128's if it's value is lower than 0 or has a value which is bigger than capacity of 16 bit values."),
+                false => num as u16
+            }
+            _ => panic!("you cannot convert non numeric values into numeric ones.")
+        }
+    }
+}
+
+impl Into<u32> for ValueType {
+    fn into(self) -> u32 {
+        match self {
+            ValueType::Uint8(num) => num as u32,
+            ValueType::Uint16(num) => num as u32,
+            ValueType::Uint32(num) => num,
+            ValueType::Uint64(num) => match num > 4_294_967_295 {
+                true => panic!("you cannot convert u64's if it's value is bigger than capacity of 32 bit values"),
+                false => num as u32
+            },
+            ValueType::Usize(num) => match num > 4_294_967_295 {
+                true => panic!("you cannot convert usizes if it's value is bigger than capacity of 32 bit values"),
+                false => num as u32
+            },
+            ValueType::Int8(num) => match num < 0 {
+                true => panic!("you cannot convert i8's if it's value is lower than 0"),
+                false => num as u32
+            },
+            ValueType::Int16(num) => match num < 0 {
+                true => panic!("you cannot convert i16's if it's value is lower than 0"),
+                false => num as u32
+            },
+            ValueType::Int32(num) => match num < 0 {
+                true => panic!("you cannot convert i32's if it's value is lower than 0"),
+                false => num as u32
+            },
+            ValueType::Int64(num) => match num < 0 || num > 4_294_967_295 {
+                true => panic!("you cannot convert i64's if it's value is lower than 0 or has a value which is bigger than capacity of 32 bit values."),
+                false => num as u32
+            },
+            ValueType::Int128(num) => match num < 0 || num > 4_294_967_295 {
+                true => panic!("you cannot convert i128's if it's value is lower than 0 or has a value which is bigger than capacity of 32 bit values."),
+                false => num as u32
+            }
+            _ => panic!("you cannot convert non numeric values into numeric ones.")
+        }
+    }
+}
+
+impl Into<u64> for ValueType {
+    fn into(self) -> u64 {
+        match self {
+            ValueType::Usize(num) => num as u64,
+            ValueType::Uint8(num) => num as u64,
+            ValueType::Uint16(num) => num as u64,
+            ValueType::Uint32(num) => num as u64,
+            ValueType::Uint64(num) => num,
+            ValueType::Int8(num) => match num < 0 {
+                true => panic!("you cannot turn a negative value into u64"),
+                false => num as u64
+            },
+            ValueType::Int16(num) => match num < 0 {
+                true => panic!("you cannot turn a negative value into u64"),
+                false => num as u64
+            },
+            ValueType::Int32(num) => match num < 0 {
+                true => panic!("you cannot turn a negative value into u64"),
+                false => num as u64
+            },
+            ValueType::Int64(num) => match num < 0 {
+                true => panic!("you cannot turn a negative value into u64"),
+                false => num as u64
+            },
+            ValueType::Int128(num) => match num < 0 {
+                true => panic!("you cannot turn a negative value into u64"),
+                false => num as u64
+            },
+            _ => panic!("you cannot convert non numeric values into numeric ones.")
+        }
+    }
+}
+
+impl Into<usize> for ValueType {
+    fn into(self) -> usize {
+        match self {
+            ValueType::Int8(num) => match num < 0 {
+                true => panic!("you cannot convert negative numbers to usize"),
+                false => num as usize
+            },
+            ValueType::Int16(num) => match num < 0 {
+                true => panic!("you cannot convert negative numbers to usize"),
+                false => num as usize
+            },
+            ValueType::Int32(num) => match num < 0 {
+                true => panic!("you cannot convert negative numbers to usize"),
+                false => num as usize
+            },
+            ValueType::Int64(num) => match num < 0 {
+                true => panic!("you cannot convert negative numbers to usize"),
+                false => num as usize
+            },
+            ValueType::Int128(num) => match num < 0 {
+                true => panic!("you cannot convert negative numbers to usize"),
+                false => num as usize
+            },
+            ValueType::Usize(num) => num,
+            ValueType::Uint8(num) => num as usize,
+            ValueType::Uint16(num) => num as usize,
+            ValueType::Uint32(num) => num as usize,
+            ValueType::Uint64(num) => num as usize,
+            _ => panic!("you cannot convert non numeric values into numeric ones.")
+        }
+    }
+}
+
+/// Struct that represents a json path. It can be built step by step or parsed from the strings such as `"$.a.b[0]"`, `".a.b"`, `"a.b"` and `"[0]"`, which are all accepted by the json methods of the `QueryBuilder` and mean the same path from the root of the document.
+/// 
+/// The keys which are not plain identifiers are quoted, such as `$."first name"`.
+/// 
+/// The conversions from the strings panic if the path is invalid, because of that the json methods panic for the invalid paths too. Use `JsonPath::parse()` to handle the error, for the paths which aren't written in the code.
+/// 
+/// ```rust
+/// 
+/// use qubl::{JsonPath, Dialect};
+/// 
+/// fn main() {
+///     let path = JsonPath::root().key("orders").index(0).key("first name");
+/// 
+///     assert_eq!(path.to_string(), "$.orders[0].\"first name\"");
+///     assert_eq!(path.to_pg_pointer(), "{orders,0,first name}");
+///     assert_eq!(JsonPath::root().key("tags").last().to_sql(&Dialect::Sqlite), "$.tags[#-1]");
+///     assert_eq!(JsonPath::parse("a.b[2 to 4]").unwrap(), JsonPath::root().key("a").key("b").range(2, 4));
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath {
+    pub steps: Vec<JsonPathStep>
+}
+
+/// JsonPathStep enum. It's a step of a `JsonPath`: `.key`, `[0]`, `[last]`, `.*`, `[*]` or `[1 to 3]`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum JsonPathStep {
+    Key(String), Index(usize), Last, Wildcard, ArrayWildcard, Range(usize, usize)
+}
+
+impl JsonPath {
+    /// It creates the root path, `$`.
+    pub fn root() -> Self {
+        Self {
+            steps: vec![]
+        }
+    }
+
+    /// It adds a member step.
+    pub fn key(mut self, key: &str) -> Self {
+        self.steps.push(JsonPathStep::Key(key.to_string()));
+
+        self
+    }
+
+    /// It adds an array index step.
+    pub fn index(mut self, index: usize) -> Self {
+        self.steps.push(JsonPathStep::Index(index));
+
+        self
+    }
+
+    /// It adds the last element step of an array.
+    pub fn last(mut self) -> Self {
+        self.steps.push(JsonPathStep::Last);
+
+        self
+    }
+
+    /// It adds the step that matches all of the members of an object, `.*`.
+    pub fn wildcard(mut self) -> Self {
+        self.steps.push(JsonPathStep::Wildcard);
+
+        self
+    }
+
+    /// It adds the step that matches all of the elements of an array, `[*]`.
+    pub fn all_elements(mut self) -> Self {
+        self.steps.push(JsonPathStep::ArrayWildcard);
+
+        self
+    }
+
+    /// It adds the step that matches the elements of an array between the given indexes, both of them are included.
+    pub fn range(mut self, from: usize, to: usize) -> Self {
+        if from > to {
+            panic!("start of a json path range cannot be bigger than it's end.")
+        }
+
+        self.steps.push(JsonPathStep::Range(from, to));
+
+        self
+    }
+
+    /// It parses a json path, the leading `$` and `.` characters are optional.
+    pub fn parse(path: &str) -> std::result::Result<Self, std::io::Error> {
+        let invalid = || std::io::Error::new(std::io::ErrorKind::InvalidInput, format!("{} is not a valid json path.", path));
+
+        let characters = path.trim().strip_prefix('$').unwrap_or(path.trim()).chars().collect::<Vec<char>>();
+        let mut json_path = Self::root();
+        let mut i = 0;
+
+        while i < characters.len() {
+            match characters[i] {
+                '[' => {
+                    let end = (i..characters.len()).find(|j| characters[*j] == ']').ok_or_else(invalid)?;
+                    let inner = characters[i + 1..end].iter().collect::<String>();
+                    let inner = inner.trim();
+
+                    json_path = match inner {
+                        "*" => json_path.all_elements(),
+                        "last" => json_path.last(),
+                        _ => match inner.split_once(" to ") {
+                            Some((from, to)) => {
+                                let from = from.trim().parse::<usize>().map_err(|_| invalid())?;
+                                let to = to.trim().parse::<usize>().map_err(|_| invalid())?;
+
+                                if from > to {
+                                    return Err(invalid())
+                                }
+
+                                json_path.range(from, to)
+                            },
+                            None => json_path.index(inner.parse::<usize>().map_err(|_| invalid())?)
+                        }
+                    };
+
+                    i = end + 1;
+                },
+                '.' if characters.get(i + 1) == Some(&'*') => {
+                    json_path = json_path.wildcard();
+                    i += 2;
+                },
+                '.' if characters.get(i + 1) == Some(&'"') => {
+                    let mut key = String::new();
+                    let mut j = i + 2;
+
+                    loop {
+                        match characters.get(j) {
+                            Some('\\') => {
+                                key.push(*characters.get(j + 1).ok_or_else(invalid)?);
+                                j += 2;
+                            },
+                            Some('"') => break,
+                            Some(character) => {
+                                key.push(*character);
+                                j += 1;
+                            },
+                            None => return Err(invalid())
+                        }
+                    }
+
+                    json_path = json_path.key(&key);
+                    i = j + 1;
+                },
+                character => {
+                    let start = match character {
+                        '.' => i + 1,
+                        _ if i == 0 => i,
+                        _ => return Err(invalid())
+                    };
+
+                    let end = (start..characters.len()).find(|j| characters[*j] == '.' || characters[*j] == '[').unwrap_or(characters.len());
+                    let key = characters[start..end].iter().collect::<String>();
+
+                    if key.is_empty() {
+                        return Err(invalid())
+                    }
+
+                    json_path = json_path.key(&key);
+                    i = end;
+                }
+            }
+        }
+
+        Ok(json_path)
+    }
+
+    /// It renders the path for the given dialect. Mysql and postgresql (jsonpath) use the same synthax, sqlite writes the last element as `[#-1]` and doesn't support wildcards and ranges.
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        let mut path = "$".to_string();
+
+        for step in self.steps.iter() {
+            match (step, dialect) {
+                (JsonPathStep::Key(key), _) => path = format!("{}.{}", path, Self::quote_key(key)),
+                (JsonPathStep::Index(index), _) => path = format!("{}[{}]", path, index),
+                (JsonPathStep::Last, Dialect::Sqlite) => path = format!("{}[#-1]", path),
+                (JsonPathStep::Last, _) => path = format!("{}[last]", path),
+                (JsonPathStep::Wildcard, Dialect::Sqlite) | (JsonPathStep::ArrayWildcard, Dialect::Sqlite) | (JsonPathStep::Range(_, _), Dialect::Sqlite) => {
+                    panic!("sqlite doesn't support wildcards and ranges in json paths.")
+                },
+                (JsonPathStep::Wildcard, _) => path = format!("{}.*", path),
+                (JsonPathStep::ArrayWildcard, _) => path = format!("{}[*]", path),
+                (JsonPathStep::Range(from, to), _) => path = format!("{}[{} to {}]", path, from, to)
+            }
+        }
+
+        path
+    }
+
+    /// It renders the path as a postgresql text array for the `#>` and `#>>` operators, such as `{a,0,b}`. It panics if the path has wildcards or ranges.
+    pub fn to_pg_pointer(&self) -> String {
+        let steps = self.steps.iter().map(|step| match step {
+            JsonPathStep::Key(key) => match key.contains([',', '{', '}', '"', '\\']) {
+                true => format!("\"{}\"", key.replace("\\", "\\\\").replace("\"", "\\\"")),
+                false => key.to_string()
+            },
+            JsonPathStep::Index(index) => index.to_string(),
+            JsonPathStep::Last => "-1".to_string(),
+            _ => panic!("postgresql path operators don't support wildcards and ranges, use a jsonpath instead.")
+        }).collect::<Vec<String>>();
+
+        format!("{{{}}}", steps.join(","))
+    }
+
+    /// It returns true if the path has wildcards or ranges, so it can match more than one value.
+    pub fn is_multiple(&self) -> bool {
+        self.steps.iter().any(|step| matches!(step, JsonPathStep::Wildcard | JsonPathStep::ArrayWildcard | JsonPathStep::Range(_, _)))
+    }
+
+    fn quote_key(key: &str) -> String {
+        let mut characters = key.chars();
+
+        let is_identifier = match characters.next() {
+            Some(first) => (first.is_ascii_alphabetic() || first == '_') && characters.all(|character| character.is_ascii_alphanumeric() || character == '_'),
+            None => false
+        };
+
+        match is_identifier {
+            true => key.to_string(),
+            false => format!("\"{}\"", key.replace("\\", "\\\\").replace("\"", "\\\""))
+        }
+    }
+}
+
+impl std::fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_sql(&Dialect::Mysql))
+    }
+}
+
+/// It parses the path with `JsonPath::parse()`, it panics if the path is invalid.
+impl From<&str> for JsonPath {
+    fn from(value: &str) -> Self {
+        match Self::parse(value) {
+            Ok(path) => path,
+            Err(error) => panic!("{}", error)
+        }
+    }
+}
+
+impl From<&String> for JsonPath { fn from(value: &String) -> Self { Self::from(value.as_str()) } }
+impl From<String> for JsonPath { fn from(value: String) -> Self { Self::from(value.as_str()) } }
+
+/// Struct that represents a `JSON_TABLE()` source, which turns the elements on a path of a json document into rows with the given columns. Use it with the `.json_table()` and `.left_join_json_table()` methods of the `QueryBuilder`.
+/// 
+/// It's rendered with the same synthax on mysql and postgresql, except the default values: mysql takes them as json texts. Sqlite doesn't support it.
+/// 
+/// ```rust
+/// 
+/// use qubl::{JsonTable, JsonTableColumn, Dialect, ValueType};
+/// 
+/// fn main() {
+///     let table = JsonTable::new("doc", "lines[*]", vec![
+///         JsonTableColumn::path("name", "VARCHAR(20)", "name").default_on_empty(ValueType::String("none".to_string())).null_on_error(),
+///         JsonTableColumn::exists("has_discount", "INT", "discount"),
+///         JsonTableColumn::nested("tags[*]", vec![JsonTableColumn::path("tag", "TEXT", "")]),
+///     ], "l");
+/// 
+///     assert_eq!(table.to_sql(&Dialect::Mysql), "JSON_TABLE(doc, '$.lines[*]' COLUMNS (name VARCHAR(20) PATH '$.name' DEFAULT '\"none\"' ON EMPTY NULL ON ERROR, has_discount INT EXISTS PATH '$.discount', NESTED PATH '$.tags[*]' COLUMNS (tag TEXT PATH '$'))) AS l");
+///     assert_eq!(table.to_sql(&Dialect::Postgresql), "JSON_TABLE(doc, '$.lines[*]' COLUMNS (name VARCHAR(20) PATH '$.name' DEFAULT 'none' ON EMPTY NULL ON ERROR, has_discount INT EXISTS PATH '$.discount', NESTED PATH '$.tags[*]' COLUMNS (tag TEXT PATH '$'))) AS l");
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone)]
+pub struct JsonTable {
+    pub document: String,
+    pub path: JsonPath,
+    pub columns: Vec<JsonTableColumn>,
+    pub alias: String
+}
+
+impl JsonTable {
+    /// It creates a json table from the json document, which is mostly a column, the path of the rows, the columns and the alias. It panics if there are no columns.
+    pub fn new<P: Into<JsonPath>>(document: &str, path: P, columns: Vec<JsonTableColumn>, alias: &str) -> Self {
+        if columns.is_empty() {
+            panic!("a json table should have at least one column.")
+        }
+
+        if alias.is_empty() {
+            panic!("a json table should have an alias.")
+        }
+
+        Self {
+            document: document.to_string(),
+            path: path.into(),
+            columns,
+            alias: alias.to_string()
+        }
+    }
+
+    /// It renders the json table for the given dialect.
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        if let Dialect::Sqlite = dialect {
+            panic!("sqlite doesn't support JSON_TABLE, use json_each() instead.")
+        }
+
+        format!("JSON_TABLE({}, '{}' COLUMNS ({})) AS {}", self.document, self.path.to_sql(dialect).replace("'", "''"), JsonTableColumn::render(&self.columns, dialect), self.alias)
+    }
+}
+
+impl std::fmt::Display for JsonTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_sql(&Dialect::Mysql))
+    }
+}
+
+/// JsonTableColumn enum. It's a column of a `JsonTable`: a typed `PATH` column, a `FOR ORDINALITY` counter, an `EXISTS PATH` flag or a `NESTED PATH` with it's own columns.
+#[derive(Debug, Clone)]
+pub enum JsonTableColumn {
+    Ordinality(String),
+    Path { name: String, data_type: String, path: JsonPath, on_empty: Option<JsonTableFallback>, on_error: Option<JsonTableFallback> },
+    Exists(String, String, JsonPath),
+    Nested(JsonPath, Vec<JsonTableColumn>)
+}
+
+/// JsonTableFallback enum. It's the value of a `PATH` column of a `JsonTable` when the path is empty or can't be converted: `NULL`, `ERROR` or `DEFAULT value`.
+#[derive(Debug, Clone)]
+pub enum JsonTableFallback {
+    Null, Error, Default(ValueType)
+}
+
+impl JsonTableColumn {
+    /// It creates a column that counts the rows, starting from 1.
+    pub fn ordinality(name: &str) -> Self {
+        JsonTableColumn::Ordinality(name.to_string())
+    }
+
+    /// It creates a column with the given type from the value on the path, which is relative to the row.
+    pub fn path<P: Into<JsonPath>>(name: &str, data_type: &str, path: P) -> Self {
+        JsonTableColumn::Path { name: name.to_string(), data_type: data_type.to_string(), path: path.into(), on_empty: None, on_error: None }
+    }
+
+    /// It creates a column which is 1 if the path exists in the row and 0 otherwise.
+    pub fn exists<P: Into<JsonPath>>(name: &str, data_type: &str, path: P) -> Self {
+        JsonTableColumn::Exists(name.to_string(), data_type.to_string(), path.into())
+    }
+
+    /// It flattens the elements on the path of the row with their own columns.
+    pub fn nested<P: Into<JsonPath>>(path: P, columns: Vec<JsonTableColumn>) -> Self {
+        if columns.is_empty() {
+            panic!("a nested path should have at least one column.")
+        }
+
+        JsonTableColumn::Nested(path.into(), columns)
+    }
+
+    /// It adds the `DEFAULT value ON EMPTY` clause. It panics if the column is not a `PATH` column.
+    pub fn default_on_empty(self, value: ValueType) -> Self {
+        self.on_empty(JsonTableFallback::Default(value))
+    }
+
+    /// It adds the `NULL ON EMPTY` clause. It panics if the column is not a `PATH` column.
+    pub fn null_on_empty(self) -> Self {
+        self.on_empty(JsonTableFallback::Null)
+    }
+
+    /// It adds the `ERROR ON EMPTY` clause. It panics if the column is not a `PATH` column.
+    pub fn error_on_empty(self) -> Self {
+        self.on_empty(JsonTableFallback::Error)
+    }
+
+    /// It adds the `DEFAULT value ON ERROR` clause. It panics if the column is not a `PATH` column.
+    pub fn default_on_error(self, value: ValueType) -> Self {
+        self.on_error(JsonTableFallback::Default(value))
+    }
+
+    /// It adds the `NULL ON ERROR` clause. It panics if the column is not a `PATH` column.
+    pub fn null_on_error(self) -> Self {
+        self.on_error(JsonTableFallback::Null)
+    }
+
+    /// It adds the `ERROR ON ERROR` clause. It panics if the column is not a `PATH` column.
+    pub fn error_on_error(self) -> Self {
+        self.on_error(JsonTableFallback::Error)
+    }
+
+    fn on_empty(mut self, fallback: JsonTableFallback) -> Self {
+        match &mut self {
+            JsonTableColumn::Path { on_empty, .. } => *on_empty = Some(fallback),
+            _ => panic!("ON EMPTY clause can only be used with the PATH columns of a json table.")
+        }
+
+        self
+    }
+
+    fn on_error(mut self, fallback: JsonTableFallback) -> Self {
+        match &mut self {
+            JsonTableColumn::Path { on_error, .. } => *on_error = Some(fallback),
+            _ => panic!("ON ERROR clause can only be used with the PATH columns of a json table.")
+        }
+
+        self
+    }
+
+    fn render(columns: &[JsonTableColumn], dialect: &Dialect) -> String {
+        columns.iter().map(|column| column.to_sql(dialect)).collect::<Vec<String>>().join(", ")
+    }
+
+    /// It renders the column for the given dialect.
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        let path_of = |path: &JsonPath| path.to_sql(dialect).replace("'", "''");
+
+        match self {
+            JsonTableColumn::Ordinality(name) => format!("{} FOR ORDINALITY", name),
+            JsonTableColumn::Path { name, data_type, path, on_empty, on_error } => {
+                let mut column = format!("{} {} PATH '{}'", name, data_type, path_of(path));
+
+                if let Some(on_empty) = on_empty {
+                    column = format!("{} {} ON EMPTY", column, on_empty.to_sql(dialect));
+                }
+
+                if let Some(on_error) = on_error {
+                    column = format!("{} {} ON ERROR", column, on_error.to_sql(dialect));
+                }
+
+                column
+            },
+            JsonTableColumn::Exists(name, data_type, path) => format!("{} {} EXISTS PATH '{}'", name, data_type, path_of(path)),
+            JsonTableColumn::Nested(path, columns) => format!("NESTED PATH '{}' COLUMNS ({})", path_of(path), Self::render(columns, dialect))
+        }
+    }
+}
+
+impl JsonTableFallback {
+    /// It renders the fallback for the given dialect, mysql takes the default values as json texts.
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        match (self, dialect) {
+            (JsonTableFallback::Null, _) => "NULL".to_string(),
+            (JsonTableFallback::Error, _) => "ERROR".to_string(),
+            (JsonTableFallback::Default(value), Dialect::Mysql) => match value {
+                ValueType::String(string) | ValueType::Datetime(string) | ValueType::JsonString(string) => format!("DEFAULT '\"{}\"'", string.replace("'", "''")),
+                ValueType::Null => "DEFAULT 'null'".to_string(),
+                ValueType::EpochTime(_) => panic!("epoch times cannot be the default value of a json table column."),
+                value => format!("DEFAULT '{}'", value)
+            },
+            (JsonTableFallback::Default(value), _) => format!("DEFAULT {}", value)
+        }
+    }
+}
+
+/// Enum that benefits you to add json values to structs. They can be used with json functions.
+/// That variants represents that kind of json values:
+#[derive(Debug, Clone)]
+pub enum JsonValue<'a> {
+    /// 
+    /// Example Value: ["hello", 21, "again"]
+    /// 
+    Array(&'a Vec<ValueType>), 
+    
+    /// 
+    /// Example Value: {"name": "necdet", "message": "hello", "id": 1}
+    /// 
+    Object(&'a Vec<(&'a str, &'a ValueType)>), 
+    
+    ///
+    /// example value: [{"name": "necdet", "message": "hello", "id": 1}, {"name": "kemal", "message": "hi", "id": 2}]
+    /// 
+    ObjectArray(&'a Vec<Vec<(&'a str, &'a ValueType)>>), 
+    
+    /// It's same with `ValueType` enums, just for simply passing it to that enum.
+    Initial(&'a ValueType), 
+
+    /// Mysql Json Object: It writes JSON_OBJECT() mysql function with it's synthax, such as: JSON_OBJECT('name', 'necdet', 'message', 'hello', 'id', 13). It's necessary or more accurate when working most of the json functions.
+    MysqlJsonObject(&'a Vec<(&'a str, &'a ValueType)>),
+
+    /// An owned `Json` value, which can be nested as deep as needed. It's written as a json value, such as: CAST('{"tags": ["a", "b"]}' AS JSON).
+    Owned(Json)
+}
+
+impl <'a>std::fmt::Display for JsonValue<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            JsonValue::Array(values) => {
+                let mut json_str = "[".to_string();
+
+                for (index, value) in values.iter().enumerate() {
+                    if index == 0 {
+                        json_str = format!("{}{}", json_str, value)
+                    } else {
+                        json_str = format!("{}, {}", json_str, value)
+                    }
+                }
+
+                json_str = format!("{}]", json_str);
+
+                write!(f, "{}", json_str)
+            },
+            JsonValue::Object(props) => {
+                let mut json_str = "{".to_string();
+
+                for (index, value) in props.iter().enumerate() {
+                    if index == 0 {
+                        json_str = format!("{}\"{}\": {}", json_str, value.0, value.1)
+                    } else {
+                        json_str = format!("{}, \"{}\": {}", json_str, value.0, value.1)
+                    }
+                }
+
+                json_str = format!("{}}}", json_str);
+
+                write!(f, "{}", json_str)
+            },
+            JsonValue::MysqlJsonObject(props) => {
+                let mut json_str = "JSON_OBJECT(".to_string();
+
+                for (index, value) in props.iter().enumerate() {
+                    if index == 0 {
+                        json_str = format!("{}'{}', {}", json_str, value.0, value.1)
+                    } else {
+                        json_str = format!("{}, '{}', {}", json_str, value.0, value.1)
+                    }
+                }
+
+                json_str = format!("{})", json_str);
+
+                write!(f, "{}", json_str)
+            },
+            JsonValue::ObjectArray(array) => {
+                let mut json_str = "[".to_string();
+
+                for (index1, object) in array.into_iter().enumerate() {
+                    let mut object_str = "{".to_string();
+
+                    for (index2, property) in object.into_iter().enumerate() {
+                        if index2 == 0 {
+                            object_str = format!("{}\"{}\": {}", object_str, property.0, property.1)
+                        } else {
+                            object_str = format!("{}, \"{}\": {}", object_str, property.0, property.1)
+                        }
+                    }
+
+                    object_str = format!("{}}}", object_str);
+
+                    if index1 == 0 {
+                        json_str = format!("{}{}", json_str, object_str)
+                    } else {
+                        json_str = format!("{}, {}", json_str, object_str)
+                    }
+                }
+
+                write!(f, "{}]", json_str)
+            },
+            JsonValue::Initial(value) => write!(f, "{}", value.to_string()),
+            JsonValue::Owned(json) => write!(f, "{}", json.to_sql(&Dialect::Mysql))
+        }
+    }
+}
+
+impl From<Json> for JsonValue<'_> { fn from(value: Json) -> Self { JsonValue::Owned(value) } }
+
+#[cfg(feature = "serde_json")]
+impl From<serde_json::Value> for JsonValue<'_> { fn from(value: serde_json::Value) -> Self { JsonValue::Owned(Json::from(value)) } }
+
+/// Owned json value. Unlike the `JsonValue`, it owns it's content and can be nested, so arrays of arrays and objects of objects can be built inside of functions. The strings are escaped by the json rules when it's written.
+/// 
+/// It can be passed to the json methods of the `QueryBuilder` and used as an `Expr`. With the `serde_json` feature, it can be converted from `serde_json::Value` and any type that implements `Serialize`.
+/// 
+/// ```rust
+/// 
+/// use qubl::{Json, QueryBuilder, ValueType, Dialect};
+/// 
+/// fn main() {
+///     let settings = Json::object(vec![
+///         ("theme", Json::from("dark")),
+///         ("shortcuts", Json::from(vec![vec!["ctrl", "s"], vec!["ctrl", "q"]])),
+///         ("note", Json::from("say \"hi\"")),
+///         ("limit", Json::Null),
+///     ]);
+/// 
+///     assert_eq!(settings.to_string(), r#"{"theme":"dark","shortcuts":[["ctrl","s"],["ctrl","q"]],"note":"say \"hi\"","limit":null}"#);
+///     assert_eq!(Json::from(vec![1, 2]).to_sql(&Dialect::Postgresql), "'[1,2]'::jsonb");
+/// 
+///     let query = QueryBuilder::update().unwrap()
+///                              .table("users")
+///                              .json_set("profile", vec![("settings", settings.clone())])
+///                              .where_("id", "=", 1)
+///                              .finish();
+/// 
+///     assert_eq!(query, r#"UPDATE users SET profile = JSON_SET(profile, '$.settings', CAST('{"theme":"dark","shortcuts":[["ctrl","s"],["ctrl","q"]],"note":"say \\"hi\\"","limit":null}' AS JSON)) WHERE id = 1;"#);
+/// }
+/// 
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null, Bool(bool), Int(i64), Float(f64), String(String), Array(Vec<Json>), Object(Vec<(String, Json)>)
+}
+
+impl Json {
+    /// It creates a json object from the key & value pairs, the order of the keys is kept.
+    pub fn object(pairs: Vec<(&str, Json)>) -> Self {
+        Json::Object(pairs.into_iter().map(|(key, value)| (key.to_string(), value)).collect())
+    }
+
+    /// It creates a json array from the values.
+    pub fn array(values: Vec<Json>) -> Self {
+        Json::Array(values)
+    }
+
+    /// It converts any type that implements `Serialize` into a json value.
+    #[cfg(feature = "serde_json")]
+    pub fn serialize<T: serde::Serialize>(value: &T) -> std::result::Result<Self, serde_json::Error> {
+        Ok(Json::from(serde_json::to_value(value)?))
+    }
+
+    /// It renders the value as an sql expression of the given dialect. The scalars are written as sql literals, the arrays and objects are written with `CAST(... AS JSON)` on mysql and `json()` on sqlite. All of the values are written as `jsonb` literals on postgresql.
+    pub fn to_sql(&self, dialect: &Dialect) -> String {
+        match (self, dialect) {
+            (_, Dialect::Postgresql) => format!("{}::jsonb", self.quoted(dialect)),
+            (Json::Null, _) => "NULL".to_string(),
+            (Json::Bool(value), _) => value.to_string(),
+            (Json::Int(_), _) | (Json::Float(_), _) => self.to_string(),
+            (Json::String(value), _) => Self::literal(value, dialect),
+            (_, Dialect::Mysql) => format!("CAST({} AS JSON)", self.quoted(dialect)),
+            (_, Dialect::Sqlite) => format!("json({})", self.quoted(dialect))
+        }
+    }
+
+    /// It writes the value as a quoted json text, such as `'{"a":1}'`, which is the form that `JSON_CONTAINS()` expects.
+    pub fn quoted(&self, dialect: &Dialect) -> String {
+        Self::literal(&self.to_string(), dialect)
+    }
+
+    fn literal(text: &str, dialect: &Dialect) -> String {
+        match dialect {
+            Dialect::Mysql => format!("'{}'", text.replace('\\', "\\\\").replace('\'', "''")),
+            _ => format!("'{}'", text.replace('\'', "''"))
+        }
+    }
+
+    fn escape(text: &str) -> String {
+        let mut escaped = String::with_capacity(text.len() + 2);
+
+        for character in text.chars() {
+            match character {
+                '"' => escaped.push_str("\\\""),
+                '\\' => escaped.push_str("\\\\"),
+                '\n' => escaped.push_str("\\n"),
+                '\r' => escaped.push_str("\\r"),
+                '\t' => escaped.push_str("\\t"),
+                character if (character as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", character as u32)),
+                character => escaped.push(character)
+            }
+        }
+
+        escaped
+    }
+}
+
+impl std::fmt::Display for Json {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(value) => write!(f, "{}", value),
+            Json::Int(value) => write!(f, "{}", value),
+            Json::Float(value) if value.is_finite() => write!(f, "{}", value),
+            Json::Float(_) => write!(f, "null"),
+            Json::String(value) => write!(f, "\"{}\"", Self::escape(value)),
+            Json::Array(values) => write!(f, "[{}]", values.iter().map(|value| value.to_string()).collect::<Vec<String>>().join(",")),
+            Json::Object(pairs) => write!(f, "{{{}}}", pairs.iter().map(|(key, value)| format!("\"{}\":{}", Self::escape(key), value)).collect::<Vec<String>>().join(","))
+        }
+    }
+}
+
+impl From<bool> for Json { fn from(value: bool) -> Self { Json::Bool(value) } }
+impl From<i8> for Json { fn from(value: i8) -> Self { Json::Int(value as i64) } }
+impl From<i16> for Json { fn from(value: i16) -> Self { Json::Int(value as i64) } }
+impl From<i32> for Json { fn from(value: i32) -> Self { Json::Int(value as i64) } }
+impl From<i64> for Json { fn from(value: i64) -> Self { Json::Int(value) } }
+impl From<u8> for Json { fn from(value: u8) -> Self { Json::Int(value as i64) } }
+impl From<u16> for Json { fn from(value: u16) -> Self { Json::Int(value as i64) } }
+impl From<u32> for Json { fn from(value: u32) -> Self { Json::Int(value as i64) } }
+impl From<f32> for Json { fn from(value: f32) -> Self { Json::Float(value as f64) } }
+impl From<f64> for Json { fn from(value: f64) -> Self { Json::Float(value) } }
+impl From<&str> for Json { fn from(value: &str) -> Self { Json::String(value.to_string()) } }
+impl From<String> for Json { fn from(value: String) -> Self { Json::String(value) } }
+impl<T: Into<Json>> From<Vec<T>> for Json { fn from(value: Vec<T>) -> Self { Json::Array(value.into_iter().map(|value| value.into()).collect()) } }
+impl<T: Into<Json>> From<Option<T>> for Json { fn from(value: Option<T>) -> Self { value.map_or(Json::Null, |value| value.into()) } }
+
+impl<T: Into<Json>> From<std::collections::BTreeMap<String, T>> for Json {
+    fn from(value: std::collections::BTreeMap<String, T>) -> Self {
+        Json::Object(value.into_iter().map(|(key, value)| (key, value.into())).collect())
+    }
+}
+
+impl<T: Into<Json>> From<std::collections::HashMap<String, T>> for Json {
+    fn from(value: std::collections::HashMap<String, T>) -> Self {
+        let mut pairs = value.into_iter().map(|(key, value)| (key, value.into())).collect::<Vec<(String, Json)>>();
+
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Json::Object(pairs)
+    }
+}
+
+impl From<&ValueType> for Json {
+    fn from(value: &ValueType) -> Self {
+        match value {
+            ValueType::String(value) | ValueType::Datetime(value) | ValueType::JsonString(value) => Json::String(value.to_string()),
+            ValueType::Null => Json::Null,
+            ValueType::Boolean(value) => Json::Bool(*value),
+            ValueType::Int8(value) => Json::Int(*value as i64),
+            ValueType::Int16(value) => Json::Int(*value as i64),
+            ValueType::Int32(value) => Json::Int(*value as i64),
+            ValueType::Int64(value) | ValueType::EpochTime(value) => Json::Int(*value),
+            ValueType::Int128(value) => i64::try_from(*value).map_or(Json::Float(*value as f64), Json::Int),
+            ValueType::Uint8(value) => Json::Int(*value as i64),
+            ValueType::Uint16(value) => Json::Int(*value as i64),
+            ValueType::Uint32(value) => Json::Int(*value as i64),
+            ValueType::Uint64(value) => i64::try_from(*value).map_or(Json::Float(*value as f64), Json::Int),
+            ValueType::Usize(value) => i64::try_from(*value).map_or(Json::Float(*value as f64), Json::Int),
+            ValueType::Float32(value) => Json::Float(*value as f64),
+            ValueType::Float64(value) => Json::Float(*value)
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<serde_json::Value> for Json {
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Null => Json::Null,
+            serde_json::Value::Bool(value) => Json::Bool(value),
+            serde_json::Value::Number(number) => match number.as_i64() {
+                Some(value) => Json::Int(value),
+                None => Json::Float(number.as_f64().unwrap_or(f64::NAN))
+            },
+            serde_json::Value::String(value) => Json::String(value),
+            serde_json::Value::Array(values) => Json::Array(values.into_iter().map(Json::from).collect()),
+            serde_json::Value::Object(map) => Json::Object(map.into_iter().map(|(key, value)| (key, Json::from(value))).collect())
+        }
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl From<Json> for serde_json::Value {
+    fn from(value: Json) -> Self {
+        match value {
+            Json::Null => serde_json::Value::Null,
+            Json::Bool(value) => serde_json::Value::Bool(value),
+            Json::Int(value) => serde_json::Value::from(value),
+            Json::Float(value) => serde_json::Number::from_f64(value).map_or(serde_json::Value::Null, serde_json::Value::Number),
+            Json::String(value) => serde_json::Value::String(value),
+            Json::Array(values) => serde_json::Value::Array(values.into_iter().map(serde_json::Value::from).collect()),
+            Json::Object(pairs) => serde_json::Value::Object(pairs.into_iter().map(|(key, value)| (key, serde_json::Value::from(value))).collect())
+        }
+    }
+}
+
+/// Timezones with Unix Timezone format, Can be used for setting timezone manually.
+/// It covers european, russian, north american, south american, arabic countries. In next releases, we'll cover other african and asian timezones. 
+#[derive(Debug, Clone)]
+pub enum Timezone {
+    System, Istanbul, Moscow, Kaliningrad, Samara, Ekaterinburg, Omsk, Krasnoyarsk, Irkutsk, Yakutsk,
+    Vladivostok, Magadan, Kamchatka, Shanghai, London, Paris, Berlin, Madrid, Rome, Amsterdam, Stockholm, Oslo,
+    Helsinki, Athens, NewYork, Chicago, Denver, LosAngeles, Anchorage, Honolulu, PuertoRico, Riyadh, Dubai, Qatar,
+    Kuwait, Bahrain, Muscat, Aden, Baghdad, Amman, Beirut, Damascus, Gaza, Hebron, Cairo, Khartoum, Tripoli,
+    Tunis, BuenosAires, LaPaz, SaoPaulo, Manaus, Recife, Cuiaba, PortoVelho, Santiago, Easter, Bogota, Guayaquil,
+    Galapagos, Guyana, Asuncion, Lima, Paramaribo, Montevideo, Caracas, StJohns, Halifax, Toronto, Winnipeg,
+    Edmonton, Vancouver, WhiteHorse, MexicoCity, Mazatlan, Chihuahua, Tijuana, Cancun, Belize, CostaRica, ElSalvador,
+    Guatemala, Tegucigalpa, Managua, Panama, Apia, Auckland, Bougainville, Chatham, Efate, Enderbury, Fakaofo,
+    Fiji, Funafuti, Gambier, Guadalcanal, Guam, Johnston, Kanton, Kiritimati, Kosrae, Kwajalein, Majuro, Marquesas,
+    Midway, Nauru, Niue, Norfolk, Noumea, PagoPago, Palau, Pitcairn, Pohnpei, PortMoresby, Saipan, Rarotonga, Tahiti,
+    Tarawa, Truk, Wake, Wallis, Yap, Tongatapu
+}
+
+impl std::fmt::Display for Timezone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Timezone::System => write!(f, "SYSTEM"), Timezone::Istanbul => write!(f, "Europe/Istanbul"), Timezone::Moscow => write!(f, "Europe/Moscow"),
+            Timezone::Kaliningrad => write!(f, "Europe/Kaliningrad"), Timezone::Samara => write!(f, "Europe/Samara"), Timezone::Ekaterinburg => write!(f, "Asia/Yekaterinburg"),
+            Timezone::Omsk => write!(f, "Asia/Omsk"), Timezone::Krasnoyarsk => write!(f, "Asia/Krasnoyarsk"), Timezone::Irkutsk => write!(f, "Asia/Irkutsk"),
+            Timezone::Yakutsk => write!(f, "Asia/Yakutsk"), Timezone::Vladivostok => write!(f, "Asia/Vladivostok"), Timezone::Magadan => write!(f, "Asia/Magadan"),
+            Timezone::Kamchatka => write!(f, "Asia/Kamchatka"), Timezone::Shanghai => write!(f, "Asia/Shanghai"), Timezone::London => write!(f, "Europe/London"),
+            Timezone::Paris => write!(f, "Europe/Paris"), Timezone::Berlin => write!(f, "Europe/Berlin"), Timezone::Madrid => write!(f, "Europe/Madrid"),
+            Timezone::Rome => write!(f, "Europe/Rome"), Timezone::Amsterdam => write!(f, "Europe/Amsterdam"), Timezone::Stockholm => write!(f, "Europe/Stockholm"),
+            Timezone::Oslo => write!(f, "Europe/Oslo"), Timezone::Helsinki => write!(f, "Europe/Helsinki"), Timezone::Athens => write!(f, "Europe/Athens"),
+            Timezone::NewYork => write!(f, "America/New_York"), Timezone::Chicago => write!(f, "America/Chicago"), Timezone::Denver => write!(f, "America/Denver"),
+            Timezone::LosAngeles => write!(f, "America/Los_Angeles"), Timezone::Anchorage => write!(f, "America/Anchorage"), Timezone::Honolulu => write!(f, "Pacific/Honolulu"),
+            Timezone::PuertoRico => write!(f, "America/Puerto_Rico"), Timezone::Riyadh => write!(f, "Asia/Riyadh"), Timezone::Dubai => write!(f, "Asia/Dubai"),
+            Timezone::Qatar => write!(f, "Asia/Qatar"), Timezone::Kuwait => write!(f, "Asia/Kuwait"), Timezone::Bahrain => write!(f, "Asia/Bahrain"), Timezone::Muscat => write!(f, "Asia/Muscat"),
+            Timezone::Aden => write!(f, "Asia/Aden"), Timezone::Baghdad => write!(f, "Asia/Baghdad"), Timezone::Amman => write!(f, "Asia/Amman"), Timezone::Beirut => write!(f, "Asia/Beirut"),
+            Timezone::Damascus => write!(f, "Asia/Damascus"), Timezone::Gaza => write!(f, "Asia/Gaza"), Timezone::Hebron => write!(f, "Asia/Hebron"), Timezone::Cairo => write!(f, "Africa/Cairo"),
+            Timezone::Khartoum => write!(f, "Africa/Khartoum"), Timezone::Tripoli => write!(f, "Africa/Tripoli"), Timezone::Tunis => write!(f, "Africa/Tunis"),
+            Timezone::BuenosAires => write!(f, "America/Argentina/Buenos_Aires"), Timezone::LaPaz => write!(f, "America/La_Paz"), Timezone::SaoPaulo => write!(f, "America/Sao_Paulo"),
+            Timezone::Manaus => write!(f, "America/Manaus"), Timezone::Recife => write!(f, "America/Recife"), Timezone::Cuiaba => write!(f, "America/Cuiaba"), Timezone::PortoVelho => write!(f, "America/Porto_Velho"),
+            Timezone::Santiago => write!(f, "America/Santiago"), Timezone::Easter => write!(f, "Pacific/Easter"), Timezone::Bogota => write!(f, "America/Bogota"), Timezone::Guayaquil => write!(f, "America/Guayaquil"),
+            Timezone::Galapagos => write!(f, "Pacific/Galapagos"), Timezone::Guyana => write!(f, "America/Guyana"), Timezone::Asuncion => write!(f, "America/Asuncion"), Timezone::Lima => write!(f, "America/Lima"),
+            Timezone::Paramaribo => write!(f, "America/Paramaribo"), Timezone::Montevideo => write!(f, "America/Montevideo"), Timezone::Caracas => write!(f, "America/Caracas"),
+            Timezone::StJohns => write!(f, "America/St_Johns"), Timezone::Halifax => write!(f, "America/Halifax"), Timezone::Toronto => write!(f, "America/Toronto"), Timezone::Winnipeg => write!(f, "America/Winnipeg"),
+            Timezone::Edmonton => write!(f, "America/Edmonton"), Timezone::Vancouver => write!(f, "America/Vancouver"), Timezone::WhiteHorse => write!(f, "America/Whitehorse"),
+            Timezone::MexicoCity => write!(f, "America/Mexico_City"), Timezone::Mazatlan => write!(f, "America/Mazatlan"), Timezone::Chihuahua => write!(f, "America/Chihuahua"),
+            Timezone::Tijuana => write!(f, "America/Tijuana"), Timezone::Cancun => write!(f, "America/Cancun"), Timezone::Belize => write!(f, "America/Belize"), Timezone::CostaRica => write!(f, "America/Costa_Rica"),
+            Timezone::ElSalvador => write!(f, "America/El_Salvador"), Timezone::Guatemala => write!(f, "America/Guatemala"), Timezone::Tegucigalpa => write!(f, "America/Tegucigalpa"),
+            Timezone::Managua => write!(f, "America/Managua"), Timezone::Panama => write!(f, "America/Panama"), Timezone::Apia => write!(f, "Pacific/Apia"),
+            Timezone::Auckland => write!(f, "Pacific/Auckland"), Timezone::Bougainville => write!(f, "Pacific/Bougainville"), Timezone::Chatham => write!(f, "Pacific/Chatham"),
+            Timezone::Efate => write!(f, "Pacific/Efate"), Timezone::Enderbury => write!(f, "Pacific/Enderbury"), Timezone::Tongatapu => write!(f, "Pacific/Tongatapu"),
+            Timezone::Fakaofo => write!(f, "Pacific/Fakaofo"), Timezone::Fiji => write!(f, "Pacific/Fiji"), Timezone::Funafuti => write!(f, "Pacific/Funafuti"),
+            Timezone::Gambier => write!(f, "Pacific/Gambier"), Timezone::Guadalcanal => write!(f, "Pacific/Guadalcanal"), Timezone::Guam => write!(f, "Pacific/Guam"),
+            Timezone::Johnston => write!(f, "Pacific/Johnston"), Timezone::Kanton => write!(f, "Pacific/Kanton"), Timezone::Kiritimati => write!(f, "Pacific/Kiritimati"),
+            Timezone::Kosrae => write!(f, "Pacific/Kosrae"), Timezone::Majuro => write!(f, "Pacific/Majuro"), Timezone::Kwajalein => write!(f, "Pacific/Kwajalein"),
+            Timezone::Midway => write!(f, "Pacific/Midway"), Timezone::Nauru => write!(f, "Pacific/Nauru"), Timezone::Niue => write!(f, "Pacific/Niue"),
+            Timezone::Marquesas => write!(f, "Pacific/Marquesas"), Timezone::Norfolk => write!(f, "Pacific/Norfolk"), Timezone::PagoPago => write!(f, "Pacific/Pago_Pago"),
+            Timezone::Noumea => write!(f, "Pacific/Noumea"), Timezone::Palau => write!(f, "Pacific/Palau"), Timezone::Pitcairn => write!(f, "Pacific/Pitcairn"),
+            Timezone::Pohnpei => write!(f, "Pacific/Pohnpei"), Timezone::PortMoresby => write!(f, "Pacific/Port_Moresby"), Timezone::Rarotonga => write!(f, "Pacific/Rarotonga"),
+            Timezone::Tahiti => write!(f, "Pacific/Tahiti"), Timezone::Tarawa => write!(f, "Pacific/Tarawa"), Timezone::Saipan => write!(f, "Pacific/Saipan"),
+            Timezone::Truk => write!(f, "Pacific/Truk"), Timezone::Wake => write!(f, "Pacific/Wake"), Timezone::Wallis => write!(f, "Pacific/Wallis"),
+            Timezone::Yap => write!(f, "Pacific/Yap")
+        }
+    }
+}
+
+/// Enum that benefits you to define what you want with a foreign key.
+#[derive(Debug, Clone)]
+pub enum ForeignKeyActions {
+    Cascade, Restrict, SetNull, NoAction, SetDefault
+}
+
+impl std::fmt::Display for ForeignKeyActions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            &ForeignKeyActions::Cascade => write!(f, "CASCADE"),
+            &ForeignKeyActions::NoAction => write!(f, "NO ACTION"),
+            &ForeignKeyActions::Restrict => write!(f, "RESTRICT"),
+            &ForeignKeyActions::SetNull => write!(f, "SET NULL"),
+            &ForeignKeyActions::SetDefault => write!(f, "SET DEFAULT")
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_schema_query_declarative(){
+        let schema = SchemaBuilder::create("blog_website").unwrap().if_not_exists().finish();
+
+        assert_eq!("CREATE DATABASE IF NOT EXISTS blog_website;", schema);
+    }
+
+    #[test]
+    pub fn test_schema_query_imperative(){
+        let mut schema = SchemaBuilder::create("blog_website").unwrap();
+        schema.if_not_exists();
+        let schema_query = schema.finish();
+
+        assert_eq!("CREATE DATABASE IF NOT EXISTS blog_website;", schema_query);
+    }
+
+    #[test]
+    pub fn test_use_another_schema(){
+        let schema = SchemaBuilder::use_another_schema("chat_website").unwrap().finish();
+
+        assert_eq!("USE chat_website;", schema);
+    }
+
+    #[test]
+    pub fn test_insert_query(){
+        let columns = vec!["title", "author", "description"];
+        let values = vec![ValueType::String("What's Up?".to_string()), ValueType::String("John Doe".to_string()), ValueType::String("Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string())];
+    
+        let insert_query = QueryBuilder::insert(columns, values).unwrap().table("blogs").finish();
+
+        println!("{}", insert_query);
+        assert_eq!("INSERT INTO blogs (title, author, description) VALUES ('What's Up?', 'John Doe', 'Lorem ipsum dolor sit amet, consectetur adipiscing elit.');".to_string(), 
+                    insert_query);
+    }
+
+    #[test]
+    pub fn test_update_query(){
+        let update_query = QueryBuilder::update().unwrap().table("blogs").set("title", ValueType::String("Hello Rust!".to_string())).set("author", ValueType::String("Necdet".to_string())).finish();
+
+        assert_eq!("UPDATE blogs SET title = 'Hello Rust!', author = 'Necdet';", update_query);
+    }
+
+    #[test]
+    pub fn test_delete_query(){
+        let delete_query = QueryBuilder::delete().unwrap().table("blogs").where_("id", "=", ValueType::String("1".to_string())).finish();
+
+        assert_eq!("DELETE FROM blogs WHERE id = '1';", delete_query);
+    }
+
+    #[test]
+    pub fn test_select_query_declarative(){
+        let mut select = QueryBuilder::select(["id", "title", "description", "point"].to_vec()).unwrap();
+
+        let select_query = select.table("blogs")
+                                    .where_("id", "=", ValueType::Int32(10))
+                                    .and("point", ">", ValueType::Int8(90))
+                                    .or("id", "=", ValueType::Int64(20))
+                                    .finish();
+
+        assert_eq!("SELECT id, title, description, point FROM blogs WHERE id = 10 AND point > 90 OR id = 20;", select_query)
+    }
+
+    #[test]
+    pub fn test_select_query_imperative(){
+        let mut select = QueryBuilder::select(["*"].to_vec()).unwrap();
+
+        let select_query = select.table("blogs");
+        select_query.where_("id", "=", ValueType::Uint8(5));
+        select_query.or("id", "=", ValueType::Usize(25));
+
+        let finish_the_select_query = select_query.finish();
+
+        assert_eq!("SELECT * FROM blogs WHERE id = 5 OR id = 25;", finish_the_select_query);
+    }
+
+    #[test]
+    pub fn test_create_table() {
+        let mut table_builder_2 = TableBuilder::create("blabla", "projects");
+        let table_builder_2 = table_builder_2.if_not_exists();
+    
+        table_builder_2.add_column("id").col_type("INT").primary_key().auto_increment();
+        table_builder_2.add_column("name").col_type("VARCHAR(40)").not_null();
+        table_builder_2.add_column("owner_id").col_type("INT").not_null();
+        
+        // if we create a table, the first ForeignKeyItem's table field is not necessary.
+        let opts = ForeignKey {
+            first: ForeignKeyItem { table: "".to_string(), column: "owner_id".to_string() },
+            second: ForeignKeyItem { table: "users".to_string(), column: "id".to_string() },
+            constraint: None,
+            on_delete: Some(ForeignKeyActions::Cascade),
+            on_update: None
+        };
+        
+        table_builder_2.foreign_key(opts);
+    
+        let table_builder_2 = table_builder_2.finish();
+
+        let raw_query = "CREATE TABLE IF NOT EXISTS blabla.projects (id INT AUTO_INCREMENT PRIMARY KEY, name VARCHAR(40) NOT NULL, owner_id INT NOT NULL, FOREIGN KEY (owner_id) REFERENCES users(id) ON DELETE CASCADE);".to_string();
+
+        assert_eq!(raw_query, table_builder_2);
+    }
+
+    #[test]
+    pub fn test_time_value_type(){
+        let columns = ["name", "password", "last_login"].to_vec();
+        let values = [ValueType::String("necoo33".to_string()), ValueType::String("123456".to_string()), ValueType::Datetime("CURRENT_TIMESTAMP".to_string())].to_vec();
+    
+        let time_insert_test = QueryBuilder::insert(columns, values).unwrap().table("users").finish();
+
+        assert_eq!(time_insert_test, "INSERT INTO users (name, password, last_login) VALUES ('necoo33', '123456', CURRENT_TIMESTAMP);");
+
+        let time_update_test = QueryBuilder::update().unwrap().table("users").set("last_login", ValueType::Datetime("CURRENT_TIMESTAMP".to_string())).where_("name", "=", ValueType::String("necoo33".to_string())).finish();
+
+        assert_eq!(time_update_test, "UPDATE users SET last_login = CURRENT_TIMESTAMP WHERE name = 'necoo33';")
+    }
+
+    #[test]
+    pub fn test_unix_epoch_times(){
+        let columns = ["name", "password", "last_login"].to_vec();
+        let values = [ValueType::String("necoo33".to_string()), ValueType::String("123456".to_string()), ValueType::EpochTime(134523452)].to_vec();
+    
+        let time_insert_with_unix_epoch_times_test = QueryBuilder::insert(columns, values).unwrap().table("users").finish();
+        assert_eq!(time_insert_with_unix_epoch_times_test, "INSERT INTO users (name, password, last_login) VALUES ('necoo33', '123456', FROM_UNIXTIME(134523452));");
+    
+        let time_update_with_unix_epoch_times_test = QueryBuilder::update().unwrap().table("users").set("last_login", ValueType::EpochTime(3456436)).where_("name", "=", ValueType::String("necoo33".to_string())).finish();
+
+        assert_eq!(time_update_with_unix_epoch_times_test, "UPDATE users SET last_login = FROM_UNIXTIME(3456436) WHERE name = 'necoo33';");
+
+        let columns = ["name", "password", "last_login", "created_at"].to_vec();
+
+        let unix_epoch_times_test_3 = QueryBuilder::select(columns).unwrap().table("users").where_("created_at", ">", ValueType::EpochTime(3234534)).or("last_login", ">=", ValueType::EpochTime(2134432)).offset(0).limit(20).finish();
+
+        assert_eq!(unix_epoch_times_test_3, "SELECT name, password, last_login, created_at FROM users WHERE created_at > FROM_UNIXTIME(3234534) OR last_login >= FROM_UNIXTIME(2134432) LIMIT 20 OFFSET 0;")
+    }
+
+    #[test]
+    pub fn test_where_ins(){
+        let columns = ["name", "age", "id", "last_login"].to_vec();
+
+        let ids = [ValueType::Int32(1), ValueType::Int16(12), ValueType::Int64(8)].to_vec();
+
+        let test_where_in = QueryBuilder::select(columns).unwrap().table("users").where_in("id", &ids).finish();
+
+        assert_eq!(test_where_in, "SELECT name, age, id, last_login FROM users WHERE id IN (1, 12, 8);");
+
+        let columns = ["name", "age", "id", "last_login"].to_vec();
+
+        let ids = [ValueType::Int32(1), ValueType::Int16(12), ValueType::Int32(8)].to_vec();
+
+        let test_where_not_in = QueryBuilder::select(columns).unwrap().table("users").where_not_in("id", &ids).finish();
+
+        assert_eq!(test_where_not_in, "SELECT name, age, id, last_login FROM users WHERE id NOT IN (1, 12, 8);");
+
+        let columns = ["name", "age", "id", "last_login"].to_vec();
+
+        let test_where_in_custom = QueryBuilder::select(columns).unwrap().table("users").where_in_custom("id", "1, 12, 8").finish();
+
+        assert_eq!(test_where_in_custom, "SELECT name, age, id, last_login FROM users WHERE id IN (1, 12, 8);");
+
+        let columns = ["name", "age", "id", "last_login"].to_vec();
+
+        let test_where_not_in_custom = QueryBuilder::select(columns).unwrap().table("users").where_not_in_custom("id", "1, 12, 8").finish();
+
+        assert_eq!(test_where_not_in_custom, "SELECT name, age, id, last_login FROM users WHERE id NOT IN (1, 12, 8);");
+
+        // test AND IN's
+
+        let columns = ["name", "id", "last_login"].to_vec();
+
+        let ids = [ValueType::Int32(1), ValueType::Int16(12), ValueType::Int64(8)].to_vec();
+
+        let test_and_in = QueryBuilder::select(columns).unwrap().table("users").where_("age", ">", ValueType::Int32(35)).and_in("id", &ids).finish();
+
+        assert_eq!(test_and_in, "SELECT name, id, last_login FROM users WHERE age > 35 AND id IN (1, 12, 8);");
+
+        let columns = ["name", "id", "last_login"].to_vec();
+
+        let ids = [ValueType::Int32(1), ValueType::Int16(12), ValueType::Int64(8)].to_vec();
+
+        let test_and_not_in = QueryBuilder::select(columns).unwrap().table("users").where_("age", ">", ValueType::Int32(35)).and_not_in("id", &ids).finish();
+
+        assert_eq!(test_and_not_in, "SELECT name, id, last_login FROM users WHERE age > 35 AND id NOT IN (1, 12, 8);");
+
+        // test OR IN's
+
+        let columns = ["name", "id", "last_login"].to_vec();
+
+        let ids = [ValueType::Int32(1), ValueType::Int16(12), ValueType::Int64(8)].to_vec();
+
+        let test_or_in = QueryBuilder::select(columns).unwrap().table("users").where_("age", ">", ValueType::Int32(35)).or_in("id", &ids).finish();
+
+        assert_eq!(test_or_in, "SELECT name, id, last_login FROM users WHERE age > 35 OR id IN (1, 12, 8);");
+
+        let columns = ["name", "id", "last_login"].to_vec();
+
+        let ids = [ValueType::Int32(1), ValueType::Int16(12), ValueType::Int64(8)].to_vec();
+
+        let test_or_not_in = QueryBuilder::select(columns).unwrap().table("users").where_("age", ">", ValueType::Int32(35)).or_not_in("id", &ids).finish();
+
+        assert_eq!(test_or_not_in, "SELECT name, id, last_login FROM users WHERE age > 35 OR id NOT IN (1, 12, 8);")
+    }
+
+    #[test]
+    pub fn test_count() {
+        let count_of_users = QueryBuilder::count("*", None).table("users").where_("age", ">", ValueType::Int32(25)).finish();
+
+        assert_eq!(count_of_users, "SELECT COUNT(*) FROM users WHERE age > 25;".to_string());
+
+        let count_of_users_as_length = QueryBuilder::count("*", Some("length")).table("users").finish();
+
+        assert_eq!(count_of_users_as_length, "SELECT COUNT(*) AS length FROM users;".to_string());
+    }
+
+    #[test]
+    pub fn test_json_extract(){
+        // tests with "select()" constructor
+
+        let select_query_1 = QueryBuilder::select(["*"].to_vec()).unwrap().json_extract("data", ".age", Some("student_age")).table("students").finish();
+
+        assert_eq!(select_query_1, "SELECT JSON_EXTRACT(data, '$.age') AS student_age FROM students;".to_string());
+        
+        let select_query_2 = QueryBuilder::select(["*"].to_vec()).unwrap().json_extract("data", ".age", Some("student_age")).table("students").where_("successfull", "=", ValueType::Int8(1)).finish();
+
+        assert_eq!(select_query_2, "SELECT JSON_EXTRACT(data, '$.age') AS student_age FROM students WHERE successfull = 1;".to_string());
+        
+        let select_query_3 = QueryBuilder::select(["*"].to_vec()).unwrap().json_extract("data", ".age", Some("student_age")).table("students").where_("points", ">", ValueType::Int32(85)).json_extract("points", ".name", None).finish();
+
+        assert_eq!(select_query_3, "SELECT JSON_EXTRACT(data, '$.age') AS student_age FROM students WHERE JSON_EXTRACT(points, '$.name') > 85;".to_string());
+
+        // tests with ".where_cond()" method
+
+        let with_where = QueryBuilder::delete().unwrap().table("users").where_("id", ">", ValueType::Int32(200)).json_extract("id", ".user_id", None).finish();
+
+        assert_eq!(with_where, "DELETE FROM users WHERE JSON_EXTRACT(id, '$.user_id') > 200;".to_string());
+
+        // tests with ".table()" method
+        
+        let fields = ["name", "age"].to_vec();
+        
+        let with_table = QueryBuilder::select(fields).unwrap().table("users").json_extract("id", ".user_id", None).finish();
+
+        assert_eq!(with_table, "SELECT JSON_EXTRACT(id, '$.user_id') FROM users;".to_string());
+
+        // tests with ".and()" method
+
+        let fields = ["name", "age"].to_vec();
+
+        let with_and_1 = QueryBuilder::select(fields).unwrap().table("height").where_("weight", ">", ValueType::Int32(60)).and("height", ">", ValueType::Float64(1.70)).json_extract("height", ".student_height", None).finish();
+
+        assert_eq!(with_and_1, "SELECT name, age FROM height WHERE weight > 60 AND JSON_EXTRACT(height, '$.student_height') > 1.7;".to_string());
+    
+        let with_and_2 = QueryBuilder::select(["*"].to_vec()).unwrap().table("students").where_("weight", ">", ValueType::Int32(60)).and("height", ">", ValueType::Float64(1.70)).json_extract("height", ".student_height", None).finish();
+
+        assert_eq!(with_and_2, "SELECT * FROM students WHERE weight > 60 AND JSON_EXTRACT(height, '$.student_height') > 1.7;".to_string());
+
+        // tests with ".or()" method
+
+        let fields = ["name", "age"].to_vec();
+
+        let with_or_1 = QueryBuilder::select(fields).unwrap().table("height").where_("weight", ">", ValueType::Int32(60)).or("height", ">", ValueType::Float64(1.71)).json_extract("height", ".student_height", None).finish();
+
+        assert_eq!(with_or_1, "SELECT name, age FROM height WHERE weight > 60 OR JSON_EXTRACT(height, '$.student_height') > 1.71;".to_string());
+    
+        let with_or_2 = QueryBuilder::select(["*"].to_vec()).unwrap().table("students").where_("weight", ">", ValueType::Int32(60)).or("height", ">", ValueType::Float64(1.71)).json_extract("height", ".student_height", None).finish();
+
+        assert_eq!(with_or_2, "SELECT * FROM students WHERE weight > 60 OR JSON_EXTRACT(height, '$.student_height') > 1.71;".to_string());
+
+        // tests with "count()" constructor
+
+        let count_query_1 = QueryBuilder::count("*", None).json_extract("age", ".student_age", Some("value")).table("students").group_by("points").having("points", ">", ValueType::Int32(75)).finish();
+
+        assert_eq!(count_query_1, "SELECT JSON_EXTRACT(age, '$.student_age') AS value, COUNT(*) FROM students GROUP BY points HAVING points > 75;".to_string());
+
+        // tests with ".order_by()" method
+        
+        let fields = ["title", "desc", "created_at", "updated_at", "keywords", "pics", "likes"].to_vec();
+
+        let order_by_query_1 = QueryBuilder::select(fields).unwrap().table("contents").where_("published", "=", ValueType::Int32(1)).order_by("likes", "ASC").json_extract("likes", ".name", None).finish();
+
+        assert_eq!(order_by_query_1, "SELECT title, desc, created_at, updated_at, keywords, pics, likes FROM contents WHERE published = 1 ORDER BY JSON_EXTRACT(likes, '$.name') ASC;".to_string());
+    
+        // tests with ".json_extract()" method
+
+        let json_extract_chaining = QueryBuilder::select(["*"].to_vec()).unwrap().json_extract("articles", "[0]", Some("blog1")).json_extract("articles", "[1]", Some("blog2")).json_extract("articles", "[2]", Some("blog3")).table("users").where_("published", "=", ValueType::Int32(1)).finish();
+
+        assert_eq!(json_extract_chaining, "SELECT JSON_EXTRACT(articles, '$[0]') AS blog1, JSON_EXTRACT(articles, '$[1]') AS blog2, JSON_EXTRACT(articles, '$[2]') AS blog3 FROM users WHERE published = 1;".to_string());
+    }
+
+    #[test]
+    pub fn test_json_contains(){
+        // test with "select()" constructor:
+
+        let ins = [ValueType::Int32(1), ValueType::Int32(5), ValueType::Int64(11)].to_vec();
+        let select_query = QueryBuilder::select(["*"].to_vec()).unwrap().json_contains("pic", JsonValue::Initial(&ValueType::String("\"/files/hello.jpg\"".to_string())), ".path").table("users").where_in("id", &ins).finish();
+
+        assert_eq!(select_query, "SELECT JSON_CONTAINS(pic, '\"/files/hello.jpg\"', '$.path') FROM users WHERE id IN (1, 5, 11);".to_string());
+
+        // test with ".where_cond()" method:
+
+        let where_query = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("pic", "=", ValueType::String("".to_string())).json_contains("pic", JsonValue::Initial(&ValueType::String("\"blablabla.jpg\"".to_string())), ".name").finish();
+
+        assert_eq!(where_query, "SELECT * FROM users WHERE JSON_CONTAINS(pic, '\"blablabla.jpg\"', '$.name');".to_string());
+
+        let and_query_1 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).and("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), ".average_point").finish();
+
+        assert_eq!(and_query_1, "SELECT * FROM users WHERE age > 15 AND JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+
+        let and_query_2 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).and("class", "=", ValueType::String("5/c".to_string())).and("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), ".average_point").finish();
+
+        assert_eq!(and_query_2, "SELECT * FROM users WHERE age > 15 AND class = '5/c' AND JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+        
+        let and_query_3 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).and("class", "=", ValueType::String("5/c".to_string())).and("surname", "=", ValueType::String("etiman".to_string())).and("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), ".average_point").finish();
+    
+        assert_eq!(and_query_3, "SELECT * FROM users WHERE age > 15 AND class = '5/c'  AND surname = 'etiman' AND JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+
+        let and_query_4 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).and("sdfgsdfg", "=", ValueType::String("".to_string())).json_contains("parents", JsonValue::Initial(&ValueType::Int32(50)), ".age").and("surname", "=", ValueType::String("etiman".to_string())).and("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float32(80.11)), ".average_point").finish();
+    
+        assert_eq!(and_query_4, "SELECT * FROM users WHERE age > 15 AND JSON_CONTAINS(parents, 50, '$.age')  AND surname = 'etiman' AND JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+
+        let and_query_5 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).and("sdfgsdfg", "=", ValueType::String("".to_string())).json_contains("parents", JsonValue::Initial(&ValueType::Int32(50)), ".age").and("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), ".average_point").finish();
+    
+        assert_eq!(and_query_5, "SELECT * FROM users WHERE age > 15 AND JSON_CONTAINS(parents, 50, '$.age') AND JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+        
+        let or_query_1 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).or("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float32(80.11)), ".average_point").finish();
+
+        assert_eq!(or_query_1, "SELECT * FROM users WHERE age > 15 OR JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+        
+        let or_query_2 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).or("class", "=", ValueType::String("5/c".to_string())).or("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), ".average_point").finish();
+        
+        assert_eq!(or_query_2, "SELECT * FROM users WHERE age > 15 OR class = '5/c' OR JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+                
+        let or_query_3 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).or("class", "=", ValueType::String("5/c".to_string())).or("surname", "=", ValueType::String("etiman".to_string())).or("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), ".average_point").finish();
+            
+        assert_eq!(or_query_3, "SELECT * FROM users WHERE age > 15 OR class = '5/c'  OR surname = 'etiman' OR JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+        
+        let or_query_4 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).or("sdfgsdfg", "=", ValueType::String("".to_string())).json_contains("parents", JsonValue::Initial(&ValueType::Int32(50)), ".age").or("surname", "=", ValueType::String("etiman".to_string())).or("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), ".average_point").finish();
+            
+        assert_eq!(or_query_4, "SELECT * FROM users WHERE age > 15 OR JSON_CONTAINS(parents, 50, '$.age')  OR surname = 'etiman' OR JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+        
+        let or_query_5 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).or("sdfgsdfg", "=", ValueType::String("".to_string())).json_contains("parents", JsonValue::Initial(&ValueType::Int64(50)), ".age").or("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float32(80.11)), ".average_point").finish();
+            
+        assert_eq!(or_query_5, "SELECT * FROM users WHERE age > 15 OR JSON_CONTAINS(parents, 50, '$.age') OR JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+
+        let name = ValueType::JsonString("necdet".to_string());
+        let id = ValueType::Int32(1);
+        let is_active = ValueType::Boolean(true);
+
+        let object = vec![("name", &name), ("id", &id), ("isActive", &is_active)];
+
+        let mysql_json_object = JsonValue::MysqlJsonObject(&object);
+
+        let where_query_2 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("pic", "=", ValueType::String("".to_string())).json_contains("pic", mysql_json_object, "$").finish();
+
+        assert_eq!("SELECT * FROM users WHERE JSON_CONTAINS(pic, JSON_OBJECT('name', \"necdet\", 'id', 1, 'isActive', true));", where_query_2)
+    }
+
+    #[test]
+    pub fn test_like_later_than_where_keywords(){
+        let mut like_query_1 = QueryBuilder::select(["*"].to_vec()).unwrap();
+
+        let like_query_1 = like_query_1.table("blogs")
+                                                      .where_("id", "=", ValueType::Int32(5))
+                                                      .like(["title", "description"].to_vec(), "hello")
+                                                      .finish();
+
+        assert_eq!(like_query_1, "SELECT * FROM blogs WHERE id = 5 AND (title LIKE '%hello%' OR description LIKE '%hello%');");
+    
+        let mut like_query_2 = QueryBuilder::select(["*"].to_vec()).unwrap();
+
+        let ins = vec![ValueType::Int32(1), ValueType::Int32(2), ValueType::Int32(3)];
+        let like_query_2 = like_query_2.table("blogs")
+                                                          .where_in("id", &ins)
+                                                          .like(["title", "description", "keywords"].to_vec(), "necdet")
+                                                          .limit(10)
+                                                          .offset(0)
+                                                          .finish();
+
+        assert_eq!(like_query_2, "SELECT * FROM blogs WHERE id IN (1, 2, 3) AND (title LIKE '%necdet%' OR description LIKE '%necdet%' OR keywords LIKE '%necdet%') LIMIT 10 OFFSET 0;")
+    }
+
+    #[test]
+    pub fn test_ordering_functions(){
+        let order_by_query = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").order_by("id", "asc").order_by("weight", "desc").order_by("point", "asc").finish();
+
+        assert_eq!(order_by_query, "SELECT * FROM users ORDER BY id ASC, weight DESC, point ASC;");
+
+        let order_by_random_query = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").order_random().finish();
+
+        assert_eq!(order_by_random_query, "SELECT * FROM users ORDER BY RAND();");
+
+        let roles = ["admin", "moderator", "member", "guest"].to_vec();
+        let field_query_1 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").order_by_field("role", roles.clone()).finish();
+
+        assert_eq!(field_query_1, "SELECT * FROM users ORDER BY FIELD(role, 'admin', 'moderator', 'member', 'guest');");
+
+        let field_query_2 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").order_by("id", "asc").order_by_field("role", roles.clone()).finish();
+
+        assert_eq!(field_query_2, "SELECT * FROM users ORDER BY id ASC, FIELD(role, 'admin', 'moderator', 'member', 'guest');");
+
+        let field_query_3 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").order_by_field("role", roles.clone()).order_by("id", "asc").finish();
+
+        assert_eq!(field_query_3, "SELECT * FROM users ORDER BY FIELD(role, 'admin', 'moderator', 'member', 'guest'), id ASC;");
+        
+        let field_query_4 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").order_by_field("role", roles).order_by_field("status", vec!["active", "banned", "unverified"]).finish();
+
+        assert_eq!(field_query_4, "SELECT * FROM users ORDER BY FIELD(role, 'admin', 'moderator', 'member', 'guest'), FIELD(status, 'active', 'banned', 'unverified');");
+    }
+
+    #[test]
+    pub fn test_unions(){
+        let mut union_1 = QueryBuilder::select(vec!["name", "age", "id"]).unwrap();
+        union_1.table("users").where_("age", ">", ValueType::Int32(7));
+
+        let union_2 = QueryBuilder::select(vec!["name", "age", "id"]).unwrap()
+                                                          .table("users")
+                                                          .where_("age", "<", ValueType::Int32(15))
+                                                          .union(vec![union_1])
+                                                          .finish();
+
+        assert_eq!(union_2, "(SELECT name, age, id FROM users WHERE age < 15) UNION (SELECT name, age, id FROM users WHERE age > 7);");
+
+        let mut union_1 = QueryBuilder::select(vec!["id", "title", "description", "published"]).unwrap();
+        union_1.table("blogs").like(vec!["title"], "text");
+
+        let mut union_2 = QueryBuilder::select(vec!["id", "title", "description", "published"]).unwrap();
+        union_2.table("blogs").like(vec!["description"], "some text");
+
+        let union_3 = QueryBuilder::select(vec!["id", "title", "description", "published"]).unwrap()
+                                                              .table("blogs")
+                                                              .where_("published", "=", ValueType::Boolean(true))
+                                                              .union_all(vec![union_1, union_2])
+                                                              .finish();
+
+        assert_eq!(union_3, "(SELECT id, title, description, published FROM blogs WHERE published = true) UNION ALL (SELECT id, title, description, published FROM blogs WHERE title LIKE '%text%') UNION ALL (SELECT id, title, description, published FROM blogs WHERE description LIKE '%some text%');");
+    }
+
+    #[test]
+    pub fn test_json_value(){
+        let name = ValueType::JsonString("necdet".to_string());
+        let age = ValueType::Int8(25);
+        let id = ValueType::Int32(1);
+
+        let values = vec![("name", &name), ("age", &age), ("id", &id)];
+
+        let json_object = JsonValue::Object(&values);
+
+        assert_eq!("{\"name\": \"necdet\", \"age\": 25, \"id\": 1}", json_object.to_string());
+
+        let mysql_json_object = JsonValue::MysqlJsonObject(&values);
+
+        assert_eq!("JSON_OBJECT('name', \"necdet\", 'age', 25, 'id', 1)", mysql_json_object.to_string());
+
+        let name2 = ValueType::JsonString("cevdet".to_string());
+        let age2 = ValueType::Int8(24);
+        let id2 = ValueType::Int32(2);
+
+        let name3 = ValueType::JsonString("serap".to_string());
+        let age3 = ValueType::Int8(21);
+        let id3 = ValueType::Int32(3);
+
+        let object1 = vec![("name", &name), ("age", &age), ("id", &id)];
+        let object2 = vec![("name", &name2), ("age", &age2), ("id", &id2)];
+        let object3 = vec![("name", &name3), ("age", &age3), ("id", &id3)];
+
+        let objects = vec![object1, object2, object3];
+        
+        let json_array = JsonValue::ObjectArray(&objects);
+
+        assert_eq!("[{\"name\": \"necdet\", \"age\": 25, \"id\": 1}, {\"name\": \"cevdet\", \"age\": 24, \"id\": 2}, {\"name\": \"serap\", \"age\": 21, \"id\": 3}]", json_array.to_string());
+    }
+
+    #[test]
+    pub fn test_json_array_append(){
+        let lesson = ("lesson", &ValueType::String("math".to_string()));
+        let point = ("point", &ValueType::Int32(100));
+
+        let values = vec![lesson, point];
+        
+        let object = JsonValue::MysqlJsonObject(&values);
+
+        let query = QueryBuilder::update().unwrap()
+                                         .table("users")
+                                         .json_array_append("points", vec![("$", object.clone())])
+                                         .where_("id", "=", ValueType::Int8(1))
+                                         .finish();
+
+        assert_eq!("UPDATE users SET points = JSON_ARRAY_APPEND(points, '$', JSON_OBJECT('lesson', 'math', 'point', 100)) WHERE id = 1;", query);
+
+        let query = QueryBuilder::update().unwrap()
+                                         .table("users")
+                                         .set("status", ValueType::String("passed".to_string()))
+                                         .json_array_append("points", vec![("$", object)])
+                                         .where_("id", "=", ValueType::Int8(1))
+                                         .finish();
+
+        assert_eq!("UPDATE users SET status = 'passed', points = JSON_ARRAY_APPEND(points, '$', JSON_OBJECT('lesson', 'math', 'point', 100)) WHERE id = 1;", query);
+    }
+
+    #[test]
+    pub fn test_json_remove() {
+        let query = QueryBuilder::update().unwrap()
+                                         .table("blogs")
+                                         .json_remove("likes", vec!["[10]"])
+                                         .where_("blog_id", "=", ValueType::Int32(20))
+                                         .finish();
+
+        assert_eq!(query, "UPDATE blogs SET likes = JSON_REMOVE(likes, '$[10]') WHERE blog_id = 20;");
+        
+        let query = QueryBuilder::update().unwrap()
+                                         .table("blogs")
+                                         .set("blabla", ValueType::Int32(50))
+                                         .json_remove("likes", vec!["[10]", "[11]", "[12]"])
+                                         .where_("blog_id", "=", ValueType::Int32(20))
+                                         .finish();
+
+        println!("{}", query)
+    }
+
+    #[test]
+    pub fn test_json_set_and_json_replace(){
+        let lesson = ("lesson", &ValueType::String("math".to_string()));
+        let point = ("point", &ValueType::Int32(100));
+
+        let values = vec![lesson, point];
+        
+        let object = JsonValue::MysqlJsonObject(&values);
+
+        let query = QueryBuilder::update().unwrap()
+                                                        .table("users")
+                                                        .json_set("points", vec![("[0]", object)])
+                                                        .where_("id", "=", ValueType::Int32(1))
+                                                        .finish();
+
+        assert_eq!("UPDATE users SET points = JSON_SET(points, '$[0]', JSON_OBJECT('lesson', 'math', 'point', 100)) WHERE id = 1;", query);
+
+        let value = ValueType::Int32(100);
+        let value = JsonValue::Initial(&value);
+
+        let query = QueryBuilder::update().unwrap()
+                                         .table("users")
+                                         .json_replace("points", vec![("[0].point", value)])
+                                         .where_("id", "=", ValueType::Int32(1))
+                                         .finish();
+
+        assert_eq!("UPDATE users SET points = JSON_REPLACE(points, '$[0].point', 100) WHERE id = 1;", query)
+    }
+
+    #[test]
+    pub fn test_json_value_initial_bugfix(){
+        let file_name_val = ValueType::JsonString("chemistry".to_string());
+        let file_name_val = JsonValue::Initial(&file_name_val);
+
+        let query = QueryBuilder::select(vec!["lesson_points"]).unwrap()
+                                         .json_extract("points", format!("[{}]", 2), Some("point"))
+                                         .table("students")
+                                         .where_("id", "=", ValueType::Int32(5))
+                                         .and("adsf", "=", ValueType::Null)
+                                         .json_contains("points", file_name_val, format!("[{}].name", 0))
+                                         .finish();
+
+        assert_eq!("SELECT JSON_EXTRACT(points, '$[2]') AS point FROM students WHERE id = 5 AND JSON_CONTAINS(points, '\"chemistry\"', '$[0].name');", query);
+    }
+
+    #[test]
+    pub fn test_timezones(){
+        let query = QueryBuilder::select(vec!["*"]).unwrap().table("users").time_zone(Timezone::Istanbul).finish();
+
+        assert_eq!(query, "SET time_zone = 'Europe/Istanbul'; SELECT * FROM users;");
+        
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .table("users")
+                                         .global_time_zone(Timezone::Amsterdam)
+                                         .where_("id", "=", ValueType::Int32(3))
+                                         .and("surname", "=", ValueType::String("Doe".to_string()))
+                                         .finish();
+
+        assert_eq!(query, "SET GLOBAL time_zone = 'Europe/Amsterdam'; SELECT * FROM users WHERE id = 3 AND surname = 'Doe';");
+
+        let query = QueryBuilder::update().unwrap().table("users").time_zone(Timezone::NewYork).set("age", ValueType::Int32(26)).set("last_online_date", ValueType::Datetime("CURRENT_TIMESTAMP".to_string())).where_("id", "=", ValueType::Int32(234)).finish();
+
+        assert_eq!(query, "SET time_zone = 'America/New_York'; UPDATE users SET age = 26, last_online_date = CURRENT_TIMESTAMP WHERE id = 234;");
+
+        let query = QueryBuilder::update().unwrap().table("users").set("age", ValueType::Int32(26)).global_time_zone(Timezone::NewYork).set("last_online_date", ValueType::Datetime("CURRENT_TIMESTAMP".to_string())).where_("id", "=", ValueType::Int32(234)).finish();
+
+        assert_eq!(query, "SET GLOBAL time_zone = 'America/New_York'; UPDATE users SET age = 26, last_online_date = CURRENT_TIMESTAMP WHERE id = 234;");
+    }
+
+    #[test]
+    pub fn test_joins(){
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .table("students s")
+                                         .inner_join("grades g", "s.id", "=", "g.student_id")
+                                         .where_("id", "=", ValueType::Int32(10))
+                                         .finish();
+
+        assert_eq!(query, "SELECT * FROM students s INNER JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .table("students s")
+                                         .left_join("grades g", "s.id", "=", "g.student_id")
+                                         .where_("id", "=", ValueType::Int32(10))
+                                         .finish();
+
+        assert_eq!(query, "SELECT * FROM students s LEFT JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .table("students s")
+                                         .right_join("grades g", "s.id", "=", "g.student_id")
+                                         .where_("id", "=", ValueType::Int32(10))
+                                         .finish();
+
+        assert_eq!(query, "SELECT * FROM students s RIGHT JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .table("students s")
+                                         .cross_join("grades g")
+                                         .where_("id", "=", ValueType::Int32(10))
+                                         .finish();
+
+        assert_eq!(query, "SELECT * FROM students s CROSS JOIN grades g WHERE id = 10;");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .table("students s")
+                                         .natural_join("grades g")
+                                         .where_("id", "=", ValueType::Int32(10))
+                                         .finish();
+                                        
+        assert_eq!(query, "SELECT * FROM students s NATURAL JOIN grades g WHERE id = 10;");
+    }
+
+    #[test]
+    pub fn test_multi_table_update_and_delete(){
+        let query = QueryBuilder::update().unwrap()
+                                         .table("students s")
+                                         .inner_join("grades g", "s.id", "=", "g.student_id")
+                                         .set("s.passed", ValueType::Boolean(true))
+                                         .where_("g.point", ">", ValueType::Int32(50))
+                                         .finish();
+
+        assert_eq!(query, "UPDATE students s INNER JOIN grades g ON s.id = g.student_id SET s.passed = true WHERE g.point > 50;");
+
+        let query = QueryBuilder::update().unwrap()
+                                         .table("students s")
+                                         .set("s.passed", ValueType::Boolean(true))
+                                         .inner_join("grades g", "s.id", "=", "g.student_id")
+                                         .set("s.graded", 1)
+                                         .where_("g.point", ">", ValueType::Int32(50))
+                                         .clone();
+
+        assert_eq!(query.finish(), "UPDATE students s INNER JOIN grades g ON s.id = g.student_id SET s.passed = true, s.graded = 1 WHERE g.point > 50;");
+        assert_eq!(PreparedStatement::from_query("grade", &query).unwrap().statement, "UPDATE students s INNER JOIN grades g ON s.id = g.student_id SET s.passed = ?, s.graded = ? WHERE g.point > ?");
+
+        let query = QueryBuilder::delete().unwrap()
+                                         .table("students s")
+                                         .left_join("grades g", "s.id", "=", "g.student_id")
+                                         .where_("g.student_id", "=", ValueType::Null)
+                                         .finish();
+
+        assert_eq!(query, "DELETE s FROM students s LEFT JOIN grades g ON s.id = g.student_id WHERE g.student_id IS NULL;");
+
+        let query = QueryBuilder::delete().unwrap()
+                                         .table("students s")
+                                         .inner_join("grades g", "s.id", "=", "g.student_id")
+                                         .targets(vec!["s", "g"])
+                                         .where_("s.id", "=", ValueType::Int32(3))
+                                         .finish();
+
+        assert_eq!(query, "DELETE s, g FROM students s INNER JOIN grades g ON s.id = g.student_id WHERE s.id = 3;");
+
+        let query = QueryBuilder::update().unwrap()
+                                         .dialect(Dialect::Postgresql)
+                                         .table("students")
+                                         .inner_join("grades", "students.id", "=", "grades.student_id")
+                                         .set("passed", ValueType::Boolean(true))
+                                         .where_("grades.point", ">", ValueType::Int32(50))
+                                         .or("grades.bonus", "=", ValueType::Boolean(true))
+                                         .finish();
+
+        assert_eq!(query, "UPDATE students SET passed = true FROM grades WHERE students.id = grades.student_id AND (grades.point > 50 OR grades.bonus = true);");
+
+        let query = QueryBuilder::update().unwrap()
+                                         .dialect(Dialect::Sqlite)
+                                         .table("students")
+                                         .inner_join("grades", "students.id", "=", "grades.student_id")
+                                         .left_join("lessons", "grades.lesson_id", "=", "lessons.id")
+                                         .set("passed", ValueType::Boolean(false))
+                                         .finish();
+
+        assert_eq!(query, "UPDATE students SET passed = false FROM grades LEFT JOIN lessons ON grades.lesson_id = lessons.id WHERE students.id = grades.student_id;");
+
+        let query = QueryBuilder::delete().unwrap()
+                                         .dialect(Dialect::Postgresql)
+                                         .table("students")
+                                         .inner_join("grades", "students.id", "=", "grades.student_id")
+                                         .where_("grades.point", "<", ValueType::Int32(10))
+                                         .finish();
+
+        assert_eq!(query, "DELETE FROM students USING grades WHERE students.id = grades.student_id AND (grades.point < 10);");
+
+        let query = QueryBuilder::update().unwrap()
+                                         .dialect(Dialect::Postgresql)
+                                         .table("students")
+                                         .inner_join("grades", "students.id", "=", "grades.student_id")
+                                         .set("note", ValueType::String("a WHERE b".to_string()))
+                                         .where_("grades.point", ">", ValueType::Int32(50))
+                                         .finish();
+
+        assert_eq!(query, "UPDATE students SET note = 'a WHERE b' FROM grades WHERE students.id = grades.student_id AND (grades.point > 50);");
+    }
+
+    #[test]
+    pub fn test_order_by_and_limit_on_update_and_delete(){
+        let query = QueryBuilder::update().unwrap()
+                                         .table("sessions")
+                                         .set("expired", ValueType::Boolean(true))
+                                         .where_("last_seen", "<", ValueType::Datetime("2024-01-01 00:00:00".to_string()))
+                                         .order_by("last_seen", "ASC")
+                                         .limit(1000)
+                                         .finish();
+
+        assert_eq!(query, "UPDATE sessions SET expired = true WHERE last_seen < '2024-01-01 00:00:00' ORDER BY last_seen ASC LIMIT 1000;");
+
+        let query = QueryBuilder::delete().unwrap()
+                                         .table("logs")
+                                         .where_("level", "=", ValueType::String("debug".to_string()))
+                                         .limit(500)
+                                         .order_by("id", "asc")
+                                         .finish();
+
+        assert_eq!(query, "DELETE FROM logs WHERE level = 'debug' ORDER BY id ASC LIMIT 500;");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .table("users")
+                                         .order_by("id", "DESC")
+                                         .offset(10)
+                                         .limit(5)
+                                         .order_by("name", "ASC")
+                                         .finish();
+
+        assert_eq!(query, "SELECT * FROM users ORDER BY id DESC, name ASC LIMIT 5 OFFSET 10;");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .table("users")
+                                         .limit(5)
+                                         .where_("name", "=", ValueType::String("x LIMIT 1".to_string()))
+                                         .order_by("id", "ASC")
+                                         .into_variables(vec!["first"])
+                                         .finish();
+
+        assert_eq!(query, "SELECT * FROM users WHERE name = 'x LIMIT 1' ORDER BY id ASC LIMIT 5 INTO @first;");
+
+        let result = std::panic::catch_unwind(|| {
+            QueryBuilder::delete().unwrap().table("logs").order_by("id", "ASC").limit(3).offset(2).finish()
+        });
+
+        assert!(result.is_err());
+
+        let result = std::panic::catch_unwind(|| {
+            QueryBuilder::update().unwrap().dialect(Dialect::Postgresql).table("logs").set("seen", ValueType::Boolean(true)).order_random().finish()
+        });
+
+        assert!(result.is_err());
+
+        let result = std::panic::catch_unwind(|| {
+            QueryBuilder::delete().unwrap().dialect(Dialect::Sqlite).table("logs").order_by_field("level", vec!["debug"]).finish()
+        });
+
+        assert!(result.is_err());
+
+        let result = std::panic::catch_unwind(|| {
+            QueryBuilder::delete().unwrap().table("logs l").inner_join("users u", "l.user_id", "=", "u.id").limit(5).finish()
+        });
+
+        assert!(result.is_err());
+
+        let result = std::panic::catch_unwind(|| {
+            QueryBuilder::update().unwrap().dialect(Dialect::Postgresql).table("logs").set("seen", ValueType::Boolean(true)).limit(5).finish()
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    pub fn test_index_and_optimizer_hints(){
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .table("students s")
+                                         .force_index(vec!["idx_class"], None)
+                                         .ignore_index(vec!["idx_name"], Some(IndexHintScope::OrderBy))
+                                         .left_join("grades g", "s.id", "=", "g.student_id")
+                                         .use_index(vec!["idx_student", "idx_point"], Some(IndexHintScope::Join))
+                                         .cross_join("lessons l")
+                                         .use_index(vec![], None)
+                                         .where_("s.id", "=", ValueType::Int32(10))
+                                         .finish();
+
+        assert_eq!(query, "SELECT * FROM students s FORCE INDEX (idx_class) IGNORE INDEX FOR ORDER BY (idx_name) LEFT JOIN grades g USE INDEX FOR JOIN (idx_student, idx_point) ON s.id = g.student_id CROSS JOIN lessons l USE INDEX () WHERE s.id = 10;");
+
+        let query = QueryBuilder::update().unwrap()
+                                         .table("users")
+                                         .optimizer_hint(OptimizerHint::SetVar("sort_buffer_size".to_string(), ValueType::Int32(16384)))
+                                         .set("active", ValueType::Boolean(false))
+                                         .finish();
+
+        assert_eq!(query, "UPDATE /*+ SET_VAR(sort_buffer_size = 16384) */ users SET active = false;");
+
+        let query = QueryBuilder::delete().unwrap()
+                                         .table("logs")
+                                         .where_("id", "<", ValueType::Int32(100))
+                                         .optimizer_hint(OptimizerHint::Index("logs".to_string(), vec!["PRIMARY".to_string()]))
+                                         .optimizer_hint(OptimizerHint::MaxExecutionTime(500))
+                                         .finish();
+
+        assert_eq!(query, "DELETE /*+ INDEX(logs PRIMARY) MAX_EXECUTION_TIME(500) */ FROM logs WHERE id < 100;");
+
+        let query = QueryBuilder::count("*", Some("length"))
+                                         .table("users")
+                                         .optimizer_hint(OptimizerHint::TimeZone(Timezone::Istanbul))
+                                         .finish();
+
+        assert_eq!(query, "SELECT /*+ SET_VAR(time_zone = 'Europe/Istanbul') */ COUNT(*) AS length FROM users;");
+
+        let query = QueryBuilder::delete().unwrap()
+                                         .table("students s")
+                                         .inner_join("grades g", "s.id", "=", "g.student_id")
+                                         .optimizer_hint(OptimizerHint::MaxExecutionTime(500))
+                                         .where_("g.point", "<", ValueType::Int32(10))
+                                         .finish();
+
+        assert_eq!(query, "DELETE /*+ MAX_EXECUTION_TIME(500) */ s FROM students s INNER JOIN grades g ON s.id = g.student_id WHERE g.point < 10;");
+
+        let mut other = QueryBuilder::select(vec!["id"]).unwrap();
+        other.table("teachers");
+
+        let query = QueryBuilder::select(vec!["id"]).unwrap()
+                                         .table("students")
+                                         .optimizer_hint(OptimizerHint::MaxExecutionTime(500))
+                                         .union(vec![other])
+                                         .finish();
+
+        assert_eq!(query, "(SELECT /*+ MAX_EXECUTION_TIME(500) */ id FROM students) UNION (SELECT id FROM teachers);");
+
+        let result = std::panic::catch_unwind(|| {
+            QueryBuilder::select(vec!["*"]).unwrap().table("users").where_("id", "=", ValueType::Int32(1)).use_index(vec!["idx"], None).finish()
+        });
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    pub fn test_keyset_pagination(){
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .table("posts")
+                                         .paginate_after(vec!["id"], vec![], "ASC", 20)
+                                         .finish();
+
+        assert_eq!(query, "SELECT * FROM posts ORDER BY id ASC LIMIT 20;");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .table("posts")
+                                         .paginate_after(vec!["id"], vec![ValueType::Int32(40)], "ASC", 20)
+                                         .finish();
+
+        assert_eq!(query, "SELECT * FROM posts WHERE id > 40 ORDER BY id ASC LIMIT 20;");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .dialect(Dialect::Postgresql)
+                                         .table("posts")
+                                         .where_("author_id", "=", ValueType::Int32(3))
+                                         .paginate_after(vec!["created_at", "id"], vec![ValueType::Datetime("2024-01-01".to_string()), ValueType::Int32(40)], "desc", 10)
+                                         .finish();
+
+        assert_eq!(query, "SELECT * FROM posts WHERE (author_id = 3) AND (created_at, id) < ('2024-01-01', 40) ORDER BY created_at DESC, id DESC LIMIT 10;");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .table("posts")
+                                         .where_("a", "=", ValueType::Int32(1))
+                                         .or("b", "=", ValueType::Int32(2))
+                                         .paginate_after(vec!["id"], vec![ValueType::Int32(40)], "ASC", 20)
+                                         .finish();
+
+        assert_eq!(query, "SELECT * FROM posts WHERE (a = 1 OR b = 2) AND id > 40 ORDER BY id ASC LIMIT 20;");
+
+        let query = QueryBuilder::select(vec!["author_id", "MAX(id)"]).unwrap()
+                                         .table("posts")
+                                         .group_by("author_id")
+                                         .paginate_after(vec!["author_id"], vec![ValueType::Int32(40)], "ASC", 20)
+                                         .finish();
+
+        assert_eq!(query, "SELECT author_id, MAX(id) FROM posts WHERE author_id > 40 GROUP BY author_id ORDER BY author_id ASC LIMIT 20;");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .dialect(Dialect::Sqlite)
+                                         .table("posts")
+                                         .paginate_after(vec!["a", "b", "c"], vec![ValueType::Int32(1), ValueType::Int32(2), ValueType::Int32(3)], "ASC", 10)
+                                         .finish();
+
+        assert_eq!(query, "SELECT * FROM posts WHERE (a > 1 OR (a = 1 AND b > 2) OR (a = 1 AND b = 2 AND c > 3)) ORDER BY a ASC, b ASC, c ASC LIMIT 10;");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                         .dialect(Dialect::Sqlite)
+                                         .table("posts")
+                                         .where_("published", "=", true)
+                                         .paginate_after(vec!["created_at", "id"], vec![ValueType::Datetime("2024-01-01".to_string()), ValueType::Int32(15)], "DESC", 10)
+                                         .clone();
+
+        assert_eq!(query.finish(), "SELECT * FROM posts WHERE (published = true) AND (created_at < '2024-01-01' OR (created_at = '2024-01-01' AND id < 15)) ORDER BY created_at DESC, id DESC LIMIT 10;");
+        assert_eq!(PreparedStatement::from_query("posts", &query).unwrap().parameters, vec!["true", "'2024-01-01'", "'2024-01-01'", "15"]);
+
+        let values = vec![ValueType::String("it's: 12:ab".to_string()), ValueType::Int64(-15), ValueType::Float64(0.1), ValueType::Boolean(true),
+                          ValueType::Datetime("2024-01-01 10:00:00".to_string()), ValueType::Uint8(7), ValueType::EpochTime(1700000000)];
+
+        let token = QueryBuilder::encode_cursor(&values);
+        let decoded = QueryBuilder::decode_cursor(&token).unwrap();
+
+        assert_eq!(format!("{:?}", values), format!("{:?}", decoded));
+        assert!(QueryBuilder::decode_cursor("zz").is_err());
+        assert!(QueryBuilder::decode_cursor(&token[..token.len() - 2]).is_err());
+        assert!(QueryBuilder::decode_cursor(&QueryBuilder::encode_cursor(&[])).unwrap().is_empty());
     }
-}
 
-/// Enum that benefits you to add json values to structs. They can be used with json functions.
-/// That variants represents that kind of json values:
-#[derive(Debug, Clone)]
-pub enum JsonValue<'a> {
-    /// 
-    /// Example Value: ["hello", 21, "again"]
-    /// 
-    Array(&'a Vec<ValueType>), 
-    
-    /// 
-    /// Example Value: {"name": "necdet", "message": "hello", "id": 1}
-    /// 
-    Object(&'a Vec<(&'a str, &'a ValueType)>), 
-    
-    ///
-    /// example value: [{"name": "necdet", "message": "hello", "id": 1}, {"name": "kemal", "message": "hi", "id": 2}]
-    /// 
-    ObjectArray(&'a Vec<Vec<(&'a str, &'a ValueType)>>), 
-    
-    /// It's same with `ValueType` enums, just for simply passing it to that enum.
-    Initial(&'a ValueType), 
+    #[test]
+    pub fn test_count_query_and_pages(){
+        let mut query = QueryBuilder::select(vec!["s.name", "(SELECT MAX(point) FROM grades) AS max_point"]).unwrap();
+        query.table("students s")
+             .left_join("grades g", "s.id", "=", "g.student_id")
+             .where_("s.name", "=", ValueType::String("john ORDER BY x LIMIT 1".to_string()))
+             .order_by("s.name", "ASC")
+             .limit(10);
 
-    /// Mysql Json Object: It writes JSON_OBJECT() mysql function with it's synthax, such as: JSON_OBJECT('name', 'necdet', 'message', 'hello', 'id', 13). It's necessary or more accurate when working most of the json functions.
-    MysqlJsonObject(&'a Vec<(&'a str, &'a ValueType)>)
-}
+        assert_eq!(query.to_count_query().finish(), "SELECT COUNT(*) FROM students s LEFT JOIN grades g ON s.id = g.student_id WHERE s.name = 'john ORDER BY x LIMIT 1';");
 
-impl <'a>std::fmt::Display for JsonValue<'a> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            JsonValue::Array(values) => {
-                let mut json_str = "[".to_string();
+        let mut query = QueryBuilder::select(vec!["class"]).unwrap();
+        query.table("students").where_("age", ">", ValueType::Int32(10)).group_by("class").having("class", "!=", ValueType::Null).order_by("class", "ASC");
 
-                for (index, value) in values.iter().enumerate() {
-                    if index == 0 {
-                        json_str = format!("{}{}", json_str, value)
-                    } else {
-                        json_str = format!("{}, {}", json_str, value)
-                    }
-                }
+        assert_eq!(query.to_count_query().finish(), "SELECT COUNT(*) FROM (SELECT class FROM students WHERE age > 10 GROUP BY class HAVING class IS NOT NULL) AS counted_rows;");
 
-                json_str = format!("{}]", json_str);
+        let mut union_1 = QueryBuilder::select(vec!["id"]).unwrap();
+        union_1.table("teachers");
 
-                write!(f, "{}", json_str)
-            },
-            JsonValue::Object(props) => {
-                let mut json_str = "{".to_string();
+        let mut query = QueryBuilder::select(vec!["id"]).unwrap();
+        query.table("students").union(vec![union_1]);
 
-                for (index, value) in props.iter().enumerate() {
-                    if index == 0 {
-                        json_str = format!("{}\"{}\": {}", json_str, value.0, value.1)
-                    } else {
-                        json_str = format!("{}, \"{}\": {}", json_str, value.0, value.1)
-                    }
-                }
+        assert_eq!(query.to_count_query().finish(), "SELECT COUNT(*) FROM ((SELECT id FROM students) UNION (SELECT id FROM teachers)) AS counted_rows;");
 
-                json_str = format!("{}}}", json_str);
+        let mut query = QueryBuilder::select(vec!["*"]).unwrap();
+        query.table("users").time_zone(Timezone::Istanbul).where_("id", ">", ValueType::Int32(5));
 
-                write!(f, "{}", json_str)
-            },
-            JsonValue::MysqlJsonObject(props) => {
-                let mut json_str = "JSON_OBJECT(".to_string();
+        let (select, count) = query.page(1, 15);
 
-                for (index, value) in props.iter().enumerate() {
-                    if index == 0 {
-                        json_str = format!("{}'{}', {}", json_str, value.0, value.1)
-                    } else {
-                        json_str = format!("{}, '{}', {}", json_str, value.0, value.1)
-                    }
-                }
+        assert_eq!(select, "SET time_zone = 'Europe/Istanbul'; SELECT * FROM users WHERE id > 5 LIMIT 15 OFFSET 0;");
+        assert_eq!(count, "SET time_zone = 'Europe/Istanbul'; SELECT COUNT(*) FROM users WHERE id > 5;");
 
-                json_str = format!("{})", json_str);
+        let mut query = QueryBuilder::select(vec!["ad"]).unwrap();
+        query.table("öğrenciler").where_("yol", "=", ValueType::String("C:\\ (".to_string())).order_by("ad", "ASC");
 
-                write!(f, "{}", json_str)
-            },
-            JsonValue::ObjectArray(array) => {
-                let mut json_str = "[".to_string();
+        assert_eq!(query.to_count_query().finish(), "SELECT COUNT(*) FROM öğrenciler WHERE yol = 'C:\\ (';");
 
-                for (index1, object) in array.into_iter().enumerate() {
-                    let mut object_str = "{".to_string();
+        let mut query = QueryBuilder::select(vec!["ad"]).unwrap();
+        query.table("öğrenciler").where_("ad", "=", ValueType::String("x FROM y".to_string())).select_expr(Expr::col("soyad"), None);
 
-                    for (index2, property) in object.into_iter().enumerate() {
-                        if index2 == 0 {
-                            object_str = format!("{}\"{}\": {}", object_str, property.0, property.1)
-                        } else {
-                            object_str = format!("{}, \"{}\": {}", object_str, property.0, property.1)
-                        }
-                    }
+        assert_eq!(query.finish(), "SELECT ad, soyad FROM öğrenciler WHERE ad = 'x FROM y';");
+        assert_eq!(query.to_count_query().finish(), "SELECT COUNT(*) FROM öğrenciler WHERE ad = 'x FROM y';");
 
-                    object_str = format!("{}}}", object_str);
+        let result = std::panic::catch_unwind(|| QueryBuilder::select(vec!["*"]).unwrap().table("users").page(0, 10));
 
-                    if index1 == 0 {
-                        json_str = format!("{}{}", json_str, object_str)
-                    } else {
-                        json_str = format!("{}, {}", json_str, object_str)
-                    }
-                }
+        assert!(result.is_err());
+    }
 
-                write!(f, "{}]", json_str)
-            },
-            JsonValue::Initial(value) => write!(f, "{}", value.to_string())
-        }
+    #[test]
+    pub fn test_expressions(){
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                 .table("posts")
+                                 .where_("updated_at", ">", Expr::col("created_at"))
+                                 .and("published_at", "<=", SqlKeyword::CurrentTimestamp)
+                                 .or("views", ">=", Expr::col("likes") * 10)
+                                 .finish();
+
+        assert_eq!(query, "SELECT * FROM posts WHERE updated_at > created_at AND published_at <= CURRENT_TIMESTAMP OR views >= likes * 10;");
+
+        let query = QueryBuilder::update().unwrap()
+                                 .table("products")
+                                 .set("views", Expr::col("views") + 1)
+                                 .set("price", (Expr::col("price") - Expr::col("discount")) * ValueType::Float64(1.2))
+                                 .set("updated_at", Expr::Keyword(SqlKeyword::Now))
+                                 .set("slug", Expr::func("LOWER", vec![Expr::col("name")]))
+                                 .where_("id", "=", 5)
+                                 .finish();
+
+        assert_eq!(query, "UPDATE products SET views = views + 1, price = (price - discount) * 1.2, updated_at = NOW(), slug = LOWER(name) WHERE id = 5;");
+
+        let query = QueryBuilder::update().unwrap()
+                                 .dialect(Dialect::Sqlite)
+                                 .table("sessions")
+                                 .set("touched_at", SqlKeyword::Now)
+                                 .where_("expires_at", ">", ValueType::Datetime("CURRENT_TIMESTAMP".to_string()))
+                                 .finish();
+
+        assert_eq!(query, "UPDATE sessions SET touched_at = DATETIME('now') WHERE expires_at > CURRENT_TIMESTAMP;");
+
+        let query = QueryBuilder::select(vec!["category", "COUNT(*)"]).unwrap()
+                                 .table("products")
+                                 .group_by("category")
+                                 .having("COUNT(*)", ">", Expr::func("AVG", vec![Expr::col("stock")]))
+                                 .finish();
+
+        assert_eq!(query, "SELECT category, COUNT(*) FROM products GROUP BY category HAVING COUNT(*) > AVG(stock);");
+        assert_eq!(ValueType::Datetime("NOW()".to_string()).to_string(), "NOW()");
+        assert_eq!(ValueType::Datetime("2024-01-01".to_string()).to_string(), "'2024-01-01'");
     }
-}
 
-/// Timezones with Unix Timezone format, Can be used for setting timezone manually.
-/// It covers european, russian, north american, south american, arabic countries. In next releases, we'll cover other african and asian timezones. 
-#[derive(Debug, Clone)]
-pub enum Timezone {
-    System, Istanbul, Moscow, Kaliningrad, Samara, Ekaterinburg, Omsk, Krasnoyarsk, Irkutsk, Yakutsk,
-    Vladivostok, Magadan, Kamchatka, Shanghai, London, Paris, Berlin, Madrid, Rome, Amsterdam, Stockholm, Oslo,
-    Helsinki, Athens, NewYork, Chicago, Denver, LosAngeles, Anchorage, Honolulu, PuertoRico, Riyadh, Dubai, Qatar,
-    Kuwait, Bahrain, Muscat, Aden, Baghdad, Amman, Beirut, Damascus, Gaza, Hebron, Cairo, Khartoum, Tripoli,
-    Tunis, BuenosAires, LaPaz, SaoPaulo, Manaus, Recife, Cuiaba, PortoVelho, Santiago, Easter, Bogota, Guayaquil,
-    Galapagos, Guyana, Asuncion, Lima, Paramaribo, Montevideo, Caracas, StJohns, Halifax, Toronto, Winnipeg,
-    Edmonton, Vancouver, WhiteHorse, MexicoCity, Mazatlan, Chihuahua, Tijuana, Cancun, Belize, CostaRica, ElSalvador,
-    Guatemala, Tegucigalpa, Managua, Panama, Apia, Auckland, Bougainville, Chatham, Efate, Enderbury, Fakaofo,
-    Fiji, Funafuti, Gambier, Guadalcanal, Guam, Johnston, Kanton, Kiritimati, Kosrae, Kwajalein, Majuro, Marquesas,
-    Midway, Nauru, Niue, Norfolk, Noumea, PagoPago, Palau, Pitcairn, Pohnpei, PortMoresby, Saipan, Rarotonga, Tahiti,
-    Tarawa, Truk, Wake, Wallis, Yap, Tongatapu
-}
+    #[test]
+    pub fn test_date_functions(){
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                 .table("orders")
+                                 .where_("created_at", ">=", Expr::date_sub(SqlKeyword::Now, 7, IntervalUnit::Day))
+                                 .finish();
 
-impl std::fmt::Display for Timezone {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Timezone::System => write!(f, "SYSTEM"), Timezone::Istanbul => write!(f, "Europe/Istanbul"), Timezone::Moscow => write!(f, "Europe/Moscow"),
-            Timezone::Kaliningrad => write!(f, "Europe/Kaliningrad"), Timezone::Samara => write!(f, "Europe/Samara"), Timezone::Ekaterinburg => write!(f, "Asia/Yekaterinburg"),
-            Timezone::Omsk => write!(f, "Asia/Omsk"), Timezone::Krasnoyarsk => write!(f, "Asia/Krasnoyarsk"), Timezone::Irkutsk => write!(f, "Asia/Irkutsk"),
-            Timezone::Yakutsk => write!(f, "Asia/Yakutsk"), Timezone::Vladivostok => write!(f, "Asia/Vladivostok"), Timezone::Magadan => write!(f, "Asia/Magadan"),
-            Timezone::Kamchatka => write!(f, "Asia/Kamchatka"), Timezone::Shanghai => write!(f, "Asia/Shanghai"), Timezone::London => write!(f, "Europe/London"),
-            Timezone::Paris => write!(f, "Europe/Paris"), Timezone::Berlin => write!(f, "Europe/Berlin"), Timezone::Madrid => write!(f, "Europe/Madrid"),
-            Timezone::Rome => write!(f, "Europe/Rome"), Timezone::Amsterdam => write!(f, "Europe/Amsterdam"), Timezone::Stockholm => write!(f, "Europe/Stockholm"),
-            Timezone::Oslo => write!(f, "Europe/Oslo"), Timezone::Helsinki => write!(f, "Europe/Helsinki"), Timezone::Athens => write!(f, "Europe/Athens"),
-            Timezone::NewYork => write!(f, "America/New_York"), Timezone::Chicago => write!(f, "America/Chicago"), Timezone::Denver => write!(f, "America/Denver"),
-            Timezone::LosAngeles => write!(f, "America/Los_Angeles"), Timezone::Anchorage => write!(f, "America/Anchorage"), Timezone::Honolulu => write!(f, "Pacific/Honolulu"),
-            Timezone::PuertoRico => write!(f, "America/Puerto_Rico"), Timezone::Riyadh => write!(f, "Asia/Riyadh"), Timezone::Dubai => write!(f, "Asia/Dubai"),
-            Timezone::Qatar => write!(f, "Asia/Qatar"), Timezone::Kuwait => write!(f, "Asia/Kuwait"), Timezone::Bahrain => write!(f, "Asia/Bahrain"), Timezone::Muscat => write!(f, "Asia/Muscat"),
-            Timezone::Aden => write!(f, "Asia/Aden"), Timezone::Baghdad => write!(f, "Asia/Baghdad"), Timezone::Amman => write!(f, "Asia/Amman"), Timezone::Beirut => write!(f, "Asia/Beirut"),
-            Timezone::Damascus => write!(f, "Asia/Damascus"), Timezone::Gaza => write!(f, "Asia/Gaza"), Timezone::Hebron => write!(f, "Asia/Hebron"), Timezone::Cairo => write!(f, "Africa/Cairo"),
-            Timezone::Khartoum => write!(f, "Africa/Khartoum"), Timezone::Tripoli => write!(f, "Africa/Tripoli"), Timezone::Tunis => write!(f, "Africa/Tunis"),
-            Timezone::BuenosAires => write!(f, "America/Argentina/Buenos_Aires"), Timezone::LaPaz => write!(f, "America/La_Paz"), Timezone::SaoPaulo => write!(f, "America/Sao_Paulo"),
-            Timezone::Manaus => write!(f, "America/Manaus"), Timezone::Recife => write!(f, "America/Recife"), Timezone::Cuiaba => write!(f, "America/Cuiaba"), Timezone::PortoVelho => write!(f, "America/Porto_Velho"),
-            Timezone::Santiago => write!(f, "America/Santiago"), Timezone::Easter => write!(f, "Pacific/Easter"), Timezone::Bogota => write!(f, "America/Bogota"), Timezone::Guayaquil => write!(f, "America/Guayaquil"),
-            Timezone::Galapagos => write!(f, "Pacific/Galapagos"), Timezone::Guyana => write!(f, "America/Guyana"), Timezone::Asuncion => write!(f, "America/Asuncion"), Timezone::Lima => write!(f, "America/Lima"),
-            Timezone::Paramaribo => write!(f, "America/Paramaribo"), Timezone::Montevideo => write!(f, "America/Montevideo"), Timezone::Caracas => write!(f, "America/Caracas"),
-            Timezone::StJohns => write!(f, "America/St_Johns"), Timezone::Halifax => write!(f, "America/Halifax"), Timezone::Toronto => write!(f, "America/Toronto"), Timezone::Winnipeg => write!(f, "America/Winnipeg"),
-            Timezone::Edmonton => write!(f, "America/Edmonton"), Timezone::Vancouver => write!(f, "America/Vancouver"), Timezone::WhiteHorse => write!(f, "America/Whitehorse"),
-            Timezone::MexicoCity => write!(f, "America/Mexico_City"), Timezone::Mazatlan => write!(f, "America/Mazatlan"), Timezone::Chihuahua => write!(f, "America/Chihuahua"),
-            Timezone::Tijuana => write!(f, "America/Tijuana"), Timezone::Cancun => write!(f, "America/Cancun"), Timezone::Belize => write!(f, "America/Belize"), Timezone::CostaRica => write!(f, "America/Costa_Rica"),
-            Timezone::ElSalvador => write!(f, "America/El_Salvador"), Timezone::Guatemala => write!(f, "America/Guatemala"), Timezone::Tegucigalpa => write!(f, "America/Tegucigalpa"),
-            Timezone::Managua => write!(f, "America/Managua"), Timezone::Panama => write!(f, "America/Panama"), Timezone::Apia => write!(f, "Pacific/Apia"),
-            Timezone::Auckland => write!(f, "Pacific/Auckland"), Timezone::Bougainville => write!(f, "Pacific/Bougainville"), Timezone::Chatham => write!(f, "Pacific/Chatham"),
-            Timezone::Efate => write!(f, "Pacific/Efate"), Timezone::Enderbury => write!(f, "Pacific/Enderbury"), Timezone::Tongatapu => write!(f, "Pacific/Tongatapu"),
-            Timezone::Fakaofo => write!(f, "Pacific/Fakaofo"), Timezone::Fiji => write!(f, "Pacific/Fiji"), Timezone::Funafuti => write!(f, "Pacific/Funafuti"),
-            Timezone::Gambier => write!(f, "Pacific/Gambier"), Timezone::Guadalcanal => write!(f, "Pacific/Guadalcanal"), Timezone::Guam => write!(f, "Pacific/Guam"),
-            Timezone::Johnston => write!(f, "Pacific/Johnston"), Timezone::Kanton => write!(f, "Pacific/Kanton"), Timezone::Kiritimati => write!(f, "Pacific/Kiritimati"),
-            Timezone::Kosrae => write!(f, "Pacific/Kosrae"), Timezone::Majuro => write!(f, "Pacific/Majuro"), Timezone::Kwajalein => write!(f, "Pacific/Kwajalein"),
-            Timezone::Midway => write!(f, "Pacific/Midway"), Timezone::Nauru => write!(f, "Pacific/Nauru"), Timezone::Niue => write!(f, "Pacific/Niue"),
-            Timezone::Marquesas => write!(f, "Pacific/Marquesas"), Timezone::Norfolk => write!(f, "Pacific/Norfolk"), Timezone::PagoPago => write!(f, "Pacific/Pago_Pago"),
-            Timezone::Noumea => write!(f, "Pacific/Noumea"), Timezone::Palau => write!(f, "Pacific/Palau"), Timezone::Pitcairn => write!(f, "Pacific/Pitcairn"),
-            Timezone::Pohnpei => write!(f, "Pacific/Pohnpei"), Timezone::PortMoresby => write!(f, "Pacific/Port_Moresby"), Timezone::Rarotonga => write!(f, "Pacific/Rarotonga"),
-            Timezone::Tahiti => write!(f, "Pacific/Tahiti"), Timezone::Tarawa => write!(f, "Pacific/Tarawa"), Timezone::Saipan => write!(f, "Pacific/Saipan"),
-            Timezone::Truk => write!(f, "Pacific/Truk"), Timezone::Wake => write!(f, "Pacific/Wake"), Timezone::Wallis => write!(f, "Pacific/Wallis"),
-            Timezone::Yap => write!(f, "Pacific/Yap")
-        }
+        assert_eq!(query, "SELECT * FROM orders WHERE created_at >= DATE_SUB(NOW(), INTERVAL 7 DAY);");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                 .dialect(Dialect::Postgresql)
+                                 .table("orders")
+                                 .where_("created_at", ">=", Expr::date_sub(SqlKeyword::Now, 7, IntervalUnit::Day))
+                                 .finish();
+
+        assert_eq!(query, "SELECT * FROM orders WHERE created_at >= NOW() - INTERVAL '7 DAY';");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                 .dialect(Dialect::Sqlite)
+                                 .table("orders")
+                                 .where_("created_at", ">=", Expr::date_sub(SqlKeyword::Now, 2, IntervalUnit::Week))
+                                 .finish();
+
+        assert_eq!(query, "SELECT * FROM orders WHERE created_at >= DATETIME(DATETIME('now'), '-14 days');");
+
+        let query = QueryBuilder::update().unwrap()
+                                 .table("subscriptions")
+                                 .set("expires_at", Expr::date_add(Expr::col("expires_at"), 1, IntervalUnit::Quarter))
+                                 .where_("id", "=", 3)
+                                 .finish();
+
+        assert_eq!(query, "UPDATE subscriptions SET expires_at = DATE_ADD(expires_at, INTERVAL 1 QUARTER) WHERE id = 3;");
+
+        let query = QueryBuilder::select(vec!["id"]).unwrap()
+                                 .select_expr(Expr::date(Expr::col("created_at")), Some("day"))
+                                 .select_expr(Expr::timestamp_diff(IntervalUnit::Hour, Expr::col("created_at"), Expr::col("shipped_at")), Some("hours"))
+                                 .select_expr(Expr::extract(IntervalUnit::Year, Expr::col("created_at")), None)
+                                 .table("orders")
+                                 .where_("UNIX_TIMESTAMP(created_at)", ">", Expr::unix_timestamp(Expr::convert_tz(Expr::col("updated_at"), Timezone::Istanbul, Timezone::London)))
+                                 .finish();
+
+        assert_eq!(query, "SELECT id, DATE(created_at) AS day, TIMESTAMPDIFF(HOUR, created_at, shipped_at) AS hours, EXTRACT(YEAR FROM created_at) FROM orders WHERE UNIX_TIMESTAMP(created_at) > UNIX_TIMESTAMP(CONVERT_TZ(updated_at, 'Europe/Istanbul', 'Europe/London'));");
+
+        let format = Expr::date_format(Expr::col("created_at"), "%Y-%m-%d %H:%i");
+        let diff = Expr::timestamp_diff(IntervalUnit::Day, Expr::col("created_at"), Expr::col("shipped_at"));
+
+        assert_eq!(format.to_sql(&Dialect::Postgresql), "TO_CHAR(created_at, 'YYYY-MM-DD HH24:MI')");
+        assert_eq!(format.to_sql(&Dialect::Sqlite), "STRFTIME('%Y-%m-%d %H:%M', created_at)");
+        assert_eq!(Expr::date_format(Expr::col("created_at"), "%Y'); DROP TABLE users; --").to_sql(&Dialect::Mysql), "DATE_FORMAT(created_at, '%Y''); DROP TABLE users; --')");
+        assert_eq!(Expr::date_format(Expr::col("created_at"), "%d 'of' %m").to_sql(&Dialect::Sqlite), "STRFTIME('%d ''of'' %m', created_at)");
+        assert_eq!(diff.to_sql(&Dialect::Postgresql), "FLOOR(EXTRACT(EPOCH FROM (shipped_at - created_at)) / 86400)");
+        assert_eq!(diff.to_sql(&Dialect::Sqlite), "CAST((JULIANDAY(shipped_at) - JULIANDAY(created_at)) * 86400 / 86400 AS INTEGER)");
+        assert_eq!(Expr::date(Expr::col("created_at")).to_sql(&Dialect::Postgresql), "CAST(created_at AS DATE)");
+        assert_eq!(Expr::extract(IntervalUnit::Month, Expr::col("created_at")).to_sql(&Dialect::Sqlite), "CAST(STRFTIME('%m', created_at) AS INTEGER)");
     }
-}
 
-/// Enum that benefits you to define what you want with a foreign key.
-#[derive(Debug, Clone)]
-pub enum ForeignKeyActions {
-    Cascade, Restrict, SetNull, NoAction, SetDefault
-}
+    #[test]
+    pub fn test_string_and_null_functions(){
+        let query = QueryBuilder::select(vec!["id"]).unwrap()
+                                 .select_expr(Expr::concat_ws(String::from(" "), vec![Expr::col("name"), Expr::col("surname")]), Some("full_name"))
+                                 .select_expr(Expr::coalesce(vec![Expr::col("nickname"), Expr::col("name"), Expr::val(String::from("anonymous"))]), Some("display_name"))
+                                 .table("users")
+                                 .where_("LOWER(email)", "=", Expr::lower(Expr::trim(String::from(" Foo@Bar.com "))))
+                                 .and("age", ">", Expr::cast(Expr::if_null(Expr::col("min_age"), 18), CastType::Unsigned))
+                                 .finish();
 
-impl std::fmt::Display for ForeignKeyActions {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            &ForeignKeyActions::Cascade => write!(f, "CASCADE"),
-            &ForeignKeyActions::NoAction => write!(f, "NO ACTION"),
-            &ForeignKeyActions::Restrict => write!(f, "RESTRICT"),
-            &ForeignKeyActions::SetNull => write!(f, "SET NULL"),
-            &ForeignKeyActions::SetDefault => write!(f, "SET DEFAULT")
-        }
+        assert_eq!(query, "SELECT id, CONCAT_WS(' ', name, surname) AS full_name, COALESCE(nickname, name, 'anonymous') AS display_name FROM users WHERE LOWER(email) = LOWER(TRIM(' Foo@Bar.com ')) AND age > CAST(IFNULL(min_age, 18) AS UNSIGNED);");
+
+        let query = QueryBuilder::update().unwrap()
+                                 .dialect(Dialect::Postgresql)
+                                 .table("users")
+                                 .set("code", Expr::upper(Expr::substring(Expr::col("name"), 1, Some(3))))
+                                 .set("title", Expr::concat(vec![Expr::col("name"), Expr::val(String::from(" - ")), Expr::col("role")]))
+                                 .set("manager_id", Expr::null_if(Expr::col("manager_id"), 0))
+                                 .where_("LENGTH(name)", ">", Expr::length(Expr::if_null(Expr::col("nickname"), String::new())))
+                                 .finish();
+
+        assert_eq!(query, "UPDATE users SET code = UPPER(SUBSTRING(name FROM 1 FOR 3)), title = (name || ' - ' || role), manager_id = NULLIF(manager_id, 0) WHERE LENGTH(name) > LENGTH(COALESCE(nickname, ''));");
+
+        assert_eq!(Expr::substring(Expr::col("name"), 2, None).to_sql(&Dialect::Sqlite), "SUBSTR(name, 2)");
+        assert_eq!(Expr::cast(Expr::col("price"), CastType::Decimal(10, 2)).to_sql(&Dialect::Sqlite), "CAST(price AS NUMERIC)");
+        assert_eq!(Expr::cast(Expr::col("created_at"), CastType::Date).to_sql(&Dialect::Sqlite), "DATE(created_at)");
+        assert_eq!(Expr::cast(Expr::col("id"), CastType::Text).to_sql(&Dialect::Mysql), "CAST(id AS CHAR)");
+        assert_eq!(Expr::concat_ws(String::from(" "), vec![Expr::col("name"), Expr::col("surname")]).to_sql(&Dialect::Sqlite), "SUBSTR(COALESCE(' ' || name, '') || COALESCE(' ' || surname, ''), LENGTH(' ') + 1)");
+        assert!(matches!(Expr::func("MAX", vec![Expr::col("id")]), Expr::Function(function) if matches!(function.as_ref(), FunctionExpr::Call(_, _))));
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    #[test]
+    pub fn test_spatial_queries(){
+        let query = QueryBuilder::select(vec!["id", "name"]).unwrap()
+                                 .select_expr(Expr::st_distance_sphere(Expr::col("location"), Geometry::Point(28.97, 41.01)), Some("distance"))
+                                 .table("stores")
+                                 .where_("active", "=", true)
+                                 .within_radius("location", 41.01, 28.97, 2500.5)
+                                 .order_by("distance", "ASC")
+                                 .finish();
+
+        assert_eq!(query, "SELECT id, name, ST_Distance_Sphere(location, POINT(28.97, 41.01)) AS distance FROM stores WHERE (active = true) AND ST_Distance_Sphere(location, POINT(28.97, 41.01)) <= 2500.5 ORDER BY distance ASC;");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                 .dialect(Dialect::Postgresql)
+                                 .table("stores")
+                                 .within_radius("location", 41.01, 28.97, 1000.0)
+                                 .finish();
+
+        assert_eq!(query, "SELECT * FROM stores WHERE ST_DistanceSphere(location, ST_GeomFromText('POINT(28.97 41.01)', 4326)) <= 1000;");
+
+        let query = QueryBuilder::select(vec!["city", "COUNT(*)"]).unwrap()
+                                 .table("stores")
+                                 .where_("name", "=", ValueType::String("a WHERE b".to_string()))
+                                 .or("active", "=", true)
+                                 .group_by("city")
+                                 .within_radius("location", 41.01, 28.97, 1000.0)
+                                 .finish();
+
+        assert_eq!(query, "SELECT city, COUNT(*) FROM stores WHERE (name = 'a WHERE b' OR active = true) AND ST_Distance_Sphere(location, POINT(28.97, 41.01)) <= 1000 GROUP BY city;");
+
+        let area = Geometry::Polygon(vec![vec![(0.0, 0.0), (10.0, 0.0), (10.0, 10.0), (0.0, 10.0)]]);
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                 .table("parcels")
+                                 .where_predicate(Expr::st_within(Expr::col("shape"), Expr::geometry(area.clone(), Some(4326))))
+                                 .where_predicate(Expr::mbr_contains(Expr::col("bounds"), Geometry::LineString(vec![(1.0, 1.0), (2.5, 3.0)])))
+                                 .finish();
+
+        assert_eq!(query, "SELECT * FROM parcels WHERE (ST_Within(shape, ST_GeomFromText('POLYGON((0 0, 10 0, 10 10, 0 10, 0 0))', 4326))) AND MBRContains(bounds, ST_GeomFromText('LINESTRING(1 1, 2.5 3)'));");
 
-    #[test]
-    pub fn test_schema_query_declarative(){
-        let schema = SchemaBuilder::create("blog_website").unwrap().if_not_exists().finish();
+        let query = QueryBuilder::update().unwrap()
+                                 .table("stores")
+                                 .set("location", Expr::geometry(Geometry::Wkt("POINT(41.01 28.97)".to_string()), Some(4326)))
+                                 .where_("id", "=", 1)
+                                 .finish();
 
-        assert_eq!("CREATE DATABASE IF NOT EXISTS blog_website;", schema);
+        assert_eq!(query, "UPDATE stores SET location = ST_GeomFromText('POINT(41.01 28.97)', 4326) WHERE id = 1;");
+        assert_eq!(Expr::mbr_contains(Expr::col("bounds"), Expr::col("shape")).to_sql(&Dialect::Postgresql), "(bounds ~ shape)");
+        assert_eq!(Expr::st_as_text(Expr::col("shape")).to_sql(&Dialect::Mysql), "ST_AsText(shape)");
     }
 
     #[test]
-    pub fn test_schema_query_imperative(){
-        let mut schema = SchemaBuilder::create("blog_website").unwrap();
-        schema.if_not_exists();
-        let schema_query = schema.finish();
+    pub fn test_scripts_and_transactions(){
+        let mut select = QueryBuilder::select(vec!["*"]).unwrap();
+        select.table("orders").where_("note", "=", ValueType::String("a; b".to_string())).time_zone(Timezone::Istanbul);
 
-        assert_eq!("CREATE DATABASE IF NOT EXISTS blog_website;", schema_query);
+        let mut script = Script::transaction(None, true);
+        script.query(&select).commit();
+
+        assert_eq!(script.statements(), vec!["START TRANSACTION READ ONLY", "SET time_zone = 'Europe/Istanbul'", "SELECT * FROM orders WHERE note = 'a; b'", "COMMIT"]);
+
+        let mut insert = QueryBuilder::insert(vec!["id", "total"], vec![ValueType::Int32(1), ValueType::Int32(100)]).unwrap();
+        insert.table("orders");
+
+        let mut script = Script::new();
+        script.dialect(Dialect::Postgresql)
+              .start_transaction(Some(IsolationLevel::RepeatableRead), true)
+              .set("statement_timeout", 5000)
+              .query(&insert)
+              .savepoint("inserted")
+              .rollback_to("inserted")
+              .release_savepoint("inserted")
+              .rollback();
+
+        assert_eq!(script.finish(), "START TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY; SET statement_timeout = 5000; INSERT INTO orders (id, total) VALUES (1, 100); SAVEPOINT inserted; ROLLBACK TO SAVEPOINT inserted; RELEASE SAVEPOINT inserted; ROLLBACK;");
+
+        assert!(std::panic::catch_unwind(|| Script::new().set("x = 1; DROP TABLE users; SET y", 1).finish()).is_err());
+        assert!(std::panic::catch_unwind(|| Script::new().dialect(Dialect::Sqlite).set("foreign_keys", 1).finish()).is_err());
+
+        let schema = SchemaBuilder::create("shop").unwrap();
+
+        let mut script = Script::transaction(None, false);
+        script.schema(&schema).commit();
+
+        assert_eq!(script.finish(), "START TRANSACTION; CREATE DATABASE shop; COMMIT;");
+
+        let result = std::panic::catch_unwind(|| Script::new().dialect(Dialect::Sqlite).schema(&schema).finish());
+
+        assert!(result.is_err());
+
+        let mut table = TableBuilder::create("", "notes");
+        table.add_column("body").col_type("TEXT").custom_query("DEFAULT 'a; b'");
+
+        let mut script = Script::new();
+        script.table(&table);
+
+        assert_eq!(script.statements(), vec!["CREATE TABLE notes (body TEXT DEFAULT 'a; b')"]);
     }
 
     #[test]
-    pub fn test_use_another_schema(){
-        let schema = SchemaBuilder::use_another_schema("chat_website").unwrap().finish();
+    pub fn test_set_statements(){
+        let mut variables = SetBuilder::new();
+        variables.session(SessionVariable::TimeZone(Timezone::Istanbul))
+                 .session(SessionVariable::GroupConcatMaxLen(1000000))
+                 .global(SessionVariable::Custom("max_connections".to_string(), ValueType::Int32(500)));
 
-        assert_eq!("USE chat_website;", schema);
+        assert_eq!(variables.finish(), "SET time_zone = 'Europe/Istanbul', group_concat_max_len = 1000000, GLOBAL max_connections = 500;");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                 .table("users")
+                                 .set_variables(&variables)
+                                 .time_zone(Timezone::London)
+                                 .finish();
+
+        assert_eq!(query, "SET time_zone = 'Europe/London'; SET time_zone = 'Europe/Istanbul', group_concat_max_len = 1000000, GLOBAL max_connections = 500; SELECT * FROM users;");
+
+        let mut query = QueryBuilder::select(vec!["*"]).unwrap();
+        query.table("users").where_("id", ">", 5).set_variables(&variables).time_zone(Timezone::London);
+
+        assert_eq!(query.to_count_query().finish(), "SET time_zone = 'Europe/London'; SET time_zone = 'Europe/Istanbul', group_concat_max_len = 1000000, GLOBAL max_connections = 500; SELECT COUNT(*) FROM users WHERE id > 5;");
+
+        let query = QueryBuilder::select(vec!["COUNT(*)", "MAX(id)"]).unwrap()
+                                 .table("users")
+                                 .into_variables(vec!["total", "last_id"])
+                                 .finish();
+
+        assert_eq!(query, "SELECT COUNT(*), MAX(id) FROM users INTO @total, @last_id;");
+
+        let mut user_variables = SetBuilder::new();
+        user_variables.user_variable("since", Expr::date_sub(SqlKeyword::Now, 1, IntervalUnit::Day));
+
+        let mut script = Script::new();
+        script.set_variables(&user_variables).set_variables(SetBuilder::new().session(SessionVariable::ForeignKeyChecks(true)));
+
+        assert_eq!(script.finish(), "SET @since = DATE_SUB(NOW(), INTERVAL 1 DAY); SET foreign_key_checks = 1;");
+
+        let mut quoted = SetBuilder::new();
+        quoted.session(SessionVariable::Custom("lc_messages".to_string(), ValueType::String("it's".to_string())))
+              .session(SessionVariable::Custom("init_connect".to_string(), ValueType::Datetime("2024-01-01' OR '1".to_string())))
+              .user_variable("name", Expr::concat(vec![Expr::col("first"), Expr::from(String::from("\\' OR 1"))]));
+
+        assert_eq!(quoted.finish(), "SET lc_messages = 'it''s', init_connect = '2024-01-01'' OR ''1', @name = CONCAT(first, '\\\\'' OR 1');");
     }
 
     #[test]
-    pub fn test_insert_query(){
-        let columns = vec!["title", "author", "description"];
-        let values = vec![ValueType::String("What's Up?".to_string()), ValueType::String("John Doe".to_string()), ValueType::String("Lorem ipsum dolor sit amet, consectetur adipiscing elit.".to_string())];
-    
-        let insert_query = QueryBuilder::insert(columns, values).unwrap().table("blogs").finish();
-
-        println!("{}", insert_query);
-        assert_eq!("INSERT INTO blogs (title, author, description) VALUES ('What's Up?', 'John Doe', 'Lorem ipsum dolor sit amet, consectetur adipiscing elit.');".to_string(), 
-                    insert_query);
+    #[should_panic]
+    pub fn test_set_statement_validation(){
+        SetBuilder::new().session(SessionVariable::GroupConcatMaxLen(2));
     }
 
     #[test]
-    pub fn test_update_query(){
-        let update_query = QueryBuilder::update().unwrap().table("blogs").set("title", ValueType::String("Hello Rust!".to_string())).set("author", ValueType::String("Necdet".to_string())).finish();
+    pub fn test_prepared_statements(){
+        let query = QueryBuilder::update().unwrap()
+                                 .table("users")
+                                 .set("bio", ValueType::String("it is \\ ok".to_string()))
+                                 .set("score", Expr::col("score2") + 1.5)
+                                 .where_("created_at", "<", Expr::date_sub(SqlKeyword::Now, 7, IntervalUnit::Day))
+                                 .and("`level 1`", "=", 3)
+                                 .time_zone(Timezone::Istanbul)
+                                 .clone();
 
-        assert_eq!("UPDATE blogs SET title = 'Hello Rust!', author = 'Necdet';", update_query);
+        let statement = PreparedStatement::from_query("update_users", &query).unwrap();
+
+        assert_eq!(statement.statement, "UPDATE users SET bio = ?, score = score2 + ? WHERE created_at < DATE_SUB(NOW(), INTERVAL 7 DAY) AND `level 1` = ?");
+        assert_eq!(statement.parameters, vec!["'it is \\ ok'", "1.5", "3"]);
+        assert_eq!(statement.statements(), vec![
+            "SET time_zone = 'Europe/Istanbul'",
+            "PREPARE update_users FROM 'UPDATE users SET bio = ?, score = score2 + ? WHERE created_at < DATE_SUB(NOW(), INTERVAL 7 DAY) AND `level 1` = ?'",
+            "SET @p1 = 'it is \\ ok', @p2 = 1.5, @p3 = 3",
+            "EXECUTE update_users USING @p1, @p2, @p3",
+            "DEALLOCATE PREPARE update_users"
+        ]);
+
+        let query = QueryBuilder::select(vec!["id"]).unwrap()
+                                 .select_expr(Expr::cast(Expr::col("price"), CastType::Decimal(10, 2)), Some("price"))
+                                 .table("products")
+                                 .where_("name", "=", ValueType::String("a \"b\"".to_string()))
+                                 .and("`code`", "=", ValueType::JsonString("x".to_string()))
+                                 .limit(10)
+                                 .clone();
+
+        let statement = PreparedStatement::from_query("products", &query).unwrap();
+
+        assert_eq!(statement.prepare(), "PREPARE products FROM 'SELECT id, CAST(price AS DECIMAL(10, 2)) AS price FROM products WHERE name = ? AND `code` = ? LIMIT 10'");
+        assert_eq!(statement.parameters, vec!["'a \"b\"'", "\"x\""]);
+
+        let mut archived = QueryBuilder::select(vec!["id"]).unwrap();
+        archived.table("archived_orders").where_("path", "=", ValueType::String("C:\\".to_string()));
+
+        let query = QueryBuilder::select(vec!["status", "COUNT(*)", "CURTIME(3)"]).unwrap()
+                                 .table("orders")
+                                 .where_in("status", &vec![ValueType::Int32(1), ValueType::Null, ValueType::String("it's".to_string())])
+                                 .like(vec!["note"], "x 1")
+                                 .paginate_after(vec!["id"], vec![ValueType::Int32(40)], "ASC", 5)
+                                 .group_by("1")
+                                 .order_by("2", "DESC")
+                                 .clone();
+
+        let statement = PreparedStatement::from_query("orders", &query).unwrap();
+
+        assert_eq!(statement.statement, "SELECT status, COUNT(*), CURTIME(3) FROM orders WHERE (status IN (?, NULL, ?) AND note LIKE ?) AND id > ? GROUP BY 1 ORDER BY id ASC, 2 DESC LIMIT 5");
+        assert_eq!(statement.parameters, vec!["1", "'it's'", "'%x 1%'", "40"]);
+
+        let query = QueryBuilder::select(vec!["id"]).unwrap()
+                                 .table("orders")
+                                 .where_("total", ">", 100)
+                                 .union(vec![archived])
+                                 .clone();
+
+        let statement = PreparedStatement::from_query("all_orders", &query).unwrap();
+
+        assert_eq!(statement.statement, "(SELECT id FROM orders WHERE total > ?) UNION (SELECT id FROM archived_orders WHERE path = ?)");
+        assert_eq!(statement.parameters, vec!["100", "'C:\\'"]);
+
+        let insert = QueryBuilder::insert(vec!["id", "created_at"], vec![ValueType::Int32(7), ValueType::Datetime("CURRENT_TIMESTAMP".to_string())]).unwrap().table("orders").clone();
+        let statement = PreparedStatement::from_query("add_order", &insert).unwrap();
+
+        assert_eq!(statement.statement, "INSERT INTO orders (id, created_at) VALUES (?, CURRENT_TIMESTAMP)");
+        assert_eq!(statement.parameters, vec!["7"]);
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap().table("users").clone();
+        let statement = PreparedStatement::from_query("all_users", &query).unwrap();
+
+        let mut script = Script::new();
+        script.prepared(&statement);
+
+        assert_eq!(script.finish(), "PREPARE all_users FROM 'SELECT * FROM users'; EXECUTE all_users; DEALLOCATE PREPARE all_users;");
+        assert!(PreparedStatement::from_query("all users", &query).is_err());
+
+        let mut rewritten = QueryBuilder::select(vec!["*"]).unwrap().table("users").where_("age", ">", 25).clone();
+        rewritten.query = rewritten.query.replace("age > 25", "age > 26");
+
+        assert!(PreparedStatement::from_query("rewritten", &rewritten).is_err());
+
+        let union = QueryBuilder::select(vec!["*"]).unwrap().table("admins").union(vec![rewritten]).clone();
+
+        assert_eq!(union.finish(), "(SELECT * FROM admins) UNION (SELECT * FROM users WHERE age > 26);");
+        assert!(PreparedStatement::from_query("union", &union).is_err());
     }
 
     #[test]
-    pub fn test_delete_query(){
-        let delete_query = QueryBuilder::delete().unwrap().table("blogs").where_("id", "=", ValueType::String("1".to_string())).finish();
-
-        assert_eq!("DELETE FROM blogs WHERE id = '1';", delete_query);
+    pub fn test_routines(){
+        let archive = QueryBuilder::insert_select(vec![], QueryBuilder::select(vec!["*"]).unwrap().table("orders").where_("created_at", "<", Expr::col("before"))).unwrap().table("orders_archive").clone();
+        let delete = QueryBuilder::delete().unwrap().table("orders").where_("created_at", "<", Expr::col("before")).clone();
+
+        let routine = RoutineBuilder::procedure("archive_orders").unwrap()
+                                     .or_replace()
+                                     .param(ParamMode::In, "before", "DATETIME")
+                                     .param(ParamMode::InOut, "moved", "INT")
+                                     .characteristic(RoutineCharacteristic::ModifiesSqlData)
+                                     .characteristic(RoutineCharacteristic::SqlSecurityInvoker)
+                                     .characteristic(RoutineCharacteristic::Comment("moves the old orders".to_string()))
+                                     .declare("started", "DATETIME", Some(ValueType::Datetime("NOW()".to_string())))
+                                     .query(&archive)
+                                     .statement("SET moved = ROW_COUNT();")
+                                     .query(&delete)
+                                     .clone();
+
+        assert_eq!(routine.finish(), "CREATE OR REPLACE PROCEDURE archive_orders(IN before DATETIME, INOUT moved INT) MODIFIES SQL DATA SQL SECURITY INVOKER COMMENT 'moves the old orders' BEGIN DECLARE started DATETIME DEFAULT NOW(); INSERT INTO orders_archive SELECT * FROM orders WHERE created_at < before; SET moved = ROW_COUNT(); DELETE FROM orders WHERE created_at < before; END;");
+
+        let mut script = Script::new();
+        script.routine(routine.clone().delimiter("$$"));
+
+        assert_eq!(script.statements().len(), 1);
+        assert_eq!(RoutineBuilder::drop(RoutineKind::Procedure, "archive_orders", true).unwrap(), "DROP PROCEDURE IF EXISTS archive_orders;");
+        assert_eq!(RoutineBuilder::drop(RoutineKind::Function, "full_name", false).unwrap(), "DROP FUNCTION full_name;");
+        assert_eq!(RoutineBuilder::call("archive_orders", vec![Expr::val(ValueType::Datetime("2024-01-01".to_string())), Expr::col("@moved")]).unwrap(), "CALL archive_orders('2024-01-01', @moved);");
+        assert!(RoutineBuilder::procedure("drop table; x").is_err());
+        assert_eq!(RoutineBuilder::call("rename_user", vec![Expr::val(1), Expr::from(String::from("O'Brien\\"))]).unwrap(), "CALL rename_user(1, 'O''Brien\\\\');");
+        assert!(std::panic::catch_unwind(|| RoutineBuilder::procedure("touch").unwrap().declare("started", "INT; DROP TABLE users", None).clone()).is_err());
     }
 
     #[test]
-    pub fn test_select_query_declarative(){
-        let mut select = QueryBuilder::select(["id", "title", "description", "point"].to_vec()).unwrap();
+    pub fn test_triggers(){
+        let mut products = TableBuilder::create("shop", "products");
+        products.add_column("id").col_type("INT").primary_key()
+                .add_column("price").col_type("DECIMAL(10, 2)")
+                .add_column("updated_at").col_type("DATETIME");
 
-        let select_query = select.table("blogs")
-                                    .where_("id", "=", ValueType::Int32(10))
-                                    .and("point", ">", ValueType::Int8(90))
-                                    .or("id", "=", ValueType::Int64(20))
-                                    .finish();
+        assert_eq!(products.all, vec!["id", "price", "updated_at"]);
 
-        assert_eq!("SELECT id, title, description, point FROM blogs WHERE id = 10 AND point > 90 OR id = 20;", select_query)
-    }
+        let mut trigger = TriggerBuilder::for_table("products_touch", TriggerTiming::Before, TriggerEvent::Update, &products).unwrap();
+        let (id, new_price, old_price) = (trigger.new_col("id"), trigger.new_col("price"), trigger.old_col("price"));
 
-    #[test]
-    pub fn test_select_query_imperative(){
-        let mut select = QueryBuilder::select(["*"].to_vec()).unwrap();
+        trigger.set_new("updated_at", SqlKeyword::Now)
+               .set_new("price", Expr::func("GREATEST", vec![new_price.clone(), Expr::val(0)]))
+               .statement(&format!("INSERT INTO price_changes (product_id, difference) VALUES ({}, {});", id, new_price - old_price))
+               .follows("products_validate")
+               .delimiter("//");
 
-        let select_query = select.table("blogs");
-        select_query.where_("id", "=", ValueType::Uint8(5));
-        select_query.or("id", "=", ValueType::Usize(25));
+        assert_eq!(trigger.finish(), "DELIMITER //\nCREATE TRIGGER products_touch BEFORE UPDATE ON shop.products FOR EACH ROW FOLLOWS products_validate BEGIN SET NEW.updated_at = NOW(); SET NEW.price = GREATEST(NEW.price, 0); INSERT INTO price_changes (product_id, difference) VALUES (NEW.id, NEW.price - OLD.price); END//\nDELIMITER ;");
 
-        let finish_the_select_query = select_query.finish();
+        let mut script = Script::new();
+        script.trigger(&trigger);
 
-        assert_eq!("SELECT * FROM blogs WHERE id = 5 OR id = 25;", finish_the_select_query);
+        assert_eq!(script.finish(), "CREATE TRIGGER products_touch BEFORE UPDATE ON shop.products FOR EACH ROW FOLLOWS products_validate BEGIN SET NEW.updated_at = NOW(); SET NEW.price = GREATEST(NEW.price, 0); INSERT INTO price_changes (product_id, difference) VALUES (NEW.id, NEW.price - OLD.price); END;");
+
+        let trigger = TriggerBuilder::for_table("products_archive", TriggerTiming::After, TriggerEvent::Delete, &products).unwrap()
+                                     .insert_row("deleted_products", TriggerRow::Old)
+                                     .precedes("products_cleanup")
+                                     .finish();
+
+        assert_eq!(trigger, "CREATE TRIGGER products_archive AFTER DELETE ON shop.products FOR EACH ROW PRECEDES products_cleanup INSERT INTO deleted_products (id, price, updated_at) VALUES (OLD.id, OLD.price, OLD.updated_at);");
+        assert_eq!(TriggerBuilder::drop("products_archive", true).unwrap(), "DROP TRIGGER IF EXISTS products_archive;");
     }
 
     #[test]
-    pub fn test_create_table() {
-        let mut table_builder_2 = TableBuilder::create("blabla", "projects");
-        let table_builder_2 = table_builder_2.if_not_exists();
-    
-        table_builder_2.add_column("id").col_type("INT").primary_key().auto_increment();
-        table_builder_2.add_column("name").col_type("VARCHAR(40)").not_null();
-        table_builder_2.add_column("owner_id").col_type("INT").not_null();
-        
-        // if we create a table, the first ForeignKeyItem's table field is not necessary.
-        let opts = ForeignKey {
-            first: ForeignKeyItem { table: "".to_string(), column: "owner_id".to_string() },
-            second: ForeignKeyItem { table: "users".to_string(), column: "id".to_string() },
-            constraint: None,
-            on_delete: Some(ForeignKeyActions::Cascade),
-            on_update: None
-        };
-        
-        table_builder_2.foreign_key(opts);
-    
-        let table_builder_2 = table_builder_2.finish();
+    #[should_panic]
+    pub fn test_trigger_unknown_column(){
+        let mut products = TableBuilder::create("shop", "products");
+        products.add_column("id").col_type("INT");
 
-        let raw_query = "CREATE TABLE projects IF NOT EXISTS (id INT PRIMARY KEY AUTO_INCREMENT, name VARCHAR(40) NOT NULL, owner_id INT NOT NULL, FOREIGN KEY (owner_id) REFERENCES users(id) ON DELETE CASCADE);".to_string();
-
-        assert_eq!(raw_query, table_builder_2);
+        TriggerBuilder::for_table("products_touch", TriggerTiming::Before, TriggerEvent::Insert, &products).unwrap().new_col("price");
     }
 
     #[test]
-    pub fn test_time_value_type(){
-        let columns = ["name", "password", "last_login"].to_vec();
-        let values = [ValueType::String("necoo33".to_string()), ValueType::String("123456".to_string()), ValueType::Datetime("CURRENT_TIMESTAMP".to_string())].to_vec();
-    
-        let time_insert_test = QueryBuilder::insert(columns, values).unwrap().table("users").finish();
+    pub fn test_views(){
+        let select = QueryBuilder::select(vec!["category", "COUNT(*)"]).unwrap()
+                                  .dialect(Dialect::Postgresql)
+                                  .table("products")
+                                  .group_by("category")
+                                  .clone();
 
-        assert_eq!(time_insert_test, "INSERT INTO users (name, password, last_login) VALUES ('necoo33', '123456', CURRENT_TIMESTAMP);");
+        let view = ViewBuilder::materialized("product_counts", &select).unwrap()
+                               .columns(vec!["category", "total"])
+                               .with_no_data()
+                               .clone();
 
-        let time_update_test = QueryBuilder::update().unwrap().table("users").set("last_login", ValueType::Datetime("CURRENT_TIMESTAMP".to_string())).where_("name", "=", ValueType::String("necoo33".to_string())).finish();
+        assert_eq!(view.finish(), "CREATE MATERIALIZED VIEW product_counts (category, total) AS SELECT category, COUNT(*) FROM products GROUP BY category WITH NO DATA;");
+        assert_eq!(ViewBuilder::refresh("product_counts", true).unwrap(), "REFRESH MATERIALIZED VIEW CONCURRENTLY product_counts;");
+        assert_eq!(ViewBuilder::drop_materialized("product_counts", true).unwrap(), "DROP MATERIALIZED VIEW IF EXISTS product_counts;");
 
-        assert_eq!(time_update_test, "UPDATE users SET last_login = CURRENT_TIMESTAMP WHERE name = 'necoo33';")
+        let mut script = Script::new();
+        script.view(&view);
+
+        assert_eq!(script.statements(), vec!["CREATE MATERIALIZED VIEW product_counts (category, total) AS SELECT category, COUNT(*) FROM products GROUP BY category WITH NO DATA"]);
+
+        let select = QueryBuilder::select(vec!["*"]).unwrap().table("orders").where_("total", ">", 100).clone();
+
+        assert_eq!(ViewBuilder::create("big_orders", &select).unwrap().finish(), "CREATE VIEW big_orders AS SELECT * FROM orders WHERE total > 100;");
+        assert_eq!(ViewBuilder::drop("big_orders", false).unwrap(), "DROP VIEW big_orders;");
+        assert!(ViewBuilder::materialized("big_orders", &select).is_err());
+        assert!(ViewBuilder::create("big_orders", &QueryBuilder::delete().unwrap().table("orders").clone()).is_err());
+        assert!(ViewBuilder::create("big_orders", &select.clone().time_zone(Timezone::London).clone()).is_err());
     }
 
     #[test]
-    pub fn test_unix_epoch_times(){
-        let columns = ["name", "password", "last_login"].to_vec();
-        let values = [ValueType::String("necoo33".to_string()), ValueType::String("123456".to_string()), ValueType::EpochTime(134523452)].to_vec();
-    
-        let time_insert_with_unix_epoch_times_test = QueryBuilder::insert(columns, values).unwrap().table("users").finish();
-        assert_eq!(time_insert_with_unix_epoch_times_test, "INSERT INTO users (name, password, last_login) VALUES ('necoo33', '123456', FROM_UNIXTIME(134523452));");
-    
-        let time_update_with_unix_epoch_times_test = QueryBuilder::update().unwrap().table("users").set("last_login", ValueType::EpochTime(3456436)).where_("name", "=", ValueType::String("necoo33".to_string())).finish();
+    pub fn test_json_paths(){
+        for path in ["[0].name", "$[0].name", "$[0].\"name\""] {
+            assert_eq!(JsonPath::from(path), JsonPath::root().index(0).key("name"));
+        }
 
-        assert_eq!(time_update_with_unix_epoch_times_test, "UPDATE users SET last_login = FROM_UNIXTIME(3456436) WHERE name = 'necoo33';");
+        assert!(JsonPath::parse(".[0].name").is_err());
+        assert!(std::panic::catch_unwind(|| JsonPath::from("$.a[x]")).is_err());
 
-        let columns = ["name", "password", "last_login", "created_at"].to_vec();
+        assert_eq!(JsonPath::from("a.b"), JsonPath::from(".a.b"));
+        assert_eq!(JsonPath::from("$.a.b"), JsonPath::from(".a.b"));
+        assert_eq!(JsonPath::from(""), JsonPath::root());
+        assert_eq!(JsonPath::from("$.*[*][last][1 to 3]").to_string(), "$.*[*][last][1 to 3]");
+        assert_eq!(JsonPath::from("$.\"it's\".x").to_string(), "$.\"it's\".x");
+        assert!(JsonPath::parse("$.a[x]").is_err());
+        assert!(JsonPath::parse("$.a[3 to 1]").is_err());
+        assert!(JsonPath::root().all_elements().is_multiple());
 
-        let unix_epoch_times_test_3 = QueryBuilder::select(columns).unwrap().table("users").where_("created_at", ">", ValueType::EpochTime(3234534)).or("last_login", ">=", ValueType::EpochTime(2134432)).offset(0).limit(20).finish();
+        let path = JsonPath::root().key("user name").key("it's");
 
-        assert_eq!(unix_epoch_times_test_3, "SELECT name, password, last_login, created_at FROM users WHERE created_at > FROM_UNIXTIME(3234534) OR last_login >= FROM_UNIXTIME(2134432) OFFSET 0 LIMIT 20;")
-    }
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                 .json_extract("profile", path.clone(), Some("name"))
+                                 .table("users")
+                                 .finish();
 
-    #[test]
-    pub fn test_where_ins(){
-        let columns = ["name", "age", "id", "last_login"].to_vec();
+        assert_eq!(query, "SELECT JSON_EXTRACT(profile, '$.\"user name\".\"it''s\"') AS name FROM users;");
 
-        let ids = [ValueType::Int32(1), ValueType::Int16(12), ValueType::Int64(8)].to_vec();
+        let query = QueryBuilder::update().unwrap()
+                                 .table("users")
+                                 .json_set("profile", vec![(JsonPath::root().key("tags").last(), JsonValue::Initial(&ValueType::String("x".to_string())))])
+                                 .where_("id", "=", 1)
+                                 .finish();
 
-        let test_where_in = QueryBuilder::select(columns).unwrap().table("users").where_in("id", &ids).finish();
+        assert_eq!(query, "UPDATE users SET profile = JSON_SET(profile, '$.tags[last]', 'x') WHERE id = 1;");
 
-        assert_eq!(test_where_in, "SELECT name, age, id, last_login FROM users WHERE id IN (1, 12, 8);");
+        let query = QueryBuilder::update().unwrap()
+                                 .table("users")
+                                 .json_replace("profile", vec![("age", JsonValue::Initial(&ValueType::Int32(30)))])
+                                 .finish();
 
-        let columns = ["name", "age", "id", "last_login"].to_vec();
+        assert_eq!(query, "UPDATE users SET profile = JSON_REPLACE(profile, '$.age', 30);");
 
-        let ids = [ValueType::Int32(1), ValueType::Int16(12), ValueType::Int32(8)].to_vec();
+        let query = QueryBuilder::update().unwrap()
+                                 .table("users")
+                                 .json_remove("profile", vec![JsonPath::root().key("tmp"), JsonPath::from("[2]")])
+                                 .finish();
 
-        let test_where_not_in = QueryBuilder::select(columns).unwrap().table("users").where_not_in("id", &ids).finish();
+        assert_eq!(query, "UPDATE users SET profile = JSON_REMOVE(profile, '$.tmp', '$[2]');");
 
-        assert_eq!(test_where_not_in, "SELECT name, age, id, last_login FROM users WHERE id NOT IN (1, 12, 8);");
+        let query = QueryBuilder::update().unwrap()
+                                 .table("users")
+                                 .json_array_append("profile", vec![("$", JsonValue::Initial(&ValueType::Int32(1)))])
+                                 .finish();
 
-        let columns = ["name", "age", "id", "last_login"].to_vec();
+        assert_eq!(query, "UPDATE users SET profile = JSON_ARRAY_APPEND(profile, '$', 1);");
 
-        let test_where_in_custom = QueryBuilder::select(columns).unwrap().table("users").where_in_custom("id", "1, 12, 8").finish();
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                 .table("users")
+                                 .where_("profile", "=", ValueType::String("".to_string()))
+                                 .json_contains("profile", JsonValue::Initial(&ValueType::Int32(5)), JsonPath::root().key("scores"))
+                                 .finish();
 
-        assert_eq!(test_where_in_custom, "SELECT name, age, id, last_login FROM users WHERE id IN (1, 12, 8);");
+        assert_eq!(query, "SELECT * FROM users WHERE JSON_CONTAINS(profile, 5, '$.scores');");
 
-        let columns = ["name", "age", "id", "last_login"].to_vec();
+        let query = QueryBuilder::select(vec!["id"]).unwrap()
+                                 .dialect(Dialect::Sqlite)
+                                 .table("users")
+                                 .where_("profile", ">", 5)
+                                 .json_extract("profile", JsonPath::root().key("a").last(), None)
+                                 .finish();
 
-        let test_where_not_in_custom = QueryBuilder::select(columns).unwrap().table("users").where_not_in_custom("id", "1, 12, 8").finish();
+        assert_eq!(query, "SELECT id FROM users WHERE json_extract(profile, '$.a[#-1]') > 5;");
 
-        assert_eq!(test_where_not_in_custom, "SELECT name, age, id, last_login FROM users WHERE id NOT IN (1, 12, 8);");
+        let query = QueryBuilder::update().unwrap()
+                                 .dialect(Dialect::Postgresql)
+                                 .table("users")
+                                 .json_set("profile", vec![("age", JsonValue::Initial(&ValueType::Int32(30)))])
+                                 .json_remove("settings", vec!["tmp"])
+                                 .finish();
 
-        // test AND IN's
+        assert_eq!(query, "UPDATE users SET profile = jsonb_set(profile, '{age}', to_jsonb(30)), settings = settings #- '{tmp}';");
 
-        let columns = ["name", "id", "last_login"].to_vec();
+        let query = QueryBuilder::update().unwrap()
+                                 .dialect(Dialect::Sqlite)
+                                 .table("users")
+                                 .json_array_append("tags", vec![("$", JsonValue::Initial(&ValueType::String("x".to_string())))])
+                                 .finish();
 
-        let ids = [ValueType::Int32(1), ValueType::Int16(12), ValueType::Int64(8)].to_vec();
+        assert_eq!(query, "UPDATE users SET tags = json_insert(tags, '$[#]', 'x');");
 
-        let test_and_in = QueryBuilder::select(columns).unwrap().table("users").where_("age", ">", ValueType::Int32(35)).and_in("id", &ids).finish();
+        assert!(std::panic::catch_unwind(|| QueryBuilder::select(vec!["*"]).unwrap().dialect(Dialect::Postgresql).table("users").json_contains("profile", Json::from(5), "$").finish()).is_err());
 
-        assert_eq!(test_and_in, "SELECT name, id, last_login FROM users WHERE age > 35 AND id IN (1, 12, 8);");
+        let query = QueryBuilder::select(vec!["id"]).unwrap()
+                                 .table("users")
+                                 .where_("age", "=", 6)
+                                 .and("data", "=", 6)
+                                 .and("data", ">", 7)
+                                 .json_extract("data", "age", None)
+                                 .clone();
 
-        let columns = ["name", "id", "last_login"].to_vec();
+        let statement = PreparedStatement::from_query("users", &query).unwrap();
 
-        let ids = [ValueType::Int32(1), ValueType::Int16(12), ValueType::Int64(8)].to_vec();
+        assert_eq!(statement.statement, "SELECT id FROM users WHERE age = ? AND data = ? AND JSON_EXTRACT(data, '$.age') > ?");
+        assert_eq!(statement.parameters, vec!["6", "6", "7"]);
 
-        let test_and_not_in = QueryBuilder::select(columns).unwrap().table("users").where_("age", ">", ValueType::Int32(35)).and_not_in("id", &ids).finish();
+        let query = QueryBuilder::select(vec!["id"]).unwrap()
+                                 .table("users")
+                                 .where_("age", ">", 6)
+                                 .and("profile", "=", ValueType::String("".to_string()))
+                                 .json_contains("profile", Json::from(5), "scores")
+                                 .clone();
 
-        assert_eq!(test_and_not_in, "SELECT name, id, last_login FROM users WHERE age > 35 AND id NOT IN (1, 12, 8);");
+        let statement = PreparedStatement::from_query("users", &query).unwrap();
 
-        // test OR IN's
+        assert_eq!(statement.statement, "SELECT id FROM users WHERE age > ? AND JSON_CONTAINS(profile, '5', '$.scores')");
+        assert_eq!(statement.parameters, vec!["6"]);
+    }
 
-        let columns = ["name", "id", "last_login"].to_vec();
+    #[test]
+    pub fn test_json_table(){
+        let table = JsonTable::new("o.items", JsonPath::root().all_elements(), vec![
+            JsonTableColumn::ordinality("n"),
+            JsonTableColumn::path("sku", "VARCHAR(32)", "sku").error_on_empty(),
+            JsonTableColumn::path("price", "DECIMAL(10,2)", "price").default_on_empty(ValueType::Int32(0)).default_on_error(ValueType::Float64(0.5)),
+            JsonTableColumn::exists("gift", "INT", "gift"),
+            JsonTableColumn::nested("options[*]", vec![JsonTableColumn::path("option", "VARCHAR(20)", "name").null_on_empty()]),
+        ], "i");
 
-        let ids = [ValueType::Int32(1), ValueType::Int16(12), ValueType::Int64(8)].to_vec();
+        let query = QueryBuilder::select(vec!["i.*"]).unwrap()
+                                 .json_table(&table)
+                                 .finish();
 
-        let test_or_in = QueryBuilder::select(columns).unwrap().table("users").where_("age", ">", ValueType::Int32(35)).or_in("id", &ids).finish();
+        assert_eq!(query, "SELECT i.* FROM JSON_TABLE(o.items, '$[*]' COLUMNS (n FOR ORDINALITY, sku VARCHAR(32) PATH '$.sku' ERROR ON EMPTY, price DECIMAL(10,2) PATH '$.price' DEFAULT '0' ON EMPTY DEFAULT '0.5' ON ERROR, gift INT EXISTS PATH '$.gift', NESTED PATH '$.options[*]' COLUMNS (option VARCHAR(20) PATH '$.name' NULL ON EMPTY))) AS i;");
 
-        assert_eq!(test_or_in, "SELECT name, id, last_login FROM users WHERE age > 35 OR id IN (1, 12, 8);");
+        let query = QueryBuilder::select(vec!["o.id", "i.sku"]).unwrap()
+                                 .dialect(Dialect::Postgresql)
+                                 .table("orders o")
+                                 .json_table(&table)
+                                 .inner_join("products p", "p.sku", "=", "i.sku")
+                                 .finish();
 
-        let columns = ["name", "id", "last_login"].to_vec();
+        assert_eq!(query, "SELECT o.id, i.sku FROM orders o CROSS JOIN JSON_TABLE(o.items, '$[*]' COLUMNS (n FOR ORDINALITY, sku VARCHAR(32) PATH '$.sku' ERROR ON EMPTY, price DECIMAL(10,2) PATH '$.price' DEFAULT 0 ON EMPTY DEFAULT 0.5 ON ERROR, gift INT EXISTS PATH '$.gift', NESTED PATH '$.options[*]' COLUMNS (option VARCHAR(20) PATH '$.name' NULL ON EMPTY))) AS i INNER JOIN products p ON p.sku = i.sku;");
 
-        let ids = [ValueType::Int32(1), ValueType::Int16(12), ValueType::Int64(8)].to_vec();
+        let tags = JsonTable::new("p.tags", "[*]", vec![JsonTableColumn::path("tag", "VARCHAR(50)", "$")], "t");
 
-        let test_or_not_in = QueryBuilder::select(columns).unwrap().table("users").where_("age", ">", ValueType::Int32(35)).or_not_in("id", &ids).finish();
+        let query = QueryBuilder::select(vec!["p.id", "t.tag"]).unwrap()
+                                 .table("posts p")
+                                 .left_join_json_table(&tags)
+                                 .where_("t.tag", "=", ValueType::String("rust".to_string()))
+                                 .finish();
 
-        assert_eq!(test_or_not_in, "SELECT name, id, last_login FROM users WHERE age > 35 OR id NOT IN (1, 12, 8);")
+        assert_eq!(query, "SELECT p.id, t.tag FROM posts p LEFT JOIN JSON_TABLE(p.tags, '$[*]' COLUMNS (tag VARCHAR(50) PATH '$')) AS t ON TRUE WHERE t.tag = 'rust';");
+
+        let result = std::panic::catch_unwind(|| JsonTableColumn::ordinality("n").null_on_empty());
+
+        assert!(result.is_err());
+
+        let result = std::panic::catch_unwind(|| tags.to_sql(&Dialect::Sqlite));
+
+        assert!(result.is_err());
     }
 
     #[test]
-    pub fn test_count() {
-        let count_of_users = QueryBuilder::count("*", None).table("users").where_("age", ">", ValueType::Int32(25)).finish();
+    pub fn test_json_aggregation(){
+        let aggregation = Expr::json_arrayagg(Expr::json_object(vec![("id", Expr::col("p.id")), ("title", Expr::col("p.title"))])).to_string();
 
-        assert_eq!(count_of_users, "SELECT COUNT(*) FROM users WHERE age > 25;".to_string());
+        let posts = QueryBuilder::select(vec![&aggregation]).unwrap()
+                                 .table("posts p")
+                                 .where_("p.user_id", "=", Expr::col("u.id"))
+                                 .to_owned();
 
-        let count_of_users_as_length = QueryBuilder::count("*", Some("length")).table("users").finish();
+        let query = QueryBuilder::select(vec!["u.id"]).unwrap()
+                                 .select_expr(Expr::json_object(vec![
+                                     ("id", Expr::col("u.id")),
+                                     ("name", Expr::col("u.name")),
+                                     ("roles", Expr::json_array(vec![Expr::val(String::from("user")), Expr::col("u.role")])),
+                                     ("posts", Expr::subquery(&posts)),
+                                 ]), Some("body"))
+                                 .table("users u")
+                                 .finish();
 
-        assert_eq!(count_of_users_as_length, "SELECT COUNT(*) AS length FROM users;".to_string());
+        assert_eq!(query, "SELECT u.id, JSON_OBJECT('id', u.id, 'name', u.name, 'roles', JSON_ARRAY('user', u.role), 'posts', (SELECT JSON_ARRAYAGG(JSON_OBJECT('id', p.id, 'title', p.title)) FROM posts p WHERE p.user_id = u.id)) AS body FROM users u;");
+        assert!(matches!(Expr::subquery(&posts), Expr::Subquery(subquery) if subquery.statement() == "SELECT JSON_ARRAYAGG(JSON_OBJECT('id', p.id, 'title', p.title)) FROM posts p WHERE p.user_id = u.id"));
+
+        let query = QueryBuilder::select(vec!["user_id"]).unwrap()
+                                 .dialect(Dialect::Postgresql)
+                                 .select_expr(Expr::json_objectagg(Expr::col("setting"), Expr::col("value")), Some("settings"))
+                                 .table("settings")
+                                 .group_by("user_id")
+                                 .finish();
+
+        assert_eq!(query, "SELECT user_id, json_object_agg(setting, value) AS settings FROM settings GROUP BY user_id;");
+
+        let expr = Expr::json_object(vec![("it's", Expr::val(1))]);
+
+        assert_eq!(expr.to_sql(&Dialect::Sqlite), "json_object('it''s', 1)");
     }
 
     #[test]
-    pub fn test_json_extract(){
-        // tests with "select()" constructor
+    pub fn test_json_modifications(){
+        let name = ValueType::String("arda".to_string());
+        let tag = ValueType::JsonString("new".to_string());
 
-        let select_query_1 = QueryBuilder::select(["*"].to_vec()).unwrap().json_extract("data", ".age", Some("student_age")).table("students").finish();
+        let query = QueryBuilder::update().unwrap()
+                                 .table("users")
+                                 .json_set("profile", vec![("name", JsonValue::Initial(&name)), ("age", JsonValue::Initial(&ValueType::Int32(30)))])
+                                 .set("updated", true)
+                                 .json_array_insert("tags", vec![("[0]", JsonValue::Initial(&tag))])
+                                 .json_remove("profile", vec!["tmp"])
+                                 .where_("id", "=", 1)
+                                 .finish();
 
-        assert_eq!(select_query_1, "SELECT JSON_EXTRACT(data, '$.age') AS student_age FROM students;".to_string());
-        
-        let select_query_2 = QueryBuilder::select(["*"].to_vec()).unwrap().json_extract("data", ".age", Some("student_age")).table("students").where_("successfull", "=", ValueType::Int8(1)).finish();
+        assert_eq!(query, "UPDATE users SET profile = JSON_SET(profile, '$.name', 'arda', '$.age', 30), updated = true, tags = JSON_ARRAY_INSERT(tags, '$[0]', '\"new\"'), profile = JSON_REMOVE(profile, '$.tmp') WHERE id = 1;");
 
-        assert_eq!(select_query_2, "SELECT JSON_EXTRACT(data, '$.age') AS student_age FROM students WHERE successfull = 1;".to_string());
-        
-        let select_query_3 = QueryBuilder::select(["*"].to_vec()).unwrap().json_extract("data", ".age", Some("student_age")).table("students").where_("points", ">", ValueType::Int32(85)).json_extract("points", ".name", None).finish();
+        let expr = Expr::json_set(Expr::col("doc"), vec![("a", Expr::val(1)), ("b.c", Expr::col("x"))]);
 
-        assert_eq!(select_query_3, "SELECT JSON_EXTRACT(data, '$.age') AS student_age FROM students WHERE JSON_EXTRACT(points, '$.name') > 85;".to_string());
+        assert_eq!(expr.to_sql(&Dialect::Mysql), "JSON_SET(doc, '$.a', 1, '$.b.c', x)");
+        assert_eq!(expr.to_sql(&Dialect::Sqlite), "json_set(doc, '$.a', 1, '$.b.c', x)");
+        assert_eq!(expr.to_sql(&Dialect::Postgresql), "jsonb_set(jsonb_set(doc, '{a}', to_jsonb(1)), '{b,c}', to_jsonb(x))");
 
-        // tests with ".where_cond()" method
+        let expr = Expr::json_array_append(Expr::col("doc"), vec![("tags", Expr::val(String::from("x")))]);
 
-        let with_where = QueryBuilder::delete().unwrap().table("users").where_("id", ">", ValueType::Int32(200)).json_extract("id", ".user_id", None).finish();
+        assert_eq!(expr.to_sql(&Dialect::Sqlite), "json_insert(doc, '$.tags[#]', 'x')");
+        assert_eq!(expr.to_sql(&Dialect::Postgresql), "jsonb_insert(doc, '{tags,-1}', to_jsonb('x'::text), true)");
 
-        assert_eq!(with_where, "DELETE FROM users WHERE JSON_EXTRACT(id, '$.user_id') > 200;".to_string());
+        let expr = Expr::json_remove(Expr::col("doc"), vec!["a", "b[0]"]);
 
-        // tests with ".table()" method
-        
-        let fields = ["name", "age"].to_vec();
-        
-        let with_table = QueryBuilder::select(fields).unwrap().table("users").json_extract("id", ".user_id", None).finish();
+        assert_eq!(expr.to_sql(&Dialect::Postgresql), "doc #- '{a}' #- '{b,0}'");
 
-        assert_eq!(with_table, "SELECT JSON_EXTRACT(id, '$.user_id') FROM users;".to_string());
+        let expr = Expr::json_merge_patch(vec![Expr::col("doc"), Expr::json_object(vec![("a", Expr::val(1))]), Expr::col("extra")]);
 
-        // tests with ".and()" method
+        assert_eq!(expr.to_sql(&Dialect::Mysql), "JSON_MERGE_PATCH(doc, JSON_OBJECT('a', 1), extra)");
+        assert_eq!(expr.to_sql(&Dialect::Sqlite), "json_patch(json_patch(doc, json_object('a', 1)), extra)");
 
-        let fields = ["name", "age"].to_vec();
+        let query = QueryBuilder::select(vec!["id"]).unwrap()
+                                 .select_expr(Expr::json_merge_preserve(vec![Expr::col("defaults"), Expr::col("settings")]), Some("settings"))
+                                 .table("users")
+                                 .finish();
 
-        let with_and_1 = QueryBuilder::select(fields).unwrap().table("height").where_("weight", ">", ValueType::Int32(60)).and("height", ">", ValueType::Float64(1.70)).json_extract("height", ".student_height", None).finish();
+        assert_eq!(query, "SELECT id, JSON_MERGE_PRESERVE(defaults, settings) AS settings FROM users;");
 
-        assert_eq!(with_and_1, "SELECT name, age FROM height WHERE weight > 60 AND JSON_EXTRACT(height, '$.student_height') > 1.7;".to_string());
-    
-        let with_and_2 = QueryBuilder::select(["*"].to_vec()).unwrap().table("students").where_("weight", ">", ValueType::Int32(60)).and("height", ">", ValueType::Float64(1.70)).json_extract("height", ".student_height", None).finish();
+        let result = std::panic::catch_unwind(|| Expr::json_insert(Expr::col("doc"), vec![("a", Expr::val(1))]).to_sql(&Dialect::Postgresql));
 
-        assert_eq!(with_and_2, "SELECT * FROM students WHERE weight > 60 AND JSON_EXTRACT(height, '$.student_height') > 1.7;".to_string());
+        assert!(result.is_err());
+    }
+
+    #[test]
+    pub fn test_json_predicates(){
+        let query = QueryBuilder::select(vec!["id"]).unwrap()
+                                 .table("users")
+                                 .where_predicate(Expr::json_valid(Expr::col("profile")))
+                                 .and_predicate(Expr::compare(Expr::json_length(Expr::col("profile"), Some("tags")), ">", 2))
+                                 .open_parenthesis(BracketType::And)
+                                 .where_predicate(Expr::compare(Expr::json_type(Expr::json_get(Expr::col("profile"), "age")), "=", String::from("INTEGER")))
+                                 .or_predicate(Expr::json_overlaps(Expr::json_get(Expr::col("profile"), "tags"), Expr::col("wanted")))
+                                 .close_parenthesis()
+                                 .finish();
+
+        assert_eq!(query, "SELECT id FROM users WHERE JSON_VALID(profile) AND JSON_LENGTH(profile, '$.tags') > 2 AND (JSON_TYPE(profile->'$.age') = 'INTEGER' OR JSON_OVERLAPS(profile->'$.tags', wanted));");
+
+        let query = QueryBuilder::select(vec!["id"]).unwrap()
+                                 .table("users")
+                                 .where_("active", "=", true)
+                                 .open_parenthesis(BracketType::Or)
+                                 .json_extract("profile", "lang", None)
+                                 .append_custom("= 'rust'")
+                                 .close_parenthesis()
+                                 .finish();
+
+        assert_eq!(query, "SELECT id FROM users WHERE active = true OR (JSON_EXTRACT(profile, '$.lang') = 'rust');");
+
+        let query = QueryBuilder::select(vec!["id"]).unwrap()
+                                 .table("users")
+                                 .where_("active", "=", true)
+                                 .open_parenthesis_predicate(BracketType::Or, Expr::compare(Expr::json_extract(Expr::col("profile"), "lang"), "=", String::from("rust")))
+                                 .and_predicate(Expr::compare(Expr::json_extract(Expr::col("profile"), "level"), ">", 2))
+                                 .close_parenthesis()
+                                 .finish();
+
+        assert_eq!(query, "SELECT id FROM users WHERE active = true OR (JSON_EXTRACT(profile, '$.lang') = 'rust' AND JSON_EXTRACT(profile, '$.level') > 2);");
+
+        let result = std::panic::catch_unwind(|| {
+            QueryBuilder::select(vec!["id"]).unwrap()
+                         .table("users")
+                         .where_("active", "=", true)
+                         .open_parenthesis_with(BracketType::Or, "profile", "=", String::from("rust"))
+                         .json_extract("profile", "lang", None)
+                         .finish()
+        });
+
+        assert!(result.is_err());
+
+        let keys = Expr::json_keys(Expr::col("doc"), Some("a"));
+
+        assert_eq!(keys.to_sql(&Dialect::Mysql), "JSON_KEYS(doc, '$.a')");
+        assert_eq!(keys.to_sql(&Dialect::Postgresql), "jsonb_path_query_array(doc, '$.a.keyvalue().key')");
+
+        let paths = Expr::json_contains_path(Expr::col("doc"), JsonSearchMode::All, vec!["a", "b"]);
+
+        assert_eq!(paths.to_sql(&Dialect::Postgresql), "(doc @? '$.a' AND doc @? '$.b')");
+        assert_eq!(paths.to_sql(&Dialect::Sqlite), "(json_type(doc, '$.a') IS NOT NULL AND json_type(doc, '$.b') IS NOT NULL)");
+
+        let member = Expr::member_of(Expr::val(3), Expr::json_get(Expr::col("doc"), "ids"));
+
+        assert_eq!(member.to_sql(&Dialect::Postgresql), "doc #> '{ids}' @> to_jsonb(3)");
+        assert_eq!(member.to_sql(&Dialect::Sqlite), "3 IN (SELECT value FROM json_each(doc->'$.ids'))");
+
+        let member = Expr::member_of(Expr::val(ValueType::String("admin".to_string())), Expr::json_get(Expr::col("doc"), "roles"));
+
+        assert_eq!(member.to_sql(&Dialect::Postgresql), "doc #> '{roles}' @> to_jsonb('admin'::text)");
+
+        let search = Expr::json_search_in(Expr::col("doc"), JsonSearchMode::All, String::from("a%"), "names[*]");
+
+        assert_eq!(search.to_sql(&Dialect::Mysql), "JSON_SEARCH(doc, 'all', 'a%', NULL, '$.names[*]')");
+        assert_eq!(Expr::json_get_text(Expr::col("doc"), "a.b").to_sql(&Dialect::Postgresql), "doc #>> '{a,b}'");
+        assert!(std::panic::catch_unwind(|| Expr::compare(Expr::col("a"), "LIKE", 1)).is_err());
+    }
+
+    #[test]
+    pub fn test_owned_json(){
+        let mut limits = std::collections::HashMap::new();
+        limits.insert("b".to_string(), vec![1, 2]);
+        limits.insert("a".to_string(), vec![]);
+
+        let json = Json::object(vec![
+            ("name", Json::from("it's \\ \"quoted\"\n")),
+            ("limits", Json::from(limits)),
+            ("score", Json::from(Some(1.5))),
+            ("missing", Json::from(None::<i32>)),
+            ("raw", Json::from(&ValueType::Uint64(u64::MAX)))
+        ]);
+
+        assert_eq!(json.to_string(), r#"{"name":"it's \\ \"quoted\"\n","limits":{"a":[],"b":[1,2]},"score":1.5,"missing":null,"raw":18446744073709552000}"#);
+        assert_eq!(Json::from("it's").to_sql(&Dialect::Mysql), "'it''s'");
+        assert_eq!(Json::from(vec!["a\\b"]).to_sql(&Dialect::Mysql), r#"CAST('["a\\\\b"]' AS JSON)"#);
+        assert_eq!(Json::from(vec!["a\\b"]).to_sql(&Dialect::Sqlite), r#"json('["a\\b"]')"#);
+        assert_eq!(Json::from(true).to_sql(&Dialect::Postgresql), "'true'::jsonb");
+        assert_eq!(Json::Float(f64::NAN).to_string(), "null");
+
+        let query = QueryBuilder::select(vec!["*"]).unwrap()
+                                 .table("users")
+                                 .where_("tags", "=", ValueType::String("".to_string()))
+                                 .json_contains("tags", Json::from(vec!["it's"]), "$")
+                                 .finish();
+
+        assert_eq!(query, r#"SELECT * FROM users WHERE JSON_CONTAINS(tags, '["it''s"]');"#);
+
+        let query = QueryBuilder::update().unwrap()
+                                 .table("users")
+                                 .json_array_append("tags", vec![("$", Json::from(vec![1, 2]))])
+                                 .json_merge_patch("profile", vec![Json::object(vec![("a", Json::Null)])])
+                                 .finish();
+
+        assert_eq!(query, "UPDATE users SET tags = JSON_ARRAY_APPEND(tags, '$', CAST('[1,2]' AS JSON)), profile = JSON_MERGE_PATCH(profile, CAST('{\"a\":null}' AS JSON));");
+
+        let expr = Expr::json_set(Expr::col("doc"), vec![("a", Expr::from(Json::from(vec![true])))]);
+
+        assert_eq!(expr.to_sql(&Dialect::Postgresql), "jsonb_set(doc, '{a}', to_jsonb('[true]'::jsonb))");
+    }
 
-        // tests with ".or()" method
+    #[cfg(feature = "serde_json")]
+    #[test]
+    pub fn test_serde_json(){
+        let value = serde_json::json!({"id": 1, "tags": ["a", "b"], "nested": {"ok": true, "ratio": 0.5}});
+        let json = Json::from(value.clone());
 
-        let fields = ["name", "age"].to_vec();
+        assert_eq!(serde_json::Value::from(json.clone()), value);
+        assert_eq!(Json::serialize(&vec![(1, "a")]).unwrap(), Json::from(vec![Json::from(vec![Json::from(1), Json::from("a")])]));
 
-        let with_or_1 = QueryBuilder::select(fields).unwrap().table("height").where_("weight", ">", ValueType::Int32(60)).or("height", ">", ValueType::Float64(1.71)).json_extract("height", ".student_height", None).finish();
+        let query = QueryBuilder::update().unwrap()
+                                 .table("users")
+                                 .json_set("profile", vec![("meta", serde_json::json!([1, "x"]))])
+                                 .finish();
 
-        assert_eq!(with_or_1, "SELECT name, age FROM height WHERE weight > 60 OR JSON_EXTRACT(height, '$.student_height') > 1.71;".to_string());
-    
-        let with_or_2 = QueryBuilder::select(["*"].to_vec()).unwrap().table("students").where_("weight", ">", ValueType::Int32(60)).or("height", ">", ValueType::Float64(1.71)).json_extract("height", ".student_height", None).finish();
+        assert_eq!(query, r#"UPDATE users SET profile = JSON_SET(profile, '$.meta', CAST('[1,"x"]' AS JSON));"#);
+    }
 
-        assert_eq!(with_or_2, "SELECT * FROM students WHERE weight > 60 OR JSON_EXTRACT(height, '$.student_height') > 1.71;".to_string());
+    #[test]
+    pub fn test_column_defs(){
+        assert_eq!(ColumnType::parse("point"), ColumnType::Point);
+        assert_eq!(ColumnType::parse("DECIMAL(10,2)"), ColumnType::Decimal(10, 2));
+        assert_eq!(ColumnType::parse("int(11)"), ColumnType::Int(Some(11)));
+        assert_eq!(ColumnType::parse("ENUM('a', 'b')"), ColumnType::Enum(vec!["a".to_string(), "b".to_string()]));
+        assert_eq!(ColumnType::parse("GEOGRAPHY(POINT)"), ColumnType::Custom("GEOGRAPHY(POINT)".to_string()));
 
-        // tests with "count()" constructor
+        let mut table = TableBuilder::create("shop", "places");
 
-        let count_query_1 = QueryBuilder::count("*", None).json_extract("age", ".student_age", Some("value")).table("students").group_by("points").having("points", ">", ValueType::Int32(75)).finish();
+        table.add_column("location").col_type("POINT").not_null()
+             .add_column("visited_at").col_type("DATETIME").default(ValueType::Datetime("2024-01-01 00:00:00".to_string()))
+             .add_column("notes").column_type(ColumnType::Text).default(ValueType::String("it's empty".to_string()))
+             .add_column("tags").column_type(ColumnType::Set(vec!["new".to_string(), "old".to_string()])).default(ValueType::String("new,old".to_string()))
+             .add_column("score").column_type(ColumnType::Decimal(5, 2)).default(ValueType::Float64(1.5))
+             .index(vec!["visited_at", "score"]);
 
-        assert_eq!(count_query_1, "SELECT JSON_EXTRACT(age, '$.student_age') AS value, COUNT(*) FROM students GROUP BY points HAVING points > 75;".to_string());
+        assert_eq!(table.finish(), "CREATE TABLE shop.places (location POINT NOT NULL, visited_at DATETIME DEFAULT '2024-01-01 00:00:00', notes TEXT DEFAULT ('it''s empty'), tags SET('new', 'old') DEFAULT 'new,old', score DECIMAL(5, 2) DEFAULT 1.5, INDEX (visited_at, score));");
+        assert_eq!(table.all, vec!["location", "visited_at", "notes", "tags", "score"]);
 
-        // tests with ".order_by()" method
-        
-        let fields = ["title", "desc", "created_at", "updated_at", "keywords", "pics", "likes"].to_vec();
+        assert!(ColumnType::Point.check_default(&ValueType::Int32(1)).is_err());
+        assert!(ColumnType::Varchar(3).check_default(&ValueType::String("four".to_string())).is_err());
+        assert!(ColumnType::Enum(vec!["a".to_string()]).check_default(&ValueType::String("b".to_string())).is_err());
+        assert!(ColumnType::Datetime(None).check_default(&ValueType::Datetime("CURRENT_TIMESTAMP".to_string())).is_ok());
+        assert!(ColumnType::Datetime(Some(3)).check_default(&ValueType::Datetime("CURRENT_TIMESTAMP(3)".to_string())).is_ok());
+        assert!(ColumnType::Timestamp(Some(6)).check_default(&ValueType::Datetime("NOW(6)".to_string())).is_ok());
+        assert!(ColumnType::Datetime(Some(3)).check_default(&ValueType::Datetime("CURRENT_TIMESTAMP".to_string())).is_err());
+        assert!(ColumnType::Timestamp(None).check_default(&ValueType::Datetime("CURRENT_TIMESTAMP(3)".to_string())).is_err());
 
-        let order_by_query_1 = QueryBuilder::select(fields).unwrap().table("contents").where_("published", "=", ValueType::Int32(1)).order_by("likes", "ASC").json_extract("likes", ".name", None).finish();
+        let mut table = TableBuilder::create("", "events");
 
-        assert_eq!(order_by_query_1, "SELECT title, desc, created_at, updated_at, keywords, pics, likes FROM contents WHERE published = 1 ORDER BY JSON_EXTRACT(likes, '$.name') ASC;".to_string());
-    
-        // tests with ".json_extract()" method
+        table.add_column("code").custom_query("VARCHAR(10)").not_null().default(ValueType::String("none".to_string()))
+             .add_column("created_at").column_type(ColumnType::Timestamp(Some(6))).default(ValueType::Datetime("NOW(6)".to_string()));
 
-        let json_extract_chaining = QueryBuilder::select(["*"].to_vec()).unwrap().json_extract("articles", "[0]", Some("blog1")).json_extract("articles", "[1]", Some("blog2")).json_extract("articles", "[2]", Some("blog3")).table("users").where_("published", "=", ValueType::Int32(1)).finish();
+        assert_eq!(table.finish(), "CREATE TABLE events (code VARCHAR(10) NOT NULL DEFAULT 'none', created_at TIMESTAMP(6) DEFAULT NOW(6));");
 
-        assert_eq!(json_extract_chaining, "SELECT JSON_EXTRACT(articles, '$[0]') AS blog1, JSON_EXTRACT(articles, '$[1]') AS blog2, JSON_EXTRACT(articles, '$[2]') AS blog3 FROM users WHERE published = 1;".to_string());
-    }
+        let result = std::panic::catch_unwind(|| {
+            TableBuilder::create("shop", "places").add_column("location").col_type("POINT").default(ValueType::Int32(1)).finish()
+        });
 
-    #[test]
-    pub fn test_json_contains(){
-        // test with "select()" constructor:
+        assert!(result.is_err());
 
-        let ins = [ValueType::Int32(1), ValueType::Int32(5), ValueType::Int64(11)].to_vec();
-        let select_query = QueryBuilder::select(["*"].to_vec()).unwrap().json_contains("pic", JsonValue::Initial(&ValueType::String("\"/files/hello.jpg\"".to_string())), Some(".path")).table("users").where_in("id", &ins).finish();
+        let result = std::panic::catch_unwind(|| {
+            TableBuilder::create("shop", "places").add_column("id").not_null().default(ValueType::Int32(1)).finish()
+        });
 
-        assert_eq!(select_query, "SELECT JSON_CONTAINS(pic, '\"/files/hello.jpg\"', '$.path') FROM users WHERE id IN (1, 5, 11);".to_string());
+        assert!(result.is_err());
+    }
 
-        // test with ".where_cond()" method:
+    #[test]
+    pub fn test_alter_table(){
+        let foreign_key = ForeignKey {
+            first: ForeignKeyItem { table: "orders".to_string(), column: "user_id".to_string() },
+            second: ForeignKeyItem { table: "users".to_string(), column: "id".to_string() },
+            constraint: Some("orders_user_fk".to_string()),
+            on_delete: Some(ForeignKeyActions::Cascade),
+            on_update: None
+        };
 
-        let where_query = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("pic", "=", ValueType::String("".to_string())).json_contains("pic", JsonValue::Initial(&ValueType::String("\"blablabla.jpg\"".to_string())), Some(".name")).finish();
+        let mut alter = AlterTableBuilder::alter("orders").unwrap();
 
-        assert_eq!(where_query, "SELECT * FROM users WHERE JSON_CONTAINS(pic, '\"blablabla.jpg\"', '$.name');".to_string());
+        alter.modify_column(ColumnDef::new("total", ColumnType::Decimal(12, 2)).not_null().clone()).first()
+             .change_column("note", ColumnDef::new("notes", ColumnType::Text))
+             .drop_column("legacy")
+             .drop_index("orders_legacy_idx")
+             .add_foreign_key(foreign_key.clone())
+             .drop_foreign_key("orders_old_fk")
+             .add_constraint("positive_total", "CHECK(total > 0)")
+             .option("engine", "InnoDB")
+             .algorithm(AlterAlgorithm::Instant);
 
-        let and_query_1 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).and("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), Some(".average_point")).finish();
+        assert_eq!(alter.finish(), "ALTER TABLE orders MODIFY COLUMN total DECIMAL(12, 2) NOT NULL FIRST, CHANGE COLUMN note notes TEXT, DROP COLUMN legacy, DROP INDEX orders_legacy_idx, ADD CONSTRAINT orders_user_fk FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE, DROP FOREIGN KEY orders_old_fk, ADD CONSTRAINT positive_total CHECK(total > 0), ENGINE = InnoDB, ALGORITHM = INSTANT;");
 
-        assert_eq!(and_query_1, "SELECT * FROM users WHERE age > 15 AND JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+        let mut alter = AlterTableBuilder::alter("orders").unwrap();
 
-        let and_query_2 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).and("class", "=", ValueType::String("5/c".to_string())).and("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), Some(".average_point")).finish();
+        alter.dialect(Dialect::Postgresql)
+             .add_column(ColumnDef::new("paid", ColumnType::Boolean).not_null().default(ValueType::Boolean(false)).clone())
+             .modify_column(ColumnDef::new("total", ColumnType::Decimal(12, 2)).not_null().default(ValueType::Int32(0)).clone())
+             .change_column("note", ColumnDef::new("notes", ColumnType::Text))
+             .add_foreign_key(foreign_key)
+             .drop_foreign_key("orders_old_fk")
+             .add_index("orders_paid_idx", vec!["paid", "total"]);
 
-        assert_eq!(and_query_2, "SELECT * FROM users WHERE age > 15 AND class = '5/c' AND JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
-        
-        let and_query_3 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).and("class", "=", ValueType::String("5/c".to_string())).and("surname", "=", ValueType::String("etiman".to_string())).and("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), Some(".average_point")).finish();
-    
-        assert_eq!(and_query_3, "SELECT * FROM users WHERE age > 15 AND class = '5/c'  AND surname = 'etiman' AND JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+        assert_eq!(alter.statements(), vec![
+            "ALTER TABLE orders ADD COLUMN paid BOOLEAN NOT NULL DEFAULT false, ALTER COLUMN total TYPE DECIMAL(12, 2), ALTER COLUMN total SET NOT NULL, ALTER COLUMN total SET DEFAULT 0",
+            "ALTER TABLE orders RENAME COLUMN note TO notes",
+            "ALTER TABLE orders ALTER COLUMN notes TYPE TEXT",
+            "ALTER TABLE orders ADD CONSTRAINT orders_user_fk FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE, DROP CONSTRAINT orders_old_fk",
+            "CREATE INDEX orders_paid_idx ON orders (paid, total)"
+        ]);
 
-        let and_query_4 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).and("sdfgsdfg", "=", ValueType::String("".to_string())).json_contains("parents", JsonValue::Initial(&ValueType::Int32(50)), Some(".age")).and("surname", "=", ValueType::String("etiman".to_string())).and("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float32(80.11)), Some(".average_point")).finish();
-    
-        assert_eq!(and_query_4, "SELECT * FROM users WHERE age > 15 AND JSON_CONTAINS(parents, 50, '$.age')  AND surname = 'etiman' AND JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+        let mut alter = AlterTableBuilder::alter("orders").unwrap();
+        alter.dialect(Dialect::Sqlite).drop_column("legacy").rename_column("note", "notes").drop_index("orders_legacy_idx");
 
-        let and_query_5 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).and("sdfgsdfg", "=", ValueType::String("".to_string())).json_contains("parents", JsonValue::Initial(&ValueType::Int32(50)), Some(".age")).and("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), Some(".average_point")).finish();
-    
-        assert_eq!(and_query_5, "SELECT * FROM users WHERE age > 15 AND JSON_CONTAINS(parents, 50, '$.age') AND JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
-        
-        let or_query_1 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).or("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float32(80.11)), Some(".average_point")).finish();
+        let mut script = Script::new();
+        script.alter_table(&alter);
 
-        assert_eq!(or_query_1, "SELECT * FROM users WHERE age > 15 OR JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
-        
-        let or_query_2 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).or("class", "=", ValueType::String("5/c".to_string())).or("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), Some(".average_point")).finish();
-        
-        assert_eq!(or_query_2, "SELECT * FROM users WHERE age > 15 OR class = '5/c' OR JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
-                
-        let or_query_3 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).or("class", "=", ValueType::String("5/c".to_string())).or("surname", "=", ValueType::String("etiman".to_string())).or("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), Some(".average_point")).finish();
-            
-        assert_eq!(or_query_3, "SELECT * FROM users WHERE age > 15 OR class = '5/c'  OR surname = 'etiman' OR JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
-        
-        let or_query_4 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).or("sdfgsdfg", "=", ValueType::String("".to_string())).json_contains("parents", JsonValue::Initial(&ValueType::Int32(50)), Some(".age")).or("surname", "=", ValueType::String("etiman".to_string())).or("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float64(80.11)), Some(".average_point")).finish();
-            
-        assert_eq!(or_query_4, "SELECT * FROM users WHERE age > 15 OR JSON_CONTAINS(parents, 50, '$.age')  OR surname = 'etiman' OR JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
-        
-        let or_query_5 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("age", ">", ValueType::Int32(15)).or("sdfgsdfg", "=", ValueType::String("".to_string())).json_contains("parents", JsonValue::Initial(&ValueType::Int64(50)), Some(".age")).or("asdfasdf", ">", ValueType::String("".to_string())).json_contains("graduation_stats", JsonValue::Initial(&ValueType::Float32(80.11)), Some(".average_point")).finish();
-            
-        assert_eq!(or_query_5, "SELECT * FROM users WHERE age > 15 OR JSON_CONTAINS(parents, 50, '$.age') OR JSON_CONTAINS(graduation_stats, 80.11, '$.average_point');".to_string());
+        assert_eq!(script.finish(), "ALTER TABLE orders DROP COLUMN legacy; ALTER TABLE orders RENAME COLUMN note TO notes; DROP INDEX orders_legacy_idx;");
 
-        let name = ValueType::JsonString("necdet".to_string());
-        let id = ValueType::Int32(1);
-        let is_active = ValueType::Boolean(true);
+        let result = std::panic::catch_unwind(|| {
+            AlterTableBuilder::alter("orders").unwrap().dialect(Dialect::Sqlite).modify_column(ColumnDef::new("total", ColumnType::Int(None))).finish()
+        });
 
-        let object = vec![("name", &name), ("id", &id), ("isActive", &is_active)];
+        assert!(result.is_err());
 
-        let mysql_json_object = JsonValue::MysqlJsonObject(&object);
+        let result = std::panic::catch_unwind(|| {
+            AlterTableBuilder::alter("orders").unwrap().add_column(ColumnDef::new("total", ColumnType::Int(None))).after("id").dialect(Dialect::Postgresql).finish()
+        });
 
-        let where_query_2 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").where_("pic", "=", ValueType::String("".to_string())).json_contains("pic", mysql_json_object, Some("")).finish();
+        assert!(result.is_err());
+        assert!(AlterTableBuilder::alter("orders; DROP TABLE users").is_err());
 
-        assert_eq!("SELECT * FROM users WHERE JSON_CONTAINS(pic, JSON_OBJECT('name', \"necdet\", 'id', 1, 'isActive', true), '$');", where_query_2)
-    }
+        let mut code = ColumnDef::new("code", ColumnType::TinyInt(Some(4)));
+        code.unique = true;
+        code.check = Some("code > 0".to_string());
 
-    #[test]
-    pub fn test_like_later_than_where_keywords(){
-        let mut like_query_1 = QueryBuilder::select(["*"].to_vec()).unwrap();
+        let mut alter = AlterTableBuilder::alter("orders").unwrap();
 
-        let like_query_1 = like_query_1.table("blogs")
-                                                      .where_("id", "=", ValueType::Int32(5))
-                                                      .like(["title", "description"].to_vec(), "hello")
-                                                      .finish();
+        alter.dialect(Dialect::Postgresql)
+             .add_column(ColumnDef::new("weight", ColumnType::Double))
+             .add_column(ColumnDef::new("shipped_at", ColumnType::Datetime(Some(3))))
+             .modify_column(code);
 
-        assert_eq!(like_query_1, "SELECT * FROM blogs WHERE id = 5 AND (title LIKE '%hello%' OR description LIKE '%hello%');");
-    
-        let mut like_query_2 = QueryBuilder::select(["*"].to_vec()).unwrap();
+        assert_eq!(alter.finish(), "ALTER TABLE orders ADD COLUMN weight DOUBLE PRECISION, ADD COLUMN shipped_at TIMESTAMP(3), ALTER COLUMN code TYPE SMALLINT, ADD UNIQUE (code), ADD CHECK(code > 0);");
 
-        let ins = vec![ValueType::Int32(1), ValueType::Int32(2), ValueType::Int32(3)];
-        let like_query_2 = like_query_2.table("blogs")
-                                                          .where_in("id", &ins)
-                                                          .like(["title", "description", "keywords"].to_vec(), "necdet")
-                                                          .limit(10)
-                                                          .offset(0)
-                                                          .finish();
+        let result = std::panic::catch_unwind(|| {
+            AlterTableBuilder::alter("orders").unwrap().dialect(Dialect::Postgresql).add_column(ColumnDef::new("id", ColumnType::Int(None)).unsigned().clone()).finish()
+        });
 
-        assert_eq!(like_query_2, "SELECT * FROM blogs WHERE id IN (1, 2, 3) AND (title LIKE '%necdet%' OR description LIKE '%necdet%' OR keywords LIKE '%necdet%') LIMIT 10 OFFSET 0;")
-    }
+        assert!(result.is_err());
 
-    #[test]
-    pub fn test_ordering_functions(){
-        let order_by_query = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").order_by("id", "asc").order_by("weight", "desc").order_by("point", "asc").finish();
+        let mut note = ColumnDef::new("note", ColumnType::Text);
+        note.comment = Some("it's a note".to_string());
 
-        assert_eq!(order_by_query, "SELECT * FROM users ORDER BY id ASC, weight DESC, point ASC;");
+        let result = std::panic::catch_unwind(|| {
+            AlterTableBuilder::alter("orders").unwrap().add_column(note).dialect(Dialect::Sqlite).finish()
+        });
 
-        let order_by_random_query = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").order_random().finish();
+        assert!(result.is_err());
 
-        assert_eq!(order_by_random_query, "SELECT * FROM users ORDER BY RAND();");
+        let query = AlterTableBuilder::alter("orders").unwrap().option("COMMENT", "'x'; DROP TABLE users").option("COMPRESSION", "'zlib'").finish();
 
-        let roles = ["admin", "moderator", "member", "guest"].to_vec();
-        let field_query_1 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").order_by_field("role", roles.clone()).finish();
+        assert_eq!(query, "ALTER TABLE orders COMMENT = '''x''; DROP TABLE users', COMPRESSION = 'zlib';");
 
-        assert_eq!(field_query_1, "SELECT * FROM users ORDER BY FIELD(role, 'admin', 'moderator', 'member', 'guest');");
+        let query = AlterTableBuilder::alter("orders").unwrap().add_constraint("note_check", "CHECK(note <> 'a;b')").finish();
 
-        let field_query_2 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").order_by("id", "asc").order_by_field("role", roles.clone()).finish();
+        assert_eq!(query, "ALTER TABLE orders ADD CONSTRAINT note_check CHECK(note <> 'a;b');");
 
-        assert_eq!(field_query_2, "SELECT * FROM users ORDER BY id ASC, FIELD(role, 'admin', 'moderator', 'member', 'guest');");
+        for definition in ["CHECK(total > 0); DROP TABLE users", "CHECK(total > 0) -- x", "CHECK(note <> 'a)"] {
+            let result = std::panic::catch_unwind(|| {
+                AlterTableBuilder::alter("orders").unwrap().add_constraint("total_check", definition).finish()
+            });
 
-        let field_query_3 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").order_by_field("role", roles.clone()).order_by("id", "asc").finish();
+            assert!(result.is_err());
+        }
 
-        assert_eq!(field_query_3, "SELECT * FROM users ORDER BY FIELD(role, 'admin', 'moderator', 'member', 'guest'), id ASC;");
-        
-        let field_query_4 = QueryBuilder::select(["*"].to_vec()).unwrap().table("users").order_by_field("role", roles).order_by_field("status", vec!["active", "banned", "unverified"]).finish();
+        let result = std::panic::catch_unwind(|| {
+            AlterTableBuilder::alter("orders").unwrap().add_table_constraint(TableConstraint::check(None, "total > 0; DROP TABLE users")).finish()
+        });
 
-        assert_eq!(field_query_4, "SELECT * FROM users ORDER BY FIELD(role, 'admin', 'moderator', 'member', 'guest'), FIELD(status, 'active', 'banned', 'unverified');");
+        assert!(result.is_err());
     }
 
     #[test]
-    pub fn test_unions(){
-        let mut union_1 = QueryBuilder::select(vec!["name", "age", "id"]).unwrap();
-        union_1.table("users").where_("age", ">", ValueType::Int32(7));
+    pub fn test_table_options(){
+        let mut table = TableBuilder::create("analytics", "events");
 
-        let union_2 = QueryBuilder::select(vec!["name", "age", "id"]).unwrap()
-                                                          .table("users")
-                                                          .where_("age", "<", ValueType::Int32(15))
-                                                          .union(vec![union_1])
-                                                          .finish();
+        table.add_column("id").col_type("BIGINT").auto_increment().primary_key()
+             .add_column("payload").col_type("JSON").check("JSON_VALID(payload)")
+             .if_not_exists()
+             .engine("InnoDB")
+             .default_charset("utf8mb4")
+             .collate("utf8mb4_unicode_ci")
+             .auto_increment_start(1000)
+             .row_format(RowFormat::Compressed)
+             .key_block_size(8)
+             .engine("MyISAM");
 
-        assert_eq!(union_2, "(SELECT name, age, id FROM users WHERE age < 15) UNION (SELECT name, age, id FROM users WHERE age > 7);");
+        assert_eq!(table.qualified_name(), "analytics.events");
+        assert_eq!(table.finish(), "CREATE TABLE IF NOT EXISTS analytics.events (id BIGINT AUTO_INCREMENT PRIMARY KEY, payload JSON CHECK(JSON_VALID(payload))) ENGINE = MyISAM DEFAULT CHARSET = utf8mb4 COLLATE = utf8mb4_unicode_ci AUTO_INCREMENT = 1000 ROW_FORMAT = COMPRESSED KEY_BLOCK_SIZE = 8;");
 
-        let mut union_1 = QueryBuilder::select(vec!["id", "title", "description", "published"]).unwrap();
-        union_1.table("blogs").like(vec!["title"], "text");
+        let query = AlterTableBuilder::alter("analytics.events").unwrap()
+                                      .table_option(TableOption::RowFormat(RowFormat::Dynamic))
+                                      .table_option(TableOption::Comment("raw events".to_string()))
+                                      .finish();
 
-        let mut union_2 = QueryBuilder::select(vec!["id", "title", "description", "published"]).unwrap();
-        union_2.table("blogs").like(vec!["description"], "some text");
+        assert_eq!(query, "ALTER TABLE analytics.events ROW_FORMAT = DYNAMIC, COMMENT = 'raw events';");
 
-        let union_3 = QueryBuilder::select(vec!["id", "title", "description", "published"]).unwrap()
-                                                              .table("blogs")
-                                                              .where_("published", "=", ValueType::Boolean(true))
-                                                              .union_all(vec![union_1, union_2])
-                                                              .finish();
+        let result = std::panic::catch_unwind(|| {
+            TableBuilder::create("", "events").add_column("id").col_type("INT").key_block_size(3).finish()
+        });
 
-        assert_eq!(union_3, "(SELECT id, title, description, published FROM blogs WHERE published = true) UNION ALL (SELECT id, title, description, published FROM blogs WHERE title LIKE '%text%') UNION ALL (SELECT id, title, description, published FROM blogs WHERE description LIKE '%some text%');");
+        assert!(result.is_err());
+
+        let result = std::panic::catch_unwind(|| {
+            TableBuilder::create("", "events").add_column("id").col_type("INT").engine("InnoDB; DROP TABLE users").finish()
+        });
+
+        assert!(result.is_err());
     }
 
     #[test]
-    pub fn test_json_value(){
-        let name = ValueType::JsonString("necdet".to_string());
-        let age = ValueType::Int8(25);
-        let id = ValueType::Int32(1);
+    pub fn test_table_constraints(){
+        let mut table = TableBuilder::create("shop", "order_items");
 
-        let values = vec![("name", &name), ("age", &age), ("id", &id)];
+        table.add_column("order_id").col_type("INT").not_null()
+             .add_column("product_id").col_type("INT").not_null()
+             .add_column("sku").col_type("VARCHAR(32)")
+             .add_column("quantity").col_type("INT").check("quantity > 0")
+             .primary_key_on(Some("order_items_pk"), vec!["order_id", "product_id"])
+             .unique_key(Some("order_sku_uq"), vec!["order_id", "sku"])
+             .check_constraint(Some("quantity_limit"), "quantity <= 1000")
+             .foreign_key(ForeignKey {
+                 first: ForeignKeyItem { table: "order_items".to_string(), column: "order_id".to_string() },
+                 second: ForeignKeyItem { table: "orders".to_string(), column: "id".to_string() },
+                 constraint: Some("order_fk".to_string()),
+                 on_delete: Some(ForeignKeyActions::Cascade),
+                 on_update: Some(ForeignKeyActions::NoAction)
+             });
 
-        let json_object = JsonValue::Object(&values);
+        assert_eq!(table.finish(), "CREATE TABLE shop.order_items (order_id INT NOT NULL, product_id INT NOT NULL, sku VARCHAR(32), quantity INT CHECK(quantity > 0), CONSTRAINT order_items_pk PRIMARY KEY (order_id, product_id), UNIQUE KEY order_sku_uq (order_id, sku), CONSTRAINT quantity_limit CHECK(quantity <= 1000), CONSTRAINT order_fk FOREIGN KEY (order_id) REFERENCES orders(id) ON DELETE CASCADE ON UPDATE NO ACTION);");
 
-        assert_eq!("{\"name\": \"necdet\", \"age\": 25, \"id\": 1}", json_object.to_string());
+        let mut shipments = TableBuilder::create("", "shipments");
+        let foreign_key = TableConstraint::foreign_key(None, vec!["order_id", "product_id"], "order_items", vec!["order_id", "product_id"]).on_delete(ForeignKeyActions::SetNull);
 
-        let mysql_json_object = JsonValue::MysqlJsonObject(&values);
+        shipments.add_column("order_id").col_type("INT").add_column("product_id").col_type("INT").constraint(foreign_key.clone());
 
-        assert_eq!("JSON_OBJECT('name', \"necdet\", 'age', 25, 'id', 1)", mysql_json_object.to_string());
+        assert_eq!(shipments.finish(), "CREATE TABLE shipments (order_id INT, product_id INT, FOREIGN KEY (order_id, product_id) REFERENCES order_items(order_id, product_id) ON DELETE SET NULL);");
 
-        let name2 = ValueType::JsonString("cevdet".to_string());
-        let age2 = ValueType::Int8(24);
-        let id2 = ValueType::Int32(2);
+        assert_eq!(foreign_key.columns(), vec!["order_id", "product_id"]);
+        assert!(std::panic::catch_unwind(|| TableConstraint::foreign_key(None, vec!["order_id", "product_id"], "order_items", vec!["order_id"])).is_err());
+        assert!(std::panic::catch_unwind(|| TableConstraint::foreign_key(None, vec![], "order_items", vec![])).is_err());
+        assert!(std::panic::catch_unwind(|| TableConstraint::unique(None, vec!["sku"]).on_delete(ForeignKeyActions::Cascade)).is_err());
 
-        let name3 = ValueType::JsonString("serap".to_string());
-        let age3 = ValueType::Int8(21);
-        let id3 = ValueType::Int32(3);
+        let query = AlterTableBuilder::alter("order_items").unwrap()
+                                      .drop_primary_key(None)
+                                      .add_table_constraint(TableConstraint::primary_key(None, vec!["order_id", "product_id", "sku"]))
+                                      .drop_unique("order_sku_uq")
+                                      .drop_check("quantity_limit")
+                                      .add_table_constraint(TableConstraint::unique(None, vec!["sku"]))
+                                      .add_table_constraint(foreign_key.clone())
+                                      .finish();
 
-        let object1 = vec![("name", &name), ("age", &age), ("id", &id)];
-        let object2 = vec![("name", &name2), ("age", &age2), ("id", &id2)];
-        let object3 = vec![("name", &name3), ("age", &age3), ("id", &id3)];
+        assert_eq!(query, "ALTER TABLE order_items DROP PRIMARY KEY, ADD PRIMARY KEY (order_id, product_id, sku), DROP INDEX order_sku_uq, DROP CHECK quantity_limit, ADD UNIQUE KEY (sku), ADD FOREIGN KEY (order_id, product_id) REFERENCES order_items(order_id, product_id) ON DELETE SET NULL;");
 
-        let objects = vec![object1, object2, object3];
-        
-        let json_array = JsonValue::ObjectArray(&objects);
+        let query = AlterTableBuilder::alter("shop.order_items").unwrap()
+                                      .dialect(Dialect::Postgresql)
+                                      .drop_primary_key(Some("order_items_pk"))
+                                      .drop_unique("order_sku_uq")
+                                      .add_table_constraint(TableConstraint::unique(Some("sku_uq"), vec!["sku"]))
+                                      .add_table_constraint(TableConstraint::check(Some("quantity_limit"), "quantity <= 500"))
+                                      .finish();
 
-        assert_eq!("[{\"name\": \"necdet\", \"age\": 25, \"id\": 1}, {\"name\": \"cevdet\", \"age\": 24, \"id\": 2}, {\"name\": \"serap\", \"age\": 21, \"id\": 3}]", json_array.to_string());
-    }
+        assert_eq!(query, "ALTER TABLE shop.order_items DROP CONSTRAINT order_items_pk, DROP CONSTRAINT order_sku_uq, ADD CONSTRAINT sku_uq UNIQUE (sku), ADD CONSTRAINT quantity_limit CHECK(quantity <= 500);");
 
-    #[test]
-    pub fn test_json_array_append(){
-        let lesson = ("lesson", &ValueType::String("math".to_string()));
-        let point = ("point", &ValueType::Int32(100));
+        let result = std::panic::catch_unwind(|| {
+            TableBuilder::create("", "items").add_column("id").col_type("INT").primary_key().add_column("code").col_type("INT").primary_key_on(None, vec!["id", "code"]).finish()
+        });
 
-        let values = vec![lesson, point];
-        
-        let object = JsonValue::MysqlJsonObject(&values);
+        assert!(result.is_err());
 
-        let query = QueryBuilder::update().unwrap()
-                                         .table("users")
-                                         .json_array_append("points", Some(""), object.clone())
-                                         .where_("id", "=", ValueType::Int8(1))
-                                         .finish();
+        let result = std::panic::catch_unwind(|| {
+            TableBuilder::create("", "items").add_column("id").col_type("INT").unique_key(None, vec!["code"]).finish()
+        });
 
-        assert_eq!("UPDATE users SET points = JSON_ARRAY_APPEND(points, '$', JSON_OBJECT('lesson', 'math', 'point', 100)) WHERE id = 1;", query);
+        assert!(result.is_err());
 
-        let query = QueryBuilder::update().unwrap()
-                                         .table("users")
-                                         .set("status", ValueType::String("passed".to_string()))
-                                         .json_array_append("points", Some(""), object)
-                                         .where_("id", "=", ValueType::Int8(1))
-                                         .finish();
+        let result = std::panic::catch_unwind(|| {
+            TableBuilder::create("", "items").add_column("id").col_type("INT").check_constraint(Some("id_check"), "id > 0").check_constraint(Some("id_check"), "id < 10").finish()
+        });
 
-        assert_eq!("UPDATE users SET status = 'passed', points = JSON_ARRAY_APPEND(points, '$', JSON_OBJECT('lesson', 'math', 'point', 100)) WHERE id = 1;", query);
-    }
+        assert!(result.is_err());
 
-    #[test]
-    pub fn test_json_remove() {
-        let query = QueryBuilder::update().unwrap()
-                                         .table("blogs")
-                                         .json_remove("likes", vec!["[10]"])
-                                         .where_("blog_id", "=", ValueType::Int32(20))
-                                         .finish();
+        let result = std::panic::catch_unwind(|| {
+            AlterTableBuilder::alter("items").unwrap().dialect(Dialect::Sqlite).drop_primary_key(None).finish()
+        });
 
-        assert_eq!(query, "UPDATE blogs SET likes = JSON_REMOVE(likes, '$[10]') WHERE blog_id = 20;");
-        
-        let query = QueryBuilder::update().unwrap()
-                                         .table("blogs")
-                                         .set("blabla", ValueType::Int32(50))
-                                         .json_remove("likes", vec!["[10]", "[11]", "[12]"])
-                                         .where_("blog_id", "=", ValueType::Int32(20))
-                                         .finish();
+        assert!(result.is_err());
 
-        println!("{}", query)
+        let query = AlterTableBuilder::alter("shop.items").unwrap().dialect(Dialect::Postgresql).drop_primary_key(None).finish();
+
+        assert_eq!(query, "ALTER TABLE shop.items DROP CONSTRAINT items_pkey;");
     }
 
     #[test]
-    pub fn test_json_set_and_json_replace(){
-        let lesson = ("lesson", &ValueType::String("math".to_string()));
-        let point = ("point", &ValueType::Int32(100));
+    pub fn test_sqlite_table_rebuild(){
+        let table = TableBuilder::create("", "orders").add_column("id").col_type("INT").primary_key()
+                                                      .add_column("note").col_type("TEXT")
+                                                      .add_column("total").col_type("INT")
+                                                      .add_column("legacy").col_type("INT")
+                                                      .check_constraint(Some("total_check"), "total >= 0").clone();
 
-        let values = vec![lesson, point];
-        
-        let object = JsonValue::MysqlJsonObject(&values);
+        let query = AlterTableBuilder::from_table(&table).unwrap()
+                                      .dialect(Dialect::Sqlite)
+                                      .modify_column(ColumnDef::new("total", ColumnType::Int(None)).not_null().clone())
+                                      .rename_column("note", "notes")
+                                      .drop_column("legacy")
+                                      .add_column(ColumnDef { unique: true, ..ColumnDef::new("code", ColumnType::Text) })
+                                      .drop_check("total_check")
+                                      .add_index("orders_code_idx", vec!["code"])
+                                      .finish();
 
-        let query = QueryBuilder::update().unwrap()
-                                                        .table("users")
-                                                        .json_set("points", "[0]", object)
-                                                        .where_("id", "=", ValueType::Int32(1))
-                                                        .finish();
+        assert_eq!(query, "CREATE TABLE orders_new (id INT PRIMARY KEY, notes TEXT, total INT NOT NULL, code TEXT UNIQUE); INSERT INTO orders_new (id, notes, total) SELECT id, note, total FROM orders; DROP TABLE orders; ALTER TABLE orders_new RENAME TO orders; CREATE INDEX orders_code_idx ON orders (code);");
 
-        assert_eq!("UPDATE users SET points = JSON_SET(points, '$[0]', JSON_OBJECT('lesson', 'math', 'point', 100)) WHERE id = 1;", query);
+        let query = AlterTableBuilder::from_table(&table).unwrap().dialect(Dialect::Sqlite).drop_column("legacy").finish();
 
-        let value = ValueType::Int32(100);
-        let value = JsonValue::Initial(&value);
+        assert_eq!(query, "ALTER TABLE orders DROP COLUMN legacy;");
 
-        let query = QueryBuilder::update().unwrap()
-                                         .table("users")
-                                         .json_replace("points", "[0].point", value)
-                                         .where_("id", "=", ValueType::Int32(1))
-                                         .finish();
+        let result = std::panic::catch_unwind(|| {
+            AlterTableBuilder::from_table(&table).unwrap().dialect(Dialect::Sqlite).drop_constraint("missing_check").finish()
+        });
 
-        assert_eq!("UPDATE users SET points = JSON_REPLACE(points, '$[0].point', 100) WHERE id = 1;", query)
+        assert!(result.is_err());
     }
 
     #[test]
-    pub fn test_json_value_initial_bugfix(){
-        let file_name_val = ValueType::JsonString("chemistry".to_string());
-        let file_name_val = JsonValue::Initial(&file_name_val);
+    pub fn test_insert_variants(){
+        let query = QueryBuilder::insert_ignore(vec!["id", "name"], vec![ValueType::Int32(1), ValueType::String("necdet".to_string())]).unwrap().table("users").finish();
 
-        let query = QueryBuilder::select(vec!["lesson_points"]).unwrap()
-                                         .json_extract("points", &format!("[{}]", 2), Some("point"))
-                                         .table("students")
-                                         .where_("id", "=", ValueType::Int32(5))
-                                         .and("adsf", "=", ValueType::Null)
-                                         .json_contains("points", file_name_val, Some(&format!("[{}].name", 0)))
-                                         .finish();
+        assert_eq!(query, "INSERT IGNORE INTO users (id, name) VALUES (1, 'necdet');");
 
-        assert_eq!("SELECT JSON_EXTRACT(points, '$[2]') AS point FROM students WHERE id = 5 AND JSON_CONTAINS(points, '\"chemistry\"', '$[0].name');", query);
-    }
+        let query = QueryBuilder::replace(vec!["id", "name"], vec![ValueType::Int32(1), ValueType::String("necdet".to_string())]).unwrap().table("users").finish();
 
-    #[test]
-    pub fn test_timezones(){
-        let query = QueryBuilder::select(vec!["*"]).unwrap().table("users").time_zone(Timezone::Istanbul).finish();
+        assert_eq!(query, "REPLACE INTO users (id, name) VALUES (1, 'necdet');");
 
-        assert_eq!(query, "SET time_zone = Europe/Istanbul; SELECT * FROM users;");
-        
-        let query = QueryBuilder::select(vec!["*"]).unwrap()
-                                         .table("users")
-                                         .global_time_zone(Timezone::Amsterdam)
-                                         .where_("id", "=", ValueType::Int32(3))
-                                         .and("surname", "=", ValueType::String("Doe".to_string()))
-                                         .finish();
+        let query = QueryBuilder::insert_low_priority(vec!["id", "name"], vec![ValueType::Int32(1), ValueType::String("necdet".to_string())]).unwrap().table("users").finish();
 
-        assert_eq!(query, "SET GLOBAL time_zone = Europe/Amsterdam; SELECT * FROM users WHERE id = 3 AND surname = 'Doe';");
+        assert_eq!(query, "INSERT LOW_PRIORITY INTO users (id, name) VALUES (1, 'necdet');");
 
-        let query = QueryBuilder::update().unwrap().table("users").time_zone(Timezone::NewYork).set("age", ValueType::Int32(26)).set("last_online_date", ValueType::Datetime("CURRENT_TIMESTAMP".to_string())).where_("id", "=", ValueType::Int32(234)).finish();
+        let query = QueryBuilder::insert_ignore(vec!["id", "name"], vec![ValueType::Int32(1), ValueType::String("necdet".to_string())]).unwrap().dialect(Dialect::Sqlite).table("users").clone();
 
-        assert_eq!(query, "SET time_zone = America/New_York; UPDATE users SET age = 26, last_online_date = CURRENT_TIMESTAMP WHERE id = 234;");
+        assert_eq!(query.finish(), "INSERT OR IGNORE INTO users (id, name) VALUES (1, 'necdet');");
+        assert_eq!(PreparedStatement::from_query("add_user", &query).unwrap().statement, "INSERT OR IGNORE INTO users (id, name) VALUES (?, ?)");
 
-        let query = QueryBuilder::update().unwrap().table("users").set("age", ValueType::Int32(26)).global_time_zone(Timezone::NewYork).set("last_online_date", ValueType::Datetime("CURRENT_TIMESTAMP".to_string())).where_("id", "=", ValueType::Int32(234)).finish();
+        let query = QueryBuilder::insert_ignore(vec!["id", "name"], vec![ValueType::Int32(1), ValueType::String("necdet".to_string())]).unwrap().dialect(Dialect::Postgresql).table("users").finish();
 
-        assert_eq!(query, "SET GLOBAL time_zone = America/New_York; UPDATE users SET age = 26, last_online_date = CURRENT_TIMESTAMP WHERE id = 234;");
-    }
+        assert_eq!(query, "INSERT INTO users (id, name) VALUES (1, 'necdet') ON CONFLICT DO NOTHING;");
 
-    #[test]
-    pub fn test_joins(){
-        let query = QueryBuilder::select(vec!["*"]).unwrap()
-                                         .table("students s")
-                                         .inner_join("grades g", "s.id", "=", "g.student_id")
-                                         .where_("id", "=", ValueType::Int32(10))
-                                         .finish();
+        let query = QueryBuilder::replace(vec!["id", "name"], vec![ValueType::Int32(1), ValueType::String("necdet".to_string())]).unwrap().dialect(Dialect::Sqlite).table("users").finish();
 
-        assert_eq!(query, "SELECT * FROM students s INNER JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+        assert_eq!(query, "INSERT OR REPLACE INTO users (id, name) VALUES (1, 'necdet');");
 
-        let query = QueryBuilder::select(vec!["*"]).unwrap()
-                                         .table("students s")
-                                         .left_join("grades g", "s.id", "=", "g.student_id")
-                                         .where_("id", "=", ValueType::Int32(10))
-                                         .finish();
+        assert!(std::panic::catch_unwind(|| QueryBuilder::replace(vec!["id"], vec![ValueType::Int32(1)]).unwrap().dialect(Dialect::Postgresql).table("users").finish()).is_err());
+        assert!(std::panic::catch_unwind(|| QueryBuilder::insert_low_priority(vec!["id"], vec![ValueType::Int32(1)]).unwrap().dialect(Dialect::Sqlite).table("logs").finish()).is_err());
 
-        assert_eq!(query, "SELECT * FROM students s LEFT JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+        let mut select = QueryBuilder::select(vec!["id", "name"]).unwrap();
+        select.table("users").where_("active", "=", ValueType::Boolean(false));
 
-        let query = QueryBuilder::select(vec!["*"]).unwrap()
-                                         .table("students s")
-                                         .right_join("grades g", "s.id", "=", "g.student_id")
-                                         .where_("id", "=", ValueType::Int32(10))
-                                         .finish();
+        let query = QueryBuilder::insert_select(vec!["id", "name"], &select).unwrap().table("archived_users").finish();
 
-        assert_eq!(query, "SELECT * FROM students s RIGHT JOIN grades g ON s.id = g.student_id WHERE id = 10;");
+        assert_eq!(query, "INSERT INTO archived_users (id, name) SELECT id, name FROM users WHERE active = false;");
 
-        let query = QueryBuilder::select(vec!["*"]).unwrap()
-                                         .table("students s")
-                                         .cross_join("grades g")
-                                         .where_("id", "=", ValueType::Int32(10))
-                                         .finish();
+        let query = QueryBuilder::insert_select(vec![], &select).unwrap().table("archived_users").finish();
 
-        assert_eq!(query, "SELECT * FROM students s CROSS JOIN grades g WHERE id = 10;");
+        assert_eq!(query, "INSERT INTO archived_users SELECT id, name FROM users WHERE active = false;");
 
-        let query = QueryBuilder::select(vec!["*"]).unwrap()
-                                         .table("students s")
-                                         .natural_join("grades g")
-                                         .where_("id", "=", ValueType::Int32(10))
-                                         .finish();
-                                        
-        assert_eq!(query, "SELECT * FROM students s NATURAL JOIN grades g WHERE id = 10;");
+        assert!(QueryBuilder::insert_ignore(vec!["id", "; drop"], vec![ValueType::Int32(1), ValueType::Int32(2)]).is_err());
+        assert!(QueryBuilder::replace(vec!["id"], vec![ValueType::String("or 1 = 1".to_string())]).is_err());
+        assert!(QueryBuilder::insert_select(vec!["--"], &select).is_err());
     }
 
     #[test]